@@ -1,9 +1,25 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+use proof::session::SessionID;
+use serde::Serialize;
+
 pub mod centralized_party;
 pub mod decentralized_party;
 
+/// The identifier of the session in which the decentralized party aggregates the proof for the
+/// encryption of its secret key share.
+///
+/// It is derived from the protocol context, so that the centralized party can verify the proof.
+pub(crate) fn encryption_of_secret_key_share_session_id(
+    protocol_context: &impl Serialize,
+) -> proof::Result<SessionID> {
+    SessionID::derive(
+        b"2pc-mpc dkg encryption of secret key share",
+        protocol_context,
+    )
+}
+
 #[cfg(all(
     any(test, feature = "benchmarking"),
     feature = "secp256k1",
@@ -18,7 +34,7 @@ pub(crate) mod tests {
     };
 
     use criterion::measurement::{Measurement, WallTime};
-    use group::{secp256k1, CyclicGroupElement, GroupElement, PartyID};
+    use group::{secp256k1, wire::WireEncoding, CyclicGroupElement, GroupElement, PartyID};
     use homomorphic_encryption::{
         AdditivelyHomomorphicDecryptionKey, GroupsPublicParametersAccessors,
     };
@@ -44,6 +60,121 @@ pub(crate) mod tests {
         generates_distributed_key_internal(threshold, number_of_parties);
     }
 
+    #[rstest]
+    #[case(2, 2, false)]
+    #[case(3, 5, false)]
+    #[case(3, 5, true)]
+    fn generates_distributed_key_over_network(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: PartyID,
+        #[case] drop_message: bool,
+    ) {
+        use proof::session::network::{Fault, Network};
+
+        use crate::Error;
+
+        let protocol_public_parameters = ProtocolPublicParameters::new(N);
+
+        let (
+            commitment_to_centralized_party_secret_key_share,
+            centralized_party_decommitment_round_party,
+        ) = centralized_party::commitment_round::Party::new(
+            protocol_public_parameters.clone(),
+            PhantomData,
+        )
+        .sample_commit_and_prove_secret_key_share(&mut OsRng)
+        .unwrap();
+
+        let parties: HashSet<_> = (1..=number_of_parties)
+            .choose_multiple(&mut OsRng, threshold.into())
+            .into_iter()
+            .collect();
+
+        let sessions = parties.iter().map(|&party_id| {
+            decentralized_party::encryption_of_secret_key_share_round::Party::new(
+                protocol_public_parameters.clone(),
+                party_id,
+                threshold,
+                parties.clone(),
+                PhantomData::<()>,
+            )
+            .sample_secret_key_share_and_initialize_session(
+                commitment_to_centralized_party_secret_key_share,
+                &mut OsRng,
+            )
+            .unwrap()
+        });
+
+        let mut network = Network::new(sessions, 1);
+
+        let unresponsive_party = *parties.iter().choose(&mut OsRng).unwrap();
+        if drop_message {
+            network = network.inject_fault(unresponsive_party, 1, Fault::Drop);
+        }
+
+        let outputs = network.run(&mut OsRng);
+
+        if drop_message {
+            assert!(outputs
+                .into_iter()
+                .filter(|(party_id, _)| *party_id != unresponsive_party)
+                .all(|(_, output)| {
+                    let Error::EnhancedMaurer(e) = output.err().unwrap() else {
+                        return false;
+                    };
+
+                    matches!(
+                        e.try_into().unwrap(),
+                        proof::aggregation::Error::UnresponsiveParties(parties) if parties == vec![unresponsive_party]
+                    )
+                }));
+
+            return;
+        }
+
+        let secret_key_share_encryption_and_proof =
+            outputs.values().next().unwrap().as_ref().unwrap().0.clone();
+
+        let (
+            centralized_party_public_key_share_decommitment_and_proof,
+            centralized_party_dkg_output,
+        ) = centralized_party_decommitment_round_party
+            .decommit_proof_public_key_share(
+                secret_key_share_encryption_and_proof.clone(),
+                &mut OsRng,
+            )
+            .unwrap();
+
+        assert!(outputs.into_values().all(|output| {
+            let (encryption_of_secret_key_share_and_proof, party) = output.unwrap();
+
+            let dkg_output = party
+                .verify_decommitment_and_proof_of_centralized_party_public_key_share(
+                    centralized_party_public_key_share_decommitment_and_proof.clone(),
+                    encryption_of_secret_key_share_and_proof.clone(),
+                )
+                .unwrap();
+
+            encryption_of_secret_key_share_and_proof == secret_key_share_encryption_and_proof
+                && dkg_output.public_key == centralized_party_dkg_output.public_key
+                && dkg_output.encrypted_secret_key_share
+                    == centralized_party_dkg_output.encrypted_decentralized_party_secret_key_share
+        }));
+    }
+
+    /// Send `message` over the wire: encode it in the wire format, and decode it back.
+    pub fn send_over_wire<Message: WireEncoding + PartialEq>(message: Message) -> Message {
+        let bytes = message.to_wire_bytes().unwrap();
+
+        let decoded_message = Message::from_wire_bytes(&bytes).unwrap();
+        assert!(
+            decoded_message == message,
+            "messages should round-trip through the wire format"
+        );
+
+        decoded_message
+    }
+
     #[allow(dead_code)]
     pub fn generates_distributed_key_internal(
         threshold: PartyID,
@@ -87,6 +218,9 @@ pub(crate) mod tests {
         centralized_party_total_time =
             measurement.add(&centralized_party_total_time, &measurement.end(now));
 
+        let commitment_to_centralized_party_secret_key_share =
+            send_over_wire(commitment_to_centralized_party_secret_key_share);
+
         let mut parties = HashSet::new();
         (1..=number_of_parties)
             .choose_multiple(&mut OsRng, threshold.into())
@@ -159,10 +293,11 @@ pub(crate) mod tests {
                 .first()
                 .unwrap();
 
-        let secret_key_share_encryption_and_proof = SecretKeyShareEncryptionAndProof::new(
-            encryption_of_decentralized_party_secret_share,
-            encryption_of_decentralized_party_secret_share_proof,
-        );
+        let secret_key_share_encryption_and_proof =
+            send_over_wire(SecretKeyShareEncryptionAndProof::new(
+                encryption_of_decentralized_party_secret_share,
+                encryption_of_decentralized_party_secret_share_proof,
+            ));
 
         let now = measurement.start();
         let (
@@ -177,6 +312,9 @@ pub(crate) mod tests {
         centralized_party_total_time =
             measurement.add(&centralized_party_total_time, &measurement.end(now));
 
+        let centralized_party_public_key_share_decommitment_and_proof =
+            send_over_wire(centralized_party_public_key_share_decommitment_and_proof);
+
         let decentralized_party_public_key_share = secp256k1::GroupElement::new(
             centralized_party_dkg_output.decentralized_party_public_key_share,
             &protocol_public_parameters.group_public_parameters,
@@ -3,14 +3,16 @@
 
 #![allow(clippy::type_complexity)]
 
-use commitment::Commitment;
-use crypto_bigint::{rand_core::CryptoRngCore, Random};
-use group::{ComputationalSecuritySizedNumber, PartyID, PrimeGroupElement, Samplable};
+use alloc::{string::ToString, vec};
+
+use commitment::{Commitment, HashCommitment};
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::{PrimeGroupElement, Samplable};
 use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
 use maurer::knowledge_of_discrete_log;
-use merlin::Transcript;
-use proof::{AggregatableRangeProof, TranscriptProtocol};
+use proof::AggregatableRangeProof;
 use serde::Serialize;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::{
     dkg::centralized_party::decommitment_round, ProtocolPublicParameters, CENTRALIZED_PARTY_ID,
@@ -25,7 +27,7 @@ pub struct Party<
     GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
     ProtocolContext: Clone + Serialize,
 > {
     protocol_context: ProtocolContext,
@@ -36,23 +38,13 @@ pub struct Party<
     range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
 }
 
-pub fn commit_public_key_share<GroupElement: group::GroupElement>(
-    party_id: PartyID,
-    public_key_share: &GroupElement,
-    commitment_randomness: &ComputationalSecuritySizedNumber,
-) -> crate::Result<Commitment> {
-    let mut transcript = Transcript::new(b"DKG commitment round of centralized party");
-
-    transcript
-        .serialize_to_transcript_as_json(b"public key share", &public_key_share.value())
-        .unwrap();
-
-    Ok(Commitment::commit_transcript(
-        party_id,
+/// The [`HashCommitment`] with which the centralized party commits to its public key share.
+pub fn public_key_share_commitment_scheme<GroupElement: group::GroupElement>(
+) -> HashCommitment<GroupElement::Value> {
+    HashCommitment::new(
+        CENTRALIZED_PARTY_ID,
         "DKG commitment round of centralized party".to_string(),
-        &mut transcript,
-        commitment_randomness,
-    ))
+    )
 }
 
 impl<
@@ -63,7 +55,7 @@ impl<
         GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     Party<
@@ -127,20 +119,15 @@ impl<
 
         // === Derive X_A ===
         // Protocol 4, step 1a
-        let public_key_share: GroupElement = public_key_share
+        let public_key_share: GroupElement = *public_key_share
             .first()
-            .ok_or(crate::Error::InternalError)?
-            .clone();
+            .ok_or(crate::Error::InternalError)?;
 
         // === Commit to X_A ===
         // Used in emulating the idealized F^{L_DL}_{com-zk} component
         // Protocol 4, step 1b
-        let commitment_randomness = ComputationalSecuritySizedNumber::random(rng);
-        let commitment = commit_public_key_share(
-            CENTRALIZED_PARTY_ID,
-            &public_key_share,
-            &commitment_randomness,
-        )?;
+        let (commitment, decommitment) = public_key_share_commitment_scheme::<GroupElement>()
+            .commit(&public_key_share.value(), rng)?;
 
         let party = decommitment_round::Party {
             group_public_parameters: self.group_public_parameters,
@@ -150,10 +137,10 @@ impl<
                 .unbounded_encdl_witness_public_parameters,
             range_proof_public_parameters: self.range_proof_public_parameters,
             protocol_context: self.protocol_context,
-            secret_key_share,
+            secret_key_share: Zeroizing::new(secret_key_share),
             public_key_share,
             knowledge_of_discrete_log_proof,
-            commitment_randomness,
+            commitment_randomness: decommitment.commitment_randomness,
         };
 
         Ok((commitment, party))
@@ -161,8 +148,8 @@ impl<
 
     pub fn new<
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
-        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
     >(
         protocol_public_parameters: ProtocolPublicParameters<
             SCALAR_LIMBS,
@@ -3,6 +3,8 @@
 
 #![allow(clippy::type_complexity)]
 
+use alloc::vec;
+
 use commitment::GroupsPublicParametersAccessors as _;
 use crypto_bigint::rand_core::CryptoRngCore;
 use enhanced_maurer::{encryption_of_discrete_log, EnhanceableLanguage};
@@ -11,11 +13,15 @@ use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicPar
 use maurer::{knowledge_of_discrete_log, SOUND_PROOFS_REPETITIONS};
 use proof::{range, range::PublicParametersAccessors, AggregatableRangeProof};
 use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
-use crate::{dkg::decentralized_party, ProtocolPublicParameters};
+use crate::{
+    dkg::{decentralized_party, encryption_of_secret_key_share_session_id},
+    ProtocolPublicParameters,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
-pub struct Output<GroupElementValue, ScalarValue, CiphertextSpaceValue> {
+pub struct Output<GroupElementValue, ScalarValue: Zeroize, CiphertextSpaceValue> {
     pub secret_key_share: ScalarValue,
     pub public_key_share: GroupElementValue,
     pub public_key: GroupElementValue,
@@ -23,6 +29,19 @@ pub struct Output<GroupElementValue, ScalarValue, CiphertextSpaceValue> {
     pub decentralized_party_public_key_share: GroupElementValue,
 }
 
+impl<GroupElementValue, ScalarValue: Zeroize, CiphertextSpaceValue> Drop
+    for Output<GroupElementValue, ScalarValue, CiphertextSpaceValue>
+{
+    fn drop(&mut self) {
+        self.secret_key_share.zeroize();
+    }
+}
+
+impl<GroupElementValue, ScalarValue: Zeroize, CiphertextSpaceValue> ZeroizeOnDrop
+    for Output<GroupElementValue, ScalarValue, CiphertextSpaceValue>
+{
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct PublicKeyShareDecommitmentAndProof<GroupElementValue, DLProof> {
     pub(in crate::dkg) proof: DLProof,
@@ -33,13 +52,26 @@ pub struct PublicKeyShareDecommitmentAndProof<GroupElementValue, DLProof> {
 /// This structs is a serializable state to use in case the `Party` struct cannot be saved in
 /// memory.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
-pub struct State<GroupElementValue, ScalarValue, DLProof> {
+pub struct State<GroupElementValue, ScalarValue: Zeroize, DLProof> {
     proof: DLProof,
     secret_key_share: ScalarValue,
     public_key_share: GroupElementValue,
     commitment_randomness: ComputationalSecuritySizedNumber,
 }
 
+impl<GroupElementValue, ScalarValue: Zeroize, DLProof> Drop
+    for State<GroupElementValue, ScalarValue, DLProof>
+{
+    fn drop(&mut self) {
+        self.secret_key_share.zeroize();
+    }
+}
+
+impl<GroupElementValue, ScalarValue: Zeroize, DLProof> ZeroizeOnDrop
+    for State<GroupElementValue, ScalarValue, DLProof>
+{
+}
+
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
     const SCALAR_LIMBS: usize,
@@ -49,7 +81,7 @@ pub struct Party<
     GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
     ProtocolContext: Clone + Serialize,
 > {
     pub(super) group_public_parameters: GroupElement::PublicParameters,
@@ -58,7 +90,7 @@ pub struct Party<
     pub(super) unbounded_encdl_witness_public_parameters: UnboundedEncDLWitness::PublicParameters,
     pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
     pub(super) protocol_context: ProtocolContext,
-    pub(super) secret_key_share: GroupElement::Scalar,
+    pub(super) secret_key_share: Zeroizing<GroupElement::Scalar>,
     pub(super) public_key_share: GroupElement,
     pub(super) knowledge_of_discrete_log_proof:
         knowledge_of_discrete_log::Proof<GroupElement::Scalar, GroupElement, ProtocolContext>,
@@ -73,7 +105,7 @@ impl<
         GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     Party<
@@ -159,14 +191,17 @@ where
         let encrypted_decentralized_party_secret_key_share =
             EncryptionKey::CiphertextSpaceGroupElement::new(
                 decentralized_party_secret_key_share_encryption_and_proof
-                    .encrypted_secret_key_share,
+                    .encrypted_secret_key_share
+                    .clone(),
                 self.encryption_scheme_public_parameters
                     .ciphertext_space_public_parameters(),
             )?;
 
         // = X_B
         let decentralized_party_public_key_share = GroupElement::new(
-            decentralized_party_secret_key_share_encryption_and_proof.public_key_share,
+            decentralized_party_secret_key_share_encryption_and_proof
+                .public_key_share
+                .clone(),
             &self.group_public_parameters,
         )?;
 
@@ -185,7 +220,7 @@ where
             range_proof_commitment,
             (
                 encrypted_decentralized_party_secret_key_share.clone(),
-                decentralized_party_public_key_share.clone(),
+                decentralized_party_public_key_share,
             )
                 .into(),
         )
@@ -227,7 +262,8 @@ where
         // TODO: also we need to verify that the public key was DKG'ed right.
         decentralized_party_secret_key_share_encryption_and_proof
             .encryption_of_secret_key_share_proof
-            .verify(
+            .verify_in_session(
+                &encryption_of_secret_key_share_session_id(&self.protocol_context)?,
                 &self.protocol_context,
                 &encryption_of_discrete_log_enhanced_language_public_parameters,
                 vec![statement],
@@ -236,7 +272,7 @@ where
 
         // === Compute X := X_A + X_B ===
         // Protocol 4, step 5a
-        let public_key = self.public_key_share.clone() + &decentralized_party_public_key_share;
+        let public_key = self.public_key_share + decentralized_party_public_key_share;
 
         // === Construct X_A proof object ===
         // Used to emulate idealized F^{L_DL}_{com-zk}
@@ -247,7 +283,7 @@ where
             knowledge_of_discrete_log::Proof<GroupElement::Scalar, GroupElement, ProtocolContext>,
         > {
             proof: self.knowledge_of_discrete_log_proof,
-            public_key_share,
+            public_key_share: public_key_share.clone(),
             commitment_randomness: self.commitment_randomness,
         };
 
@@ -282,8 +318,8 @@ where
 
     pub fn new<
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
-        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
     >(
         protocol_public_parameters: ProtocolPublicParameters<
             SCALAR_LIMBS,
@@ -310,9 +346,12 @@ where
 
         let group_public_parameters = protocol_public_parameters.group_public_parameters;
 
-        let secret_key_share =
-            GroupElement::Scalar::new(state.secret_key_share, &scalar_group_public_parameters)?;
-        let public_key_share = GroupElement::new(state.public_key_share, &group_public_parameters)?;
+        let secret_key_share = Zeroizing::new(GroupElement::Scalar::new(
+            state.secret_key_share,
+            &scalar_group_public_parameters,
+        )?);
+        let public_key_share =
+            GroupElement::new(state.public_key_share.clone(), &group_public_parameters)?;
 
         Ok(Party {
             protocol_context,
@@ -326,7 +365,7 @@ where
                 .range_proof_enc_dl_public_parameters,
             secret_key_share,
             public_key_share,
-            knowledge_of_discrete_log_proof: state.proof,
+            knowledge_of_discrete_log_proof: state.proof.clone(),
             commitment_randomness: state.commitment_randomness,
         })
     }
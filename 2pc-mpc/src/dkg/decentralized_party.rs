@@ -11,6 +11,7 @@ use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
 use maurer::SOUND_PROOFS_REPETITIONS;
 use proof::{range, AggregatableRangeProof};
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 pub mod decommitment_proof_verification_round;
 pub mod encryption_of_secret_key_share_round;
 
@@ -35,7 +36,7 @@ impl<
         GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     SecretKeyShareEncryptionAndProof<
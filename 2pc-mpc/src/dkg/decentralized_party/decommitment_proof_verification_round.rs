@@ -3,7 +3,8 @@
 
 #![allow(clippy::type_complexity)]
 
-use std::marker::PhantomData;
+use alloc::vec;
+use core::marker::PhantomData;
 
 use commitment::Commitment;
 use enhanced_maurer::{encryption_of_discrete_log, EnhanceableLanguage};
@@ -12,13 +13,14 @@ use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
 use maurer::knowledge_of_discrete_log;
 use proof::{range, AggregatableRangeProof};
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::{
     dkg::{
-        centralized_party, centralized_party::commitment_round::commit_public_key_share,
+        centralized_party, centralized_party::commitment_round::public_key_share_commitment_scheme,
         decentralized_party,
     },
-    ProtocolPublicParameters, CENTRALIZED_PARTY_ID,
+    ProtocolPublicParameters,
 };
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -38,7 +40,7 @@ pub struct Party<
     GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
     ProtocolContext: Clone + Serialize,
 > {
     pub protocol_context: ProtocolContext,
@@ -58,7 +60,7 @@ impl<
         const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
         GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         ProtocolContext: Clone + Serialize,
     >
@@ -138,27 +140,30 @@ where
     > {
         // = X_B
         let public_key_share = GroupElement::new(
-            secret_key_share_encryption_and_proof.public_key_share,
+            secret_key_share_encryption_and_proof
+                .public_key_share
+                .clone(),
             &self.group_public_parameters,
         )?;
 
         // = X_A
         let centralized_party_public_key_share = GroupElement::new(
-            decommitment_and_proof.public_key_share,
+            decommitment_and_proof.public_key_share.clone(),
             &self.group_public_parameters,
         )?;
 
         // === Check commitment X_A ===
         // Used in emulating idealized F^{L_DL}_{com-zk}
         // Protocol 4, step 4a
-        let reconstructed_commitment = commit_public_key_share(
-            CENTRALIZED_PARTY_ID,
-            &centralized_party_public_key_share,
-            &decommitment_and_proof.commitment_randomness,
-        )?;
-        if reconstructed_commitment != self.commitment_to_centralized_party_secret_key_share {
-            return Err(crate::Error::WrongDecommitment);
-        }
+        public_key_share_commitment_scheme::<GroupElement>()
+            .verify(
+                &self.commitment_to_centralized_party_secret_key_share,
+                &commitment::hash::Decommitment {
+                    value: decommitment_and_proof.public_key_share.clone(),
+                    commitment_randomness: decommitment_and_proof.commitment_randomness,
+                },
+            )
+            .map_err(|_| crate::Error::WrongDecommitment)?;
 
         // === Verify knowledge of x_A proof ===
         // Used in emulating idealized F^{L_DL}_{com-zk}
@@ -172,12 +177,12 @@ where
         decommitment_and_proof.proof.verify(
             &self.protocol_context,
             &language_public_parameters,
-            vec![centralized_party_public_key_share.clone()],
+            vec![centralized_party_public_key_share],
         )?;
 
         // === Compute X := X_A + X_B ===
         // Protocol 4, step 5b
-        let public_key = centralized_party_public_key_share.clone() + &public_key_share;
+        let public_key = centralized_party_public_key_share + public_key_share;
 
         // === Output (and record) ===
         // Protocol 4, step 5b
@@ -192,8 +197,8 @@ where
 
     pub fn new<
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
-        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
     >(
         protocol_public_parameters: ProtocolPublicParameters<
             SCALAR_LIMBS,
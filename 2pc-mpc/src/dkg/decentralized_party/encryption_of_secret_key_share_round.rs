@@ -3,8 +3,8 @@
 
 #![allow(clippy::type_complexity)]
 
+use alloc::vec;
 use core::marker::PhantomData;
-use std::collections::HashSet;
 
 use commitment::Commitment;
 use crypto_bigint::{rand_core::CryptoRngCore, Uint};
@@ -12,15 +12,25 @@ use enhanced_maurer::{
     encryption_of_discrete_log, language::EnhancedPublicParameters, EnhanceableLanguage,
     EnhancedLanguage,
 };
-use group::{GroupElement as _, PartyID, PrimeGroupElement, Samplable};
+use group::{collections::HashSet, GroupElement as _, PartyID, PrimeGroupElement, Samplable};
 use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
 use maurer::SOUND_PROOFS_REPETITIONS;
-use proof::AggregatableRangeProof;
+use proof::{
+    range,
+    session::{aggregation_session, Session},
+    AggregatableRangeProof,
+};
 use serde::Serialize;
+use zeroize::Zeroize;
 
 use crate::{
-    dkg::decentralized_party::decommitment_proof_verification_round, Error,
-    ProtocolPublicParameters,
+    dkg::{
+        decentralized_party::{
+            decommitment_proof_verification_round, SecretKeyShareEncryptionAndProof,
+        },
+        encryption_of_secret_key_share_session_id,
+    },
+    Error, ProtocolPublicParameters,
 };
 
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
@@ -32,7 +42,7 @@ pub struct Party<
     GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedEncDLWitness: group::GroupElement + Samplable,
+    UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
     ProtocolContext: Clone + Serialize,
 > {
     party_id: PartyID,
@@ -54,7 +64,7 @@ impl<
         GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     Party<
@@ -261,6 +271,7 @@ where
             >::new_session(
                 self.party_id,
                 self.parties.clone(),
+                encryption_of_secret_key_share_session_id(&self.protocol_context)?,
                 language_public_parameters,
                 self.protocol_context.clone(),
                 vec![share_of_decentralized_party_secret_key_share_witness],
@@ -293,10 +304,110 @@ where
         ))
     }
 
+    /// Samples x_i as in [`Self::sample_secret_key_share_and_initialize_proof_aggregation`], and
+    /// instantiates a [`Session`] running the proof aggregation protocol of ct_i over a broadcast
+    /// channel.
+    ///
+    /// The session outputs the encryption of the secret key share and its proof, to be sent to the
+    /// centralized party, alongside the party of the following round.
+    pub fn sample_secret_key_share_and_initialize_session(
+        self,
+        commitment_to_centralized_party_secret_key_share: Commitment,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<
+        Session<
+            (
+                SecretKeyShareEncryptionAndProof<
+                    GroupElement::Value,
+                    range::CommitmentSchemeCommitmentSpaceValue<
+                        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                        RANGE_CLAIMS_PER_SCALAR,
+                        RangeProof,
+                    >,
+                    homomorphic_encryption::CiphertextSpaceValue<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        EncryptionKey,
+                    >,
+                    encryption_of_discrete_log::Proof<
+                        RANGE_CLAIMS_PER_SCALAR,
+                        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                        RangeProof,
+                        UnboundedEncDLWitness,
+                        ProtocolContext,
+                    >,
+                >,
+                decommitment_proof_verification_round::Party<
+                    SCALAR_LIMBS,
+                    COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                    RANGE_CLAIMS_PER_SCALAR,
+                    PLAINTEXT_SPACE_SCALAR_LIMBS,
+                    GroupElement,
+                    EncryptionKey,
+                    RangeProof,
+                    UnboundedEncDLWitness,
+                    ProtocolContext,
+                >,
+            ),
+            Error,
+        >,
+    >
+    where
+        GroupElement: 'static,
+        EncryptionKey: 'static,
+        RangeProof: 'static,
+        UnboundedEncDLWitness: 'static,
+        ProtocolContext: 'static,
+        enhanced_maurer::Error: From<
+            range::AggregationError<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+            >,
+        >,
+    {
+        let party_id = self.party_id;
+
+        let (
+            encryption_of_secret_share_commitment_round_party,
+            decommitment_proof_verification_round_party,
+        ) = self.sample_secret_key_share_and_initialize_proof_aggregation(
+            commitment_to_centralized_party_secret_key_share,
+            rng,
+        )?;
+
+        Ok(
+            aggregation_session(party_id, encryption_of_secret_share_commitment_round_party)
+                .map_err(Error::from)
+                .map(
+                    move |(
+                        encryption_of_secret_key_share_proof,
+                        encryptions_of_secret_key_share,
+                    )| {
+                        let encryption_of_secret_key_share = encryptions_of_secret_key_share
+                            .into_iter()
+                            .next()
+                            .ok_or(Error::InternalError)?;
+
+                        Ok((
+                            SecretKeyShareEncryptionAndProof::new(
+                                encryption_of_secret_key_share,
+                                encryption_of_secret_key_share_proof,
+                            ),
+                            decommitment_proof_verification_round_party,
+                        ))
+                    },
+                ),
+        )
+    }
+
     pub fn new<
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
-        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
     >(
         protocol_public_parameters: ProtocolPublicParameters<
             SCALAR_LIMBS,
@@ -1,10 +1,17 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
 use group::{PartyID, PrimeGroupElement, Samplable};
 use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
 use proof::{range, AggregatableRangeProof};
 use serde::Serialize;
+use zeroize::Zeroize;
 
 pub mod dkg;
 pub mod presign;
@@ -33,6 +40,8 @@ pub enum Error {
     MismatchingEncrypedMasks(Vec<PartyID>),
     #[error("parties {:?} did not send partial decryption proofs in the signing identifiable abort protocol", .0)]
     UnresponsiveParties(Vec<PartyID>),
+    #[error("parties {:?} sent decryption shares that could not be decoded in the signing protocol", .0)]
+    InvalidDecryptionShares(Vec<PartyID>),
     #[error("not enough parties to initiate the session")]
     ThresholdNotReached,
     #[error("the other party maliciously attempted to bypass the commitment round by sending decommitment which does not match its commitment")]
@@ -50,7 +59,7 @@ pub enum Error {
 }
 
 /// 2PC-MPC result.
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 pub const CENTRALIZED_PARTY_ID: PartyID = 1;
 pub const DECENTRALIZED_PARTY_ID: PartyID = 2;
@@ -65,9 +74,9 @@ pub struct ProtocolPublicParameters<
     GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedEncDLWitness: group::GroupElement + Samplable,
-    UnboundedEncDHWitness: group::GroupElement + Samplable,
-    UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
+    UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
+    UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
 > {
     pub scalar_group_public_parameters: group::PublicParameters<GroupElement::Scalar>,
     pub group_public_parameters: group::PublicParameters<GroupElement>,
@@ -3,9 +3,50 @@
 
 #![allow(clippy::type_complexity)]
 
+use proof::session::SessionID;
+use serde::Serialize;
+
 pub mod centralized_party;
 pub mod decentralized_party;
 
+/// The identifier of the session in which the decentralized party aggregates the proof for the
+/// encryptions of its masks and masked key shares.
+///
+/// It is derived from the protocol context, so that the centralized party can verify the proof.
+pub(crate) fn masks_and_encrypted_masked_key_share_session_id(
+    protocol_context: &impl Serialize,
+) -> proof::Result<SessionID> {
+    SessionID::derive(
+        b"2pc-mpc presign masks and encrypted masked key share",
+        protocol_context,
+    )
+}
+
+/// The identifier of the session in which the decentralized party aggregates the proof for the
+/// encryptions of its nonce shares and their public shares.
+///
+/// It is derived from the protocol context, so that the centralized party can verify the proof.
+pub(crate) fn encrypted_nonce_shares_and_public_shares_session_id(
+    protocol_context: &impl Serialize,
+) -> proof::Result<SessionID> {
+    SessionID::derive(
+        b"2pc-mpc presign encrypted nonce shares and public shares",
+        protocol_context,
+    )
+}
+
+/// The identifier of the session in which the decentralized party aggregates the proof for the
+/// encryption of its `index`-th masked nonce.
+pub(crate) fn encrypted_masked_nonce_session_id(
+    protocol_context: &impl Serialize,
+    index: usize,
+) -> proof::Result<SessionID> {
+    SessionID::derive(
+        b"2pc-mpc presign encrypted masked nonce",
+        &(protocol_context, index),
+    )
+}
+
 #[cfg(all(
     any(test, feature = "benchmarking"),
     feature = "secp256k1",
@@ -37,6 +78,7 @@ pub(crate) mod tests {
             aggregates, aggregates_multiple_with_decommitments, aggregates_with_decommitments,
         },
         range::bulletproofs,
+        session::network::Network,
     };
     use rand::prelude::IteratorRandom;
     use rand_core::OsRng;
@@ -45,11 +87,13 @@ pub(crate) mod tests {
         test_exports::{N, SECRET_KEY},
         LargeBiPrimeSizedNumber,
     };
+    use zeroize::Zeroizing;
 
     use super::*;
     use crate::{
-        dkg::decentralized_party::SecretKeyShareEncryptionAndProof,
-        secp256k1::bulletproofs::RANGE_CLAIMS_PER_SCALAR, Error, ProtocolPublicParameters,
+        dkg::{decentralized_party::SecretKeyShareEncryptionAndProof, tests::send_over_wire},
+        secp256k1::bulletproofs::RANGE_CLAIMS_PER_SCALAR,
+        Error, ProtocolPublicParameters,
     };
 
     #[rstest]
@@ -174,6 +218,9 @@ pub(crate) mod tests {
         centralized_party_total_time =
             measurement.add(&centralized_party_total_time, &measurement.end(now));
 
+        let centralized_party_nonce_shares_commitments_and_batched_proof =
+            send_over_wire(centralized_party_nonce_shares_commitments_and_batched_proof);
+
         let mut parties = HashSet::new();
         (1..=number_of_parties)
             .choose_multiple(&mut OsRng, threshold.into())
@@ -215,6 +262,22 @@ pub(crate) mod tests {
             })
             .collect();
 
+        let presign_sessions: Vec<_> =
+            decentralized_party_encrypted_masked_key_share_and_public_nonce_shares_parties
+                .clone()
+                .into_values()
+                .map(|party| {
+                    party
+                        .sample_mask_and_nonce_shares_and_initialize_session(
+                            centralized_party_nonce_shares_commitments_and_batched_proof.clone(),
+                            &mut OsRng,
+                        )
+                        .unwrap()
+                })
+                .collect();
+        let centralized_party_session_proof_verification_round_party =
+            centralized_party_proof_verification_round_party.clone();
+
         let (
             aggregation_parties,
             mut decentralized_party_encrypted_masked_nonce_shares_round_parties,
@@ -310,6 +373,8 @@ pub(crate) mod tests {
         )
         .unwrap();
 
+        let output = send_over_wire(output);
+
         let now = measurement.start();
         let centralized_party_presigns = centralized_party_proof_verification_round_party
             .verify_presign_output(output, &mut OsRng)
@@ -354,17 +419,19 @@ pub(crate) mod tests {
                     decentralized_party_encrypted_masked_nonce_shares_round_parties
                         .get_mut(&party_id)
                         .unwrap()
-                        .shares_of_signature_nonce_shares_witnesses = iter::repeat(
-                        tiresias::PlaintextSpaceGroupElement::new(
-                            Uint::<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }>::ZERO,
-                            protocol_public_parameters
-                                .encryption_scheme_public_parameters
-                                .plaintext_space_public_parameters(),
+                        .shares_of_signature_nonce_shares_witnesses = Zeroizing::new(
+                        iter::repeat(
+                            tiresias::PlaintextSpaceGroupElement::new(
+                                Uint::<{ tiresias::PLAINTEXT_SPACE_SCALAR_LIMBS }>::ZERO,
+                                protocol_public_parameters
+                                    .encryption_scheme_public_parameters
+                                    .plaintext_space_public_parameters(),
+                            )
+                            .unwrap(),
                         )
-                        .unwrap(),
-                    )
-                    .take(batch_size)
-                    .collect();
+                        .take(batch_size)
+                        .collect(),
+                    );
                 });
         }
 
@@ -492,6 +559,37 @@ pub(crate) mod tests {
                         == decentralized_party_presign.encrypted_masked_key_share
             }));
 
+        let mut outputs = Network::new(presign_sessions, 1)
+            .run(&mut OsRng)
+            .into_values()
+            .map(|output| output.unwrap());
+        let (session_output, session_decentralized_party_presigns) = outputs.next().unwrap();
+        assert!(
+            outputs.all(|output| output
+                == (
+                    session_output.clone(),
+                    session_decentralized_party_presigns.clone()
+                )),
+            "all parties should agree on the presign output over the network"
+        );
+
+        let session_centralized_party_presigns =
+            centralized_party_session_proof_verification_round_party
+                .verify_presign_output(session_output, &mut OsRng)
+                .unwrap();
+        assert_eq!(session_centralized_party_presigns.len(), batch_size);
+        assert!(session_centralized_party_presigns
+            .into_iter()
+            .zip(session_decentralized_party_presigns)
+            .all(|(centralized_party_presign, decentralized_party_presign)| {
+                centralized_party_presign.decentralized_party_nonce_public_share
+                    == decentralized_party_presign.nonce_public_share
+                    && centralized_party_presign.encrypted_mask
+                        == decentralized_party_presign.encrypted_mask
+                    && centralized_party_presign.encrypted_masked_key_share
+                        == decentralized_party_presign.encrypted_masked_key_share
+            }));
+
         decentralized_party_total_time = measurement.add(
             &decentralized_party_total_time,
             &encrypted_masked_nonce_shares_time,
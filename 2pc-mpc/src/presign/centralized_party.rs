@@ -2,15 +2,60 @@
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
 use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 pub mod commitment_round;
 pub mod proof_verification_round;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-pub struct Presign<GroupElementValue, ScalarValue, CiphertextValue> {
+pub struct Presign<GroupElementValue, ScalarValue: Zeroize, CiphertextValue> {
     pub(crate) nonce_share: ScalarValue, // $k_A$
     pub(crate) decentralized_party_nonce_public_share: GroupElementValue, // $R_B$
     pub(crate) encrypted_mask: CiphertextValue, // $\ct_1$
     pub(crate) encrypted_masked_key_share: CiphertextValue, // $\ct_2$
     pub(crate) commitment_randomness: ScalarValue, // $ρ_1$
 }
+
+impl<GroupElementValue, ScalarValue: Zeroize, CiphertextValue> Zeroize
+    for Presign<GroupElementValue, ScalarValue, CiphertextValue>
+{
+    fn zeroize(&mut self) {
+        self.nonce_share.zeroize();
+        self.commitment_randomness.zeroize();
+    }
+}
+
+impl<GroupElementValue, ScalarValue: Zeroize, CiphertextValue> Drop
+    for Presign<GroupElementValue, ScalarValue, CiphertextValue>
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<GroupElementValue, ScalarValue: Zeroize, CiphertextValue> ZeroizeOnDrop
+    for Presign<GroupElementValue, ScalarValue, CiphertextValue>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{Random, U256};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn zeroizes_secrets() {
+        group::test_helpers::zeroizes_secrets(
+            Presign {
+                nonce_share: U256::random(&mut OsRng),
+                decentralized_party_nonce_public_share: (),
+                encrypted_mask: (),
+                encrypted_masked_key_share: (),
+                commitment_randomness: U256::random(&mut OsRng),
+            },
+            |presign| vec![presign.nonce_share, presign.commitment_randomness],
+        );
+    }
+}
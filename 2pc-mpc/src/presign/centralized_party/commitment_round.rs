@@ -3,6 +3,8 @@
 
 #![allow(clippy::type_complexity)]
 
+use alloc::vec::Vec;
+
 use commitment::{pedersen, Pedersen};
 use crypto_bigint::rand_core::CryptoRngCore;
 use group::{GroupElement as _, PrimeGroupElement, Samplable};
@@ -10,6 +12,7 @@ use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicPar
 use maurer::{knowledge_of_decommitment, SOUND_PROOFS_REPETITIONS};
 use proof::AggregatableRangeProof;
 use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::{dkg, presign::centralized_party::proof_verification_round, ProtocolPublicParameters};
 
@@ -22,8 +25,8 @@ pub struct Party<
     GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedEncDLWitness: group::GroupElement + Samplable,
-    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
+    UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
     ProtocolContext: Clone + Serialize,
 > {
     pub(in crate::presign) protocol_context: ProtocolContext,
@@ -59,8 +62,8 @@ impl<
         GroupElement: PrimeGroupElement<SCALAR_LIMBS> + group::HashToGroup,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     Party<
@@ -181,7 +184,9 @@ impl<
                 .unbounded_encdh_witness_public_parameters,
             range_proof_public_parameters: self.range_proof_public_parameters,
             protocol_context: self.protocol_context,
-            signature_nonce_shares_and_commitment_randomnesses,
+            signature_nonce_shares_and_commitment_randomnesses: Zeroizing::new(
+                signature_nonce_shares_and_commitment_randomnesses,
+            ),
             encrypted_decentralized_party_secret_key_share: self
                 .encrypted_decentralized_party_secret_key_share,
         };
@@ -196,7 +201,7 @@ impl<
 
     pub fn new<
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
     >(
         protocol_context: ProtocolContext,
         protocol_public_parameters: ProtocolPublicParameters<
@@ -223,7 +228,9 @@ impl<
 
         let encrypted_decentralized_party_secret_key_share =
             EncryptionKey::CiphertextSpaceGroupElement::new(
-                dkg_output.encrypted_decentralized_party_secret_key_share,
+                dkg_output
+                    .encrypted_decentralized_party_secret_key_share
+                    .clone(),
                 encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
             )?;
 
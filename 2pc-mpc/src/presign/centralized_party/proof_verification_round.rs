@@ -3,6 +3,8 @@
 
 #![allow(clippy::type_complexity)]
 
+use alloc::vec::Vec;
+
 use commitment::GroupsPublicParametersAccessors;
 use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
 use enhanced_maurer::{
@@ -16,13 +18,18 @@ use homomorphic_encryption::{
 use maurer::SOUND_PROOFS_REPETITIONS;
 use proof::{range::PublicParametersAccessors, AggregatableRangeProof};
 use serde::Serialize;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::{
     dkg,
-    presign::{centralized_party::Presign, decentralized_party},
+    presign::{
+        centralized_party::Presign, decentralized_party,
+        encrypted_nonce_shares_and_public_shares_session_id,
+        masks_and_encrypted_masked_key_share_session_id,
+    },
     Error, ProtocolPublicParameters,
 };
-#[cfg_attr(feature = "benchmarking", derive(Clone))]
+#[cfg_attr(any(test, feature = "benchmarking"), derive(Clone))]
 pub struct Party<
     const SCALAR_LIMBS: usize,
     const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
@@ -31,8 +38,8 @@ pub struct Party<
     GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedEncDLWitness: group::GroupElement + Samplable,
-    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
+    UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
     ProtocolContext: Clone + Serialize,
 > {
     pub(super) protocol_context: ProtocolContext,
@@ -43,7 +50,7 @@ pub struct Party<
     pub(super) unbounded_encdh_witness_public_parameters: UnboundedEncDHWitness::PublicParameters,
     pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
     pub signature_nonce_shares_and_commitment_randomnesses:
-        Vec<(GroupElement::Scalar, GroupElement::Scalar)>,
+        Zeroizing<Vec<(GroupElement::Scalar, GroupElement::Scalar)>>,
     pub(super) encrypted_decentralized_party_secret_key_share:
         EncryptionKey::CiphertextSpaceGroupElement,
 }
@@ -56,8 +63,8 @@ impl<
         GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     Party<
@@ -311,12 +318,15 @@ where
                 },
             )
             .collect();
-        output.masks_and_encrypted_masked_key_share_proof.verify(
-            &self.protocol_context,
-            &language_public_parameters,
-            statements,
-            rng,
-        )?;
+        output
+            .masks_and_encrypted_masked_key_share_proof
+            .verify_in_session(
+                &masks_and_encrypted_masked_key_share_session_id(&self.protocol_context)?,
+                &self.protocol_context,
+                &language_public_parameters,
+                statements,
+                rng,
+            )?;
 
         // = ct_3
         // = AHE.Enc(k)
@@ -419,7 +429,8 @@ where
             .collect();
         output
             .encrypted_nonce_shares_and_public_shares_proof
-            .verify(
+            .verify_in_session(
+                &encrypted_nonce_shares_and_public_shares_session_id(&self.protocol_context)?,
                 &self.protocol_context,
                 &language_public_parameters,
                 statements,
@@ -431,10 +442,10 @@ where
             .into_iter()
             .zip(
                 output.encrypted_masks.into_iter().zip(
-                    output
-                        .encrypted_masked_key_shares
-                        .into_iter()
-                        .zip(self.signature_nonce_shares_and_commitment_randomnesses),
+                    output.encrypted_masked_key_shares.into_iter().zip(
+                        self.signature_nonce_shares_and_commitment_randomnesses
+                            .iter(),
+                    ),
                 ),
             )
             .map(
@@ -459,7 +470,7 @@ where
 
     pub fn new<
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
     >(
         signature_nonce_shares_and_commitment_randomnesses: Vec<(
             GroupElement::Scalar,
@@ -490,7 +501,9 @@ where
 
         let encrypted_decentralized_party_secret_key_share =
             EncryptionKey::CiphertextSpaceGroupElement::new(
-                dkg_output.encrypted_decentralized_party_secret_key_share,
+                dkg_output
+                    .encrypted_decentralized_party_secret_key_share
+                    .clone(),
                 encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
             )?;
 
@@ -506,7 +519,9 @@ where
                 .unbounded_encdh_witness_public_parameters,
             range_proof_public_parameters: protocol_public_parameters
                 .range_proof_enc_dl_public_parameters,
-            signature_nonce_shares_and_commitment_randomnesses,
+            signature_nonce_shares_and_commitment_randomnesses: Zeroizing::new(
+                signature_nonce_shares_and_commitment_randomnesses,
+            ),
             encrypted_decentralized_party_secret_key_share,
         })
     }
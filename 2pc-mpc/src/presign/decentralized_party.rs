@@ -1,7 +1,7 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::{HashMap, HashSet};
+use alloc::vec::Vec;
 
 use commitment::Pedersen;
 use crypto_bigint::{Encoding, Uint};
@@ -10,11 +10,15 @@ use enhanced_maurer::{
     encryption_of_tuple, encryption_of_tuple::StatementAccessors as _,
     language::EnhancedLanguageStatementAccessors, EnhanceableLanguage,
 };
-use group::{GroupElement as _, PartyID, PrimeGroupElement, Samplable};
+use group::{
+    collections::{HashMap, HashSet},
+    GroupElement as _, PartyID, PrimeGroupElement, Samplable,
+};
 use homomorphic_encryption::AdditivelyHomomorphicEncryptionKey;
 use maurer::{knowledge_of_decommitment, SOUND_PROOFS_REPETITIONS};
 use proof::{range, AggregatableRangeProof};
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::{
     presign::centralized_party::commitment_round::SignatureNonceSharesCommitmentsAndBatchedProof,
@@ -50,8 +54,8 @@ impl<
         GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     Output<
@@ -543,7 +547,7 @@ impl<
                 EncryptionKey,
             >(
                 parties.clone(),
-                centralized_party_nonce_shares_commitments[i].clone(),
+                centralized_party_nonce_shares_commitments[i],
                 masks_and_encrypted_masked_key_share[i].clone(),
                 individual_encrypted_nonce_shares_and_public_shares.iter().map(|(party_id, statements)| (*party_id, statements[i].clone())).collect(),
                 encrypted_nonce_shares_and_public_shares[i].clone(),
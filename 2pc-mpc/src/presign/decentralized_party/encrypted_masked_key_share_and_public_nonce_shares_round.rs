@@ -3,30 +3,41 @@
 
 #![allow(clippy::type_complexity)]
 
-use std::collections::HashSet;
+use alloc::{vec, vec::Vec};
 
 use commitment::{pedersen, Pedersen};
 use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
 use enhanced_maurer::{
-    encryption_of_discrete_log, encryption_of_tuple, language::composed_witness_upper_bound,
+    encryption_of_discrete_log, encryption_of_tuple,
+    language::{composed_witness_upper_bound, EnhancedLanguageStatementAccessors as _},
     EnhanceableLanguage, EnhancedLanguage, EnhancedPublicParameters,
 };
-use group::{GroupElement, PartyID, PrimeGroupElement, Samplable};
+use group::{
+    collections::{HashMap, HashSet},
+    GroupElement, PartyID, PrimeGroupElement, Samplable,
+};
 use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
 use maurer::{knowledge_of_decommitment, SOUND_PROOFS_REPETITIONS};
-use proof::AggregatableRangeProof;
+use proof::{
+    range,
+    session::{aggregation_session, aggregation_session_with_decommitments, Session},
+    AggregatableRangeProof,
+};
 use serde::Serialize;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::{
     dkg,
     presign::{
         centralized_party::commitment_round::SignatureNonceSharesCommitmentsAndBatchedProof,
-        decentralized_party::encrypted_masked_nonces_round,
+        decentralized_party::{encrypted_masked_nonces_round, Output, Presign},
+        encrypted_nonce_shares_and_public_shares_session_id,
+        masks_and_encrypted_masked_key_share_session_id,
     },
     Error, ProtocolPublicParameters,
 };
 
-#[cfg_attr(feature = "benchmarking", derive(Clone))]
+#[cfg_attr(any(test, feature = "benchmarking"), derive(Clone))]
 pub struct Party<
     const SCALAR_LIMBS: usize,
     const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
@@ -35,8 +46,8 @@ pub struct Party<
     GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedEncDLWitness: group::GroupElement + Samplable,
-    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
+    UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
     ProtocolContext: Clone + Serialize,
 > {
     pub(in crate::presign) party_id: PartyID,
@@ -64,8 +75,8 @@ impl<
         GroupElement: PrimeGroupElement<SCALAR_LIMBS> + group::HashToGroup,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     Party<
@@ -413,6 +424,7 @@ where
             >::new_session(
                 self.party_id,
                 self.parties.clone(),
+                masks_and_encrypted_masked_key_share_session_id(&self.protocol_context)?,
                 enc_dh_public_parameters,
                 self.protocol_context.clone(),
                 witnesses,
@@ -560,6 +572,7 @@ where
             >::new_session(
                 self.party_id,
                 self.parties.clone(),
+                encrypted_nonce_shares_and_public_shares_session_id(&self.protocol_context)?,
                 enc_dl_public_parameters,
                 self.protocol_context.clone(),
                 witnesses,
@@ -575,8 +588,12 @@ where
             unbounded_encdh_witness_public_parameters: self
                 .unbounded_encdh_witness_public_parameters,
             range_proof_public_parameters: self.range_proof_public_parameters,
-            shares_of_signature_nonce_shares_witnesses,
-            shares_of_signature_nonce_shares_encryption_randomness,
+            shares_of_signature_nonce_shares_witnesses: Zeroizing::new(
+                shares_of_signature_nonce_shares_witnesses,
+            ),
+            shares_of_signature_nonce_shares_encryption_randomness: Zeroizing::new(
+                shares_of_signature_nonce_shares_encryption_randomness,
+            ),
         };
 
         Ok((
@@ -588,9 +605,256 @@ where
         ))
     }
 
+    /// Samples γ_i and k_i as in
+    /// [`Self::sample_mask_and_nonce_shares_and_initialize_proof_aggregation`], and instantiates a
+    /// [`Session`] running the rest of the decentralized party's presign protocol over a broadcast
+    /// channel: the proof aggregation protocols of ct^i_1, ct^i_2 and ct^i_3 in parallel, followed
+    /// by those of ct^i_4.
+    ///
+    /// The session outputs the message to be sent to the centralized party, alongside the
+    /// decentralized party's presigns.
+    ///
+    /// Note: this function operates on batches; the annotations are written as
+    /// if the batch size equals 1.
+    pub fn sample_mask_and_nonce_shares_and_initialize_session(
+        self,
+        centralized_party_nonce_shares_commitments_and_batched_proof:
+            SignatureNonceSharesCommitmentsAndBatchedProof<SCALAR_LIMBS, GroupElement::Value, maurer::Proof<
+            SOUND_PROOFS_REPETITIONS,
+            knowledge_of_decommitment::Language<
+                SOUND_PROOFS_REPETITIONS,
+                SCALAR_LIMBS,
+                Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+            >,
+            ProtocolContext,
+        >,>,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<
+        Session<
+            (
+                Output<
+                    GroupElement::Value,
+                    range::CommitmentSchemeCommitmentSpaceValue<
+                        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                        RANGE_CLAIMS_PER_SCALAR,
+                        RangeProof,
+                    >,
+                    homomorphic_encryption::CiphertextSpaceValue<
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        EncryptionKey,
+                    >,
+                    encryption_of_tuple::Proof<
+                        RANGE_CLAIMS_PER_SCALAR,
+                        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                        RangeProof,
+                        UnboundedEncDHWitness,
+                        ProtocolContext,
+                    >,
+                    encryption_of_discrete_log::Proof<
+                        RANGE_CLAIMS_PER_SCALAR,
+                        COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                        PLAINTEXT_SPACE_SCALAR_LIMBS,
+                        SCALAR_LIMBS,
+                        GroupElement,
+                        EncryptionKey,
+                        RangeProof,
+                        UnboundedEncDLWitness,
+                        ProtocolContext,
+                    >,
+                >,
+                Vec<
+                    Presign<
+                        GroupElement::Value,
+                        homomorphic_encryption::CiphertextSpaceValue<
+                            PLAINTEXT_SPACE_SCALAR_LIMBS,
+                            EncryptionKey,
+                        >,
+                    >,
+                >,
+            ),
+            Error,
+        >,
+    >
+    where
+        GroupElement: 'static,
+        EncryptionKey: 'static,
+        RangeProof: 'static,
+        UnboundedEncDLWitness: 'static,
+        UnboundedEncDHWitness: 'static,
+        ProtocolContext: 'static,
+        enhanced_maurer::Error: From<
+            range::AggregationError<
+                RANGE_CLAIMS_PER_SCALAR,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+            >,
+        >,
+    {
+        let party_id = self.party_id;
+        let parties = self.parties.clone();
+        let group_public_parameters = self.group_public_parameters.clone();
+
+        let (
+            (key_share_masking_commitment_round_party, nonce_sharing_commitment_round_party),
+            encrypted_masked_nonces_round_party,
+        ) = self.sample_mask_and_nonce_shares_and_initialize_proof_aggregation(
+            centralized_party_nonce_shares_commitments_and_batched_proof.clone(),
+            rng,
+        )?;
+
+        Ok(
+            aggregation_session(party_id, key_share_masking_commitment_round_party)
+                .join(aggregation_session_with_decommitments(
+                    party_id,
+                    nonce_sharing_commitment_round_party,
+                ))
+                .map_err(Error::from)
+                .and_then(
+                    move |(
+                        (
+                            masks_and_encrypted_masked_key_share_proof,
+                            masks_and_encrypted_masked_key_share,
+                        ),
+                        (
+                            (
+                                encrypted_nonce_shares_and_public_shares_proof,
+                                encrypted_nonce_shares_and_public_shares,
+                            ),
+                            encrypted_nonce_shares_and_public_shares_decommitments,
+                        ),
+                    ),
+                          mut rng| {
+                        let output = Output::new(
+                            masks_and_encrypted_masked_key_share.clone(),
+                            masks_and_encrypted_masked_key_share_proof,
+                            encrypted_nonce_shares_and_public_shares.clone(),
+                            encrypted_nonce_shares_and_public_shares_proof,
+                        )?;
+
+                        let masks_and_encrypted_masked_key_share: Vec<_> =
+                            masks_and_encrypted_masked_key_share
+                                .into_iter()
+                                .map(|mask_and_encrypted_masked_key_share| {
+                                    mask_and_encrypted_masked_key_share
+                                        .language_statement()
+                                        .clone()
+                                })
+                                .collect();
+
+                        let encrypted_nonce_shares_and_public_shares: Vec<_> =
+                            encrypted_nonce_shares_and_public_shares
+                                .into_iter()
+                                .map(|encrypted_nonce_share_and_public_share| {
+                                    encrypted_nonce_share_and_public_share
+                                        .language_statement()
+                                        .clone()
+                                })
+                                .collect();
+
+                        let individual_encrypted_nonce_shares_and_public_shares =
+                            encrypted_nonce_shares_and_public_shares_decommitments
+                                .into_iter()
+                                .map(|(party_id, (maurer_decommitment, _))| {
+                                    (
+                                        party_id,
+                                        maurer_decommitment
+                                            .statements
+                                            .into_iter()
+                                            .map(|statement| {
+                                                let (_, language_statement) = statement.into();
+
+                                                language_statement
+                                            })
+                                            .collect(),
+                                    )
+                                })
+                                .collect();
+
+                        let encrypted_masked_nonce_shares_commitment_round_parties =
+                            encrypted_masked_nonces_round_party.initialize_proof_aggregation(
+                                masks_and_encrypted_masked_key_share.clone(),
+                                encrypted_nonce_shares_and_public_shares.clone(),
+                                &mut rng,
+                            )?;
+
+                        Ok(Session::join_all(
+                            party_id,
+                            encrypted_masked_nonce_shares_commitment_round_parties
+                                .into_iter()
+                                .map(|party| {
+                                    aggregation_session_with_decommitments(party_id, party)
+                                })
+                                .collect(),
+                        )
+                        .map_err(Error::from)
+                        .map(move |outputs| {
+                            let mut individual_encrypted_masked_nonce_shares: HashMap<_, Vec<_>> =
+                                HashMap::new();
+                            let mut encrypted_masked_nonce_shares = vec![];
+
+                            outputs
+                                .into_iter()
+                                .for_each(|((_, statements), decommitments)| {
+                                    encrypted_masked_nonce_shares.extend(
+                                        statements.into_iter().map(
+                                            |encrypted_masked_nonce_share| {
+                                                encrypted_masked_nonce_share
+                                                    .language_statement()
+                                                    .clone()
+                                            },
+                                        ),
+                                    );
+
+                                    decommitments.into_iter().for_each(
+                                        |(party_id, (maurer_decommitment, _))| {
+                                            individual_encrypted_masked_nonce_shares
+                                                .entry(party_id)
+                                                .or_default()
+                                                .extend(
+                                                    maurer_decommitment.statements.into_iter().map(
+                                                        |statement| {
+                                                            let (_, language_statement) =
+                                                                statement.into();
+
+                                                            language_statement
+                                                        },
+                                                    ),
+                                                );
+                                        },
+                                    );
+                                });
+
+                            let presigns = Presign::new_batch::<
+                                SCALAR_LIMBS,
+                                PLAINTEXT_SPACE_SCALAR_LIMBS,
+                                GroupElement,
+                                EncryptionKey,
+                                ProtocolContext,
+                            >(
+                                parties,
+                                centralized_party_nonce_shares_commitments_and_batched_proof,
+                                masks_and_encrypted_masked_key_share,
+                                individual_encrypted_nonce_shares_and_public_shares,
+                                encrypted_nonce_shares_and_public_shares,
+                                individual_encrypted_masked_nonce_shares,
+                                encrypted_masked_nonce_shares,
+                                &group_public_parameters,
+                            )?;
+
+                            Ok((output, presigns))
+                        }))
+                    },
+                ),
+        )
+    }
+
     pub fn new<
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
     >(
         party_id: PartyID,
         threshold: PartyID,
@@ -3,7 +3,7 @@
 
 #![allow(clippy::type_complexity)]
 
-use std::collections::HashSet;
+use alloc::{vec, vec::Vec};
 
 use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
 use enhanced_maurer::{
@@ -11,13 +11,14 @@ use enhanced_maurer::{
     language::composed_witness_upper_bound, EnhanceableLanguage, EnhancedLanguage,
     EnhancedPublicParameters,
 };
-use group::{GroupElement as _, PartyID, PrimeGroupElement, Samplable};
+use group::{collections::HashSet, GroupElement as _, PartyID, PrimeGroupElement, Samplable};
 use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
 use maurer::SOUND_PROOFS_REPETITIONS;
 use proof::AggregatableRangeProof;
 use serde::Serialize;
+use zeroize::{Zeroize, Zeroizing};
 
-use crate::{Error, Result};
+use crate::{presign::encrypted_masked_nonce_session_id, Error, Result};
 
 #[cfg_attr(feature = "benchmarking", derive(Clone))]
 pub struct Party<
@@ -28,7 +29,7 @@ pub struct Party<
     GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedEncDHWitness: group::GroupElement + Samplable,
+    UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
     ProtocolContext: Clone + Serialize,
 > {
     pub(super) party_id: PartyID,
@@ -39,9 +40,9 @@ pub struct Party<
     pub(super) unbounded_encdh_witness_public_parameters: UnboundedEncDHWitness::PublicParameters,
     pub(super) range_proof_public_parameters: RangeProof::PublicParameters<RANGE_CLAIMS_PER_SCALAR>,
     pub(crate) shares_of_signature_nonce_shares_witnesses:
-        Vec<EncryptionKey::PlaintextSpaceGroupElement>,
+        Zeroizing<Vec<EncryptionKey::PlaintextSpaceGroupElement>>,
     pub(super) shares_of_signature_nonce_shares_encryption_randomness:
-        Vec<EncryptionKey::RandomnessSpaceGroupElement>,
+        Zeroizing<Vec<EncryptionKey::RandomnessSpaceGroupElement>>,
 }
 
 impl<
@@ -52,7 +53,7 @@ impl<
         GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     Party<
@@ -166,19 +167,28 @@ where
             .into_iter()
             .zip(
                 self.shares_of_signature_nonce_shares_witnesses
-                    .into_iter()
+                    .iter()
+                    .copied()
                     .zip(
                         self.shares_of_signature_nonce_shares_encryption_randomness
-                            .into_iter()
+                            .iter()
+                            .cloned()
                             .zip(masked_nonce_encryption_randomness.clone()),
                     ),
             )
+            .enumerate()
             .map(
                 |(
-                    // = ct_1
-                    encrypted_mask,
-                    // = (k_i, (η^i_{mask_3}, η^i_{mask_4}))
-                    (nonce, (nonces_encryption_randomness, masked_nonces_encryption_randomness)),
+                    index,
+                    (
+                        // = ct_1
+                        encrypted_mask,
+                        // = (k_i, (η^i_{mask_3}, η^i_{mask_4}))
+                        (
+                            nonce,
+                            (nonces_encryption_randomness, masked_nonces_encryption_randomness),
+                        ),
+                    ),
                 )| {
                     // Construct EncDH public parameters
                     let encrypted_mask_upper_bound = composed_witness_upper_bound::<
@@ -280,6 +290,7 @@ where
                         >::new_session(
                             self.party_id,
                             self.parties.clone(),
+                            encrypted_masked_nonce_session_id(&self.protocol_context, index)?,
                             enc_dh_public_parameters,
                             self.protocol_context.clone(),
                             vec![witness],
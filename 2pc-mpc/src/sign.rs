@@ -1,7 +1,7 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::ops::Neg;
+use core::ops::Neg;
 
 #[cfg(feature = "benchmarking")]
 pub(crate) use benches::benchmark;
@@ -69,7 +69,10 @@ pub(crate) mod tests {
         AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors,
     };
     use k256::{elliptic_curve::scalar::IsHigh, sha2::digest::FixedOutput};
-    use proof::range::bulletproofs;
+    use proof::{
+        range::bulletproofs,
+        session::network::{Fault, Network},
+    };
     use rand::prelude::IteratorRandom;
     use rand_core::OsRng;
     use rstest::rstest;
@@ -81,7 +84,7 @@ pub(crate) mod tests {
 
     use super::*;
     use crate::{
-        dkg::tests::generates_distributed_key_internal,
+        dkg::tests::{generates_distributed_key_internal, send_over_wire},
         presign::tests::generates_presignatures_internal,
         secp256k1::{
             bulletproofs::{NUM_RANGE_CLAIMS, RANGE_CLAIMS_PER_MASK, RANGE_CLAIMS_PER_SCALAR},
@@ -255,6 +258,9 @@ pub(crate) mod tests {
         centralized_party_total_time =
             measurement.add(&centralized_party_total_time, &measurement.end(now));
 
+        let public_nonce_encrypted_partial_signature_and_proof =
+            send_over_wire(public_nonce_encrypted_partial_signature_and_proof);
+
         let (decryption_key_share_public_parameters, decryption_key_shares, lagrange_coefficients) =
             setup_decryption_key_shares(threshold, number_of_parties);
 
@@ -335,6 +341,12 @@ pub(crate) mod tests {
                     decentralized_party_decryption_share_time = measurement.end(now);
                 };
 
+                let (partial_signature_decryption_share, masked_nonce_decryption_share) =
+                    send_over_wire((
+                        partial_signature_decryption_share,
+                        masked_nonce_decryption_share,
+                    ));
+
                 (
                     (
                         (party_id, partial_signature_decryption_share),
@@ -373,6 +385,30 @@ pub(crate) mod tests {
 
         let nonce_x_coordinate = public_nonce.x(); // $r$
 
+        let [threshold_decryption_sessions, faulty_threshold_decryption_sessions] =
+            [(); 2].map(|()| {
+                signature_threshold_decryption_round_parties
+                    .clone()
+                    .into_iter()
+                    .map(|(party_id, party)| {
+                        party
+                            .initialize_session(
+                                party_id,
+                                number_of_parties,
+                                signature_threshold_decryption_round_parties
+                                    .keys()
+                                    .copied()
+                                    .collect(),
+                                (
+                                    partial_signature_decryption_shares[&party_id],
+                                    masked_nonce_decryption_shares[&party_id],
+                                ),
+                            )
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>()
+            });
+
         let mut signature_threshold_decryption_round_parties =
             signature_threshold_decryption_round_parties.into_iter();
 
@@ -445,6 +481,30 @@ pub(crate) mod tests {
             return;
         }
 
+        let outputs = Network::new(threshold_decryption_sessions, 1).run(&mut OsRng);
+        assert_eq!(outputs.len(), usize::from(threshold));
+        outputs.into_values().for_each(|output| {
+            assert_eq!(
+                output.unwrap(),
+                (nonce_x_coordinate, signature_s),
+                "all decrypters should decrypt the same signature over the network"
+            )
+        });
+
+        // A decrypter whose decryption shares fail to decode is blamed for sending invalid ones,
+        // rather than identified as unresponsive.
+        let faulty_decrypter = faulty_threshold_decryption_sessions[0].party_id();
+        let outputs = Network::new(faulty_threshold_decryption_sessions, 1)
+            .inject_fault(faulty_decrypter, 0, Fault::Truncate)
+            .run(&mut OsRng);
+        assert!(outputs
+            .into_iter()
+            .filter(|(party_id, _)| *party_id != faulty_decrypter)
+            .all(|(_, output)| matches!(
+                output,
+                Err(Error::InvalidDecryptionShares(parties)) if parties == vec![faulty_decrypter]
+            )));
+
         println!(
                 "\nProtocol, Number of Parties, Threshold, Batch Size, Centralized Party Total Time (ms), Decentralized Party Decryption Share Time (ms), Decentralized Party Threshold Decryption Time (ms)",
             );
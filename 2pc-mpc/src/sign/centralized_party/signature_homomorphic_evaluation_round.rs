@@ -3,6 +3,8 @@
 
 #![allow(clippy::type_complexity)]
 
+use alloc::vec;
+
 use commitment::{pedersen, Pedersen};
 use crypto_bigint::{rand_core::CryptoRngCore, CheckedMul, Encoding, Uint};
 use enhanced_maurer::{
@@ -22,6 +24,7 @@ use maurer::{
 };
 use proof::AggregatableRangeProof;
 use serde::Serialize;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::{
     dkg, presign,
@@ -45,7 +48,7 @@ pub struct Party<
     GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
     ProtocolContext: Clone + Serialize,
 > {
     pub(in crate::sign) protocol_context: ProtocolContext,
@@ -58,10 +61,10 @@ pub struct Party<
     pub(in crate::sign) range_proof_public_parameters:
         RangeProof::PublicParameters<NUM_RANGE_CLAIMS>,
     pub(in crate::sign) public_key: GroupElement,
-    pub(in crate::sign) secret_key_share: GroupElement::Scalar,
+    pub(in crate::sign) secret_key_share: Zeroizing<GroupElement::Scalar>,
     pub(in crate::sign) public_key_share: GroupElement,
-    pub(in crate::sign) nonce_share_commitment_randomness: GroupElement::Scalar,
-    pub(in crate::sign) nonce_share: GroupElement::Scalar,
+    pub(in crate::sign) nonce_share_commitment_randomness: Zeroizing<GroupElement::Scalar>,
+    pub(in crate::sign) nonce_share: Zeroizing<GroupElement::Scalar>,
     pub(in crate::sign) decentralized_party_nonce_public_share: GroupElement,
     pub(in crate::sign) encrypted_mask: EncryptionKey::CiphertextSpaceGroupElement,
     pub(in crate::sign) encrypted_masked_key_share: EncryptionKey::CiphertextSpaceGroupElement,
@@ -77,7 +80,7 @@ impl<
         GroupElement: PrimeGroupElement<SCALAR_LIMBS> + AffineXCoordinate<SCALAR_LIMBS> + group::HashToGroup,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     Party<
@@ -236,7 +239,7 @@ where
         >::prove(
             &self.protocol_context,
             &language_public_parameters,
-            vec![[self.nonce_share, self.nonce_share_commitment_randomness].into()], /* = [k_A, ρ_1] */
+            vec![[*self.nonce_share, *self.nonce_share_commitment_randomness].into()], /* = [k_A, ρ_1] */
             rng,
         )?;
 
@@ -275,8 +278,8 @@ where
             &self.protocol_context,
             &language_public_parameters,
             vec![[
-                self.nonce_share,                               // = k_A
-                self.nonce_share_commitment_randomness,         // = ρ_1
+                *self.nonce_share,                              // = k_A
+                *self.nonce_share_commitment_randomness,        // = ρ_1
                 nonce_share_by_key_share_commitment_randomness, // = ρ_2
             ]
             .into()],
@@ -286,26 +289,26 @@ where
 
         // = U_A
         let nonce_share_by_key_share_commitment =
-            statement.altered_base_committment_of_discrete_log().clone();
+            *statement.altered_base_committment_of_discrete_log();
 
         // = r
         let nonce_x_coordinate = public_nonce.x();
 
         // = a_1
-        let first_coefficient = (nonce_x_coordinate * self.nonce_share * self.secret_key_share)
-            + (message * self.nonce_share);
+        let first_coefficient = (nonce_x_coordinate * *self.nonce_share * *self.secret_key_share)
+            + (message * *self.nonce_share);
 
         // = r * ρ_2 + m * ρ_1
         let first_coefficient_commitment_randomness = (nonce_x_coordinate
             * nonce_share_by_key_share_commitment_randomness)
-            + (message * self.nonce_share_commitment_randomness);
+            + (message * *self.nonce_share_commitment_randomness);
 
         // = a_2
-        let second_coefficient = nonce_x_coordinate * self.nonce_share;
+        let second_coefficient = nonce_x_coordinate * *self.nonce_share;
 
         // = r * ρ_1
         let second_coefficient_commitment_randomness =
-            nonce_x_coordinate * self.nonce_share_commitment_randomness;
+            nonce_x_coordinate * *self.nonce_share_commitment_randomness;
 
         // === Sample η_eval ===
         // Protocol 6, step 1d
@@ -501,8 +504,8 @@ where
     }
 
     pub fn new<
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
     >(
         protocol_context: ProtocolContext,
         protocol_public_parameters: ProtocolPublicParameters<
@@ -535,36 +538,41 @@ where
         let encryption_scheme_public_parameters =
             protocol_public_parameters.encryption_scheme_public_parameters;
 
-        let public_key = GroupElement::new(dkg_output.public_key, &group_public_parameters)?;
+        let public_key =
+            GroupElement::new(dkg_output.public_key.clone(), &group_public_parameters)?;
 
-        let secret_key_share = GroupElement::Scalar::new(
+        let secret_key_share = Zeroizing::new(GroupElement::Scalar::new(
             dkg_output.secret_key_share,
             &scalar_group_public_parameters,
-        )?;
+        )?);
 
-        let public_key_share =
-            GroupElement::new(dkg_output.public_key_share, &group_public_parameters)?;
+        let public_key_share = GroupElement::new(
+            dkg_output.public_key_share.clone(),
+            &group_public_parameters,
+        )?;
 
-        let nonce_share_commitment_randomness = GroupElement::Scalar::new(
+        let nonce_share_commitment_randomness = Zeroizing::new(GroupElement::Scalar::new(
             presign.commitment_randomness,
             &scalar_group_public_parameters,
-        )?;
+        )?);
 
-        let nonce_share =
-            GroupElement::Scalar::new(presign.nonce_share, &scalar_group_public_parameters)?;
+        let nonce_share = Zeroizing::new(GroupElement::Scalar::new(
+            presign.nonce_share,
+            &scalar_group_public_parameters,
+        )?);
 
         let decentralized_party_nonce_public_share = GroupElement::new(
-            presign.decentralized_party_nonce_public_share,
+            presign.decentralized_party_nonce_public_share.clone(),
             &group_public_parameters,
         )?;
 
         let encrypted_mask = EncryptionKey::CiphertextSpaceGroupElement::new(
-            presign.encrypted_mask,
+            presign.encrypted_mask.clone(),
             encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
         )?;
 
         let encrypted_masked_key_share = EncryptionKey::CiphertextSpaceGroupElement::new(
-            presign.encrypted_masked_key_share,
+            presign.encrypted_masked_key_share.clone(),
             encryption_scheme_public_parameters.ciphertext_space_public_parameters(),
         )?;
 
@@ -1,6 +1,8 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+use alloc::vec;
+
 use commitment::Pedersen;
 use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
 use enhanced_maurer::{committed_linear_evaluation, EnhanceableLanguage};
@@ -14,6 +16,7 @@ use maurer::{
 };
 use proof::AggregatableRangeProof;
 use serde::Serialize;
+use zeroize::Zeroize;
 
 use crate::{
     presign,
@@ -87,7 +90,7 @@ where
         const NUM_RANGE_CLAIMS: usize,
         GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >(
         threshold: PartyID,
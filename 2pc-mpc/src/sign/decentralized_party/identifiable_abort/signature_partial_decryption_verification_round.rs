@@ -1,10 +1,13 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::{HashMap, HashSet};
+use alloc::{vec, vec::Vec};
 
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::PartyID;
+use group::{
+    collections::{HashMap, HashSet},
+    PartyID,
+};
 use homomorphic_encryption::{
     AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
 };
@@ -1,6 +1,8 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+use alloc::vec;
+
 use commitment::{pedersen, GroupsPublicParametersAccessors as _, Pedersen};
 use crypto_bigint::{rand_core::CryptoRngCore, CheckedMul, Encoding, Uint};
 use enhanced_maurer::{
@@ -17,6 +19,7 @@ use maurer::{
 };
 use proof::{range::PublicParametersAccessors, AggregatableRangeProof};
 use serde::Serialize;
+use zeroize::Zeroize;
 
 use crate::{
     dkg, presign,
@@ -39,7 +42,7 @@ pub struct Party<
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedDComEvalWitness: group::GroupElement + Samplable,
+    UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
     ProtocolContext: Clone + Serialize,
 > {
     pub(in crate::sign) threshold: PartyID,
@@ -75,7 +78,7 @@ impl<
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
         DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedDComEvalWitness: group::GroupElement + Samplable,
+        UnboundedDComEvalWitness: group::GroupElement + Samplable + Zeroize,
         ProtocolContext: Clone + Serialize,
     >
     Party<
@@ -332,7 +335,9 @@ where
     ) -> crate::Result<()> {
         // = R
         let public_nonce = GroupElement::new(
-            public_nonce_encrypted_partial_signature_and_proof.public_nonce,
+            public_nonce_encrypted_partial_signature_and_proof
+                .public_nonce
+                .clone(),
             group_public_parameters,
         )?;
 
@@ -365,8 +370,8 @@ where
                 protocol_context,
                 &language_public_parameters,
                 vec![[
-                    centralized_party_nonce_share_commitment.clone(), // = K_A
-                    nonce_public_share.clone(),                       // = R_B
+                    centralized_party_nonce_share_commitment, // = K_A
+                    nonce_public_share,                       // = R_B
                 ]
                 .into()],
             )?;
@@ -381,7 +386,7 @@ where
                 scalar_group_public_parameters.clone(),
                 group_public_parameters.clone(),
                 commitment_scheme_public_parameters.clone(),
-                centralized_party_public_key_share.clone(),
+                centralized_party_public_key_share,
             );
 
         // = U_A
@@ -398,8 +403,8 @@ where
                 protocol_context,
                 &language_public_parameters,
                 vec![[
-                    centralized_party_nonce_share_commitment.clone(), // = K_A
-                    nonce_share_by_key_share_commitment.clone(),      // = U_A
+                    centralized_party_nonce_share_commitment, // = K_A
+                    nonce_share_by_key_share_commitment,      // = U_A
                 ]
                 .into()],
             )?;
@@ -504,8 +509,8 @@ where
                         encrypted_partial_signature.clone(),
                         [
                             ((nonce_x_coordinate * nonce_share_by_key_share_commitment)
-                                + (message * &centralized_party_nonce_share_commitment)),
-                            (nonce_x_coordinate * &centralized_party_nonce_share_commitment),
+                                + (message * centralized_party_nonce_share_commitment)),
+                            (nonce_x_coordinate * centralized_party_nonce_share_commitment),
                         ]
                         .into(),
                     )
@@ -631,8 +636,8 @@ where
     }
 
     pub fn new<
-        UnboundedEncDLWitness: group::GroupElement + Samplable,
-        UnboundedEncDHWitness: group::GroupElement + Samplable,
+        UnboundedEncDLWitness: group::GroupElement + Samplable + Zeroize,
+        UnboundedEncDHWitness: group::GroupElement + Samplable + Zeroize,
     >(
         threshold: PartyID,
         decryption_key_share: DecryptionKeyShare,
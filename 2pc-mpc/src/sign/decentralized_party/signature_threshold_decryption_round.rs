@@ -1,23 +1,25 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::{
-    collections::{HashMap, HashSet},
-    ops::Neg,
-};
+use alloc::{boxed::Box, vec::Vec};
+use core::ops::Neg;
 
-use crypto_bigint::{NonZero, Uint};
+use crypto_bigint::{rand_core::CryptoRngCore, NonZero, Uint};
 use group::{
+    collections::{HashMap, HashSet},
+    wire::WireEncoding,
     AffineXCoordinate, GroupElement, Invert, KnownOrderGroupElement, PartyID, PrimeGroupElement,
     Reduce,
 };
 use homomorphic_encryption::{
     AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
 };
+use proof::session::{Round, Session, Transition};
+use serde::{Deserialize, Serialize};
 
 use crate::{sign::verify_signature, Error};
 
-#[cfg_attr(feature = "benchmarking", derive(Clone))]
+#[cfg_attr(any(test, feature = "benchmarking"), derive(Clone))]
 pub struct Party<
     const SCALAR_LIMBS: usize,
     const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
@@ -156,6 +158,43 @@ where
         Ok((self.nonce_x_coordinate, signature_s))
     }
 
+    /// Instantiates a [`Session`] in which each of the `decrypters` broadcasts its decryption
+    /// shares, and then threshold decrypts the signature by itself via [`Self::decrypt_signature`].
+    ///
+    /// Decrypters which do not send their decryption shares are identified by a
+    /// [`Error::UnresponsiveParties`] abort, and ones which send malformed decryption shares by a
+    /// [`Error::InvalidDecryptionShares`] abort.
+    pub fn initialize_session(
+        self,
+        party_id: PartyID,
+        number_of_parties: PartyID,
+        decrypters: HashSet<PartyID>,
+        decryption_shares: (
+            DecryptionKeyShare::DecryptionShare,
+            DecryptionKeyShare::DecryptionShare,
+        ),
+    ) -> crate::Result<Session<(GroupElement::Scalar, GroupElement::Scalar), Error>>
+    where
+        GroupElement: 'static,
+        EncryptionKey: 'static,
+        DecryptionKeyShare: 'static,
+        DecryptionKeyShare::DecryptionShare: Serialize + for<'a> Deserialize<'a>,
+    {
+        if decrypters.len() != usize::from(self.threshold) || !decrypters.contains(&party_id) {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(Session::new(
+            party_id,
+            DecryptionSharesRound {
+                decryption_shares: decryption_shares.to_wire_bytes()?,
+                number_of_parties,
+                decrypters,
+                party: self,
+            },
+        ))
+    }
+
     /// A wrapper function for [`Self::verify_decrypted_signature`] that uses self's
     /// attributes to verify the decrypted signature.
     pub fn verify_decrypted_signature_wrapper(
@@ -186,3 +225,207 @@ where
         Ok((nonce_x_coordinate, signature_s))
     }
 }
+
+/// The round of a signing [`Session`] in which decryption shares are broadcast.
+struct DecryptionSharesRound<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+> {
+    decryption_shares: Vec<u8>,
+    number_of_parties: PartyID,
+    decrypters: HashSet<PartyID>,
+    party: Party<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+    >,
+}
+
+/// The round of a signing [`Session`] in which the signature is threshold decrypted.
+struct ThresholdDecryptionRound<
+    const SCALAR_LIMBS: usize,
+    const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+    EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
+    DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>,
+> {
+    number_of_parties: PartyID,
+    decrypters: HashSet<PartyID>,
+    party: Party<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+    >,
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>
+            + AffineXCoordinate<SCALAR_LIMBS>
+            + group::HashToGroup
+            + 'static,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS> + 'static,
+        DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>
+            + 'static,
+    > Round<(GroupElement::Scalar, GroupElement::Scalar), Error>
+    for DecryptionSharesRound<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+    >
+where
+    Error: From<DecryptionKeyShare::Error>,
+    DecryptionKeyShare::DecryptionShare: Serialize + for<'a> Deserialize<'a>,
+{
+    fn advance(
+        self: Box<Self>,
+        _messages: HashMap<PartyID, Vec<u8>>,
+        _rng: &mut dyn CryptoRngCore,
+    ) -> crate::Result<
+        Transition<
+            Box<dyn Round<(GroupElement::Scalar, GroupElement::Scalar), Error>>,
+            (GroupElement::Scalar, GroupElement::Scalar),
+        >,
+    > {
+        Ok(Transition::Continue {
+            message: self.decryption_shares,
+            next: Box::new(ThresholdDecryptionRound {
+                number_of_parties: self.number_of_parties,
+                decrypters: self.decrypters,
+                party: self.party,
+            }),
+        })
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS>
+            + AffineXCoordinate<SCALAR_LIMBS>
+            + group::HashToGroup
+            + 'static,
+        EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS> + 'static,
+        DecryptionKeyShare: AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, EncryptionKey>
+            + 'static,
+    > Round<(GroupElement::Scalar, GroupElement::Scalar), Error>
+    for ThresholdDecryptionRound<
+        SCALAR_LIMBS,
+        PLAINTEXT_SPACE_SCALAR_LIMBS,
+        GroupElement,
+        EncryptionKey,
+        DecryptionKeyShare,
+    >
+where
+    Error: From<DecryptionKeyShare::Error>,
+    DecryptionKeyShare::DecryptionShare: Serialize + for<'a> Deserialize<'a>,
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        _rng: &mut dyn CryptoRngCore,
+    ) -> crate::Result<
+        Transition<
+            Box<dyn Round<(GroupElement::Scalar, GroupElement::Scalar), Error>>,
+            (GroupElement::Scalar, GroupElement::Scalar),
+        >,
+    > {
+        let decryption_shares: HashMap<_, _> = messages
+            .into_iter()
+            .filter(|(party_id, _)| self.decrypters.contains(party_id))
+            .map(|(party_id, message)| {
+                (
+                    party_id,
+                    <(
+                        DecryptionKeyShare::DecryptionShare,
+                        DecryptionKeyShare::DecryptionShare,
+                    )>::from_wire_bytes(&message),
+                )
+            })
+            .collect();
+
+        // A decrypter which sent decryption shares that fail to decode did respond, and is blamed
+        // for sending invalid ones.
+        let mut parties_sending_invalid_decryption_shares: Vec<_> = decryption_shares
+            .iter()
+            .filter(|(_, decryption_shares)| decryption_shares.is_err())
+            .map(|(party_id, _)| *party_id)
+            .collect();
+        parties_sending_invalid_decryption_shares.sort();
+
+        if !parties_sending_invalid_decryption_shares.is_empty() {
+            return Err(Error::InvalidDecryptionShares(
+                parties_sending_invalid_decryption_shares,
+            ));
+        }
+
+        let decryption_shares: HashMap<_, _> = decryption_shares
+            .into_iter()
+            .filter_map(|(party_id, decryption_shares)| {
+                decryption_shares
+                    .ok()
+                    .map(|decryption_shares| (party_id, decryption_shares))
+            })
+            .collect();
+
+        let mut unresponsive_parties: Vec<_> = self
+            .decrypters
+            .iter()
+            .filter(|party_id| !decryption_shares.contains_key(party_id))
+            .copied()
+            .collect();
+        unresponsive_parties.sort();
+
+        if !unresponsive_parties.is_empty() {
+            return Err(Error::UnresponsiveParties(unresponsive_parties));
+        }
+
+        let decrypters: Vec<_> = self.decrypters.into_iter().collect();
+        let lagrange_coefficients = decrypters
+            .iter()
+            .map(|&party_id| {
+                (
+                    party_id,
+                    DecryptionKeyShare::compute_lagrange_coefficient(
+                        party_id,
+                        self.number_of_parties,
+                        decrypters.clone(),
+                        &self.party.decryption_key_share_public_parameters,
+                    ),
+                )
+            })
+            .collect();
+
+        let (partial_signature_decryption_shares, masked_nonce_decryption_shares) =
+            decryption_shares
+                .into_iter()
+                .map(
+                    |(
+                        party_id,
+                        (partial_signature_decryption_share, masked_nonce_decryption_share),
+                    )| {
+                        (
+                            (party_id, partial_signature_decryption_share),
+                            (party_id, masked_nonce_decryption_share),
+                        )
+                    },
+                )
+                .unzip();
+
+        Ok(Transition::Finalize(self.party.decrypt_signature(
+            lagrange_coefficients,
+            partial_signature_decryption_shares,
+            masked_nonce_decryption_shares,
+        )?))
+    }
+}
@@ -0,0 +1,300 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use group::{self_product, HashToGroup, PrimeGroupElement};
+use serde::{Deserialize, Serialize};
+
+use crate::{GroupsPublicParameters, GroupsPublicParametersAccessors, HomomorphicCommitmentScheme};
+
+/// The domain separation tag used for hashing to the group in [`PublicParameters::derive`].
+pub const GENERATORS_DST: &[u8] = b"commitment/elgamal: generators";
+
+/// An ElGamal Commitment:
+/// $$\Com_\pp(m;\rho):=(\rho\cdot G, m\cdot G + \rho\cdot H)$$
+///
+/// Unlike [`Pedersen`](crate::Pedersen), this commitment is perfectly binding: $\rho$ is
+/// determined by the first component, and then $m$ by the second. It is only computationally
+/// hiding, under the DDH assumption in the group, and as long as the discrete log of $H$ (the
+/// "public key") is unknown, which [`PublicParameters::derive`] assures by hashing it to the
+/// group.
+///
+/// The message space is that of a [`Pedersen`](crate::Pedersen) commitment to a single message,
+/// so that the two can be used interchangeably, e.g. for proving they commit to the same value.
+#[derive(PartialEq, Clone, Debug, Eq)]
+pub struct ElGamal<const SCALAR_LIMBS: usize, GroupElement> {
+    generator: GroupElement,
+    public_key: GroupElement,
+}
+
+impl<const SCALAR_LIMBS: usize, GroupElement> HomomorphicCommitmentScheme<SCALAR_LIMBS>
+    for ElGamal<SCALAR_LIMBS, GroupElement>
+where
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+{
+    type MessageSpaceGroupElement = self_product::GroupElement<1, GroupElement::Scalar>;
+    type RandomnessSpaceGroupElement = GroupElement::Scalar;
+    type CommitmentSpaceGroupElement = self_product::GroupElement<2, GroupElement>;
+    type PublicParameters = PublicParameters<
+        GroupElement::Value,
+        group::PublicParameters<GroupElement::Scalar>,
+        GroupElement::PublicParameters,
+    >;
+
+    fn new(public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        let generator = GroupElement::new(
+            public_parameters.generator.clone(),
+            &public_parameters
+                .commitment_space_public_parameters()
+                .public_parameters,
+        )?;
+
+        let public_key = GroupElement::new(
+            public_parameters.public_key.clone(),
+            &public_parameters
+                .commitment_space_public_parameters()
+                .public_parameters,
+        )?;
+
+        // Binding is perfect only if the generator generates the (prime-order) group.
+        if bool::from(generator.is_neutral()) {
+            return Err(crate::Error::InvalidPublicParameters);
+        }
+
+        Ok(Self {
+            generator,
+            public_key,
+        })
+    }
+
+    fn commit(
+        &self,
+        message: &self_product::GroupElement<1, GroupElement::Scalar>,
+        randomness: &GroupElement::Scalar,
+    ) -> self_product::GroupElement<2, GroupElement> {
+        let [message]: &[GroupElement::Scalar; 1] = message.into();
+
+        // $$\Com_\pp(m;\rho):=(\rho\cdot G, m\cdot G + \rho\cdot H)$$.
+        [
+            self.generator.scalar_mul(&(*randomness).into()),
+            self.generator.scalar_mul(&(*message).into())
+                + self.public_key.scalar_mul(&(*randomness).into()),
+        ]
+        .into()
+    }
+}
+
+pub type MessageSpaceGroupElement<Scalar> = self_product::GroupElement<1, Scalar>;
+pub type MessageSpacePublicParameters<Scalar> =
+    group::PublicParameters<MessageSpaceGroupElement<Scalar>>;
+pub type RandomnessSpaceGroupElement<Scalar> = Scalar;
+pub type RandomnessSpacePublicParameters<Scalar> =
+    group::PublicParameters<RandomnessSpaceGroupElement<Scalar>>;
+pub type CommitmentSpaceGroupElement<GroupElement> = self_product::GroupElement<2, GroupElement>;
+pub type CommitmentSpacePublicParameters<GroupElement> =
+    group::PublicParameters<CommitmentSpaceGroupElement<GroupElement>>;
+
+/// The Public Parameters of an ElGamal Commitment.
+///
+/// Use [`PublicParameters::derive`] (or [`PublicParameters::derive_default`]) unless the public
+/// key must be a specific one, e.g. one whose discrete log is held by an auditor who can then
+/// extract the committed values.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PublicParameters<GroupElementValue, ScalarPublicParameters, GroupPublicParameters> {
+    pub groups_public_parameters: GroupsPublicParameters<
+        self_product::PublicParameters<1, ScalarPublicParameters>,
+        ScalarPublicParameters,
+        self_product::PublicParameters<2, GroupPublicParameters>,
+    >,
+    pub generator: GroupElementValue,
+    pub public_key: GroupElementValue,
+}
+
+impl<GroupElementValue: Clone, ScalarPublicParameters: Clone, GroupPublicParameters: Clone>
+    PublicParameters<GroupElementValue, ScalarPublicParameters, GroupPublicParameters>
+{
+    pub fn derive_default<const SCALAR_LIMBS: usize, GroupElement>() -> crate::Result<Self>
+    where
+        GroupElement::Scalar: group::GroupElement<PublicParameters = ScalarPublicParameters>,
+        GroupElement: group::GroupElement<
+            Value = GroupElementValue,
+            PublicParameters = GroupPublicParameters,
+        >,
+        ScalarPublicParameters: Default,
+        GroupPublicParameters: Default,
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + HashToGroup,
+    {
+        Self::derive::<SCALAR_LIMBS, GroupElement>(
+            ScalarPublicParameters::default(),
+            GroupPublicParameters::default(),
+        )
+    }
+
+    /// Derives the public parameters of the group identified by `group_public_parameters`: the
+    /// generator $G$ is the generator of the group, and the public key $H$ is hashed to the group
+    /// (under [`GENERATORS_DST`]), so that its discrete log is unknown.
+    pub fn derive<const SCALAR_LIMBS: usize, GroupElement>(
+        scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: group::PublicParameters<GroupElement>,
+    ) -> crate::Result<Self>
+    where
+        GroupElement::Scalar: group::GroupElement<PublicParameters = ScalarPublicParameters>,
+        GroupElement: group::GroupElement<Value = GroupElementValue, PublicParameters = GroupPublicParameters>
+            + PrimeGroupElement<SCALAR_LIMBS>
+            + HashToGroup,
+    {
+        let generator =
+            GroupElement::generator_value_from_public_parameters(&group_public_parameters);
+        let public_key =
+            GroupElement::hash_to_group(b"commitment/elgamal: public key", GENERATORS_DST)?.value();
+
+        Ok(Self::new::<SCALAR_LIMBS, GroupElement>(
+            scalar_public_parameters,
+            group_public_parameters,
+            generator,
+            public_key,
+        ))
+    }
+
+    /// This function allows using a custom generator and public key.
+    ///
+    /// Binding holds for any public key, but hiding does not hold for whoever knows its discrete
+    /// log, so this should only be used when that is intended, or for compatability with public
+    /// parameters that were derived safely elsewhere.
+    pub fn new<const SCALAR_LIMBS: usize, GroupElement>(
+        scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: group::PublicParameters<GroupElement>,
+        generator: group::Value<GroupElement>,
+        public_key: group::Value<GroupElement>,
+    ) -> Self
+    where
+        GroupElement::Scalar: group::GroupElement<PublicParameters = ScalarPublicParameters>,
+        GroupElement: group::GroupElement<Value = GroupElementValue, PublicParameters = GroupPublicParameters>
+            + PrimeGroupElement<SCALAR_LIMBS>,
+    {
+        Self {
+            groups_public_parameters: GroupsPublicParameters {
+                message_space_public_parameters: self_product::PublicParameters::new(
+                    scalar_public_parameters.clone(),
+                ),
+                randomness_space_public_parameters: scalar_public_parameters,
+                commitment_space_public_parameters: self_product::PublicParameters::new(
+                    group_public_parameters,
+                ),
+            },
+            generator,
+            public_key,
+        }
+    }
+}
+
+impl<GroupElementValue, ScalarPublicParameters, GroupPublicParameters>
+    AsRef<
+        GroupsPublicParameters<
+            self_product::PublicParameters<1, ScalarPublicParameters>,
+            ScalarPublicParameters,
+            self_product::PublicParameters<2, GroupPublicParameters>,
+        >,
+    > for PublicParameters<GroupElementValue, ScalarPublicParameters, GroupPublicParameters>
+{
+    fn as_ref(
+        &self,
+    ) -> &GroupsPublicParameters<
+        self_product::PublicParameters<1, ScalarPublicParameters>,
+        ScalarPublicParameters,
+        self_product::PublicParameters<2, GroupPublicParameters>,
+    > {
+        &self.groups_public_parameters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{secp256k1, CyclicGroupElement, GroupElement as _, Samplable};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    type SecpElGamal = ElGamal<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>;
+
+    #[test]
+    fn commits() {
+        let public_parameters = PublicParameters::derive_default::<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::GroupElement,
+        >()
+        .unwrap();
+        let commitment_scheme = SecpElGamal::new(&public_parameters).unwrap();
+
+        let message = secp256k1::Scalar::sample(
+            public_parameters.randomness_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+        let randomness = secp256k1::Scalar::sample(
+            public_parameters.randomness_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+
+        let generator = secp256k1::GroupElement::generator_from_public_parameters(
+            &public_parameters
+                .commitment_space_public_parameters()
+                .public_parameters,
+        )
+        .unwrap();
+        let public_key = secp256k1::GroupElement::hash_to_group(
+            b"commitment/elgamal: public key",
+            GENERATORS_DST,
+        )
+        .unwrap();
+        assert_eq!(public_parameters.public_key, public_key.value());
+
+        let [first_component, second_component]: [secp256k1::GroupElement; 2] = commitment_scheme
+            .commit(&[message].into(), &randomness)
+            .into();
+        assert_eq!(first_component, randomness * generator);
+        assert_eq!(
+            second_component,
+            message * generator + randomness * public_key
+        );
+    }
+
+    #[test]
+    fn rejects_neutral_generator() {
+        let mut public_parameters = PublicParameters::derive_default::<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::GroupElement,
+        >()
+        .unwrap();
+
+        public_parameters.generator = secp256k1::GroupElement::new(
+            public_parameters.generator,
+            &public_parameters
+                .commitment_space_public_parameters()
+                .public_parameters,
+        )
+        .unwrap()
+        .neutral()
+        .value();
+
+        assert_eq!(
+            SecpElGamal::new(&public_parameters),
+            Err(crate::Error::InvalidPublicParameters)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "test_helpers")]
+    fn test_homomorphic_commitment_scheme() {
+        let public_parameters = PublicParameters::derive_default::<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::GroupElement,
+        >()
+        .unwrap();
+
+        crate::test_helpers::test_homomorphic_commitment_scheme::<
+            { secp256k1::SCALAR_LIMBS },
+            SecpElGamal,
+        >(&public_parameters);
+    }
+}
@@ -0,0 +1,171 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::string::String;
+use core::marker::PhantomData;
+
+use crypto_bigint::{rand_core::CryptoRngCore, subtle::ConstantTimeEq, Random};
+use group::{ComputationalSecuritySizedNumber, PartyID};
+use merlin::Transcript;
+use serde::{Deserialize, Serialize};
+
+use crate::{Commitment, Error, Result};
+
+/// A hash commitment to a value of type `T` by party `party_id`, computed with
+/// [`Commitment::commit_transcript()`] over the wire encoding of the value.
+///
+/// Both the committing party and the verifier instantiate the scheme with the same `party_id`
+/// (of the committing party) and `context`, so that a commitment cannot be replayed by another
+/// party or in another context. Any public data both sides agree upon (e.g. public parameters)
+/// can be bound to the commitment as well with [`Self::new_with_transcript()`].
+#[derive(Clone)]
+pub struct HashCommitment<T> {
+    party_id: PartyID,
+    context: String,
+    transcript: Transcript,
+    _value_choice: PhantomData<T>,
+}
+
+/// The opening of a [`HashCommitment`], sent by the committing party in the decommitment round.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Decommitment<T> {
+    pub value: T,
+    pub commitment_randomness: ComputationalSecuritySizedNumber,
+}
+
+impl<T: Serialize> HashCommitment<T> {
+    /// Instantiate the commitment scheme of `party_id` for `context`, e.g., a string containing
+    /// the protocol name & round name.
+    pub fn new(party_id: PartyID, context: String) -> Self {
+        Self::new_with_transcript(party_id, context, Transcript::new(b"hash commitment"))
+    }
+
+    /// Instantiate the commitment scheme of `party_id` for `context`, binding the commitment to
+    /// the public data already appended to `transcript`.
+    pub fn new_with_transcript(party_id: PartyID, context: String, transcript: Transcript) -> Self {
+        Self {
+            party_id,
+            context,
+            transcript,
+            _value_choice: PhantomData,
+        }
+    }
+
+    /// Commits to `value` with freshly sampled randomness, returning the commitment to send now
+    /// and the decommitment to send once all commitments are received.
+    pub fn commit(
+        &self,
+        value: &T,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Commitment, Decommitment<T>)>
+    where
+        T: Clone,
+    {
+        let commitment_randomness = ComputationalSecuritySizedNumber::random(rng);
+        let commitment = self.commit_with_randomness(value, &commitment_randomness)?;
+
+        Ok((
+            commitment,
+            Decommitment {
+                value: value.clone(),
+                commitment_randomness,
+            },
+        ))
+    }
+
+    /// Verifies that `decommitment` opens `commitment`, comparing the commitments in
+    /// constant-time.
+    ///
+    /// Returns [`Error::WrongDecommitment`] otherwise.
+    pub fn verify(&self, commitment: &Commitment, decommitment: &Decommitment<T>) -> Result<()> {
+        let reconstructed_commitment =
+            self.commit_with_randomness(&decommitment.value, &decommitment.commitment_randomness)?;
+
+        if bool::from(reconstructed_commitment.ct_eq(commitment)) {
+            Ok(())
+        } else {
+            Err(Error::WrongDecommitment)
+        }
+    }
+
+    fn commit_with_randomness(
+        &self,
+        value: &T,
+        commitment_randomness: &ComputationalSecuritySizedNumber,
+    ) -> Result<Commitment> {
+        let encoded_value = postcard::to_allocvec(value).map_err(|_| Error::Serialization)?;
+
+        let mut transcript = self.transcript.clone();
+        transcript.append_message(b"committed value", &encoded_value);
+
+        Ok(Commitment::commit_transcript(
+            self.party_id,
+            self.context.clone(),
+            &mut transcript,
+            commitment_randomness,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec, vec::Vec};
+
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn verifies_decommitments() {
+        let commitment_scheme = HashCommitment::<Vec<u64>>::new(1, "test".to_string());
+        let (commitment, decommitment) = commitment_scheme
+            .commit(&vec![1, 2, 3], &mut OsRng)
+            .unwrap();
+
+        assert!(commitment_scheme.verify(&commitment, &decommitment).is_ok());
+
+        let wrong_value = Decommitment {
+            value: vec![1, 2, 4],
+            ..decommitment.clone()
+        };
+        assert_eq!(
+            commitment_scheme.verify(&commitment, &wrong_value),
+            Err(Error::WrongDecommitment)
+        );
+
+        let wrong_randomness = Decommitment {
+            commitment_randomness: decommitment
+                .commitment_randomness
+                .wrapping_add(&ComputationalSecuritySizedNumber::ONE),
+            ..decommitment.clone()
+        };
+        assert_eq!(
+            commitment_scheme.verify(&commitment, &wrong_randomness),
+            Err(Error::WrongDecommitment)
+        );
+    }
+
+    #[test]
+    fn binds_party_and_context() {
+        let (commitment, decommitment) = HashCommitment::new(1, "test".to_string())
+            .commit(&42u64, &mut OsRng)
+            .unwrap();
+
+        assert_eq!(
+            HashCommitment::new(2, "test".to_string()).verify(&commitment, &decommitment),
+            Err(Error::WrongDecommitment)
+        );
+        assert_eq!(
+            HashCommitment::new(1, "other test".to_string()).verify(&commitment, &decommitment),
+            Err(Error::WrongDecommitment)
+        );
+
+        let mut transcript = Transcript::new(b"hash commitment");
+        transcript.append_message(b"public parameters", b"");
+        assert_eq!(
+            HashCommitment::new_with_transcript(1, "test".to_string(), transcript)
+                .verify(&commitment, &decommitment),
+            Err(Error::WrongDecommitment)
+        );
+    }
+}
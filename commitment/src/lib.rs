@@ -1,18 +1,42 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod elgamal;
+pub mod hash;
 pub mod multipedersen;
 pub mod pedersen;
+pub mod ring_pedersen;
+pub mod secret_sharing;
+#[cfg(any(test, all(feature = "trapdoor", commitment_trapdoor)))]
+pub mod trapdoor;
+
+// Trapdoors break the binding of commitments, so they must never make it into a release build.
+#[cfg(all(commitment_trapdoor, not(debug_assertions)))]
+compile_error!(
+    "`--cfg commitment_trapdoor` is for simulation-based tests only, and must not be set in \
+     release builds"
+);
 
+use alloc::{string::String, vec, vec::Vec};
 use core::fmt::Debug;
 
-use crypto_bigint::{Concat, Encoding, Limb};
+use crypto_bigint::{subtle::ConstantTimeEq, Concat, Encoding, Limb};
+pub use elgamal::ElGamal;
 use group::{
     BoundedGroupElement, ComputationalSecuritySizedNumber, GroupElement, PartyID, Samplable,
+    VartimeGroupElement,
 };
+pub use hash::HashCommitment;
 use merlin::Transcript;
 pub use multipedersen::MultiPedersen;
 pub use pedersen::Pedersen;
+pub use ring_pedersen::RingPedersen;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 /// Commitment error.
 #[derive(thiserror::Error, Clone, Debug, PartialEq)]
@@ -21,12 +45,16 @@ pub enum Error {
     InvalidPublicParameters,
     #[error("group error")]
     GroupInstantiation(#[from] group::Error),
+    #[error("the decommitment does not match the commitment")]
+    WrongDecommitment,
+    #[error("failed to serialize the committed value")]
+    Serialization,
     #[error("an internal error that should never have happened and signifies a bug")]
     InternalError,
 }
 
 /// Commitment result.
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// Represents an unsigned integer sized based on the commitment size that matches security
 /// parameter, which is double in size, as collisions can be found in the root of the space.
@@ -61,6 +89,12 @@ impl Commitment {
     }
 }
 
+impl ConstantTimeEq for Commitment {
+    fn ct_eq(&self, other: &Self) -> crypto_bigint::subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
 /// A Homomorphic Commitment Scheme
 ///
 /// The commitment algorithm of a non-interactive commitment scheme $\Com_{\pp}$
@@ -77,11 +111,13 @@ pub trait HomomorphicCommitmentScheme<const MESSAGE_SPACE_SCALAR_LIMBS: usize>:
     PartialEq + Clone + Debug + Eq
 {
     /// The Message space group element of the commitment scheme
-    type MessageSpaceGroupElement: BoundedGroupElement<MESSAGE_SPACE_SCALAR_LIMBS> + Samplable;
+    type MessageSpaceGroupElement: BoundedGroupElement<MESSAGE_SPACE_SCALAR_LIMBS>
+        + Samplable
+        + Zeroize;
     /// The Randomness space group element of the commitment scheme
-    type RandomnessSpaceGroupElement: GroupElement + Samplable;
+    type RandomnessSpaceGroupElement: GroupElement + Samplable + Zeroize;
     /// The Commitment space group element of the commitment scheme
-    type CommitmentSpaceGroupElement: GroupElement;
+    type CommitmentSpaceGroupElement: GroupElement + VartimeGroupElement;
 
     /// The public parameters of the commitment scheme $\Com_{\pp}$.
     ///
@@ -1,13 +1,21 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::ops::Mul;
+use alloc::vec::Vec;
+use core::ops::Mul;
 
-use group::{self_product, BoundedGroupElement, Samplable};
+use crypto_bigint::{subtle::ConditionallySelectable, Uint};
+use group::{
+    self_product, BoundedGroupElement, HashToGroup, PrimeGroupElement, Samplable,
+    VartimeGroupElement,
+};
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::{pedersen, GroupsPublicParameters, HomomorphicCommitmentScheme, Pedersen};
 
+pub mod vector;
+
 /// A Batched Pedersen Commitment:
 /// $$\Com_\pp(m;\rho):=\Ped.\Com_{\GG,G,H,q}(\vec{m},\vec{\rho})=
 /// (m_1\cdot G + \rho_1 \cdot H, \ldots, m_n\cdot G + \rho_n \cdot H)$$
@@ -17,7 +25,7 @@ pub struct MultiPedersen<
     const SCALAR_LIMBS: usize,
     Scalar: group::GroupElement,
     GroupElement: group::GroupElement,
->(Pedersen<1, SCALAR_LIMBS, Scalar, GroupElement>);
+>(pub(crate) Pedersen<1, SCALAR_LIMBS, Scalar, GroupElement>);
 
 impl<const BATCH_SIZE: usize, const SCALAR_LIMBS: usize, Scalar, GroupElement>
     HomomorphicCommitmentScheme<SCALAR_LIMBS>
@@ -27,8 +35,10 @@ where
         + Mul<GroupElement, Output = GroupElement>
         + for<'r> Mul<&'r GroupElement, Output = GroupElement>
         + Samplable
-        + Copy,
-    GroupElement: group::GroupElement,
+        + Copy
+        + Into<Uint<SCALAR_LIMBS>>
+        + Zeroize,
+    GroupElement: group::GroupElement + VartimeGroupElement + ConditionallySelectable,
 {
     type MessageSpaceGroupElement = self_product::GroupElement<BATCH_SIZE, Scalar>;
     type RandomnessSpaceGroupElement = self_product::GroupElement<BATCH_SIZE, Scalar>;
@@ -101,7 +111,7 @@ pub struct PublicParameters<
 
 impl<
         const BATCH_SIZE: usize,
-        GroupElementValue: Copy,
+        GroupElementValue,
         ScalarPublicParameters: Clone,
         GroupPublicParameters: Clone,
     >
@@ -154,6 +164,39 @@ impl<
     }
 }
 
+impl<
+        const BATCH_SIZE: usize,
+        GroupElementValue: Clone,
+        ScalarPublicParameters: Clone,
+        GroupPublicParameters: Clone,
+    >
+    PublicParameters<BATCH_SIZE, GroupElementValue, ScalarPublicParameters, GroupPublicParameters>
+{
+    /// Derives the generators of the underlying [`Pedersen`] commitment for `label`, see
+    /// [`pedersen::PublicParameters::derive_with_label`].
+    ///
+    /// These can be verified with [`pedersen::GeneratorsTranscript::verify`] against
+    /// [`Self::pedersen_public_parameters`].
+    pub fn derive_with_label<const SCALAR_LIMBS: usize, GroupElement>(
+        label: &[u8],
+        scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: group::PublicParameters<GroupElement>,
+    ) -> crate::Result<Self>
+    where
+        GroupElement::Scalar: group::GroupElement<PublicParameters = ScalarPublicParameters>,
+        GroupElement: group::GroupElement<Value = GroupElementValue, PublicParameters = GroupPublicParameters>
+            + PrimeGroupElement<SCALAR_LIMBS>
+            + HashToGroup,
+    {
+        pedersen::PublicParameters::derive_with_label::<SCALAR_LIMBS, GroupElement>(
+            label,
+            scalar_public_parameters,
+            group_public_parameters,
+        )
+        .map(Self::from)
+    }
+}
+
 impl<const BATCH_SIZE: usize, GroupElementValue, ScalarPublicParameters, GroupPublicParameters>
     AsRef<
         GroupsPublicParameters<
@@ -235,6 +278,31 @@ mod tests {
         assert_eq!(expected_commitment, commitment[0].into())
     }
 
+    #[test]
+    fn derives_generators_with_labels() {
+        let public_parameters = PublicParameters::<
+            3,
+            group::Value<group::secp256k1::GroupElement>,
+            group::secp256k1::scalar::PublicParameters,
+            group::secp256k1::group_element::PublicParameters,
+        >::derive_with_label::<
+            { group::secp256k1::SCALAR_LIMBS },
+            group::secp256k1::GroupElement,
+        >(b"protocol", Default::default(), Default::default())
+        .unwrap();
+
+        assert!(pedersen::GeneratorsTranscript::<1>::new(b"protocol")
+            .verify::<{ group::secp256k1::SCALAR_LIMBS }, group::secp256k1::GroupElement>(
+                &public_parameters.pedersen_public_parameters
+            )
+            .is_ok());
+        assert!(pedersen::GeneratorsTranscript::<1>::new(&[])
+            .verify::<{ group::secp256k1::SCALAR_LIMBS }, group::secp256k1::GroupElement>(
+                &public_parameters.pedersen_public_parameters
+            )
+            .is_err());
+    }
+
     #[test]
     #[cfg(feature = "test_helpers")]
     fn test_homomorphic_commitment_scheme() {
@@ -0,0 +1,343 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::vec::Vec;
+use core::ops::Mul;
+
+use crypto_bigint::{subtle::ConditionallySelectable, Uint};
+use group::{
+    vector, BoundedGroupElement, HashToGroup, PrimeGroupElement, Samplable, VartimeGroupElement,
+};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+use crate::{pedersen, GroupsPublicParameters, HomomorphicCommitmentScheme, Pedersen};
+
+/// A Batched Pedersen Commitment over a batch whose size is only known at runtime:
+/// $$\Com_\pp(m;\rho):=\Ped.\Com_{\GG,G,H,q}(\vec{m},\vec{\rho})=
+/// (m_1\cdot G + \rho_1 \cdot H, \ldots, m_n\cdot G + \rho_n \cdot H)$$
+///
+/// This is the counterpart of [`super::MultiPedersen`] over [`vector::GroupElement`], where the
+/// batch size $n$ is part of the [`PublicParameters`] rather than a const generic.
+#[derive(PartialEq, Clone, Debug, Eq)]
+pub struct MultiPedersen<
+    const SCALAR_LIMBS: usize,
+    Scalar: group::GroupElement,
+    GroupElement: group::GroupElement,
+>(Pedersen<1, SCALAR_LIMBS, Scalar, GroupElement>);
+
+impl<const SCALAR_LIMBS: usize, Scalar, GroupElement> HomomorphicCommitmentScheme<SCALAR_LIMBS>
+    for MultiPedersen<SCALAR_LIMBS, Scalar, GroupElement>
+where
+    Scalar: BoundedGroupElement<SCALAR_LIMBS>
+        + Mul<GroupElement, Output = GroupElement>
+        + for<'r> Mul<&'r GroupElement, Output = GroupElement>
+        + Samplable
+        + Copy
+        + Into<Uint<SCALAR_LIMBS>>
+        + Zeroize,
+    GroupElement: group::GroupElement + VartimeGroupElement + ConditionallySelectable,
+{
+    type MessageSpaceGroupElement = vector::GroupElement<Scalar>;
+    type RandomnessSpaceGroupElement = vector::GroupElement<Scalar>;
+    type CommitmentSpaceGroupElement = vector::GroupElement<GroupElement>;
+    type PublicParameters = PublicParameters<
+        GroupElement::Value,
+        Scalar::PublicParameters,
+        GroupElement::PublicParameters,
+    >;
+
+    fn new(public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        let batch_size = public_parameters
+            .groups_public_parameters
+            .message_space_public_parameters
+            .size;
+
+        if batch_size == 0
+            || public_parameters
+                .groups_public_parameters
+                .randomness_space_public_parameters
+                .size
+                != batch_size
+            || public_parameters
+                .groups_public_parameters
+                .commitment_space_public_parameters
+                .size
+                != batch_size
+        {
+            return Err(crate::Error::InvalidPublicParameters);
+        }
+
+        Pedersen::new(&public_parameters.pedersen_public_parameters).map(Self)
+    }
+
+    /// Commits to `message` with `randomness`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `message` and `randomness` are of different lengths, i.e., were not instantiated
+    /// over the message and randomness spaces of the same public parameters.
+    fn commit(
+        &self,
+        message: &Self::MessageSpaceGroupElement,
+        randomness: &Self::RandomnessSpaceGroupElement,
+    ) -> Self::CommitmentSpaceGroupElement {
+        // $$\Com_\pp(m;\rho):=\Ped.\Com_{\GG,G,H,q}(\vec{m},\vec{\rho})=
+        // (m_1\cdot G + \rho_1 \cdot H, \ldots, m_n\cdot G + \rho_n \cdot H)$$
+        let messages: &[Scalar] = message.into();
+        let randomnesses: &[Scalar] = randomness.into();
+
+        assert_eq!(
+            messages.len(),
+            randomnesses.len(),
+            "the message and randomness must be of the same length"
+        );
+
+        messages
+            .iter()
+            .zip(randomnesses)
+            .map(|(message, randomness)| self.0.commit(&[*message].into(), randomness))
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+pub type MessageSpaceGroupElement<Scalar> = vector::GroupElement<Scalar>;
+pub type MessageSpacePublicParameters<Scalar> =
+    group::PublicParameters<MessageSpaceGroupElement<Scalar>>;
+pub type RandomnessSpaceGroupElement<Scalar> = vector::GroupElement<Scalar>;
+pub type RandomnessSpacePublicParameters<Scalar> =
+    group::PublicParameters<RandomnessSpaceGroupElement<Scalar>>;
+pub type CommitmentSpaceGroupElement<GroupElement> = vector::GroupElement<GroupElement>;
+pub type CommitmentSpacePublicParameters<GroupElement> =
+    group::PublicParameters<CommitmentSpaceGroupElement<GroupElement>>;
+
+/// The Public Parameters of a runtime-sized Batched Pedersen Commitment.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PublicParameters<GroupElementValue, ScalarPublicParameters, GroupPublicParameters> {
+    pub groups_public_parameters: GroupsPublicParameters<
+        vector::PublicParameters<ScalarPublicParameters>,
+        vector::PublicParameters<ScalarPublicParameters>,
+        vector::PublicParameters<GroupPublicParameters>,
+    >,
+    pub pedersen_public_parameters: pedersen::PublicParameters<
+        1,
+        GroupElementValue,
+        ScalarPublicParameters,
+        GroupPublicParameters,
+    >,
+}
+
+impl<GroupElementValue: Clone, ScalarPublicParameters: Clone, GroupPublicParameters: Clone>
+    PublicParameters<GroupElementValue, ScalarPublicParameters, GroupPublicParameters>
+{
+    /// Instantiates the public parameters of a commitment to `batch_size` messages, under the
+    /// generators of `pedersen_public_parameters`.
+    pub fn new(
+        pedersen_public_parameters: pedersen::PublicParameters<
+            1,
+            GroupElementValue,
+            ScalarPublicParameters,
+            GroupPublicParameters,
+        >,
+        batch_size: usize,
+    ) -> Self {
+        Self {
+            groups_public_parameters: GroupsPublicParameters {
+                message_space_public_parameters: vector::PublicParameters::new(
+                    pedersen_public_parameters
+                        .groups_public_parameters
+                        .randomness_space_public_parameters
+                        .clone(),
+                    batch_size,
+                ),
+                randomness_space_public_parameters: vector::PublicParameters::new(
+                    pedersen_public_parameters
+                        .groups_public_parameters
+                        .randomness_space_public_parameters
+                        .clone(),
+                    batch_size,
+                ),
+                commitment_space_public_parameters: vector::PublicParameters::new(
+                    pedersen_public_parameters
+                        .groups_public_parameters
+                        .commitment_space_public_parameters
+                        .clone(),
+                    batch_size,
+                ),
+            },
+            pedersen_public_parameters,
+        }
+    }
+
+    /// Derives the generators of the underlying [`Pedersen`] commitment for `label`, see
+    /// [`pedersen::PublicParameters::derive_with_label`].
+    pub fn derive_with_label<const SCALAR_LIMBS: usize, GroupElement>(
+        label: &[u8],
+        scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: group::PublicParameters<GroupElement>,
+        batch_size: usize,
+    ) -> crate::Result<Self>
+    where
+        GroupElement::Scalar: group::GroupElement<PublicParameters = ScalarPublicParameters>,
+        GroupElement: group::GroupElement<Value = GroupElementValue, PublicParameters = GroupPublicParameters>
+            + PrimeGroupElement<SCALAR_LIMBS>
+            + HashToGroup,
+    {
+        pedersen::PublicParameters::derive_with_label::<SCALAR_LIMBS, GroupElement>(
+            label,
+            scalar_public_parameters,
+            group_public_parameters,
+        )
+        .map(|pedersen_public_parameters| Self::new(pedersen_public_parameters, batch_size))
+    }
+}
+
+impl<GroupElementValue, ScalarPublicParameters, GroupPublicParameters>
+    AsRef<
+        GroupsPublicParameters<
+            vector::PublicParameters<ScalarPublicParameters>,
+            vector::PublicParameters<ScalarPublicParameters>,
+            vector::PublicParameters<GroupPublicParameters>,
+        >,
+    > for PublicParameters<GroupElementValue, ScalarPublicParameters, GroupPublicParameters>
+{
+    fn as_ref(
+        &self,
+    ) -> &GroupsPublicParameters<
+        vector::PublicParameters<ScalarPublicParameters>,
+        vector::PublicParameters<ScalarPublicParameters>,
+        vector::PublicParameters<GroupPublicParameters>,
+    > {
+        &self.groups_public_parameters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{secp256k1, GroupElement as _};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    type SecpMultiPedersen =
+        MultiPedersen<{ secp256k1::SCALAR_LIMBS }, secp256k1::Scalar, secp256k1::GroupElement>;
+
+    fn public_parameters(
+        batch_size: usize,
+    ) -> PublicParameters<
+        group::Value<secp256k1::GroupElement>,
+        secp256k1::scalar::PublicParameters,
+        secp256k1::group_element::PublicParameters,
+    > {
+        PublicParameters::derive_with_label::<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>(
+            b"vector multi-pedersen",
+            Default::default(),
+            Default::default(),
+            batch_size,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn commits_coordinate_wise() {
+        for batch_size in [1, 3, 7] {
+            commits_coordinate_wise_with_batch_size(batch_size);
+        }
+    }
+
+    fn commits_coordinate_wise_with_batch_size(batch_size: usize) {
+        let public_parameters = public_parameters(batch_size);
+        let commitment_scheme = SecpMultiPedersen::new(&public_parameters).unwrap();
+        let pedersen = Pedersen::<
+            1,
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::Scalar,
+            secp256k1::GroupElement,
+        >::new(&public_parameters.pedersen_public_parameters)
+        .unwrap();
+
+        let messages = vector::GroupElement::<secp256k1::Scalar>::sample(
+            &public_parameters
+                .groups_public_parameters
+                .message_space_public_parameters,
+            &mut OsRng,
+        )
+        .unwrap();
+        let randomnesses = vector::GroupElement::<secp256k1::Scalar>::sample(
+            &public_parameters
+                .groups_public_parameters
+                .randomness_space_public_parameters,
+            &mut OsRng,
+        )
+        .unwrap();
+
+        let commitment = commitment_scheme.commit(&messages, &randomnesses);
+        assert_eq!(
+            commitment.public_parameters(),
+            public_parameters
+                .groups_public_parameters
+                .commitment_space_public_parameters
+        );
+
+        let messages: Vec<_> = messages.into();
+        let randomnesses: Vec<_> = randomnesses.into();
+        let commitments: Vec<_> = commitment.into();
+        for ((message, randomness), commitment) in
+            messages.into_iter().zip(randomnesses).zip(commitments)
+        {
+            assert_eq!(commitment, pedersen.commit(&[message].into(), &randomness));
+        }
+    }
+
+    #[test]
+    fn rejects_mismatched_batch_sizes() {
+        assert_eq!(
+            SecpMultiPedersen::new(&public_parameters(0)),
+            Err(crate::Error::InvalidPublicParameters)
+        );
+
+        let mut public_parameters = public_parameters(3);
+        public_parameters
+            .groups_public_parameters
+            .randomness_space_public_parameters
+            .size = 2;
+        assert_eq!(
+            SecpMultiPedersen::new(&public_parameters),
+            Err(crate::Error::InvalidPublicParameters)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the message and randomness must be of the same length")]
+    fn panics_on_mismatched_lengths() {
+        let public_parameters = public_parameters(3);
+        let commitment_scheme = SecpMultiPedersen::new(&public_parameters).unwrap();
+
+        let messages = vector::GroupElement::<secp256k1::Scalar>::sample(
+            &public_parameters
+                .groups_public_parameters
+                .message_space_public_parameters,
+            &mut OsRng,
+        )
+        .unwrap();
+        let randomnesses = vector::GroupElement::<secp256k1::Scalar>::sample(
+            &vector::PublicParameters::new(secp256k1::scalar::PublicParameters::default(), 2),
+            &mut OsRng,
+        )
+        .unwrap();
+
+        commitment_scheme.commit(&messages, &randomnesses);
+    }
+
+    #[test]
+    #[cfg(feature = "test_helpers")]
+    fn test_homomorphic_commitment_scheme() {
+        for batch_size in [1, 4] {
+            crate::test_helpers::test_homomorphic_commitment_scheme::<
+                { secp256k1::SCALAR_LIMBS },
+                SecpMultiPedersen,
+            >(&public_parameters(batch_size));
+        }
+    }
+}
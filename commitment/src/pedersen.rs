@@ -1,17 +1,24 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use core::fmt::Debug;
-use std::{array, marker::PhantomData, ops::Mul};
+use alloc::{format, vec::Vec};
+use core::{array, fmt::Debug, marker::PhantomData, ops::Mul};
 
+use crypto_bigint::{subtle::ConditionallySelectable, Uint};
 use group::{
     helpers::{const_generic_array_serialization, FlatMapResults},
-    self_product, BoundedGroupElement, HashToGroup, PrimeGroupElement, Samplable,
+    ristretto, self_product, BoundedGroupElement, Encoding, FixedBaseTable, HashToGroup,
+    PrimeGroupElement, Samplable, VartimeGroupElement,
 };
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::{GroupsPublicParameters, GroupsPublicParametersAccessors, HomomorphicCommitmentScheme};
 
+/// The domain separation tag used for hashing to the group in
+/// [`PublicParameters::derive_with_label`] for non-empty labels, see [`GeneratorsTranscript`].
+pub const GENERATORS_DST: &[u8] = b"commitment/pedersen: generators";
+
 /// A Batched Pedersen Commitment:
 /// $$\Com_\pp(m;\rho):=\Ped.\Com_{\GG,G,H,q}(\vec{m},\rho)=
 /// m_1\cdot G_1 + \ldots + m_n\cdot G_n + \rho \cdot H$$
@@ -28,10 +35,10 @@ pub struct Pedersen<
     Scalar: group::GroupElement,
     GroupElement: group::GroupElement,
 > {
-    /// The generators used for the messages.
-    message_generators: [GroupElement; BATCH_SIZE],
-    /// The generator used for the randomness.
-    randomness_generator: GroupElement,
+    /// The precomputed tables of the message generators $G_1, \ldots, G_n$.
+    message_generators: [FixedBaseTable<GroupElement>; BATCH_SIZE],
+    /// The precomputed table of the randomness generator $H$.
+    randomness_generator: FixedBaseTable<GroupElement>,
 
     _scalar_choice: PhantomData<Scalar>,
 }
@@ -44,8 +51,10 @@ where
         + Mul<GroupElement, Output = GroupElement>
         + for<'r> Mul<&'r GroupElement, Output = GroupElement>
         + Samplable
-        + Copy,
-    GroupElement: group::GroupElement,
+        + Copy
+        + Into<Uint<SCALAR_LIMBS>>
+        + Zeroize,
+    GroupElement: group::GroupElement + VartimeGroupElement + ConditionallySelectable,
 {
     type MessageSpaceGroupElement = self_product::GroupElement<BATCH_SIZE, Scalar>;
     type RandomnessSpaceGroupElement = Scalar;
@@ -64,6 +73,7 @@ where
 
         let message_generators = public_parameters
             .message_generators
+            .clone()
             .map(|value| {
                 GroupElement::new(
                     value,
@@ -73,13 +83,18 @@ where
             .flat_map_results()?;
 
         let randomness_generator = GroupElement::new(
-            public_parameters.randomness_generator,
+            public_parameters.randomness_generator.clone(),
             public_parameters.commitment_space_public_parameters(),
         )?;
 
+        // The tables are built once per instance, and reused for every commitment made with it.
         Ok(Self {
-            message_generators,
-            randomness_generator,
+            message_generators: message_generators
+                .map(|generator| FixedBaseTable::new(generator, Uint::<SCALAR_LIMBS>::BITS)),
+            randomness_generator: FixedBaseTable::new(
+                randomness_generator,
+                Uint::<SCALAR_LIMBS>::BITS,
+            ),
             _scalar_choice: PhantomData,
         })
     }
@@ -95,10 +110,9 @@ where
             .iter()
             .zip::<&[Scalar; BATCH_SIZE]>(message.into())
             .fold(
-                self.randomness_generator.neutral(),
-                |acc, (generator, value)| acc + (*value * generator),
+                self.randomness_generator.mul(&(*randomness).into()),
+                |acc, (generator, value)| acc + generator.mul(&(*value).into()),
             )
-            + (*randomness * &self.randomness_generator)
     }
 }
 
@@ -117,9 +131,10 @@ pub type CommitmentSpacePublicParameters<GroupElement> =
 ///
 /// This struct should be carefully instantiated,
 /// as wrong choice of generators can break the commitment's binding and/or hiding propert(ies).
-/// We offer a safe instantiation for prime-order groups with [`PublicParameters::derive`] using
-/// `HashToGroup`. Otherwise, it is on the responsibility of the caller to assure their group and
-/// generator instantiation is sound.
+/// We offer a safe instantiation for prime-order groups with [`PublicParameters::derive`] (or
+/// [`PublicParameters::derive_with_label`]) using `HashToGroup`.
+/// Otherwise, it is on the responsibility of the caller to assure their group and generator
+/// instantiation is sound.
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct PublicParameters<
     const BATCH_SIZE: usize,
@@ -162,6 +177,13 @@ impl<
         )
     }
 
+    /// Derives the generators of the group identified by `group_public_parameters` with the
+    /// unlabeled [`GeneratorsTranscript`], shared by all callers of this function.
+    ///
+    /// These are hashed to the group with [`HashToGroup::legacy_hash_to_group()`], so that they
+    /// remain the generators this function has always derived, and commitments and proofs over
+    /// them keep verifying. Use [`Self::derive_with_label`] to derive generators independent of
+    /// those of other protocols, under [`GENERATORS_DST`].
     pub fn derive<const SCALAR_LIMBS: usize, GroupElement>(
         scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
         group_public_parameters: group::PublicParameters<GroupElement>,
@@ -172,22 +194,33 @@ impl<
             + PrimeGroupElement<SCALAR_LIMBS>
             + HashToGroup,
     {
-        let message_generators = array::from_fn(|i| {
-            if i == 0 {
-                GroupElement::generator_from_public_parameters(&group_public_parameters)
-            } else {
-                GroupElement::hash_to_group(
-                    format!("commitment/pedersen: message generator #{:?}", i).as_bytes(),
-                )
-            }
-        })
-        .flat_map_results()?;
-
-        let message_generators = message_generators.map(|element| element.value());
+        Self::derive_with_label::<SCALAR_LIMBS, GroupElement>(
+            &[],
+            scalar_public_parameters,
+            group_public_parameters,
+        )
+    }
 
-        let randomness_generator =
-            GroupElement::hash_to_group("commitment/pedersen: randomness generator".as_bytes())?
-                .value();
+    /// Derives the generators of the group identified by `group_public_parameters` as described
+    /// by [`GeneratorsTranscript::new(label)`](GeneratorsTranscript::new).
+    ///
+    /// Distinct labels yield independent message and randomness generators, i.e. no discrete log
+    /// relation between the generators of different labels is known. The first message generator
+    /// is the generator of the group under any label.
+    pub fn derive_with_label<const SCALAR_LIMBS: usize, GroupElement>(
+        label: &[u8],
+        scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: group::PublicParameters<GroupElement>,
+    ) -> crate::Result<Self>
+    where
+        GroupElement::Scalar: group::GroupElement<PublicParameters = ScalarPublicParameters>,
+        GroupElement: group::GroupElement<Value = GroupElementValue, PublicParameters = GroupPublicParameters>
+            + PrimeGroupElement<SCALAR_LIMBS>
+            + HashToGroup,
+    {
+        let (message_generators, randomness_generator) =
+            GeneratorsTranscript::new(label)
+                .derive::<SCALAR_LIMBS, GroupElement>(&group_public_parameters)?;
 
         Ok(
             Self::new::<SCALAR_LIMBS, GroupElement::Scalar, GroupElement>(
@@ -263,6 +296,189 @@ impl<
     }
 }
 
+impl
+    PublicParameters<
+        1,
+        ristretto::GroupElement,
+        ristretto::scalar::PublicParameters,
+        ristretto::group_element::PublicParameters,
+    >
+{
+    /// Reproduces the generators of Bulletproofs' `PedersenGens::default()`, with which its range
+    /// proofs commit.
+    ///
+    /// The message generator is the generator of the group, i.e. the first message generator of
+    /// [`GeneratorsTranscript`] under any label. The randomness generator is the SHA3-512 digest of
+    /// the encoding of the group generator, mapped to the group (see
+    /// [`ristretto::GroupElement::hash_from_bytes_sha3_512`]), and is therefore *not* derived with
+    /// `HashToGroup`.
+    pub fn bulletproofs_default() -> crate::Result<Self> {
+        let scalar_public_parameters = ristretto::scalar::PublicParameters::default();
+        let group_public_parameters = ristretto::group_element::PublicParameters::default();
+
+        let message_generator: ristretto::GroupElement = GeneratorDerivation::GroupGenerator
+            .derive::<{ ristretto::SCALAR_LIMBS }, _>(&group_public_parameters)?;
+        let randomness_generator =
+            ristretto::GroupElement::hash_from_bytes_sha3_512(&message_generator.to_bytes());
+
+        Ok(Self::new::<
+            { ristretto::SCALAR_LIMBS },
+            ristretto::Scalar,
+            ristretto::GroupElement,
+        >(
+            scalar_public_parameters,
+            group_public_parameters,
+            [message_generator],
+            randomness_generator,
+        ))
+    }
+}
+
+/// How a single Pedersen generator is derived.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum GeneratorDerivation {
+    /// The generator of the group, as returned by
+    /// [`group::GroupElement::generator_from_public_parameters()`].
+    GroupGenerator,
+    /// The output of [`HashToGroup::hash_to_group()`] over `message` under the domain separation
+    /// tag `dst`.
+    HashToGroup { message: Vec<u8>, dst: Vec<u8> },
+    /// The output of [`HashToGroup::legacy_hash_to_group()`] over `message`.
+    LegacyHashToGroup { message: Vec<u8> },
+}
+
+impl GeneratorDerivation {
+    /// Derives the generator of the group identified by `group_public_parameters`.
+    pub fn derive<const SCALAR_LIMBS: usize, GroupElement>(
+        &self,
+        group_public_parameters: &group::PublicParameters<GroupElement>,
+    ) -> crate::Result<GroupElement>
+    where
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + HashToGroup,
+    {
+        Ok(match self {
+            GeneratorDerivation::GroupGenerator => {
+                GroupElement::generator_from_public_parameters(group_public_parameters)?
+            }
+            GeneratorDerivation::HashToGroup { message, dst } => {
+                GroupElement::hash_to_group(message, dst)?
+            }
+            GeneratorDerivation::LegacyHashToGroup { message } => {
+                GroupElement::legacy_hash_to_group(message)?
+            }
+        })
+    }
+}
+
+/// A transcript of how each generator of [`PublicParameters::derive_with_label`] is derived, with
+/// which anyone can verify that given public parameters hold the generators derived for a label.
+///
+/// The first message generator is the generator of the group, and all others are hashed to the
+/// group. For the empty label, these are the generators of [`PublicParameters::derive`]: the
+/// messages `"commitment/pedersen: message generator #i"` and `"commitment/pedersen: randomness
+/// generator"` are hashed with [`HashToGroup::legacy_hash_to_group()`]. Otherwise, the label `l`
+/// is length-prefixed into the messages as `"commitment/pedersen[len(l):l]: ..."`, so that the
+/// messages of distinct labels never collide, and they are hashed under [`GENERATORS_DST`].
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct GeneratorsTranscript<const BATCH_SIZE: usize> {
+    pub label: Vec<u8>,
+    #[serde(with = "const_generic_array_serialization")]
+    pub message_generators: [GeneratorDerivation; BATCH_SIZE],
+    pub randomness_generator: GeneratorDerivation,
+}
+
+impl<const BATCH_SIZE: usize> GeneratorsTranscript<BATCH_SIZE> {
+    /// The transcript of the generators derived for `label`.
+    pub fn new(label: &[u8]) -> Self {
+        let hash_to_group = |name: &str| {
+            if label.is_empty() {
+                return GeneratorDerivation::LegacyHashToGroup {
+                    message: format!("commitment/pedersen: {name}").into_bytes(),
+                };
+            }
+
+            let mut message = b"commitment/pedersen".to_vec();
+            message.extend_from_slice(format!("[{}:", label.len()).as_bytes());
+            message.extend_from_slice(label);
+            message.push(b']');
+            message.extend_from_slice(format!(": {name}").as_bytes());
+
+            GeneratorDerivation::HashToGroup {
+                message,
+                dst: GENERATORS_DST.to_vec(),
+            }
+        };
+
+        Self {
+            label: label.to_vec(),
+            message_generators: array::from_fn(|i| {
+                if i == 0 {
+                    GeneratorDerivation::GroupGenerator
+                } else {
+                    hash_to_group(&format!("message generator #{i}"))
+                }
+            }),
+            randomness_generator: hash_to_group("randomness generator"),
+        }
+    }
+
+    /// Derives the values of the message and randomness generators of the group identified by
+    /// `group_public_parameters`, as described by this transcript.
+    #[allow(clippy::type_complexity)]
+    pub fn derive<const SCALAR_LIMBS: usize, GroupElement>(
+        &self,
+        group_public_parameters: &group::PublicParameters<GroupElement>,
+    ) -> crate::Result<([GroupElement::Value; BATCH_SIZE], GroupElement::Value)>
+    where
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + HashToGroup,
+    {
+        let message_generators = self
+            .message_generators
+            .clone()
+            .map(|derivation| {
+                derivation.derive::<SCALAR_LIMBS, GroupElement>(group_public_parameters)
+            })
+            .flat_map_results()?
+            .map(|generator| generator.value());
+
+        let randomness_generator = self
+            .randomness_generator
+            .derive::<SCALAR_LIMBS, GroupElement>(group_public_parameters)?
+            .value();
+
+        Ok((message_generators, randomness_generator))
+    }
+
+    /// Verifies that `public_parameters` hold the generators described by this transcript.
+    ///
+    /// Returns [`crate::Error::InvalidPublicParameters`] otherwise.
+    pub fn verify<const SCALAR_LIMBS: usize, GroupElement>(
+        &self,
+        public_parameters: &PublicParameters<
+            BATCH_SIZE,
+            GroupElement::Value,
+            group::PublicParameters<GroupElement::Scalar>,
+            group::PublicParameters<GroupElement>,
+        >,
+    ) -> crate::Result<()>
+    where
+        GroupElement: PrimeGroupElement<SCALAR_LIMBS> + HashToGroup,
+    {
+        let (message_generators, randomness_generator) = self
+            .derive::<SCALAR_LIMBS, GroupElement>(
+                public_parameters.commitment_space_public_parameters(),
+            )?;
+
+        if message_generators != public_parameters.message_generators
+            || randomness_generator != public_parameters.randomness_generator
+        {
+            return Err(crate::Error::InvalidPublicParameters);
+        }
+
+        Ok(())
+    }
+}
+
 impl<const BATCH_SIZE: usize, GroupElementValue, ScalarPublicParameters, GroupPublicParameters>
     AsRef<
         GroupsPublicParameters<
@@ -292,7 +508,7 @@ impl<const BATCH_SIZE: usize, GroupElementValue, ScalarPublicParameters, GroupPu
 #[cfg(test)]
 mod tests {
     use bulletproofs::PedersenGens;
-    use group::ristretto;
+    use group::{ristretto, secp256k1, CyclicGroupElement as _, GroupElement as _};
     use rand_core::OsRng;
 
     use super::*;
@@ -342,6 +558,233 @@ mod tests {
         assert_eq!(expected_commitment, commitment)
     }
 
+    #[test]
+    fn derives_generators_with_labels() {
+        type PublicParameters = crate::PublicParameters<
+            { group::secp256k1::SCALAR_LIMBS },
+            Pedersen<
+                2,
+                { group::secp256k1::SCALAR_LIMBS },
+                group::secp256k1::Scalar,
+                group::secp256k1::GroupElement,
+            >,
+        >;
+
+        let derive_with_label = |label: &[u8]| {
+            PublicParameters::derive_with_label::<
+                { group::secp256k1::SCALAR_LIMBS },
+                group::secp256k1::GroupElement,
+            >(label, Default::default(), Default::default())
+            .unwrap()
+        };
+
+        let public_parameters = PublicParameters::derive_default::<
+            { group::secp256k1::SCALAR_LIMBS },
+            group::secp256k1::GroupElement,
+        >()
+        .unwrap();
+        assert_eq!(public_parameters, derive_with_label(&[]));
+
+        let first_public_parameters = derive_with_label(b"first protocol");
+        let second_public_parameters = derive_with_label(b"second protocol");
+        assert_eq!(
+            first_public_parameters.message_generators[0],
+            public_parameters.message_generators[0]
+        );
+        for other_public_parameters in [&public_parameters, &second_public_parameters] {
+            assert_ne!(
+                first_public_parameters.message_generators[1],
+                other_public_parameters.message_generators[1]
+            );
+            assert_ne!(
+                first_public_parameters.randomness_generator,
+                other_public_parameters.randomness_generator
+            );
+        }
+
+        let transcript = GeneratorsTranscript::<2>::new(b"first protocol");
+        assert!(transcript
+            .verify::<{ group::secp256k1::SCALAR_LIMBS }, group::secp256k1::GroupElement>(
+                &first_public_parameters
+            )
+            .is_ok());
+        assert!(matches!(
+            transcript
+                .verify::<{ group::secp256k1::SCALAR_LIMBS }, group::secp256k1::GroupElement>(
+                    &second_public_parameters
+                ),
+            Err(crate::Error::InvalidPublicParameters)
+        ));
+        assert!(matches!(
+            transcript
+                .verify::<{ group::secp256k1::SCALAR_LIMBS }, group::secp256k1::GroupElement>(
+                    &first_public_parameters.with_altered_randomness_generator(
+                        first_public_parameters.message_generators[1]
+                    )
+                ),
+            Err(crate::Error::InvalidPublicParameters)
+        ));
+    }
+
+    #[test]
+    fn derives_legacy_generators() {
+        // The generators `derive()` has always derived, as computed by independent
+        // implementations of hashing to secp256k1 with `expand_message_xof` (SHAKE-256) and to
+        // ristretto255 from the SHA3-512 digest.
+        let secp256k1_public_parameters = crate::PublicParameters::<
+            { secp256k1::SCALAR_LIMBS },
+            Pedersen<2, { secp256k1::SCALAR_LIMBS }, secp256k1::Scalar, secp256k1::GroupElement>,
+        >::derive_default::<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::GroupElement,
+        >()
+        .unwrap();
+        let secp256k1_encoding = |value| {
+            secp256k1::GroupElement::new(
+                value,
+                secp256k1_public_parameters.commitment_space_public_parameters(),
+            )
+            .unwrap()
+            .to_bytes()
+        };
+
+        assert_eq!(
+            secp256k1_public_parameters.message_generators[0],
+            secp256k1::GroupElement::generator_value_from_public_parameters(
+                secp256k1_public_parameters.commitment_space_public_parameters()
+            )
+        );
+        assert_eq!(
+            secp256k1_encoding(secp256k1_public_parameters.message_generators[1]),
+            from_hex("0286e89aa428a86d285d46a55eddaf423e17578e8e92479a72d5a056ac19ef8437")
+        );
+        assert_eq!(
+            secp256k1_encoding(secp256k1_public_parameters.randomness_generator),
+            from_hex("0393500ab9284a4e41644d7cce094434eeb1f9455944088dccdba3e378f11b2a40")
+        );
+
+        let ristretto_public_parameters = crate::PublicParameters::<
+            { ristretto::SCALAR_LIMBS },
+            Pedersen<2, { ristretto::SCALAR_LIMBS }, ristretto::Scalar, ristretto::GroupElement>,
+        >::derive_default::<
+            { ristretto::SCALAR_LIMBS },
+            ristretto::GroupElement,
+        >()
+        .unwrap();
+        let ristretto_encoding = |value| {
+            ristretto::GroupElement::new(
+                value,
+                ristretto_public_parameters.commitment_space_public_parameters(),
+            )
+            .unwrap()
+            .to_bytes()
+        };
+
+        assert_eq!(
+            ristretto_encoding(ristretto_public_parameters.message_generators[0]),
+            from_hex("e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76")
+        );
+        assert_eq!(
+            ristretto_encoding(ristretto_public_parameters.message_generators[1]),
+            from_hex("5c766441d205ee766f91034b807be2c31aa1784842932539769527ecae128b1e")
+        );
+        assert_eq!(
+            ristretto_encoding(ristretto_public_parameters.randomness_generator),
+            from_hex("6462c267a6721a7835692be2e85e8cb3d5fc6e84cc6e853d5c77caa10d30e171")
+        );
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn cross_checks_bulletproofs_generators() {
+        let commitment_generators = PedersenGens::default();
+        let group_public_parameters = ristretto::group_element::PublicParameters::default();
+        let transcript = GeneratorsTranscript::<1>::new(&[]);
+
+        // The message generator of `PedersenGens` is the generator of the group, as derived under
+        // any label.
+        let message_generator: ristretto::GroupElement = transcript.message_generators[0]
+            .derive::<{ ristretto::SCALAR_LIMBS }, _>(&group_public_parameters)
+            .unwrap();
+        assert_eq!(
+            message_generator,
+            commitment_generators.B.compress().try_into().unwrap()
+        );
+
+        // Whereas its randomness generator is derived (outside `HashToGroup`) as
+        // `hash_from_bytes::<Sha3_512>` of the encoding of the generator, and so is independent of
+        // the derived one.
+        let randomness_generator: ristretto::GroupElement = transcript
+            .randomness_generator
+            .derive::<{ ristretto::SCALAR_LIMBS }, _>(&group_public_parameters)
+            .unwrap();
+        assert_ne!(
+            randomness_generator,
+            commitment_generators
+                .B_blinding
+                .compress()
+                .try_into()
+                .unwrap()
+        );
+
+        // It is reproduced by `bulletproofs_default()`.
+        let public_parameters = PublicParameters::bulletproofs_default().unwrap();
+        assert_eq!(
+            public_parameters.message_generators,
+            [commitment_generators.B.compress().try_into().unwrap()]
+        );
+        assert_eq!(
+            public_parameters.randomness_generator,
+            commitment_generators
+                .B_blinding
+                .compress()
+                .try_into()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn commits_with_the_generators_it_was_created_with() {
+        type SecpPedersen = Pedersen<
+            2,
+            { group::secp256k1::SCALAR_LIMBS },
+            group::secp256k1::Scalar,
+            group::secp256k1::GroupElement,
+        >;
+
+        let scalar_public_parameters = group::secp256k1::scalar::PublicParameters::default();
+        let group_public_parameters = group::secp256k1::group_element::PublicParameters::default();
+        let mut public_parameters = PublicParameters::derive_default::<
+            { group::secp256k1::SCALAR_LIMBS },
+            group::secp256k1::GroupElement,
+        >()
+        .unwrap();
+
+        let message = array::from_fn(|_| {
+            group::secp256k1::Scalar::sample(&scalar_public_parameters, &mut OsRng).unwrap()
+        });
+        let randomness =
+            group::secp256k1::Scalar::sample(&scalar_public_parameters, &mut OsRng).unwrap();
+
+        public_parameters.randomness_generator = public_parameters.message_generators[1];
+        let commitment_scheme = SecpPedersen::new(&public_parameters).unwrap();
+
+        let [first_generator, second_generator] =
+            public_parameters.message_generators.map(|value| {
+                group::secp256k1::GroupElement::new(value, &group_public_parameters).unwrap()
+            });
+        assert_eq!(
+            commitment_scheme.commit(&message.into(), &randomness),
+            message[0] * first_generator + (message[1] + randomness) * second_generator
+        );
+    }
+
     #[test]
     #[cfg(feature = "test_helpers")]
     fn test_homomorphic_commitment_scheme() {
@@ -0,0 +1,359 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use core::array;
+
+use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
+use group::{
+    bounded_integers,
+    helpers::{const_generic_array_serialization, FlatMapResults},
+    multiplicative, self_product, GroupElement as _, Samplable, StatisticalSecuritySizedNumber,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{GroupsPublicParameters, GroupsPublicParametersAccessors, HomomorphicCommitmentScheme};
+
+/// A Batched Ring-Pedersen (Damgård–Fujisaki) Commitment to integers over $\mathbb{Z}_N^*$:
+/// $$\Com_\pp(\vec{m};\rho):= s_1^{m_1}\cdot \ldots \cdot s_n^{m_n} \cdot t^\rho \mod N$$
+///
+/// Unlike [`Pedersen`](crate::Pedersen), the order of the commitment group is hidden, so that the
+/// messages are (signed, bounded) integers rather than elements of $\mathbb{Z}_q$, and there is no
+/// wrap-around modulo the order of the group. This allows, for example, proving statements about
+/// the plaintexts of Paillier encryptions, which live modulo another modulus, directly.
+///
+/// The commitment is statistically hiding as long as $t$ generates the quadratic residues modulo
+/// $N$ and $s_i \in \langle t \rangle$, as the randomness is sampled from a range larger than $N$
+/// by [`StatisticalSecuritySizedNumber::BITS`] bits. It is computationally binding under the
+/// strong-RSA assumption for a modulus $N = PQ$ which is a product of safe primes whose
+/// factorization is unknown to the committing party.
+///
+/// The public parameters should therefore be generated with [`PublicParameters::setup`] by a
+/// party which proves that $N$ is a Paillier-Blum modulus, that $t$ is a quadratic residue and
+/// that $s_i \in \langle t \rangle$, as described there.
+#[derive(PartialEq, Clone, Debug, Eq)]
+pub struct RingPedersen<
+    const BATCH_SIZE: usize,
+    const MESSAGE_LIMBS: usize,
+    const RANDOMNESS_LIMBS: usize,
+    const LIMBS: usize,
+> where
+    Uint<LIMBS>: Encoding,
+{
+    message_generators: [multiplicative::GroupElement<LIMBS>; BATCH_SIZE],
+    randomness_generator: multiplicative::GroupElement<LIMBS>,
+}
+
+impl<
+        const BATCH_SIZE: usize,
+        const MESSAGE_LIMBS: usize,
+        const RANDOMNESS_LIMBS: usize,
+        const LIMBS: usize,
+    > HomomorphicCommitmentScheme<MESSAGE_LIMBS>
+    for RingPedersen<BATCH_SIZE, MESSAGE_LIMBS, RANDOMNESS_LIMBS, LIMBS>
+where
+    Uint<MESSAGE_LIMBS>: Encoding,
+    Uint<RANDOMNESS_LIMBS>: Encoding,
+    Uint<LIMBS>: Encoding,
+{
+    type MessageSpaceGroupElement =
+        self_product::GroupElement<BATCH_SIZE, bounded_integers::GroupElement<MESSAGE_LIMBS>>;
+    type RandomnessSpaceGroupElement = bounded_integers::GroupElement<RANDOMNESS_LIMBS>;
+    type CommitmentSpaceGroupElement = multiplicative::GroupElement<LIMBS>;
+    type PublicParameters = PublicParameters<BATCH_SIZE, MESSAGE_LIMBS, RANDOMNESS_LIMBS, LIMBS>;
+
+    fn new(public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        if BATCH_SIZE == 0 {
+            return Err(crate::Error::InvalidPublicParameters);
+        }
+
+        let message_generators = public_parameters
+            .message_generators
+            .map(|value| {
+                multiplicative::GroupElement::new(
+                    value,
+                    public_parameters.commitment_space_public_parameters(),
+                )
+            })
+            .flat_map_results()?;
+
+        let randomness_generator = multiplicative::GroupElement::new(
+            public_parameters.randomness_generator,
+            public_parameters.commitment_space_public_parameters(),
+        )?;
+
+        Ok(Self {
+            message_generators,
+            randomness_generator,
+        })
+    }
+
+    fn commit(
+        &self,
+        message: &Self::MessageSpaceGroupElement,
+        randomness: &bounded_integers::GroupElement<RANDOMNESS_LIMBS>,
+    ) -> multiplicative::GroupElement<LIMBS> {
+        // $$\Com_\pp(\vec{m};\rho):= s_1^{m_1}\cdot \ldots \cdot s_n^{m_n} \cdot t^\rho \mod N$$.
+        self.message_generators
+            .iter()
+            .zip::<&[bounded_integers::GroupElement<MESSAGE_LIMBS>; BATCH_SIZE]>(message.into())
+            .fold(
+                randomness.mul_group_element(&self.randomness_generator),
+                |acc, (generator, value)| acc + value.mul_group_element(generator),
+            )
+    }
+}
+
+pub type MessageSpaceGroupElement<const BATCH_SIZE: usize, const MESSAGE_LIMBS: usize> =
+    self_product::GroupElement<BATCH_SIZE, bounded_integers::GroupElement<MESSAGE_LIMBS>>;
+pub type MessageSpacePublicParameters<const BATCH_SIZE: usize, const MESSAGE_LIMBS: usize> =
+    self_product::PublicParameters<BATCH_SIZE, bounded_integers::PublicParameters<MESSAGE_LIMBS>>;
+pub type RandomnessSpaceGroupElement<const RANDOMNESS_LIMBS: usize> =
+    bounded_integers::GroupElement<RANDOMNESS_LIMBS>;
+pub type RandomnessSpacePublicParameters<const RANDOMNESS_LIMBS: usize> =
+    bounded_integers::PublicParameters<RANDOMNESS_LIMBS>;
+pub type CommitmentSpaceGroupElement<const LIMBS: usize> = multiplicative::GroupElement<LIMBS>;
+pub type CommitmentSpacePublicParameters<const LIMBS: usize> =
+    multiplicative::PublicParameters<LIMBS>;
+
+/// The Public Parameters of a Ring-Pedersen Commitment.
+///
+/// Binding relies on the committing party not knowing the factorization of $N$, nor a
+/// non-trivial relation between the generators, so this struct should be generated with
+/// [`Self::setup`] by the party knowing the former and verified by the others, e.g. with
+/// `maurer::ring_pedersen_setup`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PublicParameters<
+    const BATCH_SIZE: usize,
+    const MESSAGE_LIMBS: usize,
+    const RANDOMNESS_LIMBS: usize,
+    const LIMBS: usize,
+> where
+    Uint<LIMBS>: Encoding,
+{
+    pub groups_public_parameters: GroupsPublicParameters<
+        MessageSpacePublicParameters<BATCH_SIZE, MESSAGE_LIMBS>,
+        RandomnessSpacePublicParameters<RANDOMNESS_LIMBS>,
+        CommitmentSpacePublicParameters<LIMBS>,
+    >,
+    #[serde(with = "const_generic_array_serialization")]
+    pub message_generators: [multiplicative::Value<LIMBS>; BATCH_SIZE],
+    pub randomness_generator: multiplicative::Value<LIMBS>,
+}
+
+impl<
+        const BATCH_SIZE: usize,
+        const MESSAGE_LIMBS: usize,
+        const RANDOMNESS_LIMBS: usize,
+        const LIMBS: usize,
+    > PublicParameters<BATCH_SIZE, MESSAGE_LIMBS, RANDOMNESS_LIMBS, LIMBS>
+where
+    Uint<RANDOMNESS_LIMBS>: Encoding,
+    Uint<LIMBS>: Encoding,
+{
+    /// Generates the public parameters for committing to integers of `message_bits` bits over
+    /// $\mathbb{Z}_N^*$ for `modulus` $N$, which should be a product of safe primes.
+    ///
+    /// Samples $\tau \gets \mathbb{Z}_N^*$ and sets $t = \tau^2$, and for each message generator
+    /// samples $\lambda_i$ from the randomness space and sets $s_i = t^{\lambda_i}$.
+    ///
+    /// Returns the public parameters alongside the square root $\tau$ of $t$ and the discrete logs
+    /// $\lambda_i$, with which the well-formedness of the public parameters should be proven, and
+    /// which should otherwise be erased: knowing the $\lambda_i$ (and $\varphi(N)$) allows
+    /// equivocating commitments.
+    ///
+    /// SECURITY NOTICE:
+    /// The well-formedness of the public parameters is proven by a Paillier-Blum modulus proof for
+    /// $N$ (`proof::paillier_blum_modulus`), a proof of knowledge of $\tau$ showing that $t$ is a
+    /// quadratic residue (`maurer::knowledge_of_square_root`), and a proof of knowledge of the
+    /// $\lambda_i$ showing that $s_i \in \langle t \rangle$ (`maurer::ring_pedersen_setup`).
+    /// These do *not* prove that the prime factors of $N$ are safe primes, nor that $t$ generates
+    /// all of $\mathbb{QR}_N$ rather than a subgroup of it, which the security of the commitment
+    /// relies on. Neither does this crate: the verifier of the public parameters must get these
+    /// guarantees elsewhere, e.g. from a trusted setup.
+    pub fn setup(
+        modulus: Uint<LIMBS>,
+        message_bits: usize,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<(
+        Self,
+        multiplicative::GroupElement<LIMBS>,
+        [bounded_integers::GroupElement<RANDOMNESS_LIMBS>; BATCH_SIZE],
+    )> {
+        let commitment_space_public_parameters = multiplicative::PublicParameters::new(modulus)?;
+
+        // Sample the randomness with `StatisticalSecuritySizedNumber::BITS` bits of slack over the
+        // modulus, so that $t^\rho$ is statistically close to uniform in $\langle t \rangle$.
+        let randomness_space_public_parameters = bounded_integers::PublicParameters::new(
+            modulus.bits() + StatisticalSecuritySizedNumber::BITS,
+        )?;
+
+        let message_space_public_parameters = self_product::PublicParameters::new(
+            bounded_integers::PublicParameters::new(message_bits)?,
+        );
+
+        let randomness_generator_square_root =
+            multiplicative::GroupElement::sample(&commitment_space_public_parameters, rng)?;
+        let randomness_generator = randomness_generator_square_root.double();
+
+        let discrete_logs = array::from_fn(|_| {
+            bounded_integers::GroupElement::sample(&randomness_space_public_parameters, rng)
+        })
+        .flat_map_results()?;
+
+        let message_generators = discrete_logs.map(|discrete_log| {
+            discrete_log
+                .mul_group_element(&randomness_generator)
+                .value()
+        });
+
+        let public_parameters = Self {
+            groups_public_parameters: GroupsPublicParameters {
+                message_space_public_parameters,
+                randomness_space_public_parameters,
+                commitment_space_public_parameters,
+            },
+            message_generators,
+            randomness_generator: randomness_generator.value(),
+        };
+
+        Ok((
+            public_parameters,
+            randomness_generator_square_root,
+            discrete_logs,
+        ))
+    }
+}
+
+impl<
+        const BATCH_SIZE: usize,
+        const MESSAGE_LIMBS: usize,
+        const RANDOMNESS_LIMBS: usize,
+        const LIMBS: usize,
+    >
+    AsRef<
+        GroupsPublicParameters<
+            MessageSpacePublicParameters<BATCH_SIZE, MESSAGE_LIMBS>,
+            RandomnessSpacePublicParameters<RANDOMNESS_LIMBS>,
+            CommitmentSpacePublicParameters<LIMBS>,
+        >,
+    > for PublicParameters<BATCH_SIZE, MESSAGE_LIMBS, RANDOMNESS_LIMBS, LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn as_ref(
+        &self,
+    ) -> &GroupsPublicParameters<
+        MessageSpacePublicParameters<BATCH_SIZE, MESSAGE_LIMBS>,
+        RandomnessSpacePublicParameters<RANDOMNESS_LIMBS>,
+        CommitmentSpacePublicParameters<LIMBS>,
+    > {
+        &self.groups_public_parameters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{U1024, U2048, U256};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    /// A product of two 512-bit safe primes.
+    const N: U1024 = U1024::from_be_hex("D8BEB61E8600D725E18348E5AA9E9DCD9FE877112E76A4A4D096D972E0B43FBB1AD211B48B8272900AAD52DDCFEE9C97FE722DBBB3861C48E701B0BFBAE4CAF837CA68305BF30ADFA00516D473DCEE0C2E2024D99B220822F5109ECF8042AE9942DABCEF2A908361A35B39AD8A6D922997A6694D43E6A2C466423D96567F4675");
+
+    type Commitment = RingPedersen<2, { U256::LIMBS }, { U2048::LIMBS }, { U1024::LIMBS }>;
+
+    #[test]
+    fn commits() {
+        let (public_parameters, randomness_generator_square_root, discrete_logs) =
+            PublicParameters::<2, { U256::LIMBS }, { U2048::LIMBS }, { U1024::LIMBS }>::setup(
+                N, 200, &mut OsRng,
+            )
+            .unwrap();
+        let commitment_scheme = Commitment::new(&public_parameters).unwrap();
+
+        let message = MessageSpaceGroupElement::<2, { U256::LIMBS }>::sample(
+            public_parameters.message_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+        let randomness = RandomnessSpaceGroupElement::<{ U2048::LIMBS }>::sample(
+            public_parameters.randomness_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+
+        let [first_message, second_message]: [bounded_integers::GroupElement<{ U256::LIMBS }>; 2] =
+            message.into();
+        let randomness_generator = multiplicative::GroupElement::new(
+            public_parameters.randomness_generator,
+            public_parameters.commitment_space_public_parameters(),
+        )
+        .unwrap();
+        assert_eq!(
+            randomness_generator,
+            randomness_generator_square_root.double()
+        );
+
+        // $s_1^{m_1} s_2^{m_2} t^\rho = t^{\lambda_1 m_1 + \lambda_2 m_2 + \rho}$ over the
+        // integers.
+        let exponent = discrete_logs[0]
+            .value()
+            .wrapping_mul(&first_message.value().resize::<{ U2048::LIMBS }>())
+            .wrapping_add(
+                &discrete_logs[1]
+                    .value()
+                    .wrapping_mul(&second_message.value().resize::<{ U2048::LIMBS }>()),
+            )
+            .wrapping_add(&randomness.value());
+        assert_eq!(
+            commitment_scheme.commit(&message, &randomness),
+            randomness_generator.scalar_mul(&exponent)
+        );
+
+        // Negative messages are committed to as inverses.
+        assert!(bool::from(
+            (commitment_scheme.commit(&message, &randomness)
+                + commitment_scheme.commit(&(-message), &(-randomness)))
+            .is_neutral()
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        // The randomness space is too small for a 1024-bit modulus.
+        assert_eq!(
+            PublicParameters::<2, { U256::LIMBS }, { U1024::LIMBS }, { U1024::LIMBS }>::setup(
+                N, 200, &mut OsRng,
+            )
+            .err(),
+            Some(crate::Error::GroupInstantiation(
+                group::Error::UnsupportedPublicParameters
+            ))
+        );
+
+        // An even modulus is not supported.
+        assert!(
+            PublicParameters::<2, { U256::LIMBS }, { U2048::LIMBS }, { U1024::LIMBS }>::setup(
+                N.wrapping_add(&U1024::ONE),
+                200,
+                &mut OsRng,
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "test_helpers")]
+    fn test_homomorphic_commitment_scheme() {
+        let (public_parameters, ..) =
+            PublicParameters::<2, { U256::LIMBS }, { U2048::LIMBS }, { U1024::LIMBS }>::setup(
+                N, 200, &mut OsRng,
+            )
+            .unwrap();
+
+        crate::test_helpers::test_homomorphic_commitment_scheme::<{ U256::LIMBS }, Commitment>(
+            &public_parameters,
+        );
+    }
+}
@@ -0,0 +1,174 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Secret sharing over the prime field of a [`KnownOrderScalar`].
+//!
+//! [`shamir`] implements (plain) Shamir secret sharing, and [`feldman`] and [`pedersen`] extend it
+//! into Verifiable Secret Sharing (VSS) schemes, in which the dealer commits to its sharing
+//! polynomial so that each party can verify the validity of its share, and complain against the
+//! dealer otherwise.
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crypto_bigint::{rand_core::CryptoRngCore, U64};
+pub use feldman::FeldmanVSS;
+use group::{collections::HashMap, GroupElement, KnownOrderScalar, PartyID};
+pub use pedersen::PedersenVSS;
+use serde::{Deserialize, Serialize};
+
+pub mod feldman;
+pub mod pedersen;
+pub mod shamir;
+
+/// Secret sharing error.
+#[derive(thiserror::Error, Clone, Debug, PartialEq)]
+pub enum Error {
+    #[error("invalid secret sharing parameters")]
+    InvalidParameters,
+    #[error("parties {:?} are not valid shareholders", .0)]
+    InvalidParties(Vec<PartyID>),
+    #[error("too few shares to reconstruct the secret")]
+    ThresholdNotReached,
+    #[error("the dealer sent invalid commitments")]
+    InvalidCommitments,
+    #[error("party {0} received a share which does not match the dealer's commitments")]
+    InvalidShare(PartyID),
+    #[error("parties {:?} complained against the dealer, which would reveal the secret", .0)]
+    TooManyComplaints(Vec<PartyID>),
+    #[error("the dealer failed to resolve the complaints of parties {:?}", .0)]
+    UnresolvedComplaints(Vec<PartyID>),
+    #[error("group error")]
+    GroupInstantiation(#[from] group::Error),
+    #[error("commitment error")]
+    Commitment(#[from] crate::Error),
+}
+
+/// Secret sharing result.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// A Verifiable Secret Sharing (VSS) scheme over the prime field of [`Self::Scalar`].
+///
+/// The dealer broadcasts commitments to its sharing polynomial, and sends each party its share
+/// privately. Each party verifies its share against the commitments with [`Self::verify_share()`],
+/// and broadcasts a complaint against the dealer if it is invalid. The dealer must then answer
+/// every complaint by revealing the share of the complaining party publicly, and all parties
+/// settle the complaints with [`Self::resolve_complaints()`].
+///
+/// As the protocol assumes a reliable broadcast channel, all honest parties observe the same
+/// commitments, complaints and revealed shares, and thus agree on whether the dealer is
+/// disqualified.
+pub trait VerifiableSecretSharing<const SCALAR_LIMBS: usize> {
+    /// The scalar over the prime field of which secrets are shared.
+    type Scalar: KnownOrderScalar<SCALAR_LIMBS>;
+
+    /// The share of a single party, which the dealer sends to it privately.
+    type Share: Serialize + for<'r> Deserialize<'r> + Clone + Debug + PartialEq;
+
+    /// The commitments of the dealer to its sharing polynomial, which it broadcasts to all parties.
+    type Commitments: Serialize + for<'r> Deserialize<'r> + Clone + Debug + PartialEq;
+
+    /// Shares `secret` among the parties `1..=number_of_parties`, such that any `threshold` of
+    /// them can reconstruct it.
+    ///
+    /// Returns the commitments to broadcast, alongside the share of each party.
+    #[allow(clippy::type_complexity)]
+    fn deal(
+        &self,
+        secret: Self::Scalar,
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::Commitments, HashMap<PartyID, Self::Share>)>;
+
+    /// Verifies that `share` is the share of `party_id` under the dealer's `commitments` to a
+    /// polynomial of degree `threshold - 1`, and returns its share of the secret.
+    ///
+    /// Returns [`Error::InvalidShare`] if `share` is invalid, in which case `party_id` should
+    /// complain against the dealer, or [`Error::InvalidCommitments`] if the commitments themselves
+    /// are invalid, in which case the dealer should be disqualified.
+    fn verify_share(
+        &self,
+        party_id: PartyID,
+        threshold: PartyID,
+        share: &Self::Share,
+        commitments: &Self::Commitments,
+    ) -> Result<Self::Scalar>;
+
+    /// Resolves the complaints raised against the dealer, given the share it revealed for each
+    /// complaining party (or `None`, if it did not answer a complaint).
+    ///
+    /// Returns the verified shares of the complaining parties, which they should adopt as their
+    /// own. Otherwise, the dealer should be disqualified: if any complaint is left unanswered or is
+    /// answered with an invalid share, [`Error::UnresolvedComplaints`] is returned; and if the
+    /// revealed shares would suffice to reconstruct the secret, [`Error::TooManyComplaints`] is.
+    fn resolve_complaints(
+        &self,
+        threshold: PartyID,
+        commitments: &Self::Commitments,
+        revealed_shares: HashMap<PartyID, Option<Self::Share>>,
+    ) -> Result<HashMap<PartyID, Self::Scalar>> {
+        let mut complaining_parties: Vec<PartyID> = revealed_shares.keys().copied().collect();
+        complaining_parties.sort();
+
+        if complaining_parties.len() >= usize::from(threshold) {
+            return Err(Error::TooManyComplaints(complaining_parties));
+        }
+
+        let verified_shares: HashMap<_, _> = revealed_shares
+            .into_iter()
+            .filter_map(|(party_id, share)| {
+                share
+                    .and_then(|share| {
+                        self.verify_share(party_id, threshold, &share, commitments)
+                            .ok()
+                    })
+                    .map(|share| (party_id, share))
+            })
+            .collect();
+
+        let unresolved_complaints: Vec<PartyID> = complaining_parties
+            .into_iter()
+            .filter(|party_id| !verified_shares.contains_key(party_id))
+            .collect();
+
+        if !unresolved_complaints.is_empty() {
+            return Err(Error::UnresolvedComplaints(unresolved_complaints));
+        }
+
+        Ok(verified_shares)
+    }
+}
+
+/// Evaluates the polynomial committed to in `commitments` "in the exponent" at `party_id`, i.e.
+/// computes $\sum_j \textsf{party\\_id}^j \cdot C_j$.
+pub(crate) fn evaluate_commitments<G: GroupElement>(commitments: &[G], party_id: PartyID) -> G {
+    let party_id = U64::from(party_id);
+
+    // Horner's method, starting from the commitment to the leading coefficient. Commitments are
+    // instantiated with [`instantiate_commitments()`], which assures they are non-empty.
+    let mut reversed_commitments = commitments.iter().rev();
+    let leading_commitment = reversed_commitments.next().unwrap().clone();
+
+    reversed_commitments.fold(leading_commitment, |acc, commitment| {
+        acc.scalar_mul_bounded(&party_id, PartyID::BITS as usize) + commitment
+    })
+}
+
+/// Instantiates the dealer's `commitments` to a polynomial of degree `threshold - 1`.
+pub(crate) fn instantiate_commitments<G: GroupElement>(
+    commitments: &[G::Value],
+    threshold: PartyID,
+    public_parameters: &G::PublicParameters,
+) -> Result<Vec<G>> {
+    if threshold == 0 || commitments.len() != usize::from(threshold) {
+        return Err(Error::InvalidCommitments);
+    }
+
+    commitments
+        .iter()
+        .map(|commitment| {
+            G::new(commitment.clone(), public_parameters).map_err(|_| Error::InvalidCommitments)
+        })
+        .collect()
+}
@@ -0,0 +1,227 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::{vec, vec::Vec};
+
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::{collections::HashMap, GroupElement as _, PartyID, PrimeGroupElement};
+
+use super::{
+    evaluate_commitments, instantiate_commitments, shamir::Polynomial, Error, Result,
+    VerifiableSecretSharing,
+};
+
+/// Feldman's Verifiable Secret Sharing.
+///
+/// The dealer commits to each coefficient $a_j$ of its sharing polynomial $f$ in the exponent,
+/// $A_j = a_j \cdot G$, and party $i$ verifies its share $s_i$ by checking that $s_i \cdot G =
+/// \sum_j i^j \cdot A_j$.
+///
+/// The commitments are only computationally hiding: in particular, $A_0 = f(0) \cdot G$ is the
+/// public key of the secret, which fits distributed key generation. Use
+/// [`super::PedersenVSS`] where the secret must remain perfectly hidden.
+#[derive(PartialEq, Clone, Debug)]
+pub struct FeldmanVSS<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>> {
+    scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: GroupElement::PublicParameters,
+    generator: GroupElement,
+}
+
+impl<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>
+    FeldmanVSS<SCALAR_LIMBS, GroupElement>
+{
+    /// Instantiate Feldman's VSS over the group identified by `group_public_parameters`.
+    pub fn new(
+        scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: GroupElement::PublicParameters,
+    ) -> Result<Self> {
+        let generator = GroupElement::generator_from_public_parameters(&group_public_parameters)?;
+
+        Ok(Self {
+            scalar_public_parameters,
+            group_public_parameters,
+            generator,
+        })
+    }
+
+    /// Computes the public share $s_i \cdot G$ of `party_id` from the dealer's `commitments` to a
+    /// polynomial of degree `threshold - 1`, e.g. to verify partial signatures or decryptions.
+    pub fn public_share(
+        &self,
+        party_id: PartyID,
+        threshold: PartyID,
+        commitments: &[GroupElement::Value],
+    ) -> Result<GroupElement> {
+        if party_id == 0 {
+            return Err(Error::InvalidParties(vec![party_id]));
+        }
+
+        let commitments = instantiate_commitments::<GroupElement>(
+            commitments,
+            threshold,
+            &self.group_public_parameters,
+        )?;
+
+        Ok(evaluate_commitments(&commitments, party_id))
+    }
+}
+
+impl<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>
+    VerifiableSecretSharing<SCALAR_LIMBS> for FeldmanVSS<SCALAR_LIMBS, GroupElement>
+{
+    type Scalar = GroupElement::Scalar;
+    type Share = group::Value<GroupElement::Scalar>;
+    type Commitments = Vec<GroupElement::Value>;
+
+    fn deal(
+        &self,
+        secret: Self::Scalar,
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::Commitments, HashMap<PartyID, Self::Share>)> {
+        let polynomial = Polynomial::sample(secret, threshold, rng)?;
+
+        let commitments = GroupElement::batch_normalize(
+            polynomial
+                .coefficients()
+                .iter()
+                .map(|coefficient| self.generator.scalar_mul(&(*coefficient).into()))
+                .collect(),
+        );
+
+        let shares = polynomial
+            .shares(number_of_parties)?
+            .into_iter()
+            .map(|(party_id, share)| (party_id, share.value()))
+            .collect();
+
+        Ok((commitments, shares))
+    }
+
+    fn verify_share(
+        &self,
+        party_id: PartyID,
+        threshold: PartyID,
+        share: &Self::Share,
+        commitments: &Self::Commitments,
+    ) -> Result<Self::Scalar> {
+        let public_share = self.public_share(party_id, threshold, commitments)?;
+
+        let share = GroupElement::Scalar::new(*share, &self.scalar_public_parameters)
+            .map_err(|_| Error::InvalidShare(party_id))?;
+
+        if self.generator.scalar_mul(&share.into()) != public_share {
+            return Err(Error::InvalidShare(party_id));
+        }
+
+        Ok(share)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{secp256k1, Samplable};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::secret_sharing::shamir;
+
+    type Feldman = FeldmanVSS<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>;
+
+    fn setup() -> (Feldman, secp256k1::Scalar) {
+        let scalar_public_parameters = secp256k1::scalar::PublicParameters::default();
+        let vss = Feldman::new(
+            scalar_public_parameters.clone(),
+            secp256k1::group_element::PublicParameters::default(),
+        )
+        .unwrap();
+        let secret = secp256k1::Scalar::sample(&scalar_public_parameters, &mut OsRng).unwrap();
+
+        (vss, secret)
+    }
+
+    #[test]
+    fn verifies_valid_shares() {
+        let (vss, secret) = setup();
+        let (commitments, shares) = vss.deal(secret, 3, 5, &mut OsRng).unwrap();
+
+        let shares: HashMap<_, _> = shares
+            .iter()
+            .map(|(party_id, share)| {
+                (
+                    *party_id,
+                    vss.verify_share(*party_id, 3, share, &commitments).unwrap(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            shamir::reconstruct(&shares, 3, &vss.scalar_public_parameters).unwrap(),
+            secret
+        );
+        assert_eq!(
+            secp256k1::GroupElement::new(commitments[0], &vss.group_public_parameters).unwrap(),
+            vss.generator.scalar_mul(&secret.into()),
+            "the first commitment should be the public key of the secret"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_shares_and_commitments() {
+        let (vss, secret) = setup();
+        let (commitments, shares) = vss.deal(secret, 3, 5, &mut OsRng).unwrap();
+
+        // Party 2 received the share of party 1.
+        assert_eq!(
+            vss.verify_share(2, 3, &shares[&1], &commitments),
+            Err(Error::InvalidShare(2))
+        );
+
+        // Commitments to a polynomial of the wrong degree.
+        assert_eq!(
+            vss.verify_share(1, 2, &shares[&1], &commitments),
+            Err(Error::InvalidCommitments)
+        );
+        assert_eq!(
+            vss.verify_share(1, 4, &shares[&1], &commitments),
+            Err(Error::InvalidCommitments)
+        );
+    }
+
+    #[test]
+    fn resolves_complaints() {
+        let (vss, secret) = setup();
+        let (commitments, shares) = vss.deal(secret, 3, 5, &mut OsRng).unwrap();
+
+        let resolved_shares = vss
+            .resolve_complaints(
+                3,
+                &commitments,
+                HashMap::from([(4, Some(shares[&4])), (5, Some(shares[&5]))]),
+            )
+            .unwrap();
+        assert_eq!(
+            resolved_shares[&4],
+            secp256k1::Scalar::new(shares[&4], &vss.scalar_public_parameters).unwrap()
+        );
+
+        assert_eq!(
+            vss.resolve_complaints(
+                3,
+                &commitments,
+                HashMap::from([(2, Some(shares[&1])), (4, None)]),
+            ),
+            Err(Error::UnresolvedComplaints(vec![2, 4]))
+        );
+
+        assert_eq!(
+            vss.resolve_complaints(
+                3,
+                &commitments,
+                HashMap::from([(1, Some(shares[&1])), (2, None), (3, None)]),
+            ),
+            Err(Error::TooManyComplaints(vec![1, 2, 3]))
+        );
+    }
+}
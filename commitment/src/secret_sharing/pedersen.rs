@@ -0,0 +1,251 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::{vec, vec::Vec};
+
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::{collections::HashMap, GroupElement as _, PartyID, PrimeGroupElement, Samplable};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    evaluate_commitments, instantiate_commitments, shamir::Polynomial, Error, Result,
+    VerifiableSecretSharing,
+};
+use crate::{pedersen, GroupsPublicParametersAccessors, HomomorphicCommitmentScheme, Pedersen};
+
+/// The public parameters of [`PedersenVSS`], i.e. of the underlying (single-message) [`Pedersen`]
+/// commitment.
+pub type PublicParameters<const SCALAR_LIMBS: usize, GroupElement> = pedersen::PublicParameters<
+    1,
+    group::Value<GroupElement>,
+    group::PublicParameters<group::Scalar<SCALAR_LIMBS, GroupElement>>,
+    group::PublicParameters<GroupElement>,
+>;
+
+/// Pedersen's Verifiable Secret Sharing.
+///
+/// The dealer shares the secret with a polynomial $f$ alongside a uniformly random polynomial
+/// $r$, and commits to their coefficients $a_j, b_j$ with [`Pedersen`] commitments
+/// $C_j = a_j \cdot G + b_j \cdot H$. Party $i$ receives $(f(i), r(i))$, which it verifies by
+/// checking that $\Com(f(i); r(i)) = \sum_j i^j \cdot C_j$.
+///
+/// Unlike [`super::FeldmanVSS`], the commitments are perfectly hiding, and so reveal nothing about
+/// the secret.
+#[derive(PartialEq, Clone, Debug)]
+pub struct PedersenVSS<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>> {
+    scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+    group_public_parameters: GroupElement::PublicParameters,
+    commitment_scheme: Pedersen<1, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>,
+}
+
+/// The share of a party in [`PedersenVSS`].
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct Share<ScalarValue> {
+    /// The share $f(i)$ of the secret.
+    pub share: ScalarValue,
+    /// The share $r(i)$ of the commitment randomness.
+    pub randomness: ScalarValue,
+}
+
+impl<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>
+    PedersenVSS<SCALAR_LIMBS, GroupElement>
+{
+    /// Instantiate Pedersen's VSS from the public parameters of the underlying commitment.
+    pub fn new(public_parameters: &PublicParameters<SCALAR_LIMBS, GroupElement>) -> Result<Self> {
+        Ok(Self {
+            scalar_public_parameters: public_parameters
+                .randomness_space_public_parameters()
+                .clone(),
+            group_public_parameters: public_parameters
+                .commitment_space_public_parameters()
+                .clone(),
+            commitment_scheme: Pedersen::new(public_parameters)?,
+        })
+    }
+}
+
+impl<const SCALAR_LIMBS: usize, GroupElement: PrimeGroupElement<SCALAR_LIMBS>>
+    VerifiableSecretSharing<SCALAR_LIMBS> for PedersenVSS<SCALAR_LIMBS, GroupElement>
+{
+    type Scalar = GroupElement::Scalar;
+    type Share = Share<group::Value<GroupElement::Scalar>>;
+    type Commitments = Vec<GroupElement::Value>;
+
+    fn deal(
+        &self,
+        secret: Self::Scalar,
+        threshold: PartyID,
+        number_of_parties: PartyID,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::Commitments, HashMap<PartyID, Self::Share>)> {
+        let polynomial = Polynomial::sample(secret, threshold, rng)?;
+        let randomness_polynomial = Polynomial::sample(
+            GroupElement::Scalar::sample(&self.scalar_public_parameters, rng)?,
+            threshold,
+            rng,
+        )?;
+
+        let commitments = GroupElement::batch_normalize(
+            polynomial
+                .coefficients()
+                .iter()
+                .zip(randomness_polynomial.coefficients())
+                .map(|(coefficient, randomness)| {
+                    self.commitment_scheme
+                        .commit(&[*coefficient].into(), randomness)
+                })
+                .collect(),
+        );
+
+        let mut randomness_shares = randomness_polynomial.shares(number_of_parties)?;
+        let shares = polynomial
+            .shares(number_of_parties)?
+            .into_iter()
+            .map(|(party_id, share)| {
+                // Both polynomials are evaluated at the same parties.
+                let randomness = randomness_shares.remove(&party_id).unwrap();
+
+                (
+                    party_id,
+                    Share {
+                        share: share.value(),
+                        randomness: randomness.value(),
+                    },
+                )
+            })
+            .collect();
+
+        Ok((commitments, shares))
+    }
+
+    fn verify_share(
+        &self,
+        party_id: PartyID,
+        threshold: PartyID,
+        share: &Self::Share,
+        commitments: &Self::Commitments,
+    ) -> Result<Self::Scalar> {
+        if party_id == 0 {
+            return Err(Error::InvalidParties(vec![party_id]));
+        }
+
+        let commitments = instantiate_commitments::<GroupElement>(
+            commitments,
+            threshold,
+            &self.group_public_parameters,
+        )?;
+
+        let (secret_share, randomness) =
+            GroupElement::Scalar::new(share.share, &self.scalar_public_parameters)
+                .and_then(|secret_share| {
+                    GroupElement::Scalar::new(share.randomness, &self.scalar_public_parameters)
+                        .map(|randomness| (secret_share, randomness))
+                })
+                .map_err(|_| Error::InvalidShare(party_id))?;
+
+        if self
+            .commitment_scheme
+            .commit(&[secret_share].into(), &randomness)
+            != evaluate_commitments(&commitments, party_id)
+        {
+            return Err(Error::InvalidShare(party_id));
+        }
+
+        Ok(secret_share)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::secp256k1;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::secret_sharing::shamir;
+
+    type Pedersen = PedersenVSS<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>;
+
+    fn setup() -> (Pedersen, secp256k1::Scalar) {
+        let public_parameters = PublicParameters::<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::GroupElement,
+        >::derive_default::<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::GroupElement,
+        >()
+        .unwrap();
+        let vss = Pedersen::new(&public_parameters).unwrap();
+        let secret = secp256k1::Scalar::sample(&vss.scalar_public_parameters, &mut OsRng).unwrap();
+
+        (vss, secret)
+    }
+
+    #[test]
+    fn verifies_valid_shares() {
+        let (vss, secret) = setup();
+        let (commitments, shares) = vss.deal(secret, 3, 5, &mut OsRng).unwrap();
+
+        let shares: HashMap<_, _> = shares
+            .iter()
+            .map(|(party_id, share)| {
+                (
+                    *party_id,
+                    vss.verify_share(*party_id, 3, share, &commitments).unwrap(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            shamir::reconstruct(&shares, 3, &vss.scalar_public_parameters).unwrap(),
+            secret
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_shares_and_commitments() {
+        let (vss, secret) = setup();
+        let (commitments, shares) = vss.deal(secret, 3, 5, &mut OsRng).unwrap();
+
+        // A valid share of the secret with the randomness share of another party.
+        let share = Share {
+            share: shares[&1].share,
+            randomness: shares[&2].randomness,
+        };
+        assert_eq!(
+            vss.verify_share(1, 3, &share, &commitments),
+            Err(Error::InvalidShare(1))
+        );
+
+        assert_eq!(
+            vss.verify_share(1, 2, &shares[&1], &commitments),
+            Err(Error::InvalidCommitments)
+        );
+    }
+
+    #[test]
+    fn resolves_complaints() {
+        let (vss, secret) = setup();
+        let (commitments, shares) = vss.deal(secret, 3, 5, &mut OsRng).unwrap();
+
+        let resolved_shares = vss
+            .resolve_complaints(
+                3,
+                &commitments,
+                HashMap::from([(3, Some(shares[&3].clone()))]),
+            )
+            .unwrap();
+        assert_eq!(
+            resolved_shares[&3],
+            secp256k1::Scalar::new(shares[&3].share, &vss.scalar_public_parameters).unwrap()
+        );
+
+        assert_eq!(
+            vss.resolve_complaints(
+                3,
+                &commitments,
+                HashMap::from([(3, Some(shares[&4].clone())), (5, Some(shares[&5].clone()))]),
+            ),
+            Err(Error::UnresolvedComplaints(vec![3]))
+        );
+    }
+}
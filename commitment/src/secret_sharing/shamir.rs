@@ -0,0 +1,310 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::{vec, vec::Vec};
+use core::iter;
+
+use crypto_bigint::{rand_core::CryptoRngCore, Uint};
+use group::{
+    collections::{HashMap, HashSet},
+    KnownOrderScalar, PartyID,
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::{Error, Result};
+
+/// A polynomial $f(x) = a_0 + a_1 x + \ldots + a_{t-1} x^{t-1}$ over the prime field of `Scalar`,
+/// whose constant term $a_0$ is the shared secret, and any $t$ of whose evaluations determine it.
+///
+/// The share of party $i$ is $f(i)$. As the coefficients are secret, they are wiped from memory
+/// upon drop.
+pub struct Polynomial<const SCALAR_LIMBS: usize, Scalar: KnownOrderScalar<SCALAR_LIMBS>> {
+    coefficients: Vec<Scalar>,
+}
+
+impl<const SCALAR_LIMBS: usize, Scalar: KnownOrderScalar<SCALAR_LIMBS>>
+    Polynomial<SCALAR_LIMBS, Scalar>
+{
+    /// Samples a uniformly random polynomial of degree `threshold - 1` whose constant term is
+    /// `secret`.
+    pub fn sample(
+        secret: Scalar,
+        threshold: PartyID,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Self> {
+        if threshold == 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        let public_parameters = secret.public_parameters();
+        let coefficients = iter::once(Ok(secret))
+            .chain(
+                iter::repeat_with(|| Scalar::sample(&public_parameters, rng))
+                    .take(usize::from(threshold - 1)),
+            )
+            .collect::<group::Result<_>>()?;
+
+        Ok(Self { coefficients })
+    }
+
+    /// The coefficients $a_0, \ldots, a_{t-1}$ of the polynomial.
+    pub fn coefficients(&self) -> &[Scalar] {
+        &self.coefficients
+    }
+
+    /// Evaluates the polynomial at `x`.
+    pub fn evaluate(&self, x: &Scalar) -> Scalar {
+        // Horner's method: starting from the leading coefficient, iteratively multiply by `x` and
+        // add the next coefficient.
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(x.neutral(), |acc, coefficient| acc * x + coefficient)
+    }
+
+    /// Computes the shares $f(1), \ldots, f(n)$ of the parties `1..=number_of_parties`.
+    pub fn shares(&self, number_of_parties: PartyID) -> Result<HashMap<PartyID, Scalar>> {
+        if usize::from(number_of_parties) < self.coefficients.len() {
+            return Err(Error::InvalidParameters);
+        }
+
+        let public_parameters = self.coefficients[0].public_parameters();
+
+        (1..=number_of_parties)
+            .map(|party_id| {
+                let x = evaluation_point(party_id, &public_parameters)?;
+
+                Ok((party_id, self.evaluate(&x)))
+            })
+            .collect()
+    }
+}
+
+impl<const SCALAR_LIMBS: usize, Scalar: KnownOrderScalar<SCALAR_LIMBS>> Drop
+    for Polynomial<SCALAR_LIMBS, Scalar>
+{
+    fn drop(&mut self) {
+        self.coefficients.zeroize();
+    }
+}
+
+impl<const SCALAR_LIMBS: usize, Scalar: KnownOrderScalar<SCALAR_LIMBS>> ZeroizeOnDrop
+    for Polynomial<SCALAR_LIMBS, Scalar>
+{
+}
+
+/// Shares `secret` among the parties `1..=number_of_parties`, such that any `threshold` of them
+/// can reconstruct it.
+pub fn share<const SCALAR_LIMBS: usize, Scalar: KnownOrderScalar<SCALAR_LIMBS>>(
+    secret: Scalar,
+    threshold: PartyID,
+    number_of_parties: PartyID,
+    rng: &mut impl CryptoRngCore,
+) -> Result<HashMap<PartyID, Scalar>> {
+    Polynomial::sample(secret, threshold, rng)?.shares(number_of_parties)
+}
+
+/// Reconstructs the secret from the `shares` of (at least) `threshold` parties, by interpolating
+/// the polynomial at zero.
+pub fn reconstruct<const SCALAR_LIMBS: usize, Scalar: KnownOrderScalar<SCALAR_LIMBS>>(
+    shares: &HashMap<PartyID, Scalar>,
+    threshold: PartyID,
+    public_parameters: &Scalar::PublicParameters,
+) -> Result<Scalar> {
+    if threshold == 0 || shares.len() < usize::from(threshold) {
+        return Err(Error::ThresholdNotReached);
+    }
+
+    let parties: HashSet<PartyID> = shares.keys().copied().collect();
+    let lagrange_coefficients: HashMap<PartyID, Scalar> =
+        lagrange_coefficients(&parties, public_parameters)?;
+
+    Ok(shares.iter().fold(
+        Scalar::new(Uint::<SCALAR_LIMBS>::ZERO.into(), public_parameters)?,
+        |secret, (party_id, share)| secret + lagrange_coefficients[party_id] * share,
+    ))
+}
+
+/// Computes the Lagrange coefficient $\lambda_i = \prod_{j \in S \setminus \{i\}}\frac{j}{j - i}$
+/// of `party_id` $i$ within the set `parties` $S$, with which the secret is interpolated from the
+/// shares of $S$ as $f(0) = \sum_{i \in S}\lambda_i f(i)$.
+pub fn lagrange_coefficient<const SCALAR_LIMBS: usize, Scalar: KnownOrderScalar<SCALAR_LIMBS>>(
+    party_id: PartyID,
+    parties: &HashSet<PartyID>,
+    public_parameters: &Scalar::PublicParameters,
+) -> Result<Scalar> {
+    let (numerator, denominator): (Scalar, Scalar) =
+        lagrange_coefficient_fraction(party_id, parties, public_parameters)?;
+
+    // The evaluation points of distinct parties are distinct, so the denominator is invertible.
+    Option::<Scalar>::from(denominator.invert())
+        .map(|inverted_denominator| numerator * inverted_denominator)
+        .ok_or(Error::InvalidParameters)
+}
+
+/// Computes the Lagrange coefficients of all `parties`, see [`lagrange_coefficient()`].
+///
+/// The denominators of all coefficients are inverted together with
+/// [`group::Invert::batch_invert()`].
+pub fn lagrange_coefficients<const SCALAR_LIMBS: usize, Scalar: KnownOrderScalar<SCALAR_LIMBS>>(
+    parties: &HashSet<PartyID>,
+    public_parameters: &Scalar::PublicParameters,
+) -> Result<HashMap<PartyID, Scalar>> {
+    let (party_ids, (numerators, denominators)): (Vec<_>, (Vec<Scalar>, Vec<Scalar>)) = parties
+        .iter()
+        .map(|party_id| {
+            Ok((
+                *party_id,
+                lagrange_coefficient_fraction::<SCALAR_LIMBS, Scalar>(
+                    *party_id,
+                    parties,
+                    public_parameters,
+                )?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    let inverted_denominators: Vec<Scalar> =
+        Option::from(Scalar::batch_invert(&denominators)).ok_or(Error::InvalidParameters)?;
+
+    Ok(party_ids
+        .into_iter()
+        .zip(numerators)
+        .zip(inverted_denominators)
+        .map(|((party_id, numerator), inverted_denominator)| {
+            (party_id, numerator * inverted_denominator)
+        })
+        .collect())
+}
+
+/// Computes the numerator $\prod_{j \in S \setminus \{i\}} j$ and the denominator
+/// $\prod_{j \in S \setminus \{i\}} (j - i)$ of the Lagrange coefficient of `party_id` $i$
+/// within `parties` $S$.
+fn lagrange_coefficient_fraction<
+    const SCALAR_LIMBS: usize,
+    Scalar: KnownOrderScalar<SCALAR_LIMBS>,
+>(
+    party_id: PartyID,
+    parties: &HashSet<PartyID>,
+    public_parameters: &Scalar::PublicParameters,
+) -> Result<(Scalar, Scalar)> {
+    if !parties.contains(&party_id) {
+        return Err(Error::InvalidParties(vec![party_id]));
+    }
+
+    let one = Scalar::new(Uint::<SCALAR_LIMBS>::ONE.into(), public_parameters)?;
+    let x: Scalar = evaluation_point(party_id, public_parameters)?;
+
+    parties
+        .iter()
+        .filter(|other_party_id| **other_party_id != party_id)
+        .try_fold((one, one), |(numerator, denominator), other_party_id| {
+            let other_x: Scalar = evaluation_point(*other_party_id, public_parameters)?;
+
+            Ok((numerator * other_x, denominator * (other_x - x)))
+        })
+}
+
+/// The point $x = i$ at which the polynomial is evaluated for the share of `party_id` $i$.
+///
+/// As $f(0)$ is the secret itself, party IDs must be non-zero.
+pub fn evaluation_point<const SCALAR_LIMBS: usize, Scalar: KnownOrderScalar<SCALAR_LIMBS>>(
+    party_id: PartyID,
+    public_parameters: &Scalar::PublicParameters,
+) -> Result<Scalar> {
+    if party_id == 0 {
+        return Err(Error::InvalidParties(vec![party_id]));
+    }
+
+    Ok(Scalar::new(
+        Uint::<SCALAR_LIMBS>::from(party_id).into(),
+        public_parameters,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{secp256k1, Samplable};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn reconstructs_from_any_threshold_of_shares() {
+        let public_parameters = secp256k1::scalar::PublicParameters::default();
+        let secret = secp256k1::Scalar::sample(&public_parameters, &mut OsRng).unwrap();
+
+        let shares = share(secret, 3, 5, &mut OsRng).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        for parties in [vec![1, 2, 3], vec![2, 4, 5], vec![1, 3, 4, 5]] {
+            let subset: HashMap<_, _> = parties
+                .into_iter()
+                .map(|party_id| (party_id, shares[&party_id]))
+                .collect();
+
+            assert_eq!(reconstruct(&subset, 3, &public_parameters).unwrap(), secret);
+        }
+
+        let subset: HashMap<_, _> = [1, 2].map(|party_id| (party_id, shares[&party_id])).into();
+        assert_eq!(
+            reconstruct(&subset, 3, &public_parameters),
+            Err(Error::ThresholdNotReached)
+        );
+        assert_ne!(
+            reconstruct(&subset, 2, &public_parameters).unwrap(),
+            secret,
+            "fewer than threshold shares should not determine the secret"
+        );
+    }
+
+    #[test]
+    fn lagrange_coefficients_interpolate_constant_polynomials() {
+        let public_parameters = secp256k1::scalar::PublicParameters::default();
+        let parties = HashSet::from([1, 3, 7, 8]);
+
+        let sum = lagrange_coefficients::<{ secp256k1::SCALAR_LIMBS }, secp256k1::Scalar>(
+            &parties,
+            &public_parameters,
+        )
+        .unwrap()
+        .into_values()
+        .reduce(|a, b| a + b)
+        .unwrap();
+
+        // Interpolating $f(x) = 1$ at zero yields $\sum_i \lambda_i = 1$.
+        assert_eq!(sum, secp256k1::Scalar::from(crypto_bigint::U256::ONE));
+
+        assert_eq!(
+            lagrange_coefficient::<{ secp256k1::SCALAR_LIMBS }, secp256k1::Scalar>(
+                2,
+                &parties,
+                &public_parameters
+            ),
+            Err(Error::InvalidParties(vec![2]))
+        );
+        assert_eq!(
+            evaluation_point::<{ secp256k1::SCALAR_LIMBS }, secp256k1::Scalar>(
+                0,
+                &public_parameters
+            ),
+            Err(Error::InvalidParties(vec![0]))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let secret = secp256k1::Scalar::sample(&Default::default(), &mut OsRng).unwrap();
+
+        assert!(matches!(
+            Polynomial::sample(secret, 0, &mut OsRng),
+            Err(Error::InvalidParameters)
+        ));
+        assert_eq!(
+            share(secret, 4, 3, &mut OsRng),
+            Err(Error::InvalidParameters)
+        );
+    }
+}
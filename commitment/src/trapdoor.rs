@@ -0,0 +1,388 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Trapdoor (equivocable) [`Pedersen`] and [`MultiPedersen`] commitments, for simulation-based
+//! tests.
+//!
+//! Whoever knows the trapdoor of the public parameters can open a commitment to any message, i.e.
+//! binding does not hold against them. This module is therefore only available under the
+//! `trapdoor` feature together with `--cfg commitment_trapdoor`, which fails to compile without
+//! `debug_assertions` (i.e. in release builds), and must never be set by anything but tests.
+//! Enabling the feature alone, e.g. through `--all-features`, does not expose it.
+
+use core::array;
+
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::{helpers::FlatMapResults, self_product, PrimeGroupElement, Samplable};
+
+use crate::{
+    multipedersen, pedersen, Error, HomomorphicCommitmentScheme, MultiPedersen, Pedersen, Result,
+};
+
+/// The trapdoor of [`Pedersen`] public parameters: the discrete logs $x_i$ of the message
+/// generators $G_i = x_i\cdot H$ with respect to the randomness generator $H$.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Trapdoor<const BATCH_SIZE: usize, Scalar> {
+    pub message_generators_discrete_logs: [Scalar; BATCH_SIZE],
+}
+
+/// A commitment scheme whose commitments can be opened to any message by whoever knows the
+/// trapdoor of its public parameters.
+pub trait Equivocable<const MESSAGE_SPACE_SCALAR_LIMBS: usize>:
+    HomomorphicCommitmentScheme<MESSAGE_SPACE_SCALAR_LIMBS>
+{
+    type Trapdoor;
+
+    /// Returns the randomness with which `commitment` opens to `new_message`, given its opening
+    /// `old_opening` = (message, randomness).
+    ///
+    /// Fails with [`Error::WrongDecommitment`] if `old_opening` does not open `commitment`, and
+    /// with [`Error::InvalidPublicParameters`] if `trapdoor` is not that of the public parameters
+    /// of `self`.
+    fn equivocate(
+        &self,
+        trapdoor: &Self::Trapdoor,
+        commitment: &Self::CommitmentSpaceGroupElement,
+        old_opening: &(
+            Self::MessageSpaceGroupElement,
+            Self::RandomnessSpaceGroupElement,
+        ),
+        new_message: &Self::MessageSpaceGroupElement,
+    ) -> Result<Self::RandomnessSpaceGroupElement>;
+}
+
+impl<const BATCH_SIZE: usize, const SCALAR_LIMBS: usize, GroupElement> Equivocable<SCALAR_LIMBS>
+    for Pedersen<BATCH_SIZE, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>
+where
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+{
+    type Trapdoor = Trapdoor<BATCH_SIZE, GroupElement::Scalar>;
+
+    fn equivocate(
+        &self,
+        trapdoor: &Self::Trapdoor,
+        commitment: &GroupElement,
+        old_opening: &(
+            self_product::GroupElement<BATCH_SIZE, GroupElement::Scalar>,
+            GroupElement::Scalar,
+        ),
+        new_message: &self_product::GroupElement<BATCH_SIZE, GroupElement::Scalar>,
+    ) -> Result<GroupElement::Scalar> {
+        let (message, randomness) = old_opening;
+        if self.commit(message, randomness) != *commitment {
+            return Err(Error::WrongDecommitment);
+        }
+
+        let messages: &[GroupElement::Scalar; BATCH_SIZE] = message.into();
+        let new_messages: &[GroupElement::Scalar; BATCH_SIZE] = new_message.into();
+
+        // As $\sum_i m_i\cdot G_i + \rho\cdot H = (\sum_i m_i x_i + \rho)\cdot H$,
+        // $\rho' = \rho + \sum_i (m_i - m'_i) x_i$ opens the commitment to $\vec{m}'$.
+        let new_randomness = messages
+            .iter()
+            .zip(new_messages)
+            .zip(&trapdoor.message_generators_discrete_logs)
+            .fold(
+                *randomness,
+                |acc, ((message, new_message), discrete_log)| {
+                    acc + (*message - *new_message) * discrete_log
+                },
+            );
+
+        if self.commit(new_message, &new_randomness) != *commitment {
+            return Err(Error::InvalidPublicParameters);
+        }
+
+        Ok(new_randomness)
+    }
+}
+
+impl<const BATCH_SIZE: usize, const SCALAR_LIMBS: usize, GroupElement> Equivocable<SCALAR_LIMBS>
+    for MultiPedersen<BATCH_SIZE, SCALAR_LIMBS, GroupElement::Scalar, GroupElement>
+where
+    GroupElement: PrimeGroupElement<SCALAR_LIMBS>,
+{
+    type Trapdoor = Trapdoor<1, GroupElement::Scalar>;
+
+    fn equivocate(
+        &self,
+        trapdoor: &Self::Trapdoor,
+        commitment: &self_product::GroupElement<BATCH_SIZE, GroupElement>,
+        old_opening: &(
+            self_product::GroupElement<BATCH_SIZE, GroupElement::Scalar>,
+            self_product::GroupElement<BATCH_SIZE, GroupElement::Scalar>,
+        ),
+        new_message: &self_product::GroupElement<BATCH_SIZE, GroupElement::Scalar>,
+    ) -> Result<self_product::GroupElement<BATCH_SIZE, GroupElement::Scalar>> {
+        let (message, randomness) = old_opening;
+        let commitments: &[GroupElement; BATCH_SIZE] = commitment.into();
+        let messages: &[GroupElement::Scalar; BATCH_SIZE] = message.into();
+        let randomnesses: &[GroupElement::Scalar; BATCH_SIZE] = randomness.into();
+        let new_messages: &[GroupElement::Scalar; BATCH_SIZE] = new_message.into();
+
+        // Each commitment is a [`Pedersen`] commitment to a single message under the same
+        // generators, and is equivocated independently.
+        let new_randomnesses = array::from_fn(|i| {
+            self.0.equivocate(
+                trapdoor,
+                &commitments[i],
+                &([messages[i]].into(), randomnesses[i]),
+                &[new_messages[i]].into(),
+            )
+        })
+        .flat_map_results()?;
+
+        Ok(new_randomnesses.into())
+    }
+}
+
+impl<
+        const BATCH_SIZE: usize,
+        GroupElementValue: Clone,
+        ScalarPublicParameters: Clone,
+        GroupPublicParameters: Clone,
+    >
+    pedersen::PublicParameters<
+        BATCH_SIZE,
+        GroupElementValue,
+        ScalarPublicParameters,
+        GroupPublicParameters,
+    >
+{
+    /// Samples public parameters along with their [`Trapdoor`]: the randomness generator $H$ is a
+    /// random multiple of the generator of the group, and the message generators are
+    /// $G_i = x_i\cdot H$ for random $x_i$.
+    pub fn setup_with_trapdoor<const SCALAR_LIMBS: usize, GroupElement>(
+        scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: group::PublicParameters<GroupElement>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self, Trapdoor<BATCH_SIZE, GroupElement::Scalar>)>
+    where
+        GroupElement::Scalar: group::GroupElement<PublicParameters = ScalarPublicParameters>,
+        GroupElement: group::GroupElement<Value = GroupElementValue, PublicParameters = GroupPublicParameters>
+            + PrimeGroupElement<SCALAR_LIMBS>,
+    {
+        let generator = GroupElement::generator_from_public_parameters(&group_public_parameters)?;
+        let randomness_generator =
+            GroupElement::Scalar::sample(&scalar_public_parameters, rng)? * generator;
+
+        let message_generators_discrete_logs =
+            array::from_fn(|_| GroupElement::Scalar::sample(&scalar_public_parameters, rng))
+                .flat_map_results()?;
+
+        let message_generators = message_generators_discrete_logs.map(|discrete_log| {
+            group::GroupElement::value(&randomness_generator.scalar_mul(&discrete_log.into()))
+        });
+
+        Ok((
+            Self::new::<SCALAR_LIMBS, GroupElement::Scalar, GroupElement>(
+                scalar_public_parameters,
+                group_public_parameters,
+                message_generators,
+                group::GroupElement::value(&randomness_generator),
+            ),
+            Trapdoor {
+                message_generators_discrete_logs,
+            },
+        ))
+    }
+}
+
+impl<
+        const BATCH_SIZE: usize,
+        GroupElementValue: Clone,
+        ScalarPublicParameters: Clone,
+        GroupPublicParameters: Clone,
+    >
+    multipedersen::PublicParameters<
+        BATCH_SIZE,
+        GroupElementValue,
+        ScalarPublicParameters,
+        GroupPublicParameters,
+    >
+{
+    /// Samples public parameters along with the [`Trapdoor`] of the underlying [`Pedersen`]
+    /// commitment, see [`pedersen::PublicParameters::setup_with_trapdoor`].
+    pub fn setup_with_trapdoor<const SCALAR_LIMBS: usize, GroupElement>(
+        scalar_public_parameters: group::PublicParameters<GroupElement::Scalar>,
+        group_public_parameters: group::PublicParameters<GroupElement>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self, Trapdoor<1, GroupElement::Scalar>)>
+    where
+        GroupElement::Scalar: group::GroupElement<PublicParameters = ScalarPublicParameters>,
+        GroupElement: group::GroupElement<Value = GroupElementValue, PublicParameters = GroupPublicParameters>
+            + PrimeGroupElement<SCALAR_LIMBS>,
+    {
+        let (pedersen_public_parameters, trapdoor) =
+            pedersen::PublicParameters::setup_with_trapdoor::<SCALAR_LIMBS, GroupElement>(
+                scalar_public_parameters,
+                group_public_parameters,
+                rng,
+            )?;
+
+        Ok((pedersen_public_parameters.into(), trapdoor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{secp256k1, GroupElement as _};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::GroupsPublicParametersAccessors as _;
+
+    type SecpPedersen<const BATCH_SIZE: usize> = Pedersen<
+        BATCH_SIZE,
+        { secp256k1::SCALAR_LIMBS },
+        secp256k1::Scalar,
+        secp256k1::GroupElement,
+    >;
+
+    type SecpMultiPedersen<const BATCH_SIZE: usize> = MultiPedersen<
+        BATCH_SIZE,
+        { secp256k1::SCALAR_LIMBS },
+        secp256k1::Scalar,
+        secp256k1::GroupElement,
+    >;
+
+    #[test]
+    fn equivocates_pedersen() {
+        let (public_parameters, trapdoor) = pedersen::PublicParameters::setup_with_trapdoor::<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::GroupElement,
+        >(
+            secp256k1::scalar::PublicParameters::default(),
+            secp256k1::group_element::PublicParameters::default(),
+            &mut OsRng,
+        )
+        .unwrap();
+        let commitment_scheme = SecpPedersen::<2>::new(&public_parameters).unwrap();
+
+        let message = self_product::GroupElement::<2, secp256k1::Scalar>::sample(
+            public_parameters.message_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+        let randomness = secp256k1::Scalar::sample(
+            public_parameters.randomness_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+        let new_message = self_product::GroupElement::<2, secp256k1::Scalar>::sample(
+            public_parameters.message_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+
+        let commitment = commitment_scheme.commit(&message, &randomness);
+        let new_randomness = commitment_scheme
+            .equivocate(&trapdoor, &commitment, &(message, randomness), &new_message)
+            .unwrap();
+
+        assert_ne!(new_message, message);
+        assert_eq!(
+            commitment_scheme.commit(&new_message, &new_randomness),
+            commitment
+        );
+
+        assert_eq!(
+            commitment_scheme.equivocate(
+                &trapdoor,
+                &commitment,
+                &(new_message, randomness),
+                &message
+            ),
+            Err(Error::WrongDecommitment)
+        );
+
+        let wrong_trapdoor = Trapdoor {
+            message_generators_discrete_logs: trapdoor
+                .message_generators_discrete_logs
+                .map(|discrete_log| discrete_log + discrete_log),
+        };
+        assert_eq!(
+            commitment_scheme.equivocate(
+                &wrong_trapdoor,
+                &commitment,
+                &(message, randomness),
+                &new_message
+            ),
+            Err(Error::InvalidPublicParameters)
+        );
+    }
+
+    #[test]
+    fn equivocates_multipedersen() {
+        let (public_parameters, trapdoor) = multipedersen::PublicParameters::setup_with_trapdoor::<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::GroupElement,
+        >(
+            secp256k1::scalar::PublicParameters::default(),
+            secp256k1::group_element::PublicParameters::default(),
+            &mut OsRng,
+        )
+        .unwrap();
+        let commitment_scheme = SecpMultiPedersen::<3>::new(&public_parameters).unwrap();
+
+        let message = self_product::GroupElement::<3, secp256k1::Scalar>::sample(
+            public_parameters.message_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+        let randomness = self_product::GroupElement::<3, secp256k1::Scalar>::sample(
+            public_parameters.randomness_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+        let new_message = self_product::GroupElement::<3, secp256k1::Scalar>::sample(
+            public_parameters.message_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+
+        let commitment = commitment_scheme.commit(&message, &randomness);
+        let new_randomness = commitment_scheme
+            .equivocate(&trapdoor, &commitment, &(message, randomness), &new_message)
+            .unwrap();
+
+        assert_eq!(
+            commitment_scheme.commit(&new_message, &new_randomness),
+            commitment
+        );
+        assert_eq!(
+            commitment_scheme.equivocate(
+                &trapdoor,
+                &commitment,
+                &(message, new_randomness),
+                &new_message
+            ),
+            Err(Error::WrongDecommitment)
+        );
+    }
+
+    #[test]
+    fn trapdoor_relates_generators() {
+        let (public_parameters, trapdoor) = pedersen::PublicParameters::setup_with_trapdoor::<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::GroupElement,
+        >(
+            secp256k1::scalar::PublicParameters::default(),
+            secp256k1::group_element::PublicParameters::default(),
+            &mut OsRng,
+        )
+        .unwrap();
+
+        let randomness_generator = secp256k1::GroupElement::new(
+            public_parameters.randomness_generator,
+            public_parameters.commitment_space_public_parameters(),
+        )
+        .unwrap();
+        let [discrete_log]: [secp256k1::Scalar; 1] = trapdoor.message_generators_discrete_logs;
+
+        assert_eq!(
+            public_parameters.message_generators,
+            [(discrete_log * randomness_generator).value()]
+        );
+    }
+}
@@ -1,5 +1,7 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
+use alloc::vec::Vec;
+
 use crate::{language::StatementSpaceGroupElement, Proof};
 
 pub mod commitment_round;
@@ -46,18 +48,25 @@ pub(crate) mod tests {
 
     use crypto_bigint::U256;
     use group::{PartyID, Samplable};
-    use proof::range::{bulletproofs, bulletproofs::COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS};
+    use proof::{
+        range::{bulletproofs, bulletproofs::COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS},
+        session::SessionID,
+    };
     use rand_core::OsRng;
+    use zeroize::Zeroize;
 
     use crate::{
         aggregation::commitment_round, language::tests::enhanced_language_public_parameters,
         EnhanceableLanguage, EnhancedLanguage,
     };
 
+    /// The identifier of the aggregation sessions set up in tests.
+    pub(crate) const SESSION_ID: SessionID = SessionID::new(*b"enhanced maurer aggregation test");
+
     pub(crate) fn setup_aggregation<
         const REPETITIONS: usize,
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable,
+        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable + Zeroize,
         Lang: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -126,6 +135,7 @@ pub(crate) mod tests {
                     commitment_round::Party::new_session(
                         party_id,
                         provers.clone(),
+                        SESSION_ID,
                         enhanced_language_public_parameters.clone(),
                         PhantomData,
                         witnesses,
@@ -140,7 +150,7 @@ pub(crate) mod tests {
     pub(crate) fn aggregates<
         const REPETITIONS: usize,
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable,
+        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable + Zeroize,
         Lang: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -178,7 +188,8 @@ pub(crate) mod tests {
 
         assert!(
             proof
-                .verify(
+                .verify_in_session(
+                    &SESSION_ID,
                     &PhantomData,
                     &enhanced_language_public_parameters,
                     statements,
@@ -192,7 +203,7 @@ pub(crate) mod tests {
     pub(crate) fn party_mismatching_maurer_range_proof_statements_aborts_identifiably<
         const REPETITIONS: usize,
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable,
+        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable + Zeroize,
         Lang: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
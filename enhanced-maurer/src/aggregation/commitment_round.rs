@@ -1,13 +1,15 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::HashSet;
+use alloc::vec::Vec;
 
 use commitment::Commitment;
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::{PartyID, Samplable};
-use proof::{range, AggregatableRangeProof};
+use group::{collections::HashSet, PartyID, Samplable};
+use maurer::language::GroupsPublicParametersAccessors as _;
+use proof::{aggregation, range, session::SessionID, AggregatableRangeProof};
 use serde::Serialize;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::{
     aggregation::{decommitment_round, Output},
@@ -22,7 +24,7 @@ pub struct Party<
     const NUM_RANGE_CLAIMS: usize,
     const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedWitnessSpaceGroupElement: Samplable,
+    UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
     Language: EnhanceableLanguage<
         REPETITIONS,
         NUM_RANGE_CLAIMS,
@@ -53,7 +55,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: Samplable,
+        UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -140,7 +142,7 @@ impl<
         const REPETITIONS: usize,
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
-        UnboundedWitnessSpaceGroupElement: Samplable,
+        UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
         Language: EnhanceableLanguage<
             REPETITIONS,
@@ -163,6 +165,7 @@ impl<
     pub fn new_session(
         party_id: PartyID,
         provers: HashSet<PartyID>,
+        session_id: SessionID,
         language_public_parameters: EnhancedPublicParameters<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -195,7 +198,7 @@ impl<
             })
             .unzip();
 
-        let initial_transcript = Proof::<
+        let mut initial_transcript = Proof::<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
             COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
@@ -205,8 +208,10 @@ impl<
             ProtocolContext,
         >::setup_range_proof(
             &protocol_context,
+            language_public_parameters.transcript_version(),
             &language_public_parameters.range_proof_public_parameters,
         )?;
+        session_id.append_to_transcript(&mut initial_transcript, aggregation::COMMITMENT_ROUND);
 
         let range_proof_commitment_round_party = RangeProof::new_session(
             party_id,
@@ -231,10 +236,11 @@ impl<
         let maurer_commitment_round_party = maurer::aggregation::commitment_round::Party {
             party_id,
             provers,
+            session_id,
             language_public_parameters,
             protocol_context,
-            witnesses,
-            randomizers,
+            witnesses: Zeroizing::new(witnesses),
+            randomizers: Zeroizing::new(randomizers),
             statement_masks,
         };
 
@@ -252,7 +258,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: Samplable,
+        UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
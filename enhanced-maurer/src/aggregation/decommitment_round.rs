@@ -1,13 +1,12 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::HashMap;
-
 use commitment::Commitment;
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::{PartyID, Samplable};
+use group::{collections::HashMap, PartyID, Samplable};
 use proof::{range, AggregatableRangeProof};
 use serde::Serialize;
+use zeroize::Zeroize;
 
 use crate::{
     aggregation::{proof_share_round, Output},
@@ -19,7 +18,7 @@ pub struct Party<
     const NUM_RANGE_CLAIMS: usize,
     const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedWitnessSpaceGroupElement: Samplable,
+    UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
     Language: EnhanceableLanguage<
         REPETITIONS,
         NUM_RANGE_CLAIMS,
@@ -53,7 +52,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: Samplable,
+        UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -173,7 +172,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: Samplable,
+        UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -1,12 +1,13 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::HashMap;
+use alloc::{vec, vec::Vec};
 
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::{PartyID, Samplable};
+use group::{collections::HashMap, PartyID, Samplable};
 use proof::{aggregation, range, AggregatableRangeProof};
 use serde::Serialize;
+use zeroize::Zeroize;
 
 use crate::{
     aggregation::Output, language::EnhancedLanguageStatementAccessors, EnhanceableLanguage,
@@ -18,7 +19,7 @@ pub struct Party<
     const NUM_RANGE_CLAIMS: usize,
     const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedWitnessSpaceGroupElement: Samplable,
+    UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
     Language: EnhanceableLanguage<
         REPETITIONS,
         NUM_RANGE_CLAIMS,
@@ -52,7 +53,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: Samplable,
+        UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -208,7 +209,7 @@ where
             COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
         >(true, RangeProof::RANGE_CLAIM_BITS)?;
 
-        if !maurer_proof.responses.into_iter().all(|response| {
+        if !maurer_proof.responses.iter().cloned().all(|response| {
             let (commitment_message, ..): (_, _) = response.into();
             let (commitment_message, _) = commitment_message.into();
 
@@ -258,7 +259,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: Samplable,
+        UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
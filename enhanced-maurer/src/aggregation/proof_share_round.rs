@@ -1,12 +1,11 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::HashMap;
-
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::{PartyID, Samplable};
+use group::{collections::HashMap, PartyID, Samplable};
 use proof::{range, AggregatableRangeProof};
 use serde::Serialize;
+use zeroize::Zeroize;
 
 use crate::{
     aggregation::{proof_aggregation_round, Output},
@@ -18,7 +17,7 @@ pub struct Party<
     const NUM_RANGE_CLAIMS: usize,
     const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
     RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-    UnboundedWitnessSpaceGroupElement: Samplable,
+    UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
     Language: EnhanceableLanguage<
         REPETITIONS,
         NUM_RANGE_CLAIMS,
@@ -52,7 +51,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: Samplable,
+        UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -184,7 +183,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: AggregatableRangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: Samplable,
+        UnboundedWitnessSpaceGroupElement: Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
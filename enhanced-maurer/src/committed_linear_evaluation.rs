@@ -2,16 +2,18 @@
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 #![allow(clippy::type_complexity)]
 
-use std::{array, marker::PhantomData};
+use alloc::vec::Vec;
+use core::{array, marker::PhantomData};
 
 use commitment::{HomomorphicCommitmentScheme, MultiPedersen};
-use crypto_bigint::{Encoding, NonZero, Uint};
+use crypto_bigint::{subtle::ConditionallySelectable, Encoding, NonZero, Uint};
 use group::{
     direct_product, helpers::FlatMapResults, self_product, GroupElement, KnownOrderGroupElement,
     Reduce,
 };
 use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
 use maurer::{language::GroupsPublicParameters, Error, SOUND_PROOFS_REPETITIONS};
+use proof::TranscriptVersion;
 use serde::{Deserialize, Serialize};
 
 use crate::{language::DecomposableWitness, EnhanceableLanguage};
@@ -134,7 +136,7 @@ impl<
         const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
         const SCALAR_LIMBS: usize,
         const DIMENSION: usize,
-        GroupElement: KnownOrderGroupElement<SCALAR_LIMBS>,
+        GroupElement: KnownOrderGroupElement<SCALAR_LIMBS> + ConditionallySelectable,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
     > maurer::Language<SOUND_PROOFS_REPETITIONS>
     for Language<
@@ -197,6 +199,7 @@ where
 
         let ciphertexts_and_upper_bounds = language_public_parameters
             .ciphertexts_and_upper_bounds
+            .clone()
             .map(|(value, upper_bound)| {
                 homomorphic_encryption::CiphertextSpaceGroupElement::<
                     PLAINTEXT_SPACE_SCALAR_LIMBS,
@@ -255,7 +258,7 @@ impl<
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         const SCALAR_LIMBS: usize,
         const DIMENSION: usize,
-        GroupElement: KnownOrderGroupElement<SCALAR_LIMBS>,
+        GroupElement: KnownOrderGroupElement<SCALAR_LIMBS> + ConditionallySelectable,
     >
     EnhanceableLanguage<
         SOUND_PROOFS_REPETITIONS,
@@ -525,7 +528,8 @@ where
     ) -> Self
     where
         GroupElement: group::GroupElement<Value = GroupElementValue, PublicParameters = GroupPublicParameters>
-            + KnownOrderGroupElement<SCALAR_LIMBS>,
+            + KnownOrderGroupElement<SCALAR_LIMBS>
+            + ConditionallySelectable,
         GroupElement::Scalar: group::GroupElement<PublicParameters = ScalarPublicParameters>,
         EncryptionKey: AdditivelyHomomorphicEncryptionKey<
             PLAINTEXT_SPACE_SCALAR_LIMBS,
@@ -566,6 +570,7 @@ where
                     self_product::PublicParameters::<DIMENSION, _>::new(group_public_parameters),
                 )
                     .into(),
+                transcript_version: TranscriptVersion::default(),
             },
             encryption_scheme_public_parameters,
             commitment_scheme_public_parameters,
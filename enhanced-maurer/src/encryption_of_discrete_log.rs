@@ -1,11 +1,12 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 use crypto_bigint::Uint;
 use group::{direct_product, CyclicGroupElement, GroupElement, KnownOrderGroupElement};
 use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
 use maurer::{language::GroupsPublicParameters, SOUND_PROOFS_REPETITIONS};
+use proof::TranscriptVersion;
 use serde::Serialize;
 
 use crate::{language::DecomposableWitness, EnhanceableLanguage};
@@ -119,7 +120,7 @@ impl<
         language_public_parameters: &Self::PublicParameters,
     ) -> maurer::Result<Self::StatementSpaceGroupElement> {
         let base = GroupElement::new(
-            language_public_parameters.generator,
+            language_public_parameters.generator.clone(),
             language_public_parameters.group_public_parameters(),
         )?;
 
@@ -361,6 +362,7 @@ impl<
                     group_public_parameters,
                 )
                     .into(),
+                transcript_version: TranscriptVersion::default(),
             },
             scalar_group_public_parameters,
             encryption_scheme_public_parameters,
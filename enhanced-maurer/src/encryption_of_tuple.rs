@@ -1,6 +1,6 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 use crypto_bigint::{Encoding, Uint};
 use group::{
@@ -9,6 +9,7 @@ use group::{
 };
 use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
 use maurer::{language::GroupsPublicParameters, Error, SOUND_PROOFS_REPETITIONS};
+use proof::TranscriptVersion;
 use serde::{Deserialize, Serialize};
 
 use crate::{language::DecomposableWitness, EnhanceableLanguage};
@@ -148,7 +149,7 @@ where
             PLAINTEXT_SPACE_SCALAR_LIMBS,
             EncryptionKey,
         >::new(
-            language_public_parameters.ciphertext,
+            language_public_parameters.ciphertext.clone(),
             language_public_parameters
                 .encryption_scheme_public_parameters
                 .ciphertext_space_public_parameters(),
@@ -409,6 +410,7 @@ where
                         .ciphertext_space_public_parameters()
                         .clone(),
                 ),
+                transcript_version: TranscriptVersion::default(),
             },
             scalar_group_public_parameters,
             encryption_scheme_public_parameters,
@@ -1,6 +1,10 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::{array, marker::PhantomData};
 
 use commitment::{GroupsPublicParametersAccessors as _, HomomorphicCommitmentScheme};
@@ -19,6 +23,7 @@ use proof::range::{
 };
 use serde::Serialize;
 use tiresias::secret_sharing::shamir::Polynomial;
+use zeroize::Zeroize;
 
 use crate::{Error, Result};
 
@@ -57,7 +62,7 @@ pub trait EnhanceableLanguage<
     const REPETITIONS: usize,
     const NUM_RANGE_CLAIMS: usize,
     const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
-    UnboundedWitnessSpaceGroupElement: GroupElement + Samplable,
+    UnboundedWitnessSpaceGroupElement: GroupElement + Samplable + Zeroize,
 >: maurer::Language<REPETITIONS>
 {
     /// Compose a language witness from the message of a corresponding commitment.
@@ -112,7 +117,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: proof::RangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: GroupElement + Samplable,
+        UnboundedWitnessSpaceGroupElement: GroupElement + Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -441,7 +446,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: proof::RangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: GroupElement + Samplable,
+        UnboundedWitnessSpaceGroupElement: GroupElement + Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -716,6 +721,7 @@ impl<
                         .clone(),
                 )
                     .into(),
+                transcript_version: language_public_parameters.transcript_version(),
             },
             range_proof_public_parameters,
             language_public_parameters,
@@ -979,7 +985,7 @@ pub(crate) mod tests {
     pub(crate) fn enhanced_language_public_parameters<
         const REPETITIONS: usize,
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable,
+        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable + Zeroize,
         Lang: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -1,6 +1,10 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod aggregation;
 pub mod committed_linear_evaluation;
 pub mod encryption_of_discrete_log;
@@ -9,6 +13,8 @@ pub mod language;
 pub mod proof;
 pub mod scaling_of_discrete_log;
 
+use alloc::vec::Vec;
+
 use group::PartyID;
 pub use language::{
     EnhanceableLanguage, EnhancedLanguage, EnhancedPublicParameters, PublicParameters,
@@ -40,12 +46,12 @@ pub enum Error {
 }
 
 /// Maurer result.
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 impl TryInto<::proof::aggregation::Error> for Error {
     type Error = Error;
 
-    fn try_into(self) -> std::result::Result<::proof::aggregation::Error, Self::Error> {
+    fn try_into(self) -> core::result::Result<::proof::aggregation::Error, Self::Error> {
         match self {
             Error::Proof(::proof::Error::Aggregation(e)) => Ok(e),
             Error::Maurer(maurer::Error::Aggregation(e)) => Ok(e),
@@ -53,3 +59,9 @@ impl TryInto<::proof::aggregation::Error> for Error {
         }
     }
 }
+
+impl From<::proof::aggregation::Error> for Error {
+    fn from(e: ::proof::aggregation::Error) -> Self {
+        Error::Proof(::proof::Error::Aggregation(e))
+    }
+}
@@ -3,21 +3,25 @@
 
 #![allow(clippy::type_complexity)]
 
+use alloc::vec::Vec;
 use core::array;
 
 use commitment::GroupsPublicParametersAccessors as _;
 use crypto_bigint::{rand_core::CryptoRngCore, NonZero, RandomMod, Uint};
 use group::{helpers::FlatMapResults, GroupElement, Samplable, StatisticalSecuritySizedNumber};
-use maurer::Language;
+use maurer::{language::GroupsPublicParametersAccessors as _, Language};
 use merlin::Transcript;
 use proof::{
+    aggregation,
     range::{
         CommitmentSchemeMessageSpaceGroupElement, CommitmentSchemeRandomnessSpaceGroupElement,
         PublicParametersAccessors,
     },
-    TranscriptProtocol,
+    session::SessionID,
+    TranscriptProtocol, TranscriptVersion,
 };
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::{
     language::{
@@ -77,7 +81,7 @@ impl<
         const NUM_RANGE_CLAIMS: usize,
         const COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS: usize,
         RangeProof: proof::RangeProof<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>,
-        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable,
+        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable + Zeroize,
         Language: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -134,6 +138,7 @@ impl<
     )> {
         let transcript = Self::setup_range_proof(
             protocol_context,
+            enhanced_language_public_parameters.transcript_version(),
             &enhanced_language_public_parameters.range_proof_public_parameters,
         )?;
 
@@ -211,11 +216,83 @@ impl<
         >,
         rng: &mut impl CryptoRngCore,
     ) -> Result<()> {
-        let transcript = Self::setup_range_proof(
+        self.verify_with_session(
+            None,
+            protocol_context,
+            enhanced_language_public_parameters,
+            statements,
+            rng,
+        )
+    }
+
+    /// Verify an enhanced batched Maurer zero-knowledge proof, aggregated in session `session_id`.
+    pub fn verify_in_session(
+        &self,
+        session_id: &SessionID,
+        protocol_context: &ProtocolContext,
+        enhanced_language_public_parameters: &EnhancedPublicParameters<
+            REPETITIONS,
+            NUM_RANGE_CLAIMS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedWitnessSpaceGroupElement,
+            Language,
+        >,
+        statements: Vec<
+            StatementSpaceGroupElement<
+                REPETITIONS,
+                NUM_RANGE_CLAIMS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+                UnboundedWitnessSpaceGroupElement,
+                Language,
+            >,
+        >,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<()> {
+        self.verify_with_session(
+            Some(session_id),
+            protocol_context,
+            enhanced_language_public_parameters,
+            statements,
+            rng,
+        )
+    }
+
+    fn verify_with_session(
+        &self,
+        session_id: Option<&SessionID>,
+        protocol_context: &ProtocolContext,
+        enhanced_language_public_parameters: &EnhancedPublicParameters<
+            REPETITIONS,
+            NUM_RANGE_CLAIMS,
+            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+            RangeProof,
+            UnboundedWitnessSpaceGroupElement,
+            Language,
+        >,
+        statements: Vec<
+            StatementSpaceGroupElement<
+                REPETITIONS,
+                NUM_RANGE_CLAIMS,
+                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
+                RangeProof,
+                UnboundedWitnessSpaceGroupElement,
+                Language,
+            >,
+        >,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<()> {
+        let mut transcript = Self::setup_range_proof(
             protocol_context,
+            enhanced_language_public_parameters.transcript_version(),
             &enhanced_language_public_parameters.range_proof_public_parameters,
         )?;
 
+        if let Some(session_id) = session_id {
+            session_id.append_to_transcript(&mut transcript, aggregation::COMMITMENT_ROUND);
+        }
+
         let commitments: Vec<_> = statements
             .clone()
             .into_iter()
@@ -240,7 +317,7 @@ impl<
             COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
         >(true, RangeProof::RANGE_CLAIM_BITS)?;
 
-        if !self.maurer_proof.responses.into_iter().all(|response| {
+        if !self.maurer_proof.responses.iter().cloned().all(|response| {
             let (commitment_message, ..): (_, _) = response.into();
             let (commitment_message, _) = commitment_message.into();
 
@@ -251,23 +328,31 @@ impl<
             return Err(Error::OutOfRange);
         }
 
-        Ok(self
-            .maurer_proof
-            .verify(
+        let maurer_proof_verification = match session_id {
+            Some(session_id) => self.maurer_proof.verify_in_session(
+                session_id,
                 protocol_context,
                 enhanced_language_public_parameters,
                 statements,
-            )
-            .and(Ok(self.range_proof.verify(
-                &enhanced_language_public_parameters.range_proof_public_parameters,
-                commitments,
-                transcript,
-                rng,
-            )?))?)
+            ),
+            None => self.maurer_proof.verify(
+                protocol_context,
+                enhanced_language_public_parameters,
+                statements,
+            ),
+        };
+
+        Ok(maurer_proof_verification.and(Ok(self.range_proof.verify(
+            &enhanced_language_public_parameters.range_proof_public_parameters,
+            commitments,
+            transcript,
+            rng,
+        )?))?)
     }
 
     pub(crate) fn setup_range_proof(
         protocol_context: &ProtocolContext,
+        transcript_version: TranscriptVersion,
         range_proof_public_parameters: &proof::range::PublicParameters<
             COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
             NUM_RANGE_CLAIMS,
@@ -281,12 +366,17 @@ impl<
             RangeProof::NAME.as_bytes(),
         );
 
-        transcript.serialize_to_transcript_as_json(
+        transcript.serialize_to_transcript_with_version(
             b"range proof public parameters",
             range_proof_public_parameters,
+            transcript_version,
         )?;
 
-        transcript.serialize_to_transcript_as_json(b"protocol context", protocol_context)?;
+        transcript.serialize_to_transcript_with_version(
+            b"protocol context",
+            protocol_context,
+            transcript_version,
+        )?;
 
         Ok(transcript)
     }
@@ -436,6 +526,7 @@ pub(crate) mod tests {
 
     use ::bulletproofs::{BulletproofGens, PedersenGens};
     use crypto_bigint::{U256, U64};
+    use group::wire::WireEncoding;
     use proof::range::{
         bulletproofs,
         bulletproofs::{COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS, RANGE_CLAIM_BITS},
@@ -450,7 +541,7 @@ pub(crate) mod tests {
     pub(crate) fn valid_proof_verifies<
         const REPETITIONS: usize,
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable,
+        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable + Zeroize,
         Lang: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -500,8 +591,15 @@ pub(crate) mod tests {
         )
         .unwrap();
 
+        let decoded_proof =
+            Proof::from_wire_bytes(&proof.to_wire_bytes().unwrap()).expect("proofs should decode");
+        assert_eq!(
+            decoded_proof, proof,
+            "enhanced proofs should round-trip through the wire format"
+        );
+
         assert!(
-            proof
+            decoded_proof
                 .verify(
                     &PhantomData,
                     &enhanced_language_public_parameters,
@@ -516,7 +614,7 @@ pub(crate) mod tests {
     pub(crate) fn proof_with_out_of_range_witness_fails<
         const REPETITIONS: usize,
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable,
+        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable + Zeroize,
         Lang: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -599,6 +697,7 @@ pub(crate) mod tests {
             PhantomData<()>,
         >::setup_range_proof(
             &PhantomData,
+            enhanced_language_public_parameters.transcript_version(),
             &enhanced_language_public_parameters.range_proof_public_parameters,
         )
         .unwrap();
@@ -715,7 +814,7 @@ pub(crate) mod tests {
     pub(crate) fn proof_with_valid_range_proof_over_wrong_witness_fails<
         const REPETITIONS: usize,
         const NUM_RANGE_CLAIMS: usize,
-        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable,
+        UnboundedWitnessSpaceGroupElement: group::GroupElement + Samplable + Zeroize,
         Lang: EnhanceableLanguage<
             REPETITIONS,
             NUM_RANGE_CLAIMS,
@@ -760,6 +859,7 @@ pub(crate) mod tests {
             PhantomData<()>,
         >::setup_range_proof(
             &PhantomData,
+            enhanced_language_public_parameters.transcript_version(),
             &enhanced_language_public_parameters.range_proof_public_parameters,
         )
         .unwrap();
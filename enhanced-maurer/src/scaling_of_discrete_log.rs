@@ -1,11 +1,12 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 use crypto_bigint::{Encoding, Uint};
 use group::{direct_product, GroupElement, KnownOrderGroupElement, PrimeGroupElement};
 use homomorphic_encryption::{AdditivelyHomomorphicEncryptionKey, GroupsPublicParametersAccessors};
 use maurer::{language::GroupsPublicParameters, Error, SOUND_PROOFS_REPETITIONS};
+use proof::TranscriptVersion;
 use serde::{Deserialize, Serialize};
 
 use crate::{language::DecomposableWitness, EnhanceableLanguage};
@@ -152,7 +153,7 @@ where
             PLAINTEXT_SPACE_SCALAR_LIMBS,
             EncryptionKey,
         >::new(
-            language_public_parameters.ciphertext,
+            language_public_parameters.ciphertext.clone(),
             language_public_parameters
                 .encryption_scheme_public_parameters
                 .ciphertext_space_public_parameters(),
@@ -410,6 +411,7 @@ where
                     group_public_parameters,
                 )
                     .into(),
+                transcript_version: TranscriptVersion::default(),
             },
             scalar_group_public_parameters,
             encryption_scheme_public_parameters,
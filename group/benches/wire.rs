@@ -0,0 +1,61 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use crypto_bigint::{Random, U256};
+use group::{secp256k1, wire::WireEncoding, CyclicGroupElement, GroupElement};
+use rand_core::OsRng;
+
+/// Benchmark the encoding and decoding of secp256k1 points in the wire format against JSON, with
+/// the throughput measured over the size of each encoding.
+fn wire_encoding(c: &mut Criterion) {
+    let generator = secp256k1::GroupElement::generator_from_public_parameters(
+        &secp256k1::group_element::PublicParameters::default(),
+    )
+    .unwrap();
+
+    let mut group = c.benchmark_group("secp256k1 points");
+
+    for number_of_points in [1, 16, 256] {
+        let points: Vec<_> = (0..number_of_points)
+            .map(|_| generator.scalar_mul(&U256::random(&mut OsRng)).value())
+            .collect();
+
+        let json = serde_json::to_vec(&points).unwrap();
+        group.throughput(Throughput::Bytes(json.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("JSON encoding", number_of_points),
+            &points,
+            |b, points| b.iter(|| serde_json::to_vec(points).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("JSON decoding", number_of_points),
+            &json,
+            |b, json| {
+                b.iter(|| {
+                    serde_json::from_slice::<Vec<secp256k1::group_element::Value>>(json).unwrap()
+                })
+            },
+        );
+
+        let wire = points.to_wire_bytes().unwrap();
+        group.throughput(Throughput::Bytes(wire.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("wire encoding", number_of_points),
+            &points,
+            |b, points| b.iter(|| points.to_wire_bytes().unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("wire decoding", number_of_points),
+            &wire,
+            |b, wire| {
+                b.iter(|| Vec::<secp256k1::group_element::Value>::from_wire_bytes(wire).unwrap())
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, wire_encoding);
+criterion_main!(benches);
@@ -1,7 +1,8 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 use crypto_bigint::{
     modular::runtime_mod::{DynResidue, DynResidueParams},
@@ -9,11 +10,13 @@ use crypto_bigint::{
     Encoding, NonZero, RandomMod, Uint,
 };
 use serde::{Deserialize, Serialize};
-use subtle::CtOption;
+use subtle::{Choice, ConditionallySelectable, CtOption};
+use zeroize::Zeroize;
 
 use crate::{
-    BoundedGroupElement, CyclicGroupElement, GroupElement as _, Invert, KnownOrderGroupElement,
-    KnownOrderScalar, MulByGenerator, Reduce, Samplable,
+    hash_to_field::hash_to_field, BoundedGroupElement, CyclicGroupElement, GroupElement as _,
+    HashToScalar, Invert, KnownOrderGroupElement, KnownOrderScalar, MulByGenerator, MultiScalarMul,
+    Reduce, Samplable, VartimeGroupElement,
 };
 
 /// An element of the additive group of integers for an odd modulo `n = modulus`
@@ -36,6 +39,18 @@ where
     }
 }
 
+impl<const LIMBS: usize> Zeroize for GroupElement<LIMBS> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const LIMBS: usize> ConditionallySelectable for GroupElement<LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(DynResidue::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
 /// The public parameters of the additive group of integers modulo `n = modulus`
 /// $\mathbb{Z}_n^+$.
 #[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
@@ -308,6 +323,36 @@ where
 
 impl<const LIMBS: usize> KnownOrderScalar<LIMBS> for GroupElement<LIMBS> where Uint<LIMBS>: Encoding {}
 
+impl<const LIMBS: usize> MultiScalarMul for GroupElement<LIMBS> where Uint<LIMBS>: Encoding {}
+
+impl<const LIMBS: usize> HashToScalar for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn hash_to_scalar(
+        bytes: &[u8],
+        dst: &[u8],
+        public_parameters: &Self::PublicParameters,
+    ) -> crate::Result<Self> {
+        // `hash_to_field` reduces enough uniform bytes to be statistically close to uniform modulo
+        // any `modulus`, so this holds for composite moduli as well.
+        let [element] = hash_to_field::<1, LIMBS>(bytes, dst, &public_parameters.modulus)?;
+
+        Self::new(element, public_parameters)
+    }
+}
+
+impl<const LIMBS: usize> VartimeGroupElement for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn scalar_mul_vartime<const RHS_LIMBS: usize>(&self, scalar: &Uint<RHS_LIMBS>) -> Self {
+        // Scalar multiplication is a single modular multiplication, which has no faster
+        // variable-time counterpart.
+        self.scalar_mul(scalar)
+    }
+}
+
 impl<const LIMBS: usize> KnownOrderGroupElement<LIMBS> for GroupElement<LIMBS>
 where
     Uint<LIMBS>: Encoding,
@@ -318,3 +363,63 @@ where
         *public_parameters.modulus
     }
 }
+
+impl<const LIMBS: usize> crate::Encoding for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    const ENCODED_LEN: usize = Uint::<LIMBS>::BYTES;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.value().to_be_bytes().as_ref().to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8], public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        // Reject non-canonical encodings, i.e. ones that are not reduced modulo `n`.
+        let value = Uint::<LIMBS>::from_be_slice(bytes);
+        if value >= *public_parameters.modulus {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        Self::new(value, public_parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U256;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{secp256k1, test_helpers};
+
+    #[test]
+    fn satisfies_conformance() {
+        let public_parameters = PublicParameters::<{ U256::LIMBS }> {
+            modulus: NonZero::new(secp256k1::ORDER).unwrap(),
+        };
+
+        // `new()` reduces values modulo the modulus, so every `Value` is a valid one.
+        test_helpers::samplable_conformance::<GroupElement<{ U256::LIMBS }>>(
+            &public_parameters,
+            vec![],
+            &mut OsRng,
+        );
+    }
+
+    #[test]
+    fn batch_inverts() {
+        let public_parameters = PublicParameters::<{ U256::LIMBS }> {
+            modulus: NonZero::new(secp256k1::ORDER).unwrap(),
+        };
+
+        test_helpers::batch_invert_agrees_with_invert::<
+            { U256::LIMBS },
+            GroupElement<{ U256::LIMBS }>,
+        >(&public_parameters, &mut OsRng);
+    }
+}
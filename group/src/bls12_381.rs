@@ -0,0 +1,81 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use core::{fmt, marker::PhantomData};
+
+use crypto_bigint::{U256, U384};
+pub use g1::G1;
+pub use g2::G2;
+pub use pairing::{Bls12_381, Gt};
+pub use scalar::Scalar;
+use serde::{
+    de::{Error as _, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserializer, Serializer,
+};
+
+mod curve_group;
+pub mod g1;
+pub mod g2;
+pub mod pairing;
+pub mod scalar;
+
+pub const SCALAR_LIMBS: usize = U256::LIMBS;
+
+/// The order `q` of the BLS12-381 $\mathbb{G}_1$, $\mathbb{G}_2$ and $\mathbb{G}_T$ groups.
+pub const ORDER: U256 =
+    U256::from_be_hex("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+
+/// The modulus `p` of the base field $\mathbb{F}_p$ of BLS12-381.
+pub const MODULUS: U384 = U384::from_be_hex("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+
+// Any Weierstrass elliptic curve can be represented as an equation in the following template:
+// $y^2 = x^3 + ax^ + b mod(p)$.
+// For BLS12-381 specifically, $a = 0$ and $b = 4$ for $\mathbb{G}_1$ over $\mathbb{F}_p$, yielding
+// the equation $y^2 = x^3 + 4 mod(p)$, and $b = 4(u + 1)$ for $\mathbb{G}_2$ over
+// $\mathbb{F}_{p^2} = \mathbb{F}_p[u] / (u^2 + 1)$.
+pub const CURVE_EQUATION_A: U384 = U384::ZERO;
+pub const G1_CURVE_EQUATION_B: U384 = U384::from_u8(4);
+pub const G2_CURVE_EQUATION_B: [U384; 2] = [U384::from_u8(4), U384::from_u8(4)];
+
+/// Serializes a fixed-size byte array as a tuple, as `serde` only supports arrays of up to 32
+/// elements.
+fn serialize_bytes<S: Serializer, const N: usize>(
+    bytes: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for byte in bytes {
+        tuple.serialize_element(byte)?;
+    }
+
+    tuple.end()
+}
+
+/// Deserializes a fixed-size byte array serialized by [`serialize_bytes()`].
+fn deserialize_bytes<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    struct BytesVisitor<const N: usize>(PhantomData<[u8; N]>);
+
+    impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+        type Value = [u8; N];
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "an array of {N} bytes")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = [0u8; N];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+            }
+
+            Ok(bytes)
+        }
+    }
+
+    deserializer.deserialize_tuple(N, BytesVisitor(PhantomData))
+}
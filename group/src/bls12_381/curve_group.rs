@@ -0,0 +1,376 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+/// Implements a BLS12-381 prime-order curve group, i.e. $\mathbb{G}_1$ or $\mathbb{G}_2$, as the
+/// `$group` newtype around the `$projective` points of `bls12_381`, of which the `$affine` points
+/// are compressed into `$encoded_len` bytes. The two groups only differ in these types, their
+/// `$name` (and `$doc_name` in documentation) and the coefficient `$curve_equation_b` of their
+/// curve equation.
+///
+/// Expects to be invoked inside a (child) module of `bls12_381`, which imports `$affine` and
+/// `$projective`.
+macro_rules! curve_group {
+    (
+        group: $group:ident,
+        affine: $affine:ident,
+        projective: $projective:ident,
+        name: $name:literal,
+        doc_name: $doc_name:literal,
+        curve_equation_b: $curve_equation_b:ident: $curve_equation_b_type:ty,
+        encoded_len: $encoded_len:literal $(,)?
+    ) => {
+        use alloc::{
+            string::{String, ToString},
+            vec::Vec,
+        };
+        use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+        use bls12_381::hash_to_curve::{ExpandMsgXmd, HashToCurve};
+        use crypto_bigint::{rand_core::CryptoRngCore, Uint, U256, U384};
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+        use sha2_old::Sha256;
+        use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+        use super::{
+            deserialize_bytes, serialize_bytes, $curve_equation_b, Scalar, CURVE_EQUATION_A,
+            MODULUS, ORDER, SCALAR_LIMBS,
+        };
+        use crate::{
+            BoundedGroupElement, CyclicGroupElement, HashToGroup, KnownOrderGroupElement,
+            MulByGenerator, MultiScalarMul, PrimeGroupElement, Samplable, VartimeGroupElement,
+        };
+
+        #[doc = concat!("An element of the BLS12-381 ", $doc_name, " prime group.")]
+        #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+        pub struct $group(pub(super) $projective);
+
+        #[doc = concat!("The public parameters of the BLS12-381 ", $doc_name, " group.")]
+        #[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+        pub struct PublicParameters {
+            name: String,
+            curve_type: String,
+            pub order: U256,
+            pub modulus: U384,
+            pub generator: Value,
+            pub curve_equation_a: U384,
+            pub curve_equation_b: $curve_equation_b_type,
+        }
+
+        impl Default for PublicParameters {
+            fn default() -> Self {
+                Self {
+                    name: $name.to_string(),
+                    curve_type: "Weierstrass".to_string(),
+                    order: ORDER,
+                    modulus: MODULUS,
+                    generator: Value($affine::generator()),
+                    curve_equation_a: CURVE_EQUATION_A,
+                    curve_equation_b: $curve_equation_b,
+                }
+            }
+        }
+
+        #[doc = concat!("The value of the BLS12-381 ", $doc_name, " group used for serialization.")]
+        ///
+        #[doc = concat!("This is a `newtype` around `", stringify!($affine), "` used to control")]
+        /// instantiation; the only way to instantiate this type from outside this module is
+        /// through deserialization, which decodes the compressed encoding of the point and assures
+        /// it is on the curve and in the prime-order subgroup.
+        #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+        pub struct Value($affine);
+
+        impl Serialize for Value {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_bytes(&self.0.to_compressed(), serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Value {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Option::from($affine::from_compressed(&deserialize_bytes(deserializer)?))
+                    .map(Self)
+                    .ok_or_else(|| serde::de::Error::custom(concat!("invalid ", $name, " point")))
+            }
+        }
+
+        impl ConstantTimeEq for Value {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        impl ConditionallySelectable for Value {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                Self($affine::conditional_select(&a.0, &b.0, choice))
+            }
+        }
+
+        impl From<Value> for $affine {
+            fn from(value: Value) -> Self {
+                value.0
+            }
+        }
+
+        impl ConstantTimeEq for $group {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        impl ConditionallySelectable for $group {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                Self($projective::conditional_select(&a.0, &b.0, choice))
+            }
+        }
+
+        impl crate::GroupElement for $group {
+            type Value = Value;
+
+            fn value(&self) -> Self::Value {
+                // As this group element is valid, it's safe to instantiate a `Value`
+                // from the valid affine representation.
+                Value(self.0.into())
+            }
+
+            type PublicParameters = PublicParameters;
+
+            fn public_parameters(&self) -> Self::PublicParameters {
+                PublicParameters::default()
+            }
+
+            fn new(
+                value: Self::Value,
+                _public_parameters: &Self::PublicParameters,
+            ) -> crate::Result<Self> {
+                // `Value` can only be instantiated through deserialization, which assures it is a
+                // valid point of the prime-order subgroup, so this is always safe.
+                Ok(Self(value.0.into()))
+            }
+
+            fn neutral(&self) -> Self {
+                Self($projective::identity())
+            }
+
+            fn scalar_mul<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+                Scalar::from(scalar) * self
+            }
+
+            fn double(&self) -> Self {
+                Self(self.0.double())
+            }
+        }
+
+        impl From<$group> for Value {
+            fn from(value: $group) -> Self {
+                // As this group element is valid, it's safe to instantiate a `Value`
+                // from the valid affine representation.
+                Self(value.0.into())
+            }
+        }
+
+        impl From<$group> for PublicParameters {
+            fn from(_value: $group) -> Self {
+                Self::default()
+            }
+        }
+
+        impl From<$group> for $projective {
+            fn from(value: $group) -> Self {
+                value.0
+            }
+        }
+
+        impl Neg for $group {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(self.0.neg())
+            }
+        }
+
+        impl Add<Self> for $group {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0.add(rhs.0))
+            }
+        }
+
+        impl<'r> Add<&'r Self> for $group {
+            type Output = Self;
+
+            fn add(self, rhs: &'r Self) -> Self::Output {
+                Self(self.0.add(rhs.0))
+            }
+        }
+
+        impl Sub<Self> for $group {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0.sub(rhs.0))
+            }
+        }
+
+        impl<'r> Sub<&'r Self> for $group {
+            type Output = Self;
+
+            fn sub(self, rhs: &'r Self) -> Self::Output {
+                Self(self.0.sub(rhs.0))
+            }
+        }
+
+        impl AddAssign<Self> for $group {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0.add_assign(rhs.0)
+            }
+        }
+
+        impl<'r> AddAssign<&'r Self> for $group {
+            fn add_assign(&mut self, rhs: &'r Self) {
+                self.0.add_assign(rhs.0)
+            }
+        }
+
+        impl SubAssign<Self> for $group {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0.sub_assign(rhs.0)
+            }
+        }
+
+        impl<'r> SubAssign<&'r Self> for $group {
+            fn sub_assign(&mut self, rhs: &'r Self) {
+                self.0.sub_assign(rhs.0)
+            }
+        }
+
+        impl Samplable for $group {
+            fn sample(
+                public_parameters: &Self::PublicParameters,
+                rng: &mut impl CryptoRngCore,
+            ) -> crate::Result<Self> {
+                // As the group is cyclic of prime order, multiplying the generator by a uniformly
+                // random scalar yields a uniformly random element.
+                let scalar = Scalar::sample(&Default::default(), rng)?;
+
+                Ok(Self::generator_from_public_parameters(public_parameters)?
+                    .mul_by_generator(scalar))
+            }
+        }
+
+        impl MulByGenerator<U256> for $group {
+            fn mul_by_generator(&self, scalar: U256) -> Self {
+                self.mul_by_generator(Scalar::from(scalar))
+            }
+        }
+
+        impl<'r> MulByGenerator<&'r U256> for $group {
+            fn mul_by_generator(&self, scalar: &'r U256) -> Self {
+                self.mul_by_generator(*scalar)
+            }
+        }
+
+        impl MultiScalarMul for $group {}
+
+        impl VartimeGroupElement for $group {
+            fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+                // `bls12_381` only exposes constant-time scalar multiplication, which performs an
+                // addition (and selection) per bit of the scalar. Instead, double-and-add over the
+                // bits of the reduced scalar, skipping the leading zeros and the additions of zero
+                // bits.
+                let scalar = U256::from(Scalar::from(scalar));
+
+                Self(
+                    (0..scalar.bits_vartime())
+                        .rev()
+                        .fold($projective::identity(), |acc, i| {
+                            let acc = acc.double();
+                            if scalar.bit_vartime(i) {
+                                acc + self.0
+                            } else {
+                                acc
+                            }
+                        }),
+                )
+            }
+        }
+
+        impl CyclicGroupElement for $group {
+            fn generator(&self) -> Self {
+                Self($projective::generator())
+            }
+
+            fn generator_value_from_public_parameters(
+                _public_parameters: &Self::PublicParameters,
+            ) -> Self::Value {
+                Value($affine::generator())
+            }
+        }
+
+        impl BoundedGroupElement<SCALAR_LIMBS> for $group {
+            fn lower_bound(public_parameters: &Self::PublicParameters) -> Uint<SCALAR_LIMBS> {
+                Self::order_from_public_parameters(public_parameters)
+            }
+        }
+
+        impl KnownOrderGroupElement<SCALAR_LIMBS> for $group {
+            type Scalar = Scalar;
+
+            fn order(&self) -> Uint<SCALAR_LIMBS> {
+                ORDER
+            }
+
+            fn order_from_public_parameters(
+                _public_parameters: &Self::PublicParameters,
+            ) -> Uint<SCALAR_LIMBS> {
+                ORDER
+            }
+        }
+
+        impl MulByGenerator<Scalar> for $group {
+            fn mul_by_generator(&self, scalar: Scalar) -> Self {
+                Self($projective::generator() * scalar.0)
+            }
+        }
+
+        impl<'r> MulByGenerator<&'r Scalar> for $group {
+            fn mul_by_generator(&self, scalar: &'r Scalar) -> Self {
+                self.mul_by_generator(*scalar)
+            }
+        }
+
+        impl PrimeGroupElement<SCALAR_LIMBS> for $group {}
+
+        impl HashToGroup for $group {
+            fn hash_to_group(bytes: &[u8], dst: &[u8]) -> crate::Result<Self> {
+                Ok(Self(
+                    <$projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(bytes, dst),
+                ))
+            }
+        }
+
+        impl crate::Encoding for $group {
+            const ENCODED_LEN: usize = $encoded_len;
+
+            fn to_bytes(&self) -> Vec<u8> {
+                $affine::from(self.0).to_compressed().to_vec()
+            }
+
+            fn from_bytes(
+                bytes: &[u8],
+                _public_parameters: &Self::PublicParameters,
+            ) -> crate::Result<Self> {
+                let bytes = <[u8; $encoded_len]>::try_from(bytes)
+                    .map_err(|_| crate::Error::InvalidGroupElement)?;
+
+                // `from_compressed()` assures the point is on the curve and in the prime-order
+                // subgroup.
+                Option::<$affine>::from($affine::from_compressed(&bytes))
+                    .map(|point| Self(point.into()))
+                    .ok_or(crate::Error::InvalidGroupElement)
+            }
+        }
+    };
+}
+
+pub(super) use curve_group;
@@ -0,0 +1,32 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! The BLS12-381 $\mathbb{G}_1$ group.
+
+use bls12_381::{G1Affine, G1Projective};
+
+use super::curve_group::curve_group;
+
+curve_group!(
+    group: G1,
+    affine: G1Affine,
+    projective: G1Projective,
+    name: "BLS12-381 G1",
+    doc_name: r"$\mathbb{G}_1$",
+    curve_equation_b: G1_CURVE_EQUATION_B: U384,
+    encoded_len: 48,
+);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::test_helpers;
+
+    #[test]
+    fn satisfies_conformance() {
+        // Points are validated upon deserialization, so every `Value` is a valid one.
+        test_helpers::samplable_conformance::<G1>(&PublicParameters::default(), vec![], &mut OsRng);
+    }
+}
@@ -0,0 +1,32 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! The BLS12-381 $\mathbb{G}_2$ group.
+
+use bls12_381::{G2Affine, G2Projective};
+
+use super::curve_group::curve_group;
+
+curve_group!(
+    group: G2,
+    affine: G2Affine,
+    projective: G2Projective,
+    name: "BLS12-381 G2",
+    doc_name: r"$\mathbb{G}_2$",
+    curve_equation_b: G2_CURVE_EQUATION_B: [U384; 2],
+    encoded_len: 96,
+);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::test_helpers;
+
+    #[test]
+    fn satisfies_conformance() {
+        // Points are validated upon deserialization, so every `Value` is a valid one.
+        test_helpers::samplable_conformance::<G2>(&PublicParameters::default(), vec![], &mut OsRng);
+    }
+}
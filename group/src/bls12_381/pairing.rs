@@ -0,0 +1,205 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::vec::Vec;
+use core::ops::{Add, Neg, Sub};
+
+use bls12_381::{multi_miller_loop, G1Affine, G2Affine, G2Prepared};
+use subtle::{Choice, ConstantTimeEq};
+
+use super::{G1, G2, SCALAR_LIMBS};
+use crate::Pairing;
+
+/// The optimal Ate pairing $e: \mathbb{G}_1 \times \mathbb{G}_2 \to \mathbb{G}_T$ of BLS12-381.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Bls12_381;
+
+/// An element of the BLS12-381 target group $\mathbb{G}_T$, in additive notation.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Gt(bls12_381::Gt);
+
+impl ConstantTimeEq for Gt {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl Neg for Gt {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.neg())
+    }
+}
+
+impl Add<Self> for Gt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.add(rhs.0))
+    }
+}
+
+impl<'r> Add<&'r Self> for Gt {
+    type Output = Self;
+
+    fn add(self, rhs: &'r Self) -> Self::Output {
+        Self(self.0.add(rhs.0))
+    }
+}
+
+impl Sub<Self> for Gt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0.sub(rhs.0))
+    }
+}
+
+impl<'r> Sub<&'r Self> for Gt {
+    type Output = Self;
+
+    fn sub(self, rhs: &'r Self) -> Self::Output {
+        Self(self.0.sub(rhs.0))
+    }
+}
+
+impl From<Gt> for bls12_381::Gt {
+    fn from(value: Gt) -> Self {
+        value.0
+    }
+}
+
+impl Pairing<SCALAR_LIMBS> for Bls12_381 {
+    type G1 = G1;
+    type G2 = G2;
+    type Gt = Gt;
+
+    fn pairing(g1: &Self::G1, g2: &Self::G2) -> Self::Gt {
+        Gt(bls12_381::pairing(&g1.0.into(), &g2.0.into()))
+    }
+
+    fn multi_pairing(terms: &[(Self::G1, Self::G2)]) -> Option<Self::Gt> {
+        if terms.is_empty() {
+            return None;
+        }
+
+        // Share the final exponentiation between all terms.
+        let terms: Vec<(G1Affine, G2Prepared)> = terms
+            .iter()
+            .map(|(g1, g2)| (g1.0.into(), G2Affine::from(g2.0).into()))
+            .collect();
+        let terms: Vec<_> = terms.iter().map(|(g1, g2)| (g1, g2)).collect();
+
+        Some(Gt(multi_miller_loop(&terms).final_exponentiation()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{bls12_381::Scalar, CyclicGroupElement, GroupElement as _, HashToGroup, Samplable};
+
+    fn identity() -> Gt {
+        Gt(bls12_381::Gt::identity())
+    }
+
+    #[test]
+    fn pairing_is_bilinear() {
+        let g1 = G1::sample(&Default::default(), &mut OsRng).unwrap();
+        let g2 = G2::sample(&Default::default(), &mut OsRng).unwrap();
+        let a = Scalar::sample(&Default::default(), &mut OsRng).unwrap();
+        let b = Scalar::sample(&Default::default(), &mut OsRng).unwrap();
+
+        // $e(aP, bQ) = ab \cdot e(P, Q)$, in the additive notation of $\mathbb{G}_T$.
+        let pairing = Bls12_381::pairing(&g1, &g2);
+        assert_eq!(
+            Bls12_381::pairing(&(a * g1), &(b * g2)),
+            Gt(bls12_381::Gt::from(pairing) * (a * b).0)
+        );
+        assert_eq!(
+            Bls12_381::pairing(&(a * g1), &g2),
+            Bls12_381::pairing(&g1, &(a * g2))
+        );
+
+        let other_g1 = G1::sample(&Default::default(), &mut OsRng).unwrap();
+        assert_eq!(
+            Bls12_381::pairing(&(g1 + other_g1), &g2),
+            pairing + Bls12_381::pairing(&other_g1, &g2)
+        );
+        assert_eq!(Bls12_381::pairing(&-g1, &g2), -pairing);
+    }
+
+    #[test]
+    fn pairing_is_non_degenerate() {
+        let g1 = G1::sample(&Default::default(), &mut OsRng).unwrap();
+        let g2 = G2::sample(&Default::default(), &mut OsRng).unwrap();
+
+        assert_ne!(
+            Bls12_381::pairing(&g1.generator(), &g2.generator()),
+            identity()
+        );
+        assert_ne!(Bls12_381::pairing(&g1, &g2), identity());
+
+        assert_eq!(Bls12_381::pairing(&g1.neutral(), &g2), identity());
+        assert_eq!(Bls12_381::pairing(&g1, &g2.neutral()), identity());
+    }
+
+    #[test]
+    fn multi_pairing_agrees_with_sum_of_pairings() {
+        let terms: Vec<_> = (0..3)
+            .map(|_| {
+                (
+                    G1::sample(&Default::default(), &mut OsRng).unwrap(),
+                    G2::sample(&Default::default(), &mut OsRng).unwrap(),
+                )
+            })
+            .collect();
+
+        let sum = terms
+            .iter()
+            .map(|(g1, g2)| Bls12_381::pairing(g1, g2))
+            .reduce(|sum, pairing| sum + pairing);
+
+        assert_eq!(Bls12_381::multi_pairing(&terms), sum);
+        assert_eq!(
+            Bls12_381::multi_pairing(&terms[..1]),
+            Some(Bls12_381::pairing(&terms[0].0, &terms[0].1))
+        );
+        assert_eq!(Bls12_381::multi_pairing(&[]), None);
+    }
+
+    #[test]
+    fn verifies_bls_signatures() {
+        const DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+        let generator = G2::sample(&Default::default(), &mut OsRng)
+            .unwrap()
+            .generator();
+        let secret_key = Scalar::sample(&Default::default(), &mut OsRng).unwrap();
+        let public_key = secret_key * generator;
+
+        let message = G1::hash_to_group(b"message", DST).unwrap();
+        let signature = secret_key * message;
+
+        // $e(\sigma, g_2) = e(H(m), pk)$, checked as $e(\sigma, -g_2) + e(H(m), pk) = 0$.
+        assert_eq!(
+            Bls12_381::multi_pairing(&[(signature, -generator), (message, public_key)]),
+            Some(identity())
+        );
+
+        let other_message = G1::hash_to_group(b"other message", DST).unwrap();
+        assert_ne!(
+            Bls12_381::multi_pairing(&[(signature, -generator), (other_message, public_key)]),
+            Some(identity())
+        );
+
+        let other_public_key = Scalar::sample(&Default::default(), &mut OsRng).unwrap() * generator;
+        assert_ne!(
+            Bls12_381::multi_pairing(&[(signature, -generator), (message, other_public_key)]),
+            Some(identity())
+        );
+    }
+}
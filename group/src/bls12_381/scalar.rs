@@ -0,0 +1,481 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use bls12_381::hash_to_curve::{ExpandMsgXmd, HashToField};
+use crypto_bigint::{rand_core::CryptoRngCore, Encoding, NonZero, Uint, U256};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2_old::Sha256;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
+
+use super::{deserialize_bytes, serialize_bytes, G1, G2, ORDER, SCALAR_LIMBS};
+use crate::{
+    BoundedGroupElement, CyclicGroupElement, GroupElement as _, HashToGroup, HashToScalar, Invert,
+    KnownOrderGroupElement, KnownOrderScalar, MulByGenerator, MultiScalarMul, PrimeGroupElement,
+    Reduce, Samplable, VartimeGroupElement,
+};
+
+/// A Scalar of the prime field $\mathbb{Z}_q$ over which the BLS12-381 groups are defined.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Scalar(pub(super) bls12_381::Scalar);
+
+impl Serialize for Scalar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.0.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Scalar {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // `from_bytes()` rejects non-canonical encodings, i.e. ones that are not reduced modulo
+        // the order.
+        Option::from(bls12_381::Scalar::from_bytes(&deserialize_bytes(
+            deserializer,
+        )?))
+        .map(Self)
+        .ok_or_else(|| serde::de::Error::custom("invalid BLS12-381 scalar"))
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(bls12_381::Scalar::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl Samplable for Scalar {
+    fn sample(
+        _public_parameters: &Self::PublicParameters,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Self> {
+        // Reducing 512 uniformly random bits modulo the 255-bit order yields a statistically
+        // uniform scalar.
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+
+        Ok(Self(bls12_381::Scalar::from_bytes_wide(&bytes)))
+    }
+}
+
+/// The public parameters of the BLS12-381 scalar field.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct PublicParameters {
+    name: String,
+    order: U256,
+    generator: Scalar,
+}
+
+impl Default for PublicParameters {
+    fn default() -> Self {
+        PublicParameters {
+            name: "The finite field of integers modulo prime q $\\mathbb{Z}_q$".to_string(),
+            order: ORDER,
+            generator: Scalar(bls12_381::Scalar::one()),
+        }
+    }
+}
+
+impl crate::GroupElement for Scalar {
+    type Value = Self;
+
+    fn value(&self) -> Self::Value {
+        *self
+    }
+
+    type PublicParameters = PublicParameters;
+
+    fn public_parameters(&self) -> Self::PublicParameters {
+        PublicParameters::default()
+    }
+
+    fn new(value: Self::Value, _public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        // Since deserialization of `Scalar` assures values are valid, this is always safe.
+        Ok(value)
+    }
+
+    fn neutral(&self) -> Self {
+        Self(bls12_381::Scalar::zero())
+    }
+
+    fn scalar_mul<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        self * Self::from(scalar)
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0.double())
+    }
+}
+
+impl From<Scalar> for PublicParameters {
+    fn from(_value: Scalar) -> Self {
+        Self::default()
+    }
+}
+
+impl BoundedGroupElement<SCALAR_LIMBS> for Scalar {
+    fn lower_bound(public_parameters: &Self::PublicParameters) -> Uint<SCALAR_LIMBS> {
+        Self::order_from_public_parameters(public_parameters)
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for Scalar {
+    fn from(value: Uint<LIMBS>) -> Self {
+        let value: U256 = value.reduce(&NonZero::new(ORDER).unwrap());
+
+        // The value was reduced modulo the order, so its encoding is canonical and it is safe to
+        // `unwrap()`.
+        Self(bls12_381::Scalar::from_bytes(&value.to_le_bytes()).unwrap())
+    }
+}
+
+impl<const LIMBS: usize> From<&Uint<LIMBS>> for Scalar {
+    fn from(value: &Uint<LIMBS>) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<Scalar> for U256 {
+    fn from(value: Scalar) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&Scalar> for U256 {
+    fn from(value: &Scalar) -> Self {
+        U256::from_le_bytes(value.0.to_bytes())
+    }
+}
+
+impl From<Scalar> for bls12_381::Scalar {
+    fn from(value: Scalar) -> Self {
+        value.0
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.neg())
+    }
+}
+
+impl Add<Self> for Scalar {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.add(&rhs.0))
+    }
+}
+
+impl<'r> Add<&'r Self> for Scalar {
+    type Output = Self;
+
+    fn add(self, rhs: &'r Self) -> Self::Output {
+        Self(self.0.add(&rhs.0))
+    }
+}
+
+impl Sub<Self> for Scalar {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0.sub(&rhs.0))
+    }
+}
+
+impl<'r> Sub<&'r Self> for Scalar {
+    type Output = Self;
+
+    fn sub(self, rhs: &'r Self) -> Self::Output {
+        Self(self.0.sub(&rhs.0))
+    }
+}
+
+impl AddAssign<Self> for Scalar {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.add_assign(rhs.0)
+    }
+}
+
+impl<'r> AddAssign<&'r Self> for Scalar {
+    fn add_assign(&mut self, rhs: &'r Self) {
+        self.0.add_assign(&rhs.0)
+    }
+}
+
+impl SubAssign<Self> for Scalar {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.sub_assign(rhs.0)
+    }
+}
+
+impl<'r> SubAssign<&'r Self> for Scalar {
+    fn sub_assign(&mut self, rhs: &'r Self) {
+        self.0.sub_assign(&rhs.0)
+    }
+}
+
+impl Mul<Self> for Scalar {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0.mul(&rhs.0))
+    }
+}
+
+impl<'r> Mul<&'r Self> for Scalar {
+    type Output = Self;
+
+    fn mul(self, rhs: &'r Self) -> Self::Output {
+        Self(self.0.mul(&rhs.0))
+    }
+}
+
+impl Mul<Scalar> for &Scalar {
+    type Output = Scalar;
+
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        Scalar(self.0.mul(&rhs.0))
+    }
+}
+
+impl<'r> Mul<&'r Scalar> for &Scalar {
+    type Output = Scalar;
+
+    fn mul(self, rhs: &'r Scalar) -> Self::Output {
+        Scalar(self.0.mul(&rhs.0))
+    }
+}
+
+impl Mul<G1> for Scalar {
+    type Output = G1;
+
+    fn mul(self, rhs: G1) -> Self::Output {
+        G1(rhs.0 * self.0)
+    }
+}
+
+impl<'r> Mul<&'r G1> for Scalar {
+    type Output = G1;
+
+    fn mul(self, rhs: &'r G1) -> Self::Output {
+        G1(rhs.0 * self.0)
+    }
+}
+
+impl<'r> Mul<G1> for &'r Scalar {
+    type Output = G1;
+
+    fn mul(self, rhs: G1) -> Self::Output {
+        G1(rhs.0 * self.0)
+    }
+}
+
+impl<'r> Mul<&'r G1> for &'r Scalar {
+    type Output = G1;
+
+    fn mul(self, rhs: &'r G1) -> Self::Output {
+        G1(rhs.0 * self.0)
+    }
+}
+
+impl Mul<G2> for Scalar {
+    type Output = G2;
+
+    fn mul(self, rhs: G2) -> Self::Output {
+        G2(rhs.0 * self.0)
+    }
+}
+
+impl<'r> Mul<&'r G2> for Scalar {
+    type Output = G2;
+
+    fn mul(self, rhs: &'r G2) -> Self::Output {
+        G2(rhs.0 * self.0)
+    }
+}
+
+impl<'r> Mul<G2> for &'r Scalar {
+    type Output = G2;
+
+    fn mul(self, rhs: G2) -> Self::Output {
+        G2(rhs.0 * self.0)
+    }
+}
+
+impl<'r> Mul<&'r G2> for &'r Scalar {
+    type Output = G2;
+
+    fn mul(self, rhs: &'r G2) -> Self::Output {
+        G2(rhs.0 * self.0)
+    }
+}
+
+impl MulByGenerator<U256> for Scalar {
+    fn mul_by_generator(&self, scalar: U256) -> Self {
+        // In the additive scalar group, our generator is 1 and multiplying a group element by it
+        // results in that same element. However, a `U256` might be bigger than the field
+        // order, so we must first reduce it by the modulus to get a valid element.
+        scalar.into()
+    }
+}
+
+impl<'r> MulByGenerator<&'r U256> for Scalar {
+    fn mul_by_generator(&self, scalar: &'r U256) -> Self {
+        self.mul_by_generator(*scalar)
+    }
+}
+
+impl CyclicGroupElement for Scalar {
+    fn generator(&self) -> Self {
+        Scalar(bls12_381::Scalar::one())
+    }
+
+    fn generator_value_from_public_parameters(
+        _public_parameters: &Self::PublicParameters,
+    ) -> Self::Value {
+        Scalar(bls12_381::Scalar::one())
+    }
+}
+
+impl Invert for Scalar {
+    fn invert(&self) -> CtOption<Self> {
+        self.0.invert().map(Self)
+    }
+}
+
+impl PartialOrd for Scalar {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        U256::from(self).partial_cmp(&U256::from(other))
+    }
+}
+
+impl KnownOrderScalar<SCALAR_LIMBS> for Scalar {}
+
+impl MultiScalarMul for Scalar {}
+
+impl VartimeGroupElement for Scalar {
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        // Scalar multiplication is a single multiplication in the field, which has no faster
+        // variable-time counterpart.
+        self.scalar_mul(scalar)
+    }
+}
+
+impl KnownOrderGroupElement<SCALAR_LIMBS> for Scalar {
+    type Scalar = Self;
+    fn order(&self) -> Uint<SCALAR_LIMBS> {
+        ORDER
+    }
+
+    fn order_from_public_parameters(
+        _public_parameters: &Self::PublicParameters,
+    ) -> Uint<SCALAR_LIMBS> {
+        ORDER
+    }
+}
+
+impl MulByGenerator<Scalar> for Scalar {
+    fn mul_by_generator(&self, scalar: Scalar) -> Self {
+        // In the additive scalar group, our generator is 1 and multiplying a group element by it
+        // results in that same element.
+        scalar
+    }
+}
+
+impl<'r> MulByGenerator<&'r Scalar> for Scalar {
+    fn mul_by_generator(&self, scalar: &'r Scalar) -> Self {
+        self.mul_by_generator(*scalar)
+    }
+}
+
+impl PrimeGroupElement<SCALAR_LIMBS> for Scalar {}
+
+impl HashToScalar for Scalar {
+    fn hash_to_scalar(
+        bytes: &[u8],
+        dst: &[u8],
+        _public_parameters: &Self::PublicParameters,
+    ) -> crate::Result<Self> {
+        let mut scalar = [bls12_381::Scalar::zero()];
+        <bls12_381::Scalar as HashToField>::hash_to_field::<ExpandMsgXmd<Sha256>>(
+            bytes,
+            dst,
+            &mut scalar,
+        );
+
+        Ok(Self(scalar[0]))
+    }
+}
+
+impl HashToGroup for Scalar {
+    fn hash_to_group(bytes: &[u8], dst: &[u8]) -> crate::Result<Self> {
+        Self::hash_to_scalar(bytes, dst, &PublicParameters::default())
+    }
+}
+
+impl crate::Encoding for Scalar {
+    const ENCODED_LEN: usize = 32;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        // `bls12_381` encodes scalars in little-endian, whereas we use big-endian.
+        let mut bytes = self.0.to_bytes();
+        bytes.reverse();
+
+        bytes.to_vec()
+    }
+
+    fn from_bytes(
+        bytes: &[u8],
+        _public_parameters: &Self::PublicParameters,
+    ) -> crate::Result<Self> {
+        let mut bytes =
+            <[u8; 32]>::try_from(bytes).map_err(|_| crate::Error::InvalidGroupElement)?;
+        bytes.reverse();
+
+        // `from_bytes()` rejects encodings that are not reduced modulo the order.
+        Option::from(bls12_381::Scalar::from_bytes(&bytes))
+            .map(Self)
+            .ok_or(crate::Error::InvalidGroupElement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::test_helpers;
+
+    #[test]
+    fn satisfies_conformance() {
+        // Scalars are validated upon deserialization, so every `Value` is a valid one.
+        test_helpers::samplable_conformance::<Scalar>(
+            &PublicParameters::default(),
+            vec![],
+            &mut OsRng,
+        );
+    }
+}
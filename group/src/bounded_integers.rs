@@ -0,0 +1,328 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Bounded (signed) integers, as the additive group $\mathbb{Z}_{2^k}$ of `LIMBS`-sized integers
+//! in two's complement, where $k$ is the bit-size of `Uint<LIMBS>`.
+//!
+//! This group serves as the exponent space of hidden-order groups, e.g. for integer commitments:
+//! as the order of such a group is unknown, exponents cannot be reduced modulo it, and are instead
+//! taken to be integers. As long as the (absolute values of the) integers in a computation are
+//! far from $2^{k-1}$, which [`PublicParameters`] assures for sampled integers, its operations
+//! agree with the integer ones and never wrap around.
+
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Random, Uint};
+use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConditionallySelectable};
+use zeroize::Zeroize;
+
+use crate::{BoundedGroupElement, MultiScalarMul, Samplable, VartimeGroupElement};
+
+/// A bounded signed integer, as an element of $\mathbb{Z}_{2^k}$ in two's complement.
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub struct GroupElement<const LIMBS: usize> {
+    value: Uint<LIMBS>,
+    sample_bits: usize,
+}
+
+/// The public parameters of the bounded integers group, which set the bit-size of the
+/// (non-negative) integers sampled from it.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct PublicParameters<const LIMBS: usize> {
+    sample_bits: usize,
+}
+
+impl<const LIMBS: usize> PublicParameters<LIMBS> {
+    /// Instantiate the public parameters of integers in $[0, 2^{\textsf{sample\\_bits}})$.
+    ///
+    /// Returns [`crate::Error::UnsupportedPublicParameters`] unless `sample_bits` is smaller than
+    /// $k - 1$, so that neither the sum of two sampled integers nor their negation overflow.
+    pub fn new(sample_bits: usize) -> crate::Result<Self> {
+        if sample_bits >= Uint::<LIMBS>::BITS - 1 {
+            return Err(crate::Error::UnsupportedPublicParameters);
+        }
+
+        Ok(Self { sample_bits })
+    }
+
+    /// The bit-size of sampled integers.
+    pub fn sample_bits(&self) -> usize {
+        self.sample_bits
+    }
+}
+
+impl<const LIMBS: usize> GroupElement<LIMBS> {
+    /// Whether this integer is negative, i.e. whether its most significant bit is set.
+    pub fn is_negative(&self) -> Choice {
+        self.value.bit(Uint::<LIMBS>::BITS - 1).into()
+    }
+
+    /// The absolute value of this integer.
+    pub fn abs(&self) -> Uint<LIMBS> {
+        Uint::<LIMBS>::conditional_select(
+            &self.value,
+            &self.value.wrapping_neg(),
+            self.is_negative(),
+        )
+    }
+
+    /// Computes `base` times this (signed) integer in constant-time, i.e. $m \cdot B$ in additive
+    /// notation (or $B^m$, in multiplicative notation).
+    pub fn mul_group_element<G: crate::GroupElement + ConditionallySelectable>(
+        &self,
+        base: &G,
+    ) -> G {
+        let product = base.scalar_mul(&self.abs());
+
+        G::conditional_select(&product, &-product, self.is_negative())
+    }
+}
+
+impl<const LIMBS: usize> Samplable for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn sample(
+        public_parameters: &Self::PublicParameters,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Self> {
+        let mask =
+            (Uint::<LIMBS>::ONE << public_parameters.sample_bits).wrapping_sub(&Uint::<LIMBS>::ONE);
+
+        Ok(Self {
+            value: Uint::<LIMBS>::random(rng) & mask,
+            sample_bits: public_parameters.sample_bits,
+        })
+    }
+}
+
+impl<const LIMBS: usize> Zeroize for GroupElement<LIMBS> {
+    fn zeroize(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl<const LIMBS: usize> ConditionallySelectable for GroupElement<LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            value: Uint::<LIMBS>::conditional_select(&a.value, &b.value, choice),
+            sample_bits: a.sample_bits,
+        }
+    }
+}
+
+impl<const LIMBS: usize> crate::GroupElement for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Value = Uint<LIMBS>;
+
+    fn value(&self) -> Self::Value {
+        self.value
+    }
+
+    type PublicParameters = PublicParameters<LIMBS>;
+
+    fn new(value: Self::Value, public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        Ok(Self {
+            value,
+            sample_bits: public_parameters.sample_bits,
+        })
+    }
+
+    fn neutral(&self) -> Self {
+        Self {
+            value: Uint::<LIMBS>::ZERO,
+            sample_bits: self.sample_bits,
+        }
+    }
+
+    fn scalar_mul<const RHS_LIMBS: usize>(&self, scalar: &Uint<RHS_LIMBS>) -> Self {
+        Self {
+            value: self.value.wrapping_mul(&scalar.resize::<LIMBS>()),
+            sample_bits: self.sample_bits,
+        }
+    }
+
+    fn double(&self) -> Self {
+        Self {
+            value: self.value.wrapping_add(&self.value),
+            sample_bits: self.sample_bits,
+        }
+    }
+}
+
+impl<const LIMBS: usize> From<GroupElement<LIMBS>> for PublicParameters<LIMBS> {
+    fn from(value: GroupElement<LIMBS>) -> Self {
+        Self {
+            sample_bits: value.sample_bits,
+        }
+    }
+}
+
+impl<const LIMBS: usize> From<GroupElement<LIMBS>> for Uint<LIMBS> {
+    fn from(value: GroupElement<LIMBS>) -> Self {
+        value.value
+    }
+}
+
+impl<'r, const LIMBS: usize> From<&'r GroupElement<LIMBS>> for Uint<LIMBS> {
+    fn from(value: &'r GroupElement<LIMBS>) -> Self {
+        value.value
+    }
+}
+
+impl<const LIMBS: usize> Neg for GroupElement<LIMBS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_neg(),
+            sample_bits: self.sample_bits,
+        }
+    }
+}
+
+impl<const LIMBS: usize> Add<Self> for GroupElement<LIMBS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add(&rhs)
+    }
+}
+
+impl<'r, const LIMBS: usize> Add<&'r Self> for GroupElement<LIMBS> {
+    type Output = Self;
+
+    fn add(self, rhs: &'r Self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_add(&rhs.value),
+            sample_bits: self.sample_bits,
+        }
+    }
+}
+
+impl<const LIMBS: usize> Sub<Self> for GroupElement<LIMBS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.sub(&rhs)
+    }
+}
+
+impl<'r, const LIMBS: usize> Sub<&'r Self> for GroupElement<LIMBS> {
+    type Output = Self;
+
+    fn sub(self, rhs: &'r Self) -> Self::Output {
+        Self {
+            value: self.value.wrapping_sub(&rhs.value),
+            sample_bits: self.sample_bits,
+        }
+    }
+}
+
+impl<const LIMBS: usize> AddAssign<Self> for GroupElement<LIMBS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<'r, const LIMBS: usize> AddAssign<&'r Self> for GroupElement<LIMBS> {
+    fn add_assign(&mut self, rhs: &'r Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const LIMBS: usize> SubAssign<Self> for GroupElement<LIMBS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<'r, const LIMBS: usize> SubAssign<&'r Self> for GroupElement<LIMBS> {
+    fn sub_assign(&mut self, rhs: &'r Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const LIMBS: usize> BoundedGroupElement<LIMBS> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn lower_bound(_public_parameters: &Self::PublicParameters) -> Uint<LIMBS> {
+        // The order of the group is $2^k$, which does not fit in `Uint<LIMBS>`.
+        Uint::<LIMBS>::MAX
+    }
+}
+
+impl<const LIMBS: usize> MultiScalarMul for GroupElement<LIMBS> where Uint<LIMBS>: Encoding {}
+
+impl<const LIMBS: usize> VartimeGroupElement for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn scalar_mul_vartime<const RHS_LIMBS: usize>(&self, scalar: &Uint<RHS_LIMBS>) -> Self {
+        // Scalar multiplication is a single integer multiplication, which has no faster
+        // variable-time counterpart.
+        crate::GroupElement::scalar_mul(self, scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{U256, U64};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{multiplicative, secp256k1, test_helpers, GroupElement as _};
+
+    #[test]
+    fn satisfies_conformance() {
+        let public_parameters = PublicParameters::<{ U256::LIMBS }>::new(200).unwrap();
+
+        // Every `Uint` is a valid integer.
+        test_helpers::samplable_conformance::<GroupElement<{ U256::LIMBS }>>(
+            &public_parameters,
+            vec![],
+            &mut OsRng,
+        );
+
+        assert_eq!(
+            PublicParameters::<{ U256::LIMBS }>::new(U256::BITS - 1),
+            Err(crate::Error::UnsupportedPublicParameters)
+        );
+    }
+
+    #[test]
+    fn multiplies_group_elements_by_signed_integers() {
+        let public_parameters = PublicParameters::<{ U64::LIMBS }>::new(32).unwrap();
+        let group_public_parameters =
+            multiplicative::PublicParameters::new(secp256k1::MODULUS).unwrap();
+        let base = multiplicative::GroupElement::<{ U256::LIMBS }>::sample(
+            &group_public_parameters,
+            &mut OsRng,
+        )
+        .unwrap();
+
+        let integer = GroupElement::sample(&public_parameters, &mut OsRng).unwrap();
+        assert!(!bool::from(integer.is_negative()));
+        assert_eq!(integer.abs(), integer.value());
+        assert_eq!(
+            integer.mul_group_element(&base),
+            base.scalar_mul(&integer.value())
+        );
+
+        let negated_integer = -integer;
+        assert!(bool::from(negated_integer.is_negative()));
+        assert_eq!(negated_integer.abs(), integer.value());
+        assert_eq!(
+            negated_integer.mul_group_element(&base),
+            -base.scalar_mul(&integer.value())
+        );
+        assert!(bool::from(
+            (negated_integer.mul_group_element(&base) + integer.mul_group_element(&base))
+                .is_neutral()
+        ));
+    }
+}
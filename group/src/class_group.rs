@@ -0,0 +1,17 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! The class group $Cl(\Delta)$ of binary quadratic forms of a negative discriminant $\Delta$,
+//! which is a group of unknown order.
+//!
+//! NOTICE: unlike the other groups of this crate, the group operation here is *variable-time*: the
+//! composition and reduction of forms run the Euclidean algorithm on the coefficients. Scalar
+//! multiplication never branches on the scalar, but the timing of each composition may still
+//! depend on it.
+
+pub use group_element::{GroupElement, PublicParameters, Value};
+
+mod discriminant;
+mod form;
+pub mod group_element;
+mod integer;
@@ -0,0 +1,42 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use crypto_bigint::Uint;
+use sha3::{
+    digest::{ExtendableOutput, Update},
+    Shake256,
+};
+
+use crate::helpers::primality::{is_prime, random_uint};
+
+/// The domain separation tag used for deriving discriminants from seeds.
+const DISCRIMINANT_DST: &[u8] = b"dWallet class group discriminant";
+
+/// Deterministically derives, from `seed`, the absolute value of a negative fundamental
+/// discriminant $\Delta = -p$ of `discriminant_bits` bits, for a prime $p = 3 \mod 4$.
+///
+/// As anyone can re-derive the discriminant from the seed, there is no trapdoor (e.g., a
+/// factorization) known to whoever generated it.
+pub(super) fn negated_discriminant_from_seed<const LIMBS: usize>(
+    seed: &[u8],
+    discriminant_bits: usize,
+) -> Uint<LIMBS> {
+    let mut reader = Shake256::default()
+        .chain(DISCRIMINANT_DST)
+        .chain((seed.len() as u64).to_le_bytes())
+        .chain(seed)
+        .chain((discriminant_bits as u64).to_le_bytes())
+        .finalize_xof();
+
+    loop {
+        // Take a `discriminant_bits`-bit candidate, and set its least significant bits so that it
+        // is $3 \mod 4$.
+        let candidate = random_uint::<LIMBS>(&mut reader, discriminant_bits)
+            .bitor(&Uint::ONE.shl_vartime(discriminant_bits - 1))
+            .bitor(&Uint::from_u8(3));
+
+        if is_prime(&candidate) {
+            return candidate;
+        }
+    }
+}
@@ -0,0 +1,346 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use core::mem;
+
+use crypto_bigint::{Limb, NonZero, Uint, U64};
+
+use super::integer::{div_rem_vartime, xgcd, Int};
+
+/// A binary quadratic form $ax^2 + bxy + cy^2$ of negative discriminant $\Delta = b^2 - 4ac$,
+/// which we denote by $(a, b, c)$.
+///
+/// All forms are positive-definite (i.e. $a, c > 0$) and primitive (i.e. $\gcd(a, b, c) = 1$.)
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub(super) struct QuadraticForm<const LIMBS: usize> {
+    pub(super) a: Int<LIMBS>,
+    pub(super) b: Int<LIMBS>,
+    pub(super) c: Int<LIMBS>,
+}
+
+impl<const LIMBS: usize> QuadraticForm<LIMBS> {
+    /// Instantiates the form $(a, b, c)$ of discriminant $\Delta$, computing $c = (b^2 - \Delta) /
+    /// 4a$ from `negated_discriminant` $= -\Delta$.
+    ///
+    /// Returns `None` if $4a \nmid b^2 - \Delta$, i.e. if there is no such form.
+    pub(super) fn new(
+        a: Int<LIMBS>,
+        b: Int<LIMBS>,
+        negated_discriminant: &Uint<LIMBS>,
+    ) -> Option<Self> {
+        if a.is_negative() || a.is_zero() {
+            return None;
+        }
+
+        let numerator = b * b + Int::from_uint(*negated_discriminant);
+        let denominator = a.abs().shl_vartime(2);
+        let (c, remainder) = div_rem_vartime(&numerator.abs(), &denominator);
+
+        (remainder == Uint::ZERO).then_some(Self {
+            a,
+            b,
+            c: Int::from_uint(c),
+        })
+    }
+
+    /// The neutral element of the class group of discriminant $\Delta = 1 \mod 4$, which is the
+    /// principal form $(1, 1, (1 - \Delta) / 4)$.
+    pub(super) fn principal(negated_discriminant: &Uint<LIMBS>) -> Self {
+        Self {
+            a: Int::ONE,
+            b: Int::ONE,
+            c: Int::from_uint(negated_discriminant.wrapping_add(&Uint::ONE).shr_vartime(2)),
+        }
+    }
+
+    /// The prime form of a small prime $\ell$, i.e. the reduced form $(\ell, b, c)$ for the
+    /// smallest positive $b$ satisfying $b^2 = \Delta \mod 4\ell$.
+    ///
+    /// Returns `None` if $\ell$ is not split, i.e. if $\Delta$ is not a non-zero quadratic
+    /// residue modulo $\ell$. $\ell$ must be an odd prime, and $\Delta$ must be $1 \mod 4$.
+    pub(super) fn prime_form(ell: u32, negated_discriminant: &Uint<LIMBS>) -> Option<Self> {
+        let ell_limb = NonZero::new(Limb::from_u32(ell)).unwrap();
+        let (_, negated_discriminant_mod_ell) = negated_discriminant.div_rem_limb(ell_limb);
+        // Go through `U64`, as limbs are only 32 bits wide on 32-bit targets.
+        let discriminant_mod_ell =
+            (u64::from(ell) - u64::from(U64::from(negated_discriminant_mod_ell))) % u64::from(ell);
+        if discriminant_mod_ell == 0 {
+            return None;
+        }
+
+        // The primes for which we compute prime forms are small, so we can afford an exhaustive
+        // search for the square root.
+        let root = (1..u64::from(ell))
+            .find(|root| (root * root) % u64::from(ell) == discriminant_mod_ell)?;
+
+        // Both $b$ and $\Delta$ must be odd for $b^2 = \Delta \mod 4$ (as $\Delta = 1 \mod 4$ and
+        // all odd squares are $1 \mod 8$), and as $\ell$ is odd one of the roots is.
+        let b = if root % 2 == 1 {
+            root
+        } else {
+            u64::from(ell) - root
+        };
+
+        Self::new(
+            Int::from_uint(Uint::from_u32(ell)),
+            Int::from_uint(Uint::from_u64(b)),
+            negated_discriminant,
+        )
+        .map(Self::reduce)
+    }
+
+    /// Whether the form is reduced, i.e. $|b| \leq a \leq c$, and $b \geq 0$ if either $|b| = a$ or
+    /// $a = c$.
+    ///
+    /// Every form is equivalent to exactly one reduced form, which therefore serves as the
+    /// canonical representative of its class.
+    pub(super) fn is_reduced(&self) -> bool {
+        let is_normal = -self.a < self.b && self.b <= self.a;
+
+        is_normal && self.a <= self.c && !(self.a == self.c && self.b.is_negative())
+    }
+
+    /// Whether the form is primitive, i.e. $\gcd(a, b, c) = 1$.
+    pub(super) fn is_primitive(&self) -> bool {
+        let (gcd, ..) = xgcd(&self.a, &self.b);
+        let (gcd, ..) = xgcd(&Int::from_uint(gcd), &self.c);
+
+        gcd == Uint::ONE
+    }
+
+    /// The inverse of the form in the class group, which is its opposite $(a, -b, c)$.
+    pub(super) fn inverse(&self) -> Self {
+        Self {
+            a: self.a,
+            b: -self.b,
+            c: self.c,
+        }
+        .reduce()
+    }
+
+    /// Normalizes the form, i.e. transforms it into an equivalent form with $-a < b \leq a$.
+    fn normalize(self) -> Self {
+        let two_a = self.a.abs().shl_vartime(1);
+        let (mut quotient, remainder) = self.b.div_rem_floor(&two_a);
+        let mut remainder = Int::from_uint(remainder);
+
+        if remainder > self.a {
+            remainder = remainder - Int::from_uint(two_a);
+            quotient = quotient + Int::ONE;
+        }
+
+        Self {
+            a: self.a,
+            b: remainder,
+            c: self.c - (self.b + remainder).half() * quotient,
+        }
+    }
+
+    /// Reduces the form into the unique reduced form in its class (Algorithm 5.4.2 of Cohen's "A
+    /// Course in Computational Algebraic Number Theory".)
+    pub(super) fn reduce(self) -> Self {
+        let mut form = if -self.a < self.b && self.b <= self.a {
+            self
+        } else {
+            self.normalize()
+        };
+
+        while form.a > form.c {
+            mem::swap(&mut form.a, &mut form.c);
+            form.b = -form.b;
+            form = form.normalize();
+        }
+
+        if form.a == form.c && form.b.is_negative() {
+            form.b = -form.b;
+        }
+
+        form
+    }
+
+    /// Composes two forms of the same discriminant using Shanks' NUCOMP algorithm, as described in
+    /// Jacobson and van der Poorten's "Computational aspects of NUCOMP", and reduces the result.
+    ///
+    /// NUCOMP interleaves the composition with a partial reduction, driven by
+    /// `partial_reduction_bound` $= \lfloor |\Delta / 4|^{1/4} \rfloor$, so that its intermediate
+    /// values are about the size of the operands rather than their product.
+    pub(super) fn compose(&self, rhs: &Self, partial_reduction_bound: &Uint<LIMBS>) -> Self {
+        let (lhs, rhs) = if self.a < rhs.a {
+            (rhs, self)
+        } else {
+            (self, rhs)
+        };
+        let (u1, v1, w1) = (lhs.a, lhs.b, lhs.c);
+        let (u2, v2, w2) = (rhs.a, rhs.b, rhs.c);
+
+        let s = (v1 + v2).half();
+        let m = v2 - s;
+
+        let (f, b, c) = xgcd(&u2, &u1);
+        let (g, bx, original_by, cy, dy) = if s.is_divisible_by(&f) {
+            let g = Int::from_uint(f);
+
+            (
+                g,
+                m * b,
+                u1.div_exact(&g),
+                u2.div_exact(&g),
+                s.div_exact(&g),
+            )
+        } else {
+            let (g, _, y) = xgcd(&Int::from_uint(f), &s);
+            let (h, _) = div_rem_vartime(&f, &g);
+            let g = Int::from_uint(g);
+            let by = u1.div_exact(&g);
+
+            // Reduce all operands modulo $h$ first, to keep the intermediate values small.
+            let reduce = |value: &Int<LIMBS>| Int::from_uint(value.rem_euclid(&h));
+            let l = reduce(&(reduce(&b) * reduce(&w1) + reduce(&c) * reduce(&w2)));
+            let l = (reduce(&y) * l).rem_euclid(&h);
+            let h = Int::from_uint(h);
+            let bx = b * m.div_exact(&h) + Int::from_uint(l) * by.div_exact(&h);
+
+            (g, bx, by, u2.div_exact(&g), s.div_exact(&g))
+        };
+
+        // Partially reduce `bx / by` with the (extended) Euclidean algorithm, until the remainder
+        // is below the bound.
+        let mut bx = Int::from_uint(bx.rem_euclid(&original_by.abs()));
+        let mut by = original_by;
+        let (mut x, mut y) = (Int::ONE, Int::ZERO);
+        let mut steps = 0usize;
+        while by.abs() > *partial_reduction_bound && !bx.is_zero() {
+            let (quotient, remainder) = div_rem_vartime(&by.abs(), &bx.abs());
+            (by, bx) = (bx, Int::from_uint(remainder));
+            (x, y) = (y - x.mul_uint(&quotient), x);
+            steps += 1;
+        }
+        if steps % 2 == 1 {
+            by = -by;
+            y = -y;
+        }
+
+        let (u3, v3, w3) = if steps == 0 {
+            let q1 = cy * bx;
+            let cx = (q1 - m).div_exact(&original_by);
+            let dx = (bx * dy - w2).div_exact(&original_by);
+
+            (by * cy, v2 - q1 - q1, bx * cx - g * dx)
+        } else {
+            let (ax, ay) = (g * x, g * y);
+            let cx = (cy * bx - m * x).div_exact(&original_by);
+            let q1 = by * cx;
+            let q2 = q1 + m;
+            let dx = (dy * bx - w2 * x).div_exact(&original_by);
+            let q3 = y * dx;
+            let q4 = q3 + dy;
+            let dy = q4.div_exact(&x);
+            let cy = if bx.is_zero() {
+                (cx * dy - w1).div_exact(&dx)
+            } else {
+                q2.div_exact(&bx)
+            };
+
+            (
+                by * cy - ay * dy,
+                g * (q3 + q4) - q1 - q2,
+                bx * cx - ax * dx,
+            )
+        };
+
+        Self {
+            a: u3,
+            b: v3,
+            c: w3,
+        }
+        .reduce()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U3072;
+
+    use super::*;
+    use crate::class_group::discriminant::negated_discriminant_from_seed;
+
+    const LIMBS: usize = U3072::LIMBS;
+
+    /// Composes two forms of the same discriminant with the classical composition formulae (e.g.
+    /// Definition 5.4.6 in Cohen's "A Course in Computational Algebraic Number Theory"), without
+    /// any partial reduction, and reduces the result.
+    fn compose_naively(
+        lhs: &QuadraticForm<LIMBS>,
+        rhs: &QuadraticForm<LIMBS>,
+        negated_discriminant: &Uint<LIMBS>,
+    ) -> QuadraticForm<LIMBS> {
+        // Write $e = \gcd(a_1, a_2, (b_1 + b_2) / 2) = u a_1 + v a_2 + w (b_1 + b_2) / 2$.
+        let s = (lhs.b + rhs.b).half();
+        let (gcd, x, y) = xgcd(&lhs.a, &rhs.a);
+        let (e, z, w) = xgcd(&Int::from_uint(gcd), &s);
+        let e = Int::from_uint(e);
+
+        // $a_3 = a_1 a_2 / e^2$, and $b_3 = (u a_1 b_2 + v a_2 b_1 + w (b_1 b_2 + \Delta) / 2) / e
+        // \mod 2 a_3$.
+        let a = lhs.a.div_exact(&e) * rhs.a.div_exact(&e);
+        let b = (z * x * lhs.a * rhs.b
+            + z * y * rhs.a * lhs.b
+            + w * (lhs.b * rhs.b - Int::from_uint(*negated_discriminant)).half())
+        .div_exact(&e);
+        let b = Int::from_uint(b.rem_euclid(&a.abs().shl_vartime(1)));
+
+        QuadraticForm::new(a, b, negated_discriminant)
+            .expect("composition should yield a form of the same discriminant")
+            .reduce()
+    }
+
+    #[test]
+    fn composes_as_naive_composition() {
+        let negated_discriminant =
+            negated_discriminant_from_seed::<LIMBS>(b"nucomp vs. composition", 1024);
+        let partial_reduction_bound = negated_discriminant
+            .shr_vartime(2)
+            .sqrt_vartime()
+            .sqrt_vartime();
+
+        let prime_forms: Vec<_> = (3..100)
+            .step_by(2)
+            .filter_map(|ell| QuadraticForm::prime_form(ell, &negated_discriminant))
+            .take(4)
+            .collect();
+        assert_eq!(prime_forms.len(), 4);
+
+        // Walk the group from a prime form with naive compositions, until the forms are of the size
+        // of reduced forms, i.e. $a \approx \sqrt{|\Delta|}$.
+        let mut forms = prime_forms.clone();
+        let mut form = prime_forms[0];
+        for i in 0..64 {
+            form = compose_naively(&form, &form, &negated_discriminant);
+            form = compose_naively(&form, &prime_forms[i % 4], &negated_discriminant);
+            if i >= 16 {
+                forms.push(form);
+            }
+        }
+
+        // The large forms are far above the partial reduction bound, so that NUCOMP partially
+        // reduces them.
+        assert!(forms[4..]
+            .iter()
+            .all(|form| form.a.abs().bits_vartime() > partial_reduction_bound.bits_vartime() + 64));
+
+        for lhs in &forms {
+            for rhs in forms.iter().step_by(7) {
+                assert_eq!(
+                    lhs.compose(rhs, &partial_reduction_bound),
+                    compose_naively(lhs, rhs, &negated_discriminant)
+                );
+            }
+
+            // Composing a form with its inverse yields the neutral element.
+            assert_eq!(
+                lhs.compose(&lhs.inverse(), &partial_reduction_bound),
+                QuadraticForm::principal(&negated_discriminant)
+            );
+        }
+    }
+}
@@ -0,0 +1,616 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Random, Uint};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::{discriminant::negated_discriminant_from_seed, form::QuadraticForm, integer::Int};
+use crate::{
+    helpers::primality::is_prime, BoundedGroupElement, GroupElement as _, MultiScalarMul,
+    Samplable, VartimeGroupElement,
+};
+
+/// The statistical security parameter used in sampling: exponents are sampled this many bits
+/// larger than an upper bound on the class number.
+const SAMPLING_STATISTICAL_SECURITY: usize = 128;
+
+/// The bound on the primes $\ell$ tried when searching for a prime form to serve as the
+/// generator.
+const GENERATOR_SEARCH_BOUND: u32 = 1 << 10;
+
+/// The minimal bit-length of a supported discriminant, for which the class number is bounded as
+/// in [`GroupElement::lower_bound()`].
+pub const MIN_DISCRIMINANT_BITS: usize = 64;
+
+/// An element of the class group $Cl(\Delta)$ of the imaginary quadratic order of discriminant
+/// $\Delta < 0$, represented by the unique reduced binary quadratic form in its class.
+///
+/// `LIMBS` must be large enough to hold the square of the discriminant, as intermediate values of
+/// the group operation reach that size.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct GroupElement<const LIMBS: usize> {
+    form: QuadraticForm<LIMBS>,
+    negated_discriminant: Uint<LIMBS>,
+    partial_reduction_bound: Uint<LIMBS>,
+}
+
+/// The value of a class group element, i.e. the coefficients $(a, b)$ of its reduced form; the
+/// third coefficient $c$ is determined by them and the discriminant.
+///
+/// $b$ is encoded in two's complement.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Value<const LIMBS: usize>
+where
+    Uint<LIMBS>: Encoding,
+{
+    a: Uint<LIMBS>,
+    b: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> ConstantTimeEq for Value<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.a.ct_eq(&other.a) & self.b.ct_eq(&other.b)
+    }
+}
+
+impl<const LIMBS: usize> ConditionallySelectable for Value<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            a: Uint::conditional_select(&a.a, &b.a, choice),
+            b: Uint::conditional_select(&a.b, &b.b, choice),
+        }
+    }
+}
+
+/// The public parameters of the class group $Cl(\Delta)$, for a negative discriminant $\Delta =
+/// -p$ where $p = 3 \mod 4$ is a prime.
+///
+/// As $\Delta$ is prime, the class number $h(\Delta)$ is odd; its value (and so the order of the
+/// group) is unknown, and is infeasible to compute for large enough discriminants.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct PublicParameters<const LIMBS: usize>
+where
+    Uint<LIMBS>: Encoding,
+{
+    negated_discriminant: Uint<LIMBS>,
+    partial_reduction_bound: Uint<LIMBS>,
+    generator: Value<LIMBS>,
+}
+
+impl<const LIMBS: usize> PublicParameters<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    /// Instantiates the public parameters of the class group of discriminant $\Delta =
+    /// -$`negated_discriminant`.
+    ///
+    /// `negated_discriminant` must be a prime $p = 3 \mod 4$ of at least [`MIN_DISCRIMINANT_BITS`]
+    /// and at most [`Self::max_discriminant_bits()`] bits.
+    pub fn new(negated_discriminant: Uint<LIMBS>) -> crate::Result<Self> {
+        let discriminant_bits = negated_discriminant.bits_vartime();
+        if discriminant_bits < MIN_DISCRIMINANT_BITS
+            || discriminant_bits > Self::max_discriminant_bits()
+        {
+            return Err(crate::Error::UnsupportedPublicParameters);
+        }
+
+        if negated_discriminant.as_words()[0] & 3 != 3 || !is_prime(&negated_discriminant) {
+            return Err(crate::Error::InvalidPublicParameters);
+        }
+
+        Self::new_unchecked(negated_discriminant)
+    }
+
+    /// Deterministically derives the public parameters of a class group with a discriminant of
+    /// `discriminant_bits` bits from `seed`.
+    ///
+    /// The derivation is public, and so anyone holding `seed` can verify that the discriminant
+    /// (and therefore, the group) was generated honestly, without a trapdoor.
+    pub fn new_from_seed(seed: &[u8], discriminant_bits: usize) -> crate::Result<Self> {
+        if discriminant_bits < MIN_DISCRIMINANT_BITS
+            || discriminant_bits > Self::max_discriminant_bits()
+        {
+            return Err(crate::Error::UnsupportedPublicParameters);
+        }
+
+        Self::new_unchecked(negated_discriminant_from_seed(seed, discriminant_bits))
+    }
+
+    /// The maximal bit-length of a discriminant supported for `LIMBS`, which leaves room for the
+    /// intermediate values of the group operation.
+    pub const fn max_discriminant_bits() -> usize {
+        Uint::<LIMBS>::BITS / 2 - 4
+    }
+
+    /// The absolute value of the discriminant, $-\Delta$.
+    pub fn negated_discriminant(&self) -> &Uint<LIMBS> {
+        &self.negated_discriminant
+    }
+
+    /// The generator, which is the prime form of the smallest prime that splits in the order.
+    ///
+    /// Note that the class group is not necessarily cyclic, in which case this element generates
+    /// a (large) subgroup of it.
+    pub fn generator(&self) -> &Value<LIMBS> {
+        &self.generator
+    }
+
+    /// Instantiates the public parameters from a valid discriminant, computing the generator.
+    fn new_unchecked(negated_discriminant: Uint<LIMBS>) -> crate::Result<Self> {
+        let generator = (3..GENERATOR_SEARCH_BOUND)
+            .step_by(2)
+            .find_map(|ell| QuadraticForm::prime_form(ell, &negated_discriminant))
+            .ok_or(crate::Error::UnsupportedPublicParameters)?;
+
+        Ok(Self {
+            negated_discriminant,
+            partial_reduction_bound: partial_reduction_bound(&negated_discriminant),
+            generator: generator.into(),
+        })
+    }
+}
+
+impl<const LIMBS: usize> Serialize for PublicParameters<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.negated_discriminant.serialize(serializer)
+    }
+}
+
+impl<'de, const LIMBS: usize> Deserialize<'de> for PublicParameters<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let negated_discriminant = Uint::<LIMBS>::deserialize(deserializer)?;
+
+        PublicParameters::new(negated_discriminant).map_err(Error::custom)
+    }
+}
+
+/// The bound $\lfloor |\Delta / 4|^{1/4} \rfloor$ for the partial reduction in NUCOMP.
+fn partial_reduction_bound<const LIMBS: usize>(negated_discriminant: &Uint<LIMBS>) -> Uint<LIMBS> {
+    negated_discriminant
+        .shr_vartime(2)
+        .sqrt_vartime()
+        .sqrt_vartime()
+}
+
+impl<const LIMBS: usize> From<QuadraticForm<LIMBS>> for Value<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn from(form: QuadraticForm<LIMBS>) -> Self {
+        Self {
+            a: form.a.0,
+            b: form.b.0,
+        }
+    }
+}
+
+impl<const LIMBS: usize> GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn with_form(&self, form: QuadraticForm<LIMBS>) -> Self {
+        Self {
+            form,
+            negated_discriminant: self.negated_discriminant,
+            partial_reduction_bound: self.partial_reduction_bound,
+        }
+    }
+}
+
+/// NOTICE: this is an exception to the constant-time guarantee of [`crate::GroupElement`]. The
+/// composition of forms (NUCOMP) and their reduction run the extended Euclidean algorithm on the
+/// coefficients, and so [`Add`], [`Sub`], [`Neg`], [`crate::GroupElement::double()`] and the
+/// scalar multiplications take time that depends on the operands. Do not use class group elements
+/// to hold secrets whose timing must not leak.
+impl<const LIMBS: usize> crate::GroupElement for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Value = Value<LIMBS>;
+    type PublicParameters = PublicParameters<LIMBS>;
+
+    fn new(value: Self::Value, public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        let (a, b) = (Int::from_uint(value.a), Int(value.b));
+
+        // A reduced form satisfies $|b| \leq a \leq \sqrt{|\Delta| / 3}$; checking this bound
+        // before computing $c$ also assures that no intermediate value overflows.
+        if a.is_negative()
+            || a.0
+                .cmp_vartime(&public_parameters.negated_discriminant.sqrt_vartime())
+                == core::cmp::Ordering::Greater
+            || b.abs().cmp_vartime(&a.0) == core::cmp::Ordering::Greater
+        {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        let form = QuadraticForm::new(a, b, &public_parameters.negated_discriminant)
+            .filter(|form| form.is_reduced() && form.is_primitive())
+            .ok_or(crate::Error::InvalidGroupElement)?;
+
+        Ok(Self {
+            form,
+            negated_discriminant: public_parameters.negated_discriminant,
+            partial_reduction_bound: public_parameters.partial_reduction_bound,
+        })
+    }
+
+    fn neutral(&self) -> Self {
+        self.with_form(QuadraticForm::principal(&self.negated_discriminant))
+    }
+
+    /// Multiplication by (any bounded) natural number (scalar).
+    ///
+    /// NOTE: this is *not* constant-time, see [`Self::scalar_mul_bounded()`].
+    fn scalar_mul<const RHS_LIMBS: usize>(&self, scalar: &Uint<RHS_LIMBS>) -> Self {
+        self.scalar_mul_bounded(scalar, Uint::<RHS_LIMBS>::BITS)
+    }
+
+    /// Multiplication by (any bounded) natural number (scalar), with `scalar_bits` representing
+    /// the number of (least significant) bits to take into account for the scalar.
+    ///
+    /// NOTE: this is *not* constant-time. It does not branch on the bits of the scalar, but each
+    /// of the `scalar_bits` compositions is variable-time, so the overall running time may still
+    /// depend on the scalar.
+    fn scalar_mul_bounded<const RHS_LIMBS: usize>(
+        &self,
+        scalar: &Uint<RHS_LIMBS>,
+        scalar_bits: usize,
+    ) -> Self {
+        // As we can't reduce the scalar modulo the (unknown) order, this is a plain
+        // double-and-add over `scalar_bits` bits, which always composes and selects the result
+        // rather than branching on the bits of the scalar.
+        let mut result = self.neutral();
+        for i in (0..scalar_bits.min(Uint::<RHS_LIMBS>::BITS)).rev() {
+            result = result.double();
+            let sum = result + self;
+
+            let choice = Choice::from(u8::from(scalar.bit_vartime(i)));
+            result.form.a.0 = Uint::conditional_select(&result.form.a.0, &sum.form.a.0, choice);
+            result.form.b.0 = Uint::conditional_select(&result.form.b.0, &sum.form.b.0, choice);
+            result.form.c.0 = Uint::conditional_select(&result.form.c.0, &sum.form.c.0, choice);
+        }
+
+        result
+    }
+
+    /// Double this element.
+    ///
+    /// NOTE: this is *not* constant-time, as it composes the form with itself.
+    fn double(&self) -> Self {
+        self.with_form(self.form.compose(&self.form, &self.partial_reduction_bound))
+    }
+}
+
+impl<const LIMBS: usize> From<GroupElement<LIMBS>> for Value<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn from(value: GroupElement<LIMBS>) -> Self {
+        value.form.into()
+    }
+}
+
+impl<const LIMBS: usize> From<GroupElement<LIMBS>> for PublicParameters<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn from(value: GroupElement<LIMBS>) -> Self {
+        // The discriminant of a valid group element was already validated, so we only need to
+        // recompute the generator, which always succeeds for it.
+        PublicParameters::new_unchecked(value.negated_discriminant).unwrap()
+    }
+}
+
+impl<const LIMBS: usize> Neg for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.with_form(self.form.inverse())
+    }
+}
+
+impl<const LIMBS: usize> Add<Self> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // The group operation is the composition of forms, which we write additively.
+        self.with_form(self.form.compose(&rhs.form, &self.partial_reduction_bound))
+    }
+}
+
+impl<'r, const LIMBS: usize> Add<&'r Self> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Output = Self;
+
+    fn add(self, rhs: &'r Self) -> Self::Output {
+        self.with_form(self.form.compose(&rhs.form, &self.partial_reduction_bound))
+    }
+}
+
+impl<const LIMBS: usize> Sub<Self> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.with_form(
+            self.form
+                .compose(&rhs.form.inverse(), &self.partial_reduction_bound),
+        )
+    }
+}
+
+impl<'r, const LIMBS: usize> Sub<&'r Self> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: &'r Self) -> Self::Output {
+        self.with_form(
+            self.form
+                .compose(&rhs.form.inverse(), &self.partial_reduction_bound),
+        )
+    }
+}
+
+impl<const LIMBS: usize> AddAssign<Self> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<'r, const LIMBS: usize> AddAssign<&'r Self> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn add_assign(&mut self, rhs: &'r Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const LIMBS: usize> SubAssign<Self> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<'r, const LIMBS: usize> SubAssign<&'r Self> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn sub_assign(&mut self, rhs: &'r Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const LIMBS: usize> Samplable for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn sample(
+        public_parameters: &Self::PublicParameters,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Self> {
+        // The class number is bounded by $h(\Delta) < \sqrt{|\Delta|}\log{|\Delta|}$, so
+        // multiplying the generator by an exponent larger than that by
+        // `SAMPLING_STATISTICAL_SECURITY` bits yields an element that is statistically close to
+        // uniform in the subgroup it generates.
+        let discriminant_bits = public_parameters.negated_discriminant.bits_vartime();
+        let exponent_bits = (discriminant_bits / 2
+            + discriminant_bits.ilog2() as usize
+            + 1
+            + SAMPLING_STATISTICAL_SECURITY)
+            .min(Uint::<LIMBS>::BITS);
+        let exponent = Uint::<LIMBS>::random(rng);
+
+        let generator = Self::new(public_parameters.generator, public_parameters)?;
+
+        Ok(generator.scalar_mul_bounded(&exponent, exponent_bits))
+    }
+}
+
+/// NOTICE: as [`crate::GroupElement::scalar_mul_bounded()`], the linear combinations of class group
+/// elements are *not* constant-time.
+impl<const LIMBS: usize> MultiScalarMul for GroupElement<LIMBS> where Uint<LIMBS>: Encoding {}
+
+impl<const LIMBS: usize> VartimeGroupElement for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn scalar_mul_vartime<const RHS_LIMBS: usize>(&self, scalar: &Uint<RHS_LIMBS>) -> Self {
+        // Only iterate up to the bit-length of the (public) scalar, and skip the compositions for
+        // its zero bits.
+        let mut result = self.neutral();
+        for i in (0..scalar.bits_vartime()).rev() {
+            result = result.double();
+            if scalar.bit_vartime(i) {
+                result += self;
+            }
+        }
+
+        result
+    }
+}
+
+impl<const LIMBS: usize> BoundedGroupElement<LIMBS> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn lower_bound(public_parameters: &Self::PublicParameters) -> Uint<LIMBS> {
+        // The class number is $h(\Delta) = \sqrt{|\Delta|} L(1, \chi_\Delta) / \pi$. Under GRH,
+        // Lamzouri, Li and Soundararajan ("Conditional bounds for the least quadratic non-residue
+        // and related problems", Math. Comp. 84, 2015) make Littlewood's bound explicit: for
+        // $|\Delta| \geq 10^{10}$,
+        // $$1 / L(1, \chi_\Delta) \leq \frac{12 e^\gamma}{\pi^2} \left(\log\log{|\Delta|} - \log{2}
+        // + \frac{1}{2} + \frac{1}{\log\log{|\Delta|}}
+        // + \frac{14 \log\log{|\Delta|}}{\log{|\Delta|}}\right).$$
+        // For discriminants of at least `MIN_DISCRIMINANT_BITS` bits (so $|\Delta| \geq 2^{63}$),
+        // and below $e^{e^{60}}$ (far beyond any `Uint`), the right-hand side is less than
+        // $2^8 / \pi$, leaving $h(\Delta) > \sqrt{|\Delta|} / 2^8$.
+        public_parameters
+            .negated_discriminant
+            .sqrt_vartime()
+            .shr_vartime(8)
+    }
+}
+
+impl<const LIMBS: usize> crate::Encoding for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    const ENCODED_LEN: usize = 2 * Uint::<LIMBS>::BYTES;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let value = Value::from(*self);
+
+        [value.a.to_be_bytes(), value.b.to_be_bytes()]
+            .iter()
+            .flat_map(|bytes| bytes.as_ref().to_vec())
+            .collect()
+    }
+
+    fn from_bytes(bytes: &[u8], public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        let (a, b) = bytes.split_at(Uint::<LIMBS>::BYTES);
+        let value = Value {
+            a: Uint::from_be_slice(a),
+            b: Uint::from_be_slice(b),
+        };
+
+        // As every class has a unique reduced form, and `new()` only accepts reduced forms, this
+        // encoding is canonical.
+        Self::new(value, public_parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U256;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::test_helpers;
+
+    #[test]
+    fn satisfies_conformance() {
+        let public_parameters =
+            PublicParameters::<{ U256::LIMBS }>::new_from_seed(b"conformance", 124).unwrap();
+
+        // Forms that are not reduced, or are not of the discriminant of the group.
+        let invalid_values = vec![
+            Value {
+                a: public_parameters.negated_discriminant,
+                b: U256::ONE,
+            },
+            Value {
+                a: U256::from_u8(2),
+                b: U256::from_u8(3),
+            },
+            Value {
+                a: U256::from_u8(2),
+                b: U256::from_u8(2),
+            },
+        ];
+
+        test_helpers::samplable_conformance::<GroupElement<{ U256::LIMBS }>>(
+            &public_parameters,
+            invalid_values,
+            &mut OsRng,
+        );
+    }
+
+    #[test]
+    fn derives_discriminants_from_seeds() {
+        type PublicParameters = super::PublicParameters<{ U256::LIMBS }>;
+
+        let public_parameters = PublicParameters::new_from_seed(b"seed", 120).unwrap();
+        assert_eq!(
+            PublicParameters::new_from_seed(b"seed", 120).unwrap(),
+            public_parameters,
+            "derivation should be deterministic"
+        );
+        assert_ne!(
+            PublicParameters::new_from_seed(b"other seed", 120).unwrap(),
+            public_parameters
+        );
+        assert_ne!(
+            PublicParameters::new_from_seed(b"seed", 121).unwrap(),
+            public_parameters
+        );
+
+        // $|\Delta|$ is a prime of exactly the requested size, and is $3 \mod 4$, i.e. $\Delta = 1
+        // \mod 4$.
+        let negated_discriminant = public_parameters.negated_discriminant();
+        assert_eq!(negated_discriminant.bits_vartime(), 120);
+        assert_eq!(negated_discriminant.as_words()[0] & 3, 3);
+        assert!(is_prime(negated_discriminant));
+        assert_eq!(
+            PublicParameters::new(*negated_discriminant).unwrap(),
+            public_parameters
+        );
+
+        assert!(matches!(
+            PublicParameters::new_from_seed(b"seed", MIN_DISCRIMINANT_BITS - 1),
+            Err(crate::Error::UnsupportedPublicParameters)
+        ));
+        assert!(matches!(
+            PublicParameters::new_from_seed(b"seed", PublicParameters::max_discriminant_bits() + 1),
+            Err(crate::Error::UnsupportedPublicParameters)
+        ));
+
+        // Discriminants that are too small, composite, or $1 \mod 4$.
+        assert!(matches!(
+            PublicParameters::new(U256::from_u64(1_000_003)),
+            Err(crate::Error::UnsupportedPublicParameters)
+        ));
+        assert!(matches!(
+            PublicParameters::new(negated_discriminant.wrapping_mul(&U256::from_u8(5))),
+            Err(crate::Error::InvalidPublicParameters)
+        ));
+        assert!(matches!(
+            PublicParameters::new(negated_discriminant.wrapping_add(&U256::from_u8(2))),
+            Err(crate::Error::InvalidPublicParameters)
+        ));
+    }
+}
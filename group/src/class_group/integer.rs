@@ -0,0 +1,209 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use core::{
+    cmp::Ordering,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use crypto_bigint::{Limb, Uint, U64};
+
+/// A signed integer, represented in two's complement over [`Uint<LIMBS>`].
+///
+/// Arithmetic is wrapping, and so is only correct as long as the (signed) result fits in `LIMBS`;
+/// callers are responsible for choosing `LIMBS` accordingly.
+///
+/// NOTICE: unlike [`Uint`], operations on this type are *variable-time*.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub(super) struct Int<const LIMBS: usize>(pub(super) Uint<LIMBS>);
+
+impl<const LIMBS: usize> Int<LIMBS> {
+    pub(super) const ZERO: Self = Self(Uint::ZERO);
+    pub(super) const ONE: Self = Self(Uint::ONE);
+
+    /// Instantiates a non-negative integer from its absolute value.
+    pub(super) const fn from_uint(value: Uint<LIMBS>) -> Self {
+        Self(value)
+    }
+
+    pub(super) const fn is_negative(&self) -> bool {
+        self.0.bit_vartime(Uint::<LIMBS>::BITS - 1)
+    }
+
+    pub(super) fn is_zero(&self) -> bool {
+        self.0 == Uint::ZERO
+    }
+
+    /// The absolute value of `self`.
+    pub(super) fn abs(&self) -> Uint<LIMBS> {
+        if self.is_negative() {
+            self.0.wrapping_neg()
+        } else {
+            self.0
+        }
+    }
+
+    /// Multiplies `self` by a non-negative `rhs`, taking a fast path when `rhs` fits in a limb (as
+    /// is the case for almost all quotients in Euclid's algorithm.)
+    pub(super) fn mul_uint(&self, rhs: &Uint<LIMBS>) -> Self {
+        if rhs.bits_vartime() <= Limb::BITS {
+            Self(self.0.wrapping_mul(&U64::from_word(rhs.as_words()[0])))
+        } else {
+            Self(self.0.wrapping_mul(rhs))
+        }
+    }
+
+    /// Exact division by two.
+    pub(super) fn half(&self) -> Self {
+        if self.is_negative() {
+            -Self(self.abs().shr_vartime(1))
+        } else {
+            Self(self.0.shr_vartime(1))
+        }
+    }
+
+    /// Euclidean division by a positive `rhs`, returning the quotient $\lfloor self / rhs
+    /// \rfloor$ and the remainder in $[0, rhs)$.
+    pub(super) fn div_rem_floor(&self, rhs: &Uint<LIMBS>) -> (Self, Uint<LIMBS>) {
+        let (quotient, remainder) = div_rem_vartime(&self.abs(), rhs);
+
+        if !self.is_negative() {
+            (Self(quotient), remainder)
+        } else if remainder == Uint::ZERO {
+            (-Self(quotient), remainder)
+        } else {
+            (-Self(quotient) - Self::ONE, rhs.wrapping_sub(&remainder))
+        }
+    }
+
+    /// The remainder of the Euclidean division by a positive `rhs`, in $[0, rhs)$.
+    pub(super) fn rem_euclid(&self, rhs: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.div_rem_floor(rhs).1
+    }
+
+    /// Division by `rhs`, which must divide `self`.
+    pub(super) fn div_exact(&self, rhs: &Self) -> Self {
+        let (quotient, remainder) = div_rem_vartime(&self.abs(), &rhs.abs());
+        debug_assert_eq!(remainder, Uint::ZERO);
+
+        if self.is_negative() == rhs.is_negative() {
+            Self(quotient)
+        } else {
+            -Self(quotient)
+        }
+    }
+
+    /// Whether `rhs` divides `self`.
+    pub(super) fn is_divisible_by(&self, rhs: &Uint<LIMBS>) -> bool {
+        div_rem_vartime(&self.abs(), rhs).1 == Uint::ZERO
+    }
+}
+
+impl<const LIMBS: usize> Neg for Int<LIMBS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+impl<const LIMBS: usize> Add for Int<LIMBS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.wrapping_add(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Sub for Int<LIMBS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> Mul for Int<LIMBS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0.wrapping_mul(&rhs.0))
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for Int<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for Int<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            // Within the same sign, two's complement preserves the order of the underlying
+            // unsigned representation.
+            _ => self.0.cmp_vartime(&other.0),
+        }
+    }
+}
+
+/// Computes `(lhs / rhs, lhs % rhs)` in time proportional to the bit-length of the quotient, which
+/// is much faster than [`Uint::div_rem()`] for the small quotients that are typical for Euclid's
+/// algorithm.
+///
+/// `rhs` must be non-zero.
+pub(super) fn div_rem_vartime<const LIMBS: usize>(
+    lhs: &Uint<LIMBS>,
+    rhs: &Uint<LIMBS>,
+) -> (Uint<LIMBS>, Uint<LIMBS>) {
+    debug_assert_ne!(*rhs, Uint::ZERO);
+
+    if lhs.cmp_vartime(rhs) == Ordering::Less {
+        return (Uint::ZERO, *lhs);
+    }
+
+    let shift = lhs.bits_vartime() - rhs.bits_vartime();
+    let mut quotient = Uint::ZERO;
+    let mut remainder = *lhs;
+    let mut shifted_rhs = rhs.shl_vartime(shift);
+
+    for i in (0..=shift).rev() {
+        if remainder.cmp_vartime(&shifted_rhs) != Ordering::Less {
+            remainder = remainder.wrapping_sub(&shifted_rhs);
+            quotient = quotient.bitor(&Uint::ONE.shl_vartime(i));
+        }
+        shifted_rhs = shifted_rhs.shr_vartime(1);
+    }
+
+    (quotient, remainder)
+}
+
+/// The extended Euclidean algorithm: computes `(g, u, v)` such that `u * a + v * b = g`, where
+/// `g = gcd(a, b)` is non-negative.
+pub(super) fn xgcd<const LIMBS: usize>(
+    a: &Int<LIMBS>,
+    b: &Int<LIMBS>,
+) -> (Uint<LIMBS>, Int<LIMBS>, Int<LIMBS>) {
+    let (mut r0, mut r1) = (a.abs(), b.abs());
+    let (mut s0, mut s1) = (Int::ONE, Int::ZERO);
+    let (mut t0, mut t1) = (Int::ZERO, Int::ONE);
+
+    while r1 != Uint::ZERO {
+        let (quotient, remainder) = div_rem_vartime(&r0, &r1);
+        (r0, r1) = (r1, remainder);
+        (s0, s1) = (s1, s0 - s1.mul_uint(&quotient));
+        (t0, t1) = (t1, t0 - t1.mul_uint(&quotient));
+    }
+
+    // We computed the coefficients for the absolute values; fix their signs.
+    if a.is_negative() {
+        s0 = -s0;
+    }
+    if b.is_negative() {
+        t0 = -t0;
+    }
+
+    (r0, s0, t0)
+}
@@ -0,0 +1,14 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! The collections used to key messages and shares by [`PartyID`](crate::PartyID) throughout the
+//! protocols.
+//!
+//! With the `std` feature, these are the hash-based collections of `std`. Without it, they fall
+//! back to the ordered collections of `alloc`, which offer the same interface for the (ordered)
+//! keys used here.
+
+#[cfg(not(feature = "std"))]
+pub use alloc::collections::{BTreeMap as HashMap, BTreeSet as HashSet};
+#[cfg(feature = "std")]
+pub use std::collections::{HashMap, HashSet};
@@ -1,13 +1,15 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::ops::{Add, AddAssign, BitAnd, Mul, Neg, Sub, SubAssign};
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, BitAnd, Mul, Neg, Sub, SubAssign};
 
 use crypto_bigint::{rand_core::CryptoRngCore, Uint};
 use serde::{Deserialize, Serialize};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
 
-use crate::{GroupElement as _, Samplable};
+use crate::{Encoding, GroupElement as _, MultiScalarMul, Samplable, VartimeGroupElement};
 
 /// An element of the Direct Product of the two Groups `FirstGroupElement` and `SecondGroupElement`.
 #[derive(PartialEq, Eq, Clone, Debug, Copy)]
@@ -45,6 +47,15 @@ impl<
     }
 }
 
+impl<FirstGroupElement: Zeroize, SecondGroupElement: Zeroize> Zeroize
+    for GroupElement<FirstGroupElement, SecondGroupElement>
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+        self.1.zeroize();
+    }
+}
+
 /// The public parameters of the Direct Product of the two Groups `FirstGroupElement` and
 /// `SecondGroupElement`.
 #[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
@@ -96,6 +107,15 @@ impl<
     }
 }
 
+impl<FirstGroupElementValue: Zeroize, SecondGroupElementValue: Zeroize> Zeroize
+    for Value<FirstGroupElementValue, SecondGroupElementValue>
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+        self.1.zeroize();
+    }
+}
+
 impl<FirstGroupElementValue: ConstantTimeEq, SecondGroupElementValue: ConstantTimeEq> ConstantTimeEq
     for Value<FirstGroupElementValue, SecondGroupElementValue>
 {
@@ -146,6 +166,52 @@ impl<FirstGroupElement: crate::GroupElement, SecondGroupElement: crate::GroupEle
     }
 }
 
+impl<FirstGroupElement: crate::GroupElement, SecondGroupElement: crate::GroupElement> MultiScalarMul
+    for GroupElement<FirstGroupElement, SecondGroupElement>
+{
+    fn multi_scalar_mul_bounded<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+        scalar_bits: usize,
+    ) -> Option<Self> {
+        let (first_bases_and_scalars, second_bases_and_scalars): (Vec<_>, Vec<_>) =
+            bases_and_scalars
+                .iter()
+                .map(|(base, scalar)| ((base.0.clone(), *scalar), (base.1.clone(), *scalar)))
+                .unzip();
+
+        Some(Self(
+            FirstGroupElement::multi_scalar_mul_bounded(&first_bases_and_scalars, scalar_bits)?,
+            SecondGroupElement::multi_scalar_mul_bounded(&second_bases_and_scalars, scalar_bits)?,
+        ))
+    }
+}
+
+impl<FirstGroupElement: VartimeGroupElement, SecondGroupElement: VartimeGroupElement>
+    VartimeGroupElement for GroupElement<FirstGroupElement, SecondGroupElement>
+{
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        Self(
+            self.0.scalar_mul_vartime(scalar),
+            self.1.scalar_mul_vartime(scalar),
+        )
+    }
+
+    fn multi_scalar_mul_vartime<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+    ) -> Option<Self> {
+        let (first_bases_and_scalars, second_bases_and_scalars): (Vec<_>, Vec<_>) =
+            bases_and_scalars
+                .iter()
+                .map(|(base, scalar)| ((base.0.clone(), *scalar), (base.1.clone(), *scalar)))
+                .unzip();
+
+        Some(Self(
+            FirstGroupElement::multi_scalar_mul_vartime(&first_bases_and_scalars)?,
+            SecondGroupElement::multi_scalar_mul_vartime(&second_bases_and_scalars)?,
+        ))
+    }
+}
+
 impl<FirstGroupElement: crate::GroupElement, SecondGroupElement: crate::GroupElement>
     From<GroupElement<FirstGroupElement, SecondGroupElement>>
     for Value<FirstGroupElement::Value, SecondGroupElement::Value>
@@ -745,3 +811,56 @@ impl<
         )
     }
 }
+
+impl<FirstGroupElement: Encoding, SecondGroupElement: Encoding> Encoding
+    for GroupElement<FirstGroupElement, SecondGroupElement>
+{
+    const ENCODED_LEN: usize = FirstGroupElement::ENCODED_LEN + SecondGroupElement::ENCODED_LEN;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.0.to_bytes();
+        bytes.extend(self.1.to_bytes());
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8], public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        let (first, second) = bytes.split_at(FirstGroupElement::ENCODED_LEN);
+
+        Ok(Self(
+            FirstGroupElement::from_bytes(first, &public_parameters.0)?,
+            SecondGroupElement::from_bytes(second, &public_parameters.1)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U256;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{multiplicative, secp256k1, test_helpers};
+
+    #[test]
+    fn satisfies_conformance() {
+        let public_parameters = PublicParameters(
+            multiplicative::PublicParameters::new(secp256k1::MODULUS).unwrap(),
+            secp256k1::scalar::PublicParameters::default(),
+        );
+        let zero = multiplicative::Value::new(U256::ZERO, &public_parameters.0).unwrap();
+
+        // An invalid element of the first group invalidates the entire element.
+        test_helpers::samplable_conformance::<
+            GroupElement<multiplicative::GroupElement<{ U256::LIMBS }>, secp256k1::Scalar>,
+        >(
+            &public_parameters,
+            vec![Value::from((zero, secp256k1::Scalar::from(U256::ONE)))],
+            &mut OsRng,
+        );
+    }
+}
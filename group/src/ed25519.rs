@@ -0,0 +1,31 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+use crypto_bigint::U256;
+pub use group_element::GroupElement;
+pub use scalar::Scalar;
+
+pub mod group_element;
+mod hash_to_curve;
+pub mod scalar;
+
+pub const SCALAR_LIMBS: usize = U256::LIMBS;
+
+/// The order `q` of the prime-order subgroup of the edwards25519 curve.
+pub const ORDER: U256 =
+    U256::from_be_hex("1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed");
+
+/// The modulus `p` of the edwards25519 curve.
+pub const MODULUS: U256 =
+    U256::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed");
+
+/// The cofactor `h` of the edwards25519 curve, i.e. the ratio between the order of the curve and
+/// that of its prime-order subgroup.
+pub const COFACTOR: u8 = 8;
+
+// Any twisted Edwards elliptic curve can be represented as an equation in the following template:
+// $ax^2 + y^2 = 1 + dx^2y^2 mod(p)$. For edwards25519 specifically, $a = -1$ and
+// $d = -121665/121666$, yielding the equation $-x^2 + y^2 = 1 - (121665/121666)x^2y^2 mod(p)$.
+pub const CURVE_EQUATION_A: U256 =
+    U256::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffec");
+pub const CURVE_EQUATION_D: U256 =
+    U256::from_be_hex("52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3");
@@ -0,0 +1,409 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    iter,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
+
+use crypto_bigint::{Uint, U256};
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_POINT,
+    edwards::{CompressedEdwardsY, EdwardsPoint},
+    traits::{Identity, MultiscalarMul, VartimeMultiscalarMul},
+};
+use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::{hash_to_curve::hash_to_curve, SCALAR_LIMBS};
+use crate::{
+    ed25519::{scalar::Scalar, CURVE_EQUATION_A, CURVE_EQUATION_D, MODULUS, ORDER},
+    BoundedGroupElement, CyclicGroupElement, HashToGroup, KnownOrderGroupElement, MulByGenerator,
+    MultiScalarMul, PrimeGroupElement, VartimeGroupElement,
+};
+
+/// An element of the prime-order subgroup of the edwards25519 curve.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct GroupElement(pub(super) EdwardsPoint);
+
+/// The public parameters of the edwards25519 group.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct PublicParameters {
+    name: String,
+    curve_type: String,
+    pub order: U256,
+    pub modulus: U256,
+    pub generator: Value,
+    pub curve_equation_a: U256,
+    pub curve_equation_d: U256,
+}
+
+impl Default for PublicParameters {
+    fn default() -> Self {
+        Self {
+            name: "Ed25519".to_string(),
+            curve_type: "Twisted Edwards".to_string(),
+            order: ORDER,
+            modulus: MODULUS,
+            generator: Value(ED25519_BASEPOINT_POINT.compress()),
+            curve_equation_a: CURVE_EQUATION_A,
+            curve_equation_d: CURVE_EQUATION_D,
+        }
+    }
+}
+
+/// The value of the edwards25519 group used for serialization.
+///
+/// This is a `newtype` around the compressed encoding of the point, `CompressedEdwardsY`.
+/// As edwards25519 has a cofactor of 8, a valid encoding of a point on the curve does not imply
+/// it belongs to the prime-order subgroup; this is checked in [`GroupElement::new()`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Value(CompressedEdwardsY);
+
+impl ConstantTimeEq for Value {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.as_bytes().ct_eq(other.0.as_bytes())
+    }
+}
+
+impl ConditionallySelectable for Value {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(CompressedEdwardsY(<[u8; 32]>::conditional_select(
+            &a.0 .0, &b.0 .0, choice,
+        )))
+    }
+}
+
+impl From<Value> for CompressedEdwardsY {
+    fn from(value: Value) -> Self {
+        value.0
+    }
+}
+
+impl ConstantTimeEq for GroupElement {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // There are two `subtle` crates used in various crates in the Rust crypto ecosystem; the
+        // original `dalek` one and `zkcrypto`'s fork. The former being most widely used was chosen
+        // for the group traits, whereas the latter is used in the working `zkcrypto`
+        // `curve25519-dalek-ng` crate used in this code. Therefore, an adaptation between the two
+        // must-occur, which is implemented here via unwrapping and wrapping the `u8` inner value of
+        // `Choice`.
+        <EdwardsPoint as subtle_ng::ConstantTimeEq>::ct_eq(&self.0, &other.0)
+            .unwrap_u8()
+            .into()
+    }
+}
+
+impl ConditionallySelectable for GroupElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(
+            <EdwardsPoint as subtle_ng::ConditionallySelectable>::conditional_select(
+                &a.0,
+                &b.0,
+                choice.unwrap_u8().into(),
+            ),
+        )
+    }
+}
+
+impl crate::GroupElement for GroupElement {
+    type Value = Value;
+
+    fn value(&self) -> Self::Value {
+        Value(self.0.compress())
+    }
+
+    type PublicParameters = PublicParameters;
+
+    fn public_parameters(&self) -> Self::PublicParameters {
+        PublicParameters::default()
+    }
+
+    fn new(value: Self::Value, _public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        // `decompress()` ensures the point is on the curve, but it accepts non-canonical
+        // encodings, which we reject by re-compressing the point.
+        //
+        // A point on the curve may still have a component in the small-order (torsion) subgroup,
+        // e.g. be one of the 8 small-order points itself. Such points do not belong to the
+        // prime-order group, and so we reject any point that is not torsion-free (the identity,
+        // which is the neutral element of the prime-order group, being the only exception to
+        // being of small-order.)
+        value
+            .0
+            .decompress()
+            .filter(|point| point.compress() == value.0 && point.is_torsion_free())
+            .map(Self)
+            .ok_or(crate::Error::InvalidGroupElement)
+    }
+
+    fn neutral(&self) -> Self {
+        Self(EdwardsPoint::identity())
+    }
+
+    fn scalar_mul<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        Scalar::from(scalar) * self
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0 + self.0)
+    }
+}
+
+impl From<GroupElement> for Value {
+    fn from(value: GroupElement) -> Self {
+        Self(value.0.compress())
+    }
+}
+
+impl From<GroupElement> for PublicParameters {
+    fn from(_value: GroupElement) -> Self {
+        Self::default()
+    }
+}
+
+impl From<GroupElement> for EdwardsPoint {
+    fn from(value: GroupElement) -> Self {
+        value.0
+    }
+}
+
+impl Neg for GroupElement {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.neg())
+    }
+}
+
+impl Add<Self> for GroupElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.add(rhs.0))
+    }
+}
+
+impl<'r> Add<&'r Self> for GroupElement {
+    type Output = Self;
+
+    fn add(self, rhs: &'r Self) -> Self::Output {
+        Self(self.0.add(rhs.0))
+    }
+}
+
+impl Sub<Self> for GroupElement {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0.sub(rhs.0))
+    }
+}
+
+impl<'r> Sub<&'r Self> for GroupElement {
+    type Output = Self;
+
+    fn sub(self, rhs: &'r Self) -> Self::Output {
+        Self(self.0.sub(rhs.0))
+    }
+}
+
+impl AddAssign<Self> for GroupElement {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.add_assign(rhs.0)
+    }
+}
+
+impl<'r> AddAssign<&'r Self> for GroupElement {
+    fn add_assign(&mut self, rhs: &'r Self) {
+        self.0.add_assign(rhs.0)
+    }
+}
+
+impl SubAssign<Self> for GroupElement {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.sub_assign(rhs.0)
+    }
+}
+
+impl<'r> SubAssign<&'r Self> for GroupElement {
+    fn sub_assign(&mut self, rhs: &'r Self) {
+        self.0.sub_assign(rhs.0)
+    }
+}
+
+impl MulByGenerator<U256> for GroupElement {
+    fn mul_by_generator(&self, scalar: U256) -> Self {
+        self.mul_by_generator(Scalar::from(scalar))
+    }
+}
+
+impl<'r> MulByGenerator<&'r U256> for GroupElement {
+    fn mul_by_generator(&self, scalar: &'r U256) -> Self {
+        self.mul_by_generator(*scalar)
+    }
+}
+
+impl MultiScalarMul for GroupElement {
+    fn multi_scalar_mul_bounded<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+        scalar_bits: usize,
+    ) -> Option<Self> {
+        if bases_and_scalars.is_empty() {
+            return None;
+        }
+
+        // `curve25519_dalek`'s (Straus) multi-scalar multiplication operates in constant-time over
+        // the full scalars, so we only need to take the `scalar_bits` least significant bits of
+        // each scalar.
+        let mask = (Uint::<LIMBS>::ONE << scalar_bits).wrapping_sub(&Uint::<LIMBS>::ONE);
+
+        Some(Self(EdwardsPoint::multiscalar_mul(
+            bases_and_scalars
+                .iter()
+                .map(|(_, scalar)| Scalar::from(scalar & mask).0),
+            bases_and_scalars.iter().map(|(base, _)| base.0),
+        )))
+    }
+}
+
+impl VartimeGroupElement for GroupElement {
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        Self(EdwardsPoint::vartime_multiscalar_mul(
+            iter::once(Scalar::from(scalar).0),
+            iter::once(self.0),
+        ))
+    }
+
+    fn multi_scalar_mul_vartime<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+    ) -> Option<Self> {
+        if bases_and_scalars.is_empty() {
+            return None;
+        }
+
+        Some(Self(EdwardsPoint::vartime_multiscalar_mul(
+            bases_and_scalars
+                .iter()
+                .map(|(_, scalar)| Scalar::from(scalar).0),
+            bases_and_scalars.iter().map(|(base, _)| base.0),
+        )))
+    }
+}
+
+impl CyclicGroupElement for GroupElement {
+    fn generator(&self) -> Self {
+        Self(ED25519_BASEPOINT_POINT)
+    }
+
+    fn generator_value_from_public_parameters(
+        _public_parameters: &Self::PublicParameters,
+    ) -> Self::Value {
+        Value(ED25519_BASEPOINT_POINT.compress())
+    }
+}
+
+impl BoundedGroupElement<SCALAR_LIMBS> for GroupElement {
+    fn lower_bound(public_parameters: &Self::PublicParameters) -> Uint<SCALAR_LIMBS> {
+        Self::order_from_public_parameters(public_parameters)
+    }
+}
+
+impl KnownOrderGroupElement<SCALAR_LIMBS> for GroupElement {
+    type Scalar = Scalar;
+
+    fn order(&self) -> Uint<SCALAR_LIMBS> {
+        ORDER
+    }
+
+    fn order_from_public_parameters(
+        _public_parameters: &Self::PublicParameters,
+    ) -> Uint<SCALAR_LIMBS> {
+        ORDER
+    }
+}
+
+impl MulByGenerator<Scalar> for GroupElement {
+    fn mul_by_generator(&self, scalar: Scalar) -> Self {
+        // Uses the precomputed basepoint table.
+        Self(&scalar.0 * &curve25519_dalek::constants::ED25519_BASEPOINT_TABLE)
+    }
+}
+
+impl<'r> MulByGenerator<&'r Scalar> for GroupElement {
+    fn mul_by_generator(&self, scalar: &'r Scalar) -> Self {
+        self.mul_by_generator(*scalar)
+    }
+}
+
+impl PrimeGroupElement<SCALAR_LIMBS> for GroupElement {}
+
+impl HashToGroup for GroupElement {
+    fn hash_to_group(bytes: &[u8], dst: &[u8]) -> crate::Result<Self> {
+        hash_to_curve(bytes, dst).map(Self)
+    }
+}
+
+impl crate::Encoding for GroupElement {
+    const ENCODED_LEN: usize = 32;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.compress().to_bytes().to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8], public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        let bytes = <[u8; 32]>::try_from(bytes).map_err(|_| crate::Error::InvalidGroupElement)?;
+
+        // `new()` rejects non-canonical encodings, and points outside the prime-order subgroup.
+        <Self as crate::GroupElement>::new(Value(CompressedEdwardsY(bytes)), public_parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::Random;
+    use curve25519_dalek::constants::EIGHT_TORSION;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{test_helpers, GroupElement as _};
+
+    #[test]
+    fn rejects_small_order_points() {
+        let public_parameters = PublicParameters::default();
+
+        // Points of small order are on the curve, but not in the prime-order subgroup.
+        for point in &EIGHT_TORSION[1..] {
+            assert!(GroupElement::new(Value(point.compress()), &public_parameters).is_err());
+        }
+
+        // Neither are the sums of small-order points with points of the prime-order subgroup.
+        let generator = ED25519_BASEPOINT_POINT + EIGHT_TORSION[1];
+        assert!(GroupElement::new(Value(generator.compress()), &public_parameters).is_err());
+
+        assert!(
+            GroupElement::new(Value(EIGHT_TORSION[0].compress()), &public_parameters).is_ok(),
+            "the identity should be accepted"
+        );
+    }
+
+    #[test]
+    fn satisfies_conformance() {
+        let generator =
+            GroupElement::generator_from_public_parameters(&PublicParameters::default()).unwrap();
+        let elements: Vec<_> = (0..4)
+            .map(|_| generator.scalar_mul(&U256::random(&mut OsRng)))
+            .collect();
+
+        // Points of small order are on the curve, but not in the prime-order subgroup.
+        let invalid_values = EIGHT_TORSION[1..]
+            .iter()
+            .map(|point| Value(point.compress()))
+            .collect();
+
+        test_helpers::conformance(&elements, invalid_values, &mut OsRng);
+    }
+}
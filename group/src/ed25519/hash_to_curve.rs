@@ -0,0 +1,223 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Hashing to the edwards25519 curve, following the `edwards25519_XMD:SHA-512_ELL2_RO_` suite of
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+
+use crypto_bigint::{impl_modulus, modular::constant_mod::Residue, Encoding, NonZero, U256};
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::MODULUS;
+use crate::hash_to_field::hash_to_field;
+
+impl_modulus!(
+    FieldModulus,
+    U256,
+    "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+);
+
+/// An element of the base field $\mathbb{F}_p$ of edwards25519.
+type FieldElement = Residue<FieldModulus, { U256::LIMBS }>;
+
+/// The Montgomery curve25519 coefficient `J`.
+const J: U256 = U256::from_u32(486662);
+
+/// Computes `hash_to_curve` (Section 3.) for the `edwards25519_XMD:SHA-512_ELL2_RO_` suite.
+///
+/// The output is cofactor-cleared, and so belongs to the prime-order subgroup.
+pub(super) fn hash_to_curve(msg: &[u8], dst: &[u8]) -> crate::Result<EdwardsPoint> {
+    // `MODULUS` is non-zero, so it is safe to `unwrap()`.
+    let [u0, u1] = hash_to_field::<2, { U256::LIMBS }>(msg, dst, &NonZero::new(MODULUS).unwrap())?;
+
+    let q0 = map_to_curve(FieldElement::new(&u0))?;
+    let q1 = map_to_curve(FieldElement::new(&u1))?;
+
+    Ok((q0 + q1).mul_by_cofactor())
+}
+
+/// Maps a field element to a point of edwards25519 using the Elligator 2 method, via its
+/// straight-line implementation for curve25519 and the birational map to edwards25519
+/// (Appendix G.2.).
+fn map_to_curve(u: FieldElement) -> crate::Result<EdwardsPoint> {
+    let (xn, xd, yn, yd) = map_to_curve_elligator2_edwards25519(u);
+
+    let (xd_inverse, _) = xd.invert();
+    let (yd_inverse, _) = yd.invert();
+    let x = xn * xd_inverse;
+    let y = yn * yd_inverse;
+
+    // Encode the point by its `y`-coordinate and the sign of its `x`-coordinate, and decode it.
+    let mut encoding = y.retrieve().to_le_bytes();
+    encoding[31] |= sgn0(&x).unwrap_u8() << 7;
+
+    CompressedEdwardsY(encoding)
+        .decompress()
+        .ok_or(crate::Error::HashToGroup)
+}
+
+/// Appendix G.2.2.
+fn map_to_curve_elligator2_edwards25519(
+    u: FieldElement,
+) -> (FieldElement, FieldElement, FieldElement, FieldElement) {
+    // c1 = sqrt(-486664), with sgn0(c1) = 0.
+    let c1 = sqrt(&FieldElement::new(&U256::from_u32(486664)).neg());
+    let c1 = FieldElement::conditional_select(&c1, &c1.neg(), sgn0(&c1));
+
+    let (x_mn, x_md, y_mn, y_md) = map_to_curve_elligator2_curve25519(u);
+    let xn = x_mn * y_md * c1;
+    let xd = x_md * y_mn;
+    let yn = x_mn - x_md;
+    let yd = x_mn + x_md;
+
+    let e = (xd * yd).ct_eq(&FieldElement::ZERO);
+    let xn = FieldElement::conditional_select(&xn, &FieldElement::ZERO, e);
+    let xd = FieldElement::conditional_select(&xd, &FieldElement::ONE, e);
+    let yn = FieldElement::conditional_select(&yn, &FieldElement::ONE, e);
+    let yd = FieldElement::conditional_select(&yd, &FieldElement::ONE, e);
+
+    (xn, xd, yn, yd)
+}
+
+/// Appendix G.2.1.
+fn map_to_curve_elligator2_curve25519(
+    u: FieldElement,
+) -> (FieldElement, FieldElement, FieldElement, FieldElement) {
+    let j = FieldElement::new(&J);
+    // c1 = (p + 3) / 8
+    let c1 = MODULUS.wrapping_add(&U256::from_u8(3)).shr_vartime(3);
+    // c2 = 2^c1
+    let c2 = FieldElement::new(&U256::from_u8(2)).pow(&c1);
+    // c3 = sqrt(-1)
+    let c3 = sqrt_minus_one();
+    // c4 = (p - 5) / 8
+    let c4 = MODULUS.wrapping_sub(&U256::from_u8(5)).shr_vartime(3);
+
+    let tv1 = u.square();
+    let tv1 = tv1 + tv1;
+    let xd = tv1 + FieldElement::ONE;
+    let x1n = j.neg();
+    let tv2 = xd.square();
+    let gxd = tv2 * xd;
+    let gx1 = j * tv1;
+    let gx1 = gx1 * x1n;
+    let gx1 = gx1 + tv2;
+    let gx1 = gx1 * x1n;
+    let tv3 = gxd.square();
+    let tv2 = tv3.square();
+    let tv3 = tv3 * gxd;
+    let tv3 = tv3 * gx1;
+    let tv2 = tv2 * tv3;
+    let y11 = tv2.pow(&c4);
+    let y11 = y11 * tv3;
+    let y12 = y11 * c3;
+    let tv2 = y11.square();
+    let tv2 = tv2 * gxd;
+    let e1 = tv2.ct_eq(&gx1);
+    let y1 = FieldElement::conditional_select(&y12, &y11, e1);
+    let x2n = x1n * tv1;
+    let y21 = y11 * u;
+    let y21 = y21 * c2;
+    let y22 = y21 * c3;
+    let gx2 = gx1 * tv1;
+    let tv2 = y21.square();
+    let tv2 = tv2 * gxd;
+    let e2 = tv2.ct_eq(&gx2);
+    let y2 = FieldElement::conditional_select(&y22, &y21, e2);
+    let tv2 = y1.square();
+    let tv2 = tv2 * gxd;
+    let e3 = tv2.ct_eq(&gx1);
+    let xn = FieldElement::conditional_select(&x2n, &x1n, e3);
+    let y = FieldElement::conditional_select(&y2, &y1, e3);
+    let e4 = sgn0(&y);
+    let y = FieldElement::conditional_select(&y, &y.neg(), e3 ^ e4);
+
+    (xn, xd, y, FieldElement::ONE)
+}
+
+/// The sign of a field element, i.e. the parity of its canonical representative (Section 4.1.).
+fn sgn0(x: &FieldElement) -> Choice {
+    Choice::from((x.retrieve().as_words()[0] & 1) as u8)
+}
+
+/// A square root of $-1$, namely $2^{(p - 1) / 4}$.
+fn sqrt_minus_one() -> FieldElement {
+    FieldElement::new(&U256::from_u8(2)).pow(&MODULUS.wrapping_sub(&U256::ONE).shr_vartime(2))
+}
+
+/// A square root of the square `x`, computed as for any $p = 5 \mod 8$.
+fn sqrt(x: &FieldElement) -> FieldElement {
+    let candidate = x.pow(&MODULUS.wrapping_add(&U256::from_u8(3)).shr_vartime(3));
+
+    FieldElement::conditional_select(
+        &(candidate * sqrt_minus_one()),
+        &candidate,
+        candidate.square().ct_eq(x),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_to_curve_as_in_rfc_9380() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+
+        // The test vectors of Appendix J.5.1., as the affine coordinates `(x, y)` of `P`.
+        let q128 = [&b"q128_"[..], &[b'q'; 128]].concat();
+        let a512 = [&b"a512_"[..], &[b'a'; 512]].concat();
+        let test_vectors: [(&[u8], &str, &str); 5] = [
+            (
+                b"",
+                "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+                "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+            ),
+            (
+                b"abc",
+                "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+                "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+            ),
+            (
+                b"abcdef0123456789",
+                "6d7fabf47a2dc03fe7d47f7dddd21082c5fb8f86743cd020f3fb147d57161472",
+                "53060a3d140e7fbcda641ed3cf42c88a75411e648a1add71217f70ea8ec561a6",
+            ),
+            (
+                &q128,
+                "5fb0b92acedd16f3bcb0ef83f5c7b7a9466b5f1e0d8d217421878ea3686f8524",
+                "2eca15e355fcfa39d2982f67ddb0eea138e2994f5956ed37b7f72eea5e89d2f7",
+            ),
+            (
+                &a512,
+                "0efcfde5898a839b00997fbe40d2ebe950bc81181afbd5cd6b9618aa336c1e8c",
+                "6dc2fc04f266c5c27f236a80b14f92ccd051ef1ff027f26a07f8c0f327d8f995",
+            ),
+        ];
+
+        // The edwards25519 constant `d = -121665 / 121666`.
+        let d = -(FieldElement::new(&U256::from_u32(121665))
+            * FieldElement::new(&U256::from_u32(121666)).invert().0);
+
+        for (msg, x, y) in test_vectors {
+            let x = FieldElement::new(&U256::from_be_hex(x));
+            let y = FieldElement::new(&U256::from_be_hex(y));
+
+            // `(x, y)` is on the curve `-x^2 + y^2 = 1 + d * x^2 * y^2`, so that it is determined
+            // by `y` and the sign of `x`, i.e. by its compressed encoding.
+            assert_eq!(
+                y.square() - x.square(),
+                FieldElement::ONE + d * x.square() * y.square()
+            );
+            let mut encoding = y.retrieve().to_le_bytes();
+            encoding[31] |= sgn0(&x).unwrap_u8() << 7;
+
+            assert_eq!(
+                hash_to_curve(msg, DST).unwrap().compress(),
+                CompressedEdwardsY(encoding),
+                "message {:?}",
+                core::str::from_utf8(msg).unwrap()
+            );
+        }
+    }
+}
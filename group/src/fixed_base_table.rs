@@ -0,0 +1,159 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::vec::Vec;
+
+use crypto_bigint::{Uint, Word};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+use crate::GroupElement;
+
+/// The number of bits of the scalar consumed by each window of the table.
+const WINDOW_BITS: usize = 4;
+
+/// The number of entries of each window of the table.
+const WINDOW_SIZE: usize = 1 << WINDOW_BITS;
+
+/// A precomputed table of multiples of a fixed base, allowing for faster constant-time
+/// multiplications of that base.
+///
+/// The table is built once from `base` (e.g. a generator stored in public parameters), and holds
+/// $j\cdot 16^i\cdot \textsf{base}$ for every window $i$ and digit $0 \leq j < 16$. Multiplying by
+/// a scalar then requires a single addition per (radix-16) digit of the scalar and no doublings,
+/// where the digits are looked up in constant-time.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct FixedBaseTable<G> {
+    base: G,
+    windows: Vec<[G; WINDOW_SIZE]>,
+}
+
+impl<G: GroupElement + ConditionallySelectable> FixedBaseTable<G> {
+    /// Precomputes the table of `base`, for multiplications by scalars of (at most) `scalar_bits`
+    /// bits.
+    pub fn new(base: G, scalar_bits: usize) -> Self {
+        let mut window_base = base;
+        let windows = (0..scalar_bits.div_ceil(WINDOW_BITS))
+            .map(|_| {
+                let mut multiple = base.neutral();
+                let window = core::array::from_fn(|_| {
+                    let entry = multiple;
+                    multiple += &window_base;
+
+                    entry
+                });
+                // After the loop, `multiple` holds $16^{i+1}\cdot \textsf{base}$.
+                window_base = multiple;
+
+                window
+            })
+            .collect();
+
+        Self { base, windows }
+    }
+
+    /// Returns the base of this table.
+    pub fn base(&self) -> &G {
+        &self.base
+    }
+
+    /// Constant-time multiplication of the base by `scalar`.
+    ///
+    /// Only the (least significant) bits covered by the table, as specified at [`Self::new()`],
+    /// are taken into account.
+    pub fn mul<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> G {
+        let words = scalar.as_words();
+        let digits_per_word = Word::BITS as usize / WINDOW_BITS;
+
+        self.windows
+            .iter()
+            .enumerate()
+            .fold(self.base.neutral(), |acc, (i, window)| {
+                let digit = words.get(i / digits_per_word).map_or(0, |word| {
+                    (word >> ((i % digits_per_word) * WINDOW_BITS)) & (WINDOW_SIZE as Word - 1)
+                });
+
+                // Scan the entire window to select the entry of `digit` in constant-time.
+                let mut entry = window[0];
+                for (j, multiple) in window.iter().enumerate().skip(1) {
+                    entry.conditional_assign(multiple, digit.ct_eq(&(j as Word)));
+                }
+
+                acc + entry
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{Random, U256};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{ristretto, secp256k1, CyclicGroupElement};
+
+    /// The number of random scalars each table is tested against.
+    const RANDOM_SCALARS: usize = 64;
+
+    /// Asserts that `table` multiplies like [`GroupElement::scalar_mul()`] by edge-case scalars of
+    /// the group of order `order` (including ones whose every radix-16 digit is `0xF`, which
+    /// select the last entry of each window), and by random scalars.
+    fn assert_multiplies_like_scalar_mul<G: GroupElement + ConditionallySelectable>(
+        table: &FixedBaseTable<G>,
+        order: U256,
+    ) {
+        let edge_scalars = [
+            U256::ZERO,
+            U256::ONE,
+            order.wrapping_sub(&U256::ONE),
+            order,
+            U256::from_u64(0xF),
+            U256::from_u64(u64::MAX),
+            U256::MAX,
+        ];
+        let random_scalars = (0..RANDOM_SCALARS).map(|_| U256::random(&mut OsRng));
+
+        for scalar in edge_scalars.into_iter().chain(random_scalars) {
+            assert_eq!(
+                table.mul(&scalar),
+                table.base().scalar_mul(&scalar),
+                "table multiplication by {scalar} differs from scalar_mul"
+            );
+        }
+    }
+
+    #[test]
+    fn multiplies_like_scalar_mul() {
+        let generator = secp256k1::GroupElement::generator_from_public_parameters(
+            &secp256k1::group_element::PublicParameters::default(),
+        )
+        .unwrap();
+        for base in [generator, generator.scalar_mul(&U256::random(&mut OsRng))] {
+            let table = FixedBaseTable::new(base, U256::BITS);
+            assert_multiplies_like_scalar_mul(&table, secp256k1::ORDER);
+        }
+
+        let generator = ristretto::GroupElement::generator_from_public_parameters(
+            &ristretto::group_element::PublicParameters::default(),
+        )
+        .unwrap();
+        for base in [generator, generator.scalar_mul(&U256::random(&mut OsRng))] {
+            let table = FixedBaseTable::new(base, U256::BITS);
+            assert_multiplies_like_scalar_mul(&table, ristretto::ORDER);
+        }
+    }
+
+    #[test]
+    fn ignores_bits_beyond_the_table() {
+        let base = secp256k1::GroupElement::generator_from_public_parameters(
+            &secp256k1::group_element::PublicParameters::default(),
+        )
+        .unwrap();
+        let table = FixedBaseTable::new(base, 64);
+
+        let scalar = U256::random(&mut OsRng);
+        assert_eq!(
+            table.mul(&scalar),
+            base.scalar_mul(&(scalar & U256::from_u64(u64::MAX)))
+        );
+    }
+}
@@ -0,0 +1,200 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Hashing arbitrary sequences of bytes to prime fields, following the `hash_to_field` routine of
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html) with `expand_message_xmd` and SHA-512.
+
+use alloc::vec::Vec;
+
+use crypto_bigint::{NonZero, Uint};
+use sha2::{Digest, Sha512};
+
+/// The output size of SHA-512 in bytes, `b_in_bytes`.
+const B_IN_BYTES: usize = 64;
+
+/// The input block size of SHA-512 in bytes, `s_in_bytes`.
+const S_IN_BYTES: usize = 128;
+
+/// The target security level in bits, `k`.
+const SECURITY_PARAMETER: usize = 128;
+
+/// The prefix of the hash of domain separation tags longer than 255 bytes (Section 5.3.3.).
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Computes `expand_message_xmd` with SHA-512 (Section 5.3.1.), which expands `msg` to
+/// `len_in_bytes` uniformly random bytes under the domain separation tag `dst`.
+///
+/// A `dst` longer than 255 bytes is replaced by `H("H2C-OVERSIZE-DST-" || dst)`, as specified in
+/// Section 5.3.3.
+pub(crate) fn expand_message_xmd(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> crate::Result<Vec<u8>> {
+    let ell = len_in_bytes.div_ceil(B_IN_BYTES);
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return Err(crate::Error::HashToGroup);
+    }
+
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = Sha512::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize();
+
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    let b_0 = Sha512::new()
+        .chain_update([0u8; S_IN_BYTES])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut b_i = Sha512::new()
+        .chain_update(b_0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = b_i.to_vec();
+    for i in 2..=ell {
+        let b_0_xor_b_i: Vec<u8> = b_0.iter().zip(b_i).map(|(a, b)| a ^ b).collect();
+
+        b_i = Sha512::new()
+            .chain_update(b_0_xor_b_i)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+
+    Ok(uniform_bytes)
+}
+
+/// Computes `hash_to_field` (Section 5.2.), which hashes `msg` to `COUNT` elements of the prime
+/// field of order `modulus` under the domain separation tag `dst`.
+///
+/// Each element is reduced from `L = ceil((ceil(log2(p)) + k) / 8)` uniform bytes, so that its
+/// distribution is within statistical distance $2^{-k}$ of uniform.
+pub(crate) fn hash_to_field<const COUNT: usize, const LIMBS: usize>(
+    msg: &[u8],
+    dst: &[u8],
+    modulus: &NonZero<Uint<LIMBS>>,
+) -> crate::Result<[Uint<LIMBS>; COUNT]> {
+    let l = (modulus.bits_vartime() + SECURITY_PARAMETER).div_ceil(8);
+    let uniform_bytes = expand_message_xmd(msg, dst, COUNT * l)?;
+
+    let mut elements = [Uint::<LIMBS>::ZERO; COUNT];
+    for (element, bytes) in elements.iter_mut().zip(uniform_bytes.chunks_exact(l)) {
+        *element = reduce_be_bytes(bytes, modulus);
+    }
+
+    Ok(elements)
+}
+
+/// Reduces the big-endian integer `bytes`, which may be wider than `Uint<LIMBS>`, modulo
+/// `modulus` in constant-time (with respect to the value of `bytes`).
+fn reduce_be_bytes<const LIMBS: usize>(bytes: &[u8], modulus: &Uint<LIMBS>) -> Uint<LIMBS> {
+    // Horner's method over the bits: double the accumulator and add the next bit, modulo
+    // `modulus`.
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1))
+        .fold(Uint::<LIMBS>::ZERO, |element, bit| {
+            element
+                .add_mod(&element, modulus)
+                .add_mod(&Uint::<LIMBS>::from_u8(bit), modulus)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U256;
+
+    use super::*;
+    use crate::{additive, GroupElement as _, HashToScalar};
+
+    #[test]
+    fn expands_message_as_in_rfc_9380() {
+        // The test vector of Appendix K.3. for an empty message.
+        let uniform_bytes =
+            expand_message_xmd(b"", b"QUUX-V01-CS02-with-expander-SHA512-256", 0x20).unwrap();
+
+        assert_eq!(
+            uniform_bytes,
+            [
+                0x6b, 0x9a, 0x73, 0x12, 0x41, 0x1d, 0x92, 0xf9, 0x21, 0xc6, 0xf6, 0x8c, 0xa0, 0xb6,
+                0x38, 0x07, 0x30, 0xa1, 0xa4, 0xd9, 0x82, 0xc5, 0x07, 0x21, 0x1a, 0x90, 0x96, 0x4c,
+                0x39, 0x41, 0x79, 0xba
+            ]
+        );
+    }
+
+    #[test]
+    fn hashes_oversize_domain_separation_tags() {
+        // A tag of 295 bytes, longer than the 255 bytes `DST_prime` can encode the length of.
+        let dst = [
+            b"QUUX-V01-CS02-with-expander-SHA512-256-".as_slice(),
+            &[b'a'; 256],
+        ]
+        .concat();
+
+        let uniform_bytes = expand_message_xmd(b"abc", &dst, 0x20).unwrap();
+
+        // As computed by an independent implementation of Section 5.3.3.
+        assert_eq!(
+            uniform_bytes,
+            [
+                0x71, 0xe3, 0x30, 0xc8, 0xbb, 0x2a, 0x7e, 0xa0, 0x12, 0x66, 0xfa, 0xb1, 0x1e, 0x2c,
+                0xc5, 0xec, 0x14, 0xf6, 0x9d, 0x6d, 0x51, 0x07, 0x00, 0x6f, 0xdc, 0x2e, 0xe1, 0xf7,
+                0x05, 0x15, 0xbe, 0x03
+            ]
+        );
+
+        // Which is the expansion under the hashed tag.
+        let hashed_dst = Sha512::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(&dst)
+            .finalize();
+        assert_eq!(
+            uniform_bytes,
+            expand_message_xmd(b"abc", &hashed_dst, 0x20).unwrap()
+        );
+    }
+
+    #[test]
+    fn hashes_to_additive_group_elements() {
+        let public_parameters = additive::PublicParameters::<{ U256::LIMBS }> {
+            modulus: NonZero::new(U256::from_u64(1000003)).unwrap(),
+        };
+
+        let element =
+            additive::GroupElement::hash_to_scalar(b"message", b"DST", &public_parameters).unwrap();
+
+        // `L = ceil((20 + 128) / 8) = 19` uniform bytes are reduced modulo the 20-bit modulus.
+        let expected = expand_message_xmd(b"message", b"DST", 19)
+            .unwrap()
+            .into_iter()
+            .fold(0u64, |element, byte| {
+                (element * 256 + byte as u64) % 1000003
+            });
+        assert_eq!(element.value(), U256::from_u64(expected));
+
+        assert_ne!(
+            additive::GroupElement::hash_to_scalar(b"message", b"other DST", &public_parameters)
+                .unwrap(),
+            element,
+            "distinct domain separation tags should yield distinct elements"
+        );
+    }
+}
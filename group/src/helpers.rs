@@ -1,9 +1,11 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::fmt;
+use alloc::vec::Vec;
+use core::fmt;
 
 pub mod const_generic_array_serialization;
+pub mod primality;
 
 pub trait FlatMapResults<T, E: fmt::Debug>: AsRef<[Result<T, E>]> {
     type Output: AsRef<[T]>;
@@ -3,7 +3,8 @@
 
 // Adapted from: https://github.com/serde-rs/serde/issues/1937#issuecomment-812137971
 
-use std::{convert::TryInto, marker::PhantomData};
+use alloc::{format, vec::Vec};
+use core::{convert::TryInto, marker::PhantomData};
 
 use serde::{
     de::{SeqAccess, Visitor},
@@ -30,7 +31,7 @@ where
 {
     type Value = [T; N];
 
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         formatter.write_str(&format!("an array of length {}", N))
     }
 
@@ -0,0 +1,194 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Primality testing of public, possibly adversarially chosen, candidates.
+
+use alloc::vec::Vec;
+
+use crypto_bigint::{
+    modular::runtime_mod::{DynResidue, DynResidueParams},
+    Limb, NonZero, Uint, Word,
+};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+/// The domain separation tag used for deriving the Miller-Rabin bases of a candidate prime.
+const MILLER_RABIN_DST: &[u8] = b"dWallet Miller-Rabin bases";
+
+/// The number of Miller-Rabin rounds with (pseudo-)random bases; each round lets a composite pass
+/// with probability at most $1/4$.
+const MILLER_RABIN_ROUNDS: usize = 64;
+
+/// The bound on the primes used for trial division before running Miller-Rabin.
+const TRIAL_DIVISION_BOUND: u32 = 1 << 10;
+
+/// Tests `candidate` for primality, using trial division by small primes followed by the
+/// Miller-Rabin test with bases derived from hashing `candidate`.
+///
+/// As the bases are derived from the candidate itself, this test is also sound for adversarially
+/// chosen candidates, failing with probability at most $2^{-128}$. Its running time is bounded
+/// for candidates of any size, as trial division only goes up to [`TRIAL_DIVISION_BOUND`].
+pub fn is_prime<const LIMBS: usize>(candidate: &Uint<LIMBS>) -> bool {
+    if candidate.as_words()[0] & 1 == 0 {
+        return candidate == &Uint::from_u8(2);
+    }
+
+    if let Some(prime) = small_primes().find(|prime| {
+        let (_, remainder) = candidate.div_rem_limb(NonZero::new(Limb::from_u32(*prime)).unwrap());
+
+        remainder == Limb::ZERO
+    }) {
+        return candidate == &Uint::from_u32(prime);
+    }
+
+    // An odd composite has a prime factor no larger than its square root, so a candidate below
+    // `TRIAL_DIVISION_BOUND^2` with no small factor is either $1$ or a prime.
+    if candidate.bits_vartime() <= 2 * TRIAL_DIVISION_BOUND.ilog2() as usize {
+        return candidate != &Uint::ONE;
+    }
+
+    let mut reader = Shake256::default()
+        .chain(MILLER_RABIN_DST)
+        .chain(
+            candidate
+                .as_words()
+                .iter()
+                .flat_map(|word| word.to_le_bytes())
+                .collect::<Vec<_>>(),
+        )
+        .finalize_xof();
+
+    // Bases are taken in $[2, 2^{bits - 1})$, which is a subset of $[2, candidate - 2]$ as
+    // `candidate` is at least `TRIAL_DIVISION_BOUND^2`.
+    let base_bits = candidate.bits_vartime() - 1;
+    let params = DynResidueParams::new(candidate);
+
+    (0..MILLER_RABIN_ROUNDS).all(|_| {
+        let base = random_uint::<LIMBS>(&mut reader, base_bits);
+        let base = if base.cmp_vartime(&Uint::from_u8(2)).is_lt() {
+            Uint::from_u8(2)
+        } else {
+            base
+        };
+
+        miller_rabin_round(candidate, &DynResidue::new(&base, params))
+    })
+}
+
+/// A single round of the Miller-Rabin test for the odd `candidate` with base `base`.
+fn miller_rabin_round<const LIMBS: usize>(
+    candidate: &Uint<LIMBS>,
+    base: &DynResidue<LIMBS>,
+) -> bool {
+    let one = DynResidue::one(*base.params());
+    let minus_one = -one;
+
+    // Write $candidate - 1 = 2^s \cdot d$ for an odd $d$.
+    let candidate_minus_one = candidate.wrapping_sub(&Uint::ONE);
+    let s = candidate_minus_one.trailing_zeros_vartime();
+    let d = candidate_minus_one.shr_vartime(s);
+
+    let mut x = base.pow_bounded_exp(&d, d.bits_vartime());
+    if x == one || x == minus_one {
+        return true;
+    }
+
+    for _ in 1..s {
+        x = x.square();
+        if x == minus_one {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// The odd primes below [`TRIAL_DIVISION_BOUND`], in increasing order.
+fn small_primes() -> impl Iterator<Item = u32> {
+    (3..TRIAL_DIVISION_BOUND).step_by(2).filter(|n| {
+        (3..)
+            .step_by(2)
+            .take_while(|d| d * d <= *n)
+            .all(|d| n % d != 0)
+    })
+}
+
+/// Reads a uniformly random `bits`-bit number from `reader`.
+pub(crate) fn random_uint<const LIMBS: usize>(
+    reader: &mut impl XofReader,
+    bits: usize,
+) -> Uint<LIMBS> {
+    let mut words = [0 as Word; LIMBS];
+    for word in words.iter_mut().take(bits.div_ceil(Limb::BITS)) {
+        let mut bytes = [0u8; Limb::BYTES];
+        reader.read(&mut bytes);
+        *word = Word::from_le_bytes(bytes);
+    }
+
+    let mask = Uint::ONE.shl_vartime(bits).wrapping_sub(&Uint::ONE);
+
+    Uint::from_words(words).bitand(&mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{U128, U256, U64};
+
+    use super::*;
+
+    #[test]
+    fn miller_rabin_rejects_carmichael_numbers() {
+        // Carmichael numbers pass the Fermat test for all coprime bases, but none of these is a
+        // strong pseudoprime to the base 2.
+        for carmichael_number in [561u64, 1105, 1729, 2465, 2821, 6601, 8911] {
+            let candidate = U64::from_u64(carmichael_number);
+            let base = DynResidue::new(&U64::from_u8(2), DynResidueParams::new(&candidate));
+
+            assert!(
+                !miller_rabin_round(&candidate, &base),
+                "{carmichael_number} passed"
+            );
+        }
+
+        // Primes pass any round.
+        for prime in [8191u64, 131071, 524287] {
+            let candidate = U64::from_u64(prime);
+            let base = DynResidue::new(&U64::from_u8(2), DynResidueParams::new(&candidate));
+
+            assert!(miller_rabin_round(&candidate, &base));
+        }
+    }
+
+    #[test]
+    fn tests_primality() {
+        // $2400187 \cdot 4800373 \cdot 7200559$, a Carmichael number with no factor below
+        // `TRIAL_DIVISION_BOUND`, so that only Miller-Rabin can reject it.
+        let carmichael_number = U128::from_u128(82963349344421390809);
+        assert!(!is_prime(&carmichael_number));
+
+        // The Mersenne prime $2^{127} - 1$, and its product with the prime $2^{61} - 1$.
+        let prime = U256::MAX.shr_vartime(129);
+        assert!(is_prime(&prime));
+        assert!(!is_prime(
+            &prime.wrapping_mul(&U256::from_u64((1 << 61) - 1))
+        ));
+
+        // Small candidates are settled by trial division alone.
+        assert!(is_prime(&U128::from_u8(2)));
+        assert!(is_prime(&U128::from_u8(3)));
+        assert!(is_prime(&U128::from_u32(1021)));
+        assert!(is_prime(&U128::from_u32(1031)));
+        assert!(!is_prime(&U128::ZERO));
+        assert!(!is_prime(&U128::ONE));
+        assert!(!is_prime(&U128::from_u64(561)));
+
+        // Larger single-limb candidates go through Miller-Rabin: the Mersenne prime
+        // $2^{61} - 1$, the product of the two smallest primes above `TRIAL_DIVISION_BOUND`, and
+        // that of the two largest primes below $2^{32}$.
+        assert!(is_prime(&U128::from_u64((1 << 61) - 1)));
+        assert!(!is_prime(&U128::from_u64(1031 * 1033)));
+        assert!(!is_prime(&U128::from_u64(4294967291 * 4294967279)));
+    }
+}
@@ -1,6 +1,11 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
 use core::{
     fmt::Debug,
     iter,
@@ -8,19 +13,33 @@ use core::{
 };
 
 use crypto_bigint::{rand_core::CryptoRngCore, Uint, U128, U64};
+pub use fixed_base_table::FixedBaseTable;
 pub use reduce::Reduce;
 use serde::{Deserialize, Serialize};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
 
+pub mod collections;
 pub mod helpers;
 
 pub mod additive;
+pub mod bls12_381;
+pub mod bounded_integers;
+pub mod class_group;
 pub mod direct_product;
+pub mod ed25519;
+mod fixed_base_table;
+mod hash_to_field;
+pub mod multiplicative;
 mod reduce;
 pub mod ristretto;
 pub mod scalar;
 pub mod secp256k1;
 pub mod self_product;
+#[cfg(any(test, feature = "test_helpers"))]
+pub mod test_helpers;
+pub mod vector;
+pub mod wire;
 
 /// Represents an unsigned integer sized based on the computation security parameter, denoted as
 /// $\kappa$.
@@ -49,17 +68,21 @@ pub enum Error {
 
     #[error("hash to group: failed to encode bytes to a group element.")]
     HashToGroup,
+
+    #[error("wire encoding: failed to encode or decode a value in the wire format.")]
+    WireEncoding,
 }
 
 /// The Result of `new()` operation for types implementing the [`GroupElement`] trait
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// An element of an abelian group, in additive notation.
 ///
 /// Group operations are only valid between elements
 /// within the group (otherwise the result is undefined).
 ///
-/// All group operations are guaranteed to be constant time
+/// All group operations are guaranteed to be constant time, with the documented exception of
+/// [`class_group::GroupElement`].
 pub trait GroupElement:
     Neg<Output = Self>
     + Add<Self, Output = Self>
@@ -76,6 +99,7 @@ pub trait GroupElement:
     + PartialEq
     + Eq
     + Clone
+    + MultiScalarMul
 {
     /// The actual value of the group point used for encoding/decoding.
     ///
@@ -100,9 +124,7 @@ pub trait GroupElement:
         + Debug
         + PartialEq
         + Eq
-        + ConstantTimeEq
-        + ConditionallySelectable
-        + Copy;
+        + ConstantTimeEq;
 
     /// Returns the value of this group element.
     fn value(&self) -> Self::Value {
@@ -238,13 +260,17 @@ impl<
         T: GroupElement + BoundedGroupElement<SCALAR_LIMBS> + Into<Uint<SCALAR_LIMBS>> + Samplable,
     > NumbersGroupElement<SCALAR_LIMBS> for T
 where
-    T::Value: From<Uint<SCALAR_LIMBS>> + Into<Uint<SCALAR_LIMBS>> + PartialOrd,
+    T::Value: From<Uint<SCALAR_LIMBS>>
+        + Into<Uint<SCALAR_LIMBS>>
+        + PartialOrd
+        + ConditionallySelectable
+        + Copy,
 {
     type ValueExt = Self::Value;
 }
 
 pub trait KnownOrderScalar<const SCALAR_LIMBS: usize>:
-    KnownOrderGroupElement<SCALAR_LIMBS, Scalar = Self>
+    KnownOrderGroupElement<SCALAR_LIMBS, Scalar = Self, Value: Zeroize>
     + NumbersGroupElement<SCALAR_LIMBS>
     + Mul<Self, Output = Self>
     + for<'r> Mul<&'r Self, Output = Self>
@@ -252,12 +278,13 @@ pub trait KnownOrderScalar<const SCALAR_LIMBS: usize>:
     + Samplable
     + Copy
     + Into<Uint<SCALAR_LIMBS>>
+    + Zeroize
 {
 }
 
 /// An element of a known-order abelian group, in additive notation.
 pub trait KnownOrderGroupElement<const SCALAR_LIMBS: usize>:
-    BoundedGroupElement<SCALAR_LIMBS>
+    BoundedGroupElement<SCALAR_LIMBS> + VartimeGroupElement
 {
     type Scalar: KnownOrderScalar<SCALAR_LIMBS>
         + Mul<Self, Output = Self>
@@ -289,6 +316,68 @@ pub trait MulByGenerator<T> {
     fn mul_by_generator(&self, scalar: T) -> Self;
 }
 
+/// Multi-scalar multiplication, i.e. the evaluation of linear combinations of group elements.
+///
+/// The default implementation multiplies each term separately; groups may override it with an
+/// optimized algorithm (e.g. Straus' or Pippenger's), which shares work between the terms.
+pub trait MultiScalarMul: Sized {
+    /// Constant-time computation of the linear combination $\sum_i{k_i \cdot P_i}$ of the
+    /// `bases_and_scalars` $(P_i, k_i)$, with `scalar_bits` representing the number of (least
+    /// significant) bits to take into account for each scalar.
+    ///
+    /// Returns `None` if `bases_and_scalars` is empty.
+    ///
+    /// NOTE: `scalar_bits` may be leaked in the time pattern.
+    fn multi_scalar_mul_bounded<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+        scalar_bits: usize,
+    ) -> Option<Self>
+    where
+        Self: GroupElement,
+    {
+        bases_and_scalars
+            .iter()
+            .map(|(base, scalar)| base.scalar_mul_bounded(scalar, scalar_bits))
+            .reduce(|a, b| a + b)
+    }
+
+    /// Constant-time computation of the linear combination $\sum_i{k_i \cdot P_i}$ of the
+    /// `bases_and_scalars` $(P_i, k_i)$.
+    ///
+    /// Returns `None` if `bases_and_scalars` is empty.
+    fn multi_scalar_mul<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+    ) -> Option<Self>
+    where
+        Self: GroupElement,
+    {
+        Self::multi_scalar_mul_bounded(bases_and_scalars, Uint::<LIMBS>::BITS)
+    }
+}
+
+/// Variable-time group operations, for evaluating expressions over public data only.
+///
+/// *** NOTICE ***: unlike those of [`GroupElement`], these operations leak their inputs (in
+/// particular, the scalars) in their time pattern. They must only be used in verification code
+/// paths, in which all inputs are public (e.g. statements and proofs), and never with secrets.
+pub trait VartimeGroupElement: GroupElement {
+    /// Variable-time multiplication by (any bounded) natural number (scalar).
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self;
+
+    /// Variable-time computation of the linear combination $\sum_i{k_i \cdot P_i}$ of the
+    /// `bases_and_scalars` $(P_i, k_i)$.
+    ///
+    /// Returns `None` if `bases_and_scalars` is empty.
+    fn multi_scalar_mul_vartime<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+    ) -> Option<Self> {
+        bases_and_scalars
+            .iter()
+            .map(|(base, scalar)| base.scalar_mul_vartime(scalar))
+            .reduce(|a, b| a + b)
+    }
+}
+
 /// An element of an abelian, cyclic group of bounded (by `Uint<SCALAR_LIMBS>::MAX`) order, in
 /// additive notation.
 pub trait CyclicGroupElement: GroupElement {
@@ -314,9 +403,13 @@ pub trait CyclicGroupElement: GroupElement {
 /// A marker trait for elements of a (known) prime-order group.
 /// Any prime-order group is also cyclic.
 /// In additive notation.
+///
+/// These can be selected in constant-time, so that they can be multiplied using a
+/// [`FixedBaseTable`].
 pub trait PrimeGroupElement<const SCALAR_LIMBS: usize>:
     KnownOrderGroupElement<SCALAR_LIMBS>
     + CyclicGroupElement
+    + ConditionallySelectable
     + MulByGenerator<Self::Scalar>
     + for<'r> MulByGenerator<&'r Self::Scalar>
 {
@@ -341,12 +434,82 @@ pub trait Samplable: GroupElement {
     }
 }
 
+/// A canonical, fixed-size byte encoding of group elements.
+///
+/// Unlike the `serde` serialization of [`GroupElement::Value`], which depends on the chosen
+/// format, this encoding is stable and interoperable with the standard encodings of each group
+/// (e.g. SEC1-compressed for secp256k1 points, 32-bytes big-endian for scalars.)
+pub trait Encoding: GroupElement {
+    /// The length, in bytes, of the encoding of every element of the group.
+    const ENCODED_LEN: usize;
+
+    /// Encodes `self` into exactly [`Self::ENCODED_LEN`] bytes.
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Decodes a group element from its encoding.
+    ///
+    /// *** NOTICE ***: as in [`GroupElement::new()`], this must check that the decoded value
+    /// belongs to the group identified by `public_parameters`, and return
+    /// [`Error::InvalidGroupElement`] otherwise. Non-canonical encodings, and encodings which are
+    /// not exactly [`Self::ENCODED_LEN`] bytes long, must also be rejected.
+    fn from_bytes(bytes: &[u8], public_parameters: &Self::PublicParameters) -> Result<Self>;
+}
+
 /// Perform an inversion on a field element (i.e., base field element or scalar)
-pub trait Invert: Sized {
+pub trait Invert:
+    Sized + ConditionallySelectable + for<'r> Mul<&'r Self, Output = Self> + Zeroize
+{
     /// Invert a field element.
     fn invert(&self) -> CtOption<Self>;
+
+    /// Invert each of `elements` in a batch, using Montgomery's trick: a single inversion and
+    /// $3(n-1)$ multiplications replace the $n$ inversions of inverting them one by one.
+    ///
+    /// Runs in constant time, and returns `None` if any of `elements` is not invertible.
+    fn batch_invert(elements: &[Self]) -> CtOption<Vec<Self>> {
+        batch_invert(elements)
+    }
 }
 
+/// Montgomery's trick for batch inversion, on top of which the default
+/// [`Invert::batch_invert()`] is implemented.
+///
+/// Computes the prefix products $p_i = a_0 \cdots a_i$ and inverts $p_{n-1}$ alone; then, going
+/// backwards, $a_i^{-1} = p_i^{-1} p_{i-1}$ and $p_{i-1}^{-1} = p_i^{-1} a_i$.
+fn batch_invert<F: Invert>(elements: &[F]) -> CtOption<Vec<F>> {
+    let Some((first_element, elements_tail)) = elements.split_first() else {
+        return CtOption::new(vec![], Choice::from(1));
+    };
+
+    let mut prefix_products = Vec::with_capacity(elements.len());
+    prefix_products.push(*first_element);
+    for element in elements_tail {
+        let prefix_product = *prefix_products.last().unwrap() * element;
+        prefix_products.push(prefix_product);
+    }
+
+    // The product is invertible if and only if all the elements are. Otherwise, carry on with an
+    // arbitrary element so as not to branch on it, and discard the result.
+    let inverted_product = prefix_products.last().unwrap().invert();
+    let is_invertible = inverted_product.is_some();
+    let mut inverted_prefix_product = inverted_product.unwrap_or(*first_element);
+
+    let mut inverses = vec![*first_element; elements.len()];
+    for i in (1..elements.len()).rev() {
+        inverses[i] = inverted_prefix_product * &prefix_products[i - 1];
+        inverted_prefix_product = inverted_prefix_product * &elements[i];
+    }
+    inverses[0] = inverted_prefix_product;
+
+    prefix_products.zeroize();
+    inverted_prefix_product.zeroize();
+
+    CtOption::new(inverses, is_invertible)
+}
+
+/// The domain separation tag [`HashToGroup::legacy_hash_to_group()`] hashes under.
+pub const LEGACY_HASH_TO_GROUP_DST: &[u8] = b"CURVE_XOF:SHAKE-256_SSWU_RO_";
+
 /// Uniform encoding of arbitrary sequences for bytes to group elements.
 pub trait HashToGroup: GroupElement {
     /// Computes the hash to group (a.k.a. `hash2curve`) routine, which takes an arbitrary sequence
@@ -358,7 +521,80 @@ pub trait HashToGroup: GroupElement {
     /// group element should be infeasible to compute. This is an important trait, e.g., for
     /// choosing commitment generators, as in `Pedersen`, where discrete log relations between
     /// the generators must be kept hidden.
-    fn hash_to_group(bytes: &[u8]) -> Result<Self>;
+    ///
+    /// The caller-provided domain separation tag `dst` binds the output to its context: hashing
+    /// the same `bytes` under distinct tags yields independent elements, and so every protocol
+    /// (and every usage within it) should use a tag of its own. Tags longer than 255 bytes are
+    /// hashed first, as specified in Section 5.3.3 of RFC 9380.
+    fn hash_to_group(bytes: &[u8], dst: &[u8]) -> Result<Self>;
+
+    /// Hashes `bytes` to the group as `hash_to_group()` did before it took a domain separation
+    /// tag, so as to reproduce values derived back then, e.g. the generators of
+    /// `pedersen::PublicParameters::derive()`.
+    ///
+    /// This hashes under [`LEGACY_HASH_TO_GROUP_DST`], which was hard-coded at the time, and is
+    /// overridden by groups that hashed otherwise. New derivations should use
+    /// [`Self::hash_to_group()`] under a tag of their own instead.
+    fn legacy_hash_to_group(bytes: &[u8]) -> Result<Self> {
+        Self::hash_to_group(bytes, LEGACY_HASH_TO_GROUP_DST)
+    }
+}
+
+/// Uniform encoding of arbitrary sequences of bytes to scalars, following the `hash_to_field`
+/// routine of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-5).
+pub trait HashToScalar: GroupElement {
+    /// Hashes `bytes` to an element of the scalar field identified by `public_parameters`, under
+    /// the domain separation tag `dst`, which is hashed first if longer than 255 bytes (Section
+    /// 5.3.3 of RFC 9380).
+    ///
+    /// The distribution of the output is statistically close (up to $2^{-128}$) to uniform over
+    /// the field, and hashing the same `bytes` under distinct tags yields independent scalars.
+    /// This should be used whenever a scalar is derived from a transcript or any other public
+    /// data, e.g. for Fiat-Shamir challenges.
+    fn hash_to_scalar(
+        bytes: &[u8],
+        dst: &[u8],
+        public_parameters: &Self::PublicParameters,
+    ) -> Result<Self>;
+}
+
+/// A bilinear pairing $e: \mathbb{G}_1 \times \mathbb{G}_2 \to \mathbb{G}_T$ between groups of
+/// the same prime order, in additive notation.
+///
+/// Bilinearity means that $e(aP, bQ) = ab \cdot e(P, Q)$ for any scalars $a, b$, which is what
+/// allows e.g. verifying BLS signatures.
+pub trait Pairing<const SCALAR_LIMBS: usize> {
+    /// The group of the first argument of the pairing.
+    type G1: PrimeGroupElement<SCALAR_LIMBS>;
+
+    /// The group of the second argument of the pairing.
+    type G2: PrimeGroupElement<SCALAR_LIMBS, Scalar = Scalar<SCALAR_LIMBS, Self::G1>>;
+
+    /// The target group of the pairing.
+    type Gt: Neg<Output = Self::Gt>
+        + Add<Self::Gt, Output = Self::Gt>
+        + for<'r> Add<&'r Self::Gt, Output = Self::Gt>
+        + Sub<Self::Gt, Output = Self::Gt>
+        + for<'r> Sub<&'r Self::Gt, Output = Self::Gt>
+        + Debug
+        + PartialEq
+        + Eq
+        + Clone
+        + ConstantTimeEq;
+
+    /// Computes the pairing $e(g1, g2)$.
+    fn pairing(g1: &Self::G1, g2: &Self::G2) -> Self::Gt;
+
+    /// Computes the sum of pairings $\Sigma_i e(g1_i, g2_i)$, or `None` if `terms` is empty.
+    ///
+    /// The default implementation computes each pairing separately; implementations may override
+    /// it to share work between the terms.
+    fn multi_pairing(terms: &[(Self::G1, Self::G2)]) -> Option<Self::Gt> {
+        terms
+            .iter()
+            .map(|(g1, g2)| Self::pairing(g1, g2))
+            .reduce(|sum, pairing| sum + pairing)
+    }
 }
 
 /// Access to the x affine coordinate of an elliptic curve point, for ECDSA.
@@ -366,3 +602,104 @@ pub trait AffineXCoordinate<const SCALAR_LIMBS: usize>: PrimeGroupElement<SCALAR
     /// Get the affine x-coordinate as a scalar.
     fn x(&self) -> Self::Scalar;
 }
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{U256, U512};
+
+    use super::*;
+
+    /// Asserts that [`MultiScalarMul`] agrees with summing [`GroupElement::scalar_mul`] and
+    /// [`GroupElement::scalar_mul_bounded`] over each term, for every prefix of `bases` (including
+    /// the empty and single-term ones).
+    fn assert_multi_scalar_mul_agrees_with_scalar_mul<G: GroupElement>(bases: &[G]) {
+        let scalars = [
+            U256::ZERO,
+            U256::ONE,
+            U256::MAX,
+            U256::from_be_hex("9d4a3e1c6b2f8057e3a1c4d6b8f0e2a4c6d8f0b2a4c6e8f1a3b5c7d9e1f3a5b7"),
+            U256::from_be_hex("00000000000000000000000000000000f1e2d3c4b5a69788796a5b4c3d2e1f00"),
+        ];
+        let wide_scalars =
+            scalars.map(|scalar| U512::from((scalar, U256::MAX.wrapping_sub(&scalar))));
+
+        for number_of_terms in 0..=bases.len() {
+            let bases_and_scalars: Vec<_> = bases[..number_of_terms]
+                .iter()
+                .cloned()
+                .zip(scalars.iter().cycle().copied())
+                .collect();
+            let bases_and_wide_scalars: Vec<_> = bases[..number_of_terms]
+                .iter()
+                .cloned()
+                .zip(wide_scalars.iter().cycle().copied())
+                .collect();
+
+            assert_eq!(
+                G::multi_scalar_mul(&bases_and_scalars),
+                bases_and_scalars
+                    .iter()
+                    .map(|(base, scalar)| base.scalar_mul(scalar))
+                    .reduce(|a, b| a + b)
+            );
+            assert_eq!(
+                G::multi_scalar_mul(&bases_and_wide_scalars),
+                bases_and_wide_scalars
+                    .iter()
+                    .map(|(base, scalar)| base.scalar_mul(scalar))
+                    .reduce(|a, b| a + b)
+            );
+
+            for scalar_bits in [1, 64, 255, 256] {
+                assert_eq!(
+                    G::multi_scalar_mul_bounded(&bases_and_scalars, scalar_bits),
+                    bases_and_scalars
+                        .iter()
+                        .map(|(base, scalar)| base.scalar_mul_bounded(scalar, scalar_bits))
+                        .reduce(|a, b| a + b)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn multi_scalar_mul_agrees_with_scalar_mul() {
+        let secp256k1_generator = secp256k1::GroupElement::generator_from_public_parameters(
+            &secp256k1::group_element::PublicParameters::default(),
+        )
+        .unwrap();
+        let secp256k1_bases: Vec<_> = (1..=4u64)
+            .map(|i| secp256k1_generator.scalar_mul(&U256::from(i * 0x9e37_79b9_7f4a)))
+            .collect();
+
+        let ristretto_generator = ristretto::GroupElement::generator_from_public_parameters(
+            &ristretto::group_element::PublicParameters::default(),
+        )
+        .unwrap();
+        let ristretto_bases: Vec<_> = (1..=4u64)
+            .map(|i| ristretto_generator.scalar_mul(&U256::from(i * 0x9e37_79b9_7f4a)))
+            .collect();
+
+        assert_multi_scalar_mul_agrees_with_scalar_mul(&secp256k1_bases);
+        assert_multi_scalar_mul_agrees_with_scalar_mul(&ristretto_bases);
+        assert_multi_scalar_mul_agrees_with_scalar_mul(
+            &(1..=4u64)
+                .map(|i| secp256k1::Scalar::from(U256::from(i * 0x9e37_79b9_7f4a)))
+                .collect::<Vec<_>>(),
+        );
+        assert_multi_scalar_mul_agrees_with_scalar_mul(
+            &secp256k1_bases
+                .iter()
+                .cloned()
+                .zip(ristretto_bases.iter().cloned())
+                .map(direct_product::GroupElement::from)
+                .collect::<Vec<_>>(),
+        );
+        assert_multi_scalar_mul_agrees_with_scalar_mul(
+            &secp256k1_bases
+                .windows(2)
+                .map(|bases| self_product::GroupElement::<2, _>::from([bases[0], bases[1]]))
+                .collect::<Vec<_>>(),
+        );
+    }
+}
@@ -0,0 +1,15 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! The multiplicative group of integers modulo an RSA modulus $\mathbb{Z}_N^*$ (or its square),
+//! its subgroup $\mathbb{J}_N$ of elements with Jacobi symbol $1$, and the group of signed
+//! quadratic residues $\mathbb{QR}_N^+$. These are hidden-order groups, used by e.g. the Paillier
+//! cryptosystem, ring-Pedersen commitments and Paillier-Blum modulus proofs.
+
+pub use group_element::{GroupElement, PublicParameters, Value};
+pub use jacobi_one::JacobiOne;
+pub use quadratic_residue::QuadraticResidue;
+
+pub mod group_element;
+pub mod jacobi_one;
+pub mod quadratic_residue;
@@ -1,21 +1,27 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 use crypto_bigint::{
     modular::runtime_mod::{DynResidue, DynResidueParams},
     rand_core::CryptoRngCore,
-    Encoding, NonZero, RandomMod, Uint,
+    Encoding, MultiExponentiateBoundedExp, NonZero, RandomMod, Uint,
 };
-use group::{BoundedGroupElement, GroupElement as _, Samplable};
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+use crate::{
+    BoundedGroupElement, GroupElement as _, MultiScalarMul, Samplable, VartimeGroupElement,
+};
 
 /// An element of the [Multiplicative group of integers modulo N](https://en.wikipedia.org/wiki/Multiplicative_group_of_integers_modulo_n)
-/// where `N = PQ` $\mathbb{Z}_N^*$ for the randomness space of the Paillier cryptosystem
-/// or $\mathbb{Z}_N^{2*}$ for the ciphertext space.
+/// $\mathbb{Z}_N^*$, for an RSA modulus `N = PQ` (e.g. the randomness space of the Paillier
+/// cryptosystem) or its square (e.g. $\mathbb{Z}_{N^2}^*$, the Paillier ciphertext space).
 #[derive(PartialEq, Eq, Clone, Debug, Copy)]
-pub struct GroupElement<const LIMBS: usize>(pub(crate) DynResidue<LIMBS>);
+pub struct GroupElement<const LIMBS: usize>(DynResidue<LIMBS>);
 
 impl<const LIMBS: usize> Samplable for GroupElement<LIMBS>
 where
@@ -24,7 +30,7 @@ where
     fn sample(
         public_parameters: &Self::PublicParameters,
         rng: &mut impl CryptoRngCore,
-    ) -> group::Result<Self> {
+    ) -> crate::Result<Self> {
         // Montgomery form only works for odd modulus, and this is assured in `DynResidue`
         // instantiation; therefore, the modulus of an instance can never be zero and it is safe to
         // `unwrap()`.
@@ -35,7 +41,7 @@ where
             let value = Value::new(Uint::<LIMBS>::random_mod(rng, &modulus), public_parameters)?;
 
             match Self::new(value, public_parameters) {
-                Err(group::Error::InvalidGroupElement) => {
+                Err(crate::Error::InvalidGroupElement) => {
                     continue;
                 }
                 Ok(sampled_element) => {
@@ -47,6 +53,18 @@ where
     }
 }
 
+impl<const LIMBS: usize> Zeroize for GroupElement<LIMBS> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const LIMBS: usize> ConditionallySelectable for GroupElement<LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(DynResidue::<LIMBS>::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
 /// The value of a group element of the multiplicative group of integers modulo `n` $\mathbb{Z}_n^*$
 #[derive(PartialEq, Eq, Clone, Debug, Copy, Serialize, Deserialize)]
 pub struct Value<const LIMBS: usize>(Uint<LIMBS>)
@@ -57,16 +75,26 @@ impl<const LIMBS: usize> Value<LIMBS>
 where
     Uint<LIMBS>: Encoding,
 {
+    /// Instantiates the value of `value` modulo `n`, i.e. its Montgomery form.
     pub fn new(
         value: Uint<LIMBS>,
         public_parameters: &PublicParameters<LIMBS>,
-    ) -> group::Result<Self> {
+    ) -> crate::Result<Self> {
         let element = DynResidue::<LIMBS>::new(&value, public_parameters.params);
 
         Ok(Self(*element.as_montgomery()))
     }
 }
 
+impl<const LIMBS: usize> Zeroize for Value<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl<const LIMBS: usize> ConstantTimeEq for Value<LIMBS>
 where
     Uint<LIMBS>: Encoding,
@@ -86,13 +114,13 @@ where
 }
 
 /// The public parameters of the multiplicative group of integers modulo `n = modulus`
-/// $\mathbb{Z}_n^+$
+/// $\mathbb{Z}_n^*$
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct PublicParameters<const LIMBS: usize>
 where
     Uint<LIMBS>: Encoding,
 {
-    pub(crate) params: DynResidueParams<LIMBS>,
+    params: DynResidueParams<LIMBS>,
 }
 
 impl<const LIMBS: usize> Serialize for PublicParameters<LIMBS>
@@ -125,21 +153,26 @@ impl<const LIMBS: usize> PublicParameters<LIMBS>
 where
     Uint<LIMBS>: Encoding,
 {
-    pub fn new(modulus: Uint<LIMBS>) -> group::Result<Self> {
+    pub fn new(modulus: Uint<LIMBS>) -> crate::Result<Self> {
         #[allow(deprecated)]
         let params = DynResidueParams::<LIMBS>::new_checked(&modulus);
 
         if params.is_none().into() {
-            return Err(group::Error::UnsupportedPublicParameters);
+            return Err(crate::Error::UnsupportedPublicParameters);
         }
 
         Ok(Self {
             params: params.unwrap(),
         })
     }
+
+    /// The modulus `n` of the group.
+    pub fn modulus(&self) -> &Uint<LIMBS> {
+        self.params.modulus()
+    }
 }
 
-impl<const LIMBS: usize> group::GroupElement for GroupElement<LIMBS>
+impl<const LIMBS: usize> crate::GroupElement for GroupElement<LIMBS>
 where
     Uint<LIMBS>: Encoding,
 {
@@ -150,7 +183,7 @@ where
         self.0.into()
     }
 
-    fn new(value: Self::Value, public_parameters: &Self::PublicParameters) -> group::Result<Self> {
+    fn new(value: Self::Value, public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
         let element = DynResidue::<LIMBS>::from_montgomery(value.0, public_parameters.params);
 
         // `element` is valid if and only if it has an inverse if and only if it is not co-primed to
@@ -165,7 +198,7 @@ where
         // bypassing of the proof).
 
         if element.square() == DynResidue::<LIMBS>::zero(public_parameters.params) {
-            Err(group::Error::InvalidGroupElement)
+            Err(crate::Error::InvalidGroupElement)
         } else {
             Ok(Self(element))
         }
@@ -432,6 +465,15 @@ where
     }
 }
 
+impl<const LIMBS: usize> From<DynResidue<LIMBS>> for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn from(value: DynResidue<LIMBS>) -> Self {
+        Self(value)
+    }
+}
+
 impl<const LIMBS: usize> From<DynResidue<LIMBS>> for Value<LIMBS>
 where
     Uint<LIMBS>: Encoding,
@@ -450,6 +492,43 @@ where
     }
 }
 
+impl<const LIMBS: usize> MultiScalarMul for GroupElement<LIMBS> where Uint<LIMBS>: Encoding {}
+
+impl<const LIMBS: usize> VartimeGroupElement for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn scalar_mul_vartime<const RHS_LIMBS: usize>(&self, scalar: &Uint<RHS_LIMBS>) -> Self {
+        // Only exponentiate up to the bit-length of the (public) exponent.
+        Self(self.0.pow_bounded_exp(scalar, scalar.bits_vartime()))
+    }
+
+    fn multi_scalar_mul_vartime<const RHS_LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<RHS_LIMBS>)],
+    ) -> Option<Self> {
+        if bases_and_scalars.is_empty() {
+            return None;
+        }
+
+        // Straus' multi-exponentiation, up to the bit-length of the largest (public) exponent.
+        let exponent_bits = bases_and_scalars
+            .iter()
+            .map(|(_, scalar)| scalar.bits_vartime())
+            .max()
+            .unwrap_or_default();
+
+        let bases_and_exponents: Vec<_> = bases_and_scalars
+            .iter()
+            .map(|(base, scalar)| (base.0, *scalar))
+            .collect();
+
+        Some(Self(DynResidue::multi_exponentiate_bounded_exp(
+            bases_and_exponents.as_slice(),
+            exponent_bits,
+        )))
+    }
+}
+
 impl<const LIMBS: usize> BoundedGroupElement<LIMBS> for GroupElement<LIMBS>
 where
     Uint<LIMBS>: Encoding,
@@ -458,3 +537,49 @@ where
         (*public_parameters.params.modulus()) / NonZero::new(Uint::<LIMBS>::from(2u8)).unwrap()
     }
 }
+
+impl<const LIMBS: usize> crate::Encoding for GroupElement<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    const ENCODED_LEN: usize = Uint::<LIMBS>::BYTES;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        // Encode the residue itself rather than its Montgomery form.
+        Uint::<LIMBS>::from(self).to_be_bytes().as_ref().to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8], public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        // Reject non-canonical encodings, i.e. ones that are not reduced modulo `n`.
+        let value = Uint::<LIMBS>::from_be_slice(bytes);
+        if &value >= public_parameters.modulus() {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        Self::new(Value::new(value, public_parameters)?, public_parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U256;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{secp256k1, test_helpers};
+
+    #[test]
+    fn satisfies_conformance() {
+        let public_parameters = PublicParameters::new(secp256k1::MODULUS).unwrap();
+
+        test_helpers::samplable_conformance::<GroupElement<{ U256::LIMBS }>>(
+            &public_parameters,
+            vec![Value::new(U256::ZERO, &public_parameters).unwrap()],
+            &mut OsRng,
+        );
+    }
+}
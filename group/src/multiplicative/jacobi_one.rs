@@ -0,0 +1,344 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    mem,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
+
+use crypto_bigint::{rand_core::CryptoRngCore, Encoding, Uint};
+
+use super::{GroupElement, PublicParameters, Value};
+use crate::{
+    BoundedGroupElement, GroupElement as _, MultiScalarMul, Samplable, VartimeGroupElement,
+};
+
+/// An element of the subgroup $\mathbb{J}_N \subset \mathbb{Z}_N^*$ of elements with Jacobi
+/// symbol $1$, for an RSA modulus `N = PQ`.
+///
+/// NOTICE: this is *not* the subgroup of quadratic residues $\mathbb{QR}_N$, e.g. the group used
+/// for ring-Pedersen commitments. Membership in $\mathbb{QR}_N$ cannot be verified without the
+/// factorization of `N`, so [`Self::new()`] only verifies the Jacobi symbol: $\mathbb{QR}_N$ is a
+/// subgroup of index $2$ of $\mathbb{J}_N$, and half of the accepted elements are non-residues
+/// (those that are non-residues modulo both `P` and `Q`). Protocols relying on quadratic
+/// residuosity must prove it separately (e.g. by proving knowledge of a square root), or use
+/// [`super::QuadraticResidue`], which is isomorphic to $\mathbb{QR}_N$ for Blum moduli.
+///
+/// [`Samplable::sample()`] does however sample uniform quadratic residues.
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub struct JacobiOne<const LIMBS: usize>(GroupElement<LIMBS>);
+
+impl<const LIMBS: usize> Samplable for JacobiOne<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn sample(
+        public_parameters: &Self::PublicParameters,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Self> {
+        // Squaring is a 4-to-1 mapping from $\mathbb{Z}_N^*$ to $\mathbb{QR}_N$, so the square of
+        // a uniform element of $\mathbb{Z}_N^*$ is a uniform quadratic residue.
+        Ok(Self(GroupElement::sample(public_parameters, rng)?.double()))
+    }
+}
+
+impl<const LIMBS: usize> crate::GroupElement for JacobiOne<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Value = Value<LIMBS>;
+    type PublicParameters = PublicParameters<LIMBS>;
+
+    fn value(&self) -> Self::Value {
+        self.0.value()
+    }
+
+    fn new(value: Self::Value, public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        let element = GroupElement::new(value, public_parameters)?;
+        let residue = Uint::<LIMBS>::from(&element);
+
+        if jacobi_symbol_vartime(&residue, public_parameters.modulus()) != 1 {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        Ok(Self(element))
+    }
+
+    fn neutral(&self) -> Self {
+        Self(self.0.neutral())
+    }
+
+    fn scalar_mul<const RHS_LIMBS: usize>(&self, scalar: &Uint<RHS_LIMBS>) -> Self {
+        Self(self.0.scalar_mul(scalar))
+    }
+
+    fn scalar_mul_bounded<const RHS_LIMBS: usize>(
+        &self,
+        scalar: &Uint<RHS_LIMBS>,
+        scalar_bits: usize,
+    ) -> Self {
+        Self(self.0.scalar_mul_bounded(scalar, scalar_bits))
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0.double())
+    }
+}
+
+/// Computes the Jacobi symbol $(a / n)$ for an odd `n` using the binary algorithm, which only
+/// requires shifts and subtractions.
+///
+/// NOTICE: this is *variable-time*, and so must only be called on public values.
+fn jacobi_symbol_vartime<const LIMBS: usize>(a: &Uint<LIMBS>, n: &Uint<LIMBS>) -> i8 {
+    let (mut a, mut n) = (*a, *n);
+    let mut symbol = 1;
+
+    while a != Uint::ZERO {
+        // $(2 / n) = -1$ if and only if $n = \pm 3 \mod 8$.
+        let trailing_zeros = a.trailing_zeros_vartime();
+        a = a.shr_vartime(trailing_zeros);
+        if trailing_zeros % 2 == 1 && matches!(n.as_words()[0] & 7, 3 | 5) {
+            symbol = -symbol;
+        }
+
+        // Quadratic reciprocity: for odd $a, n$, swapping them flips the sign if and only if both
+        // are $3 \mod 4$.
+        if a.cmp_vartime(&n) == Ordering::Less {
+            mem::swap(&mut a, &mut n);
+            if a.as_words()[0] & 3 == 3 && n.as_words()[0] & 3 == 3 {
+                symbol = -symbol;
+            }
+        }
+
+        a = a.wrapping_sub(&n);
+    }
+
+    if n == Uint::ONE {
+        symbol
+    } else {
+        0
+    }
+}
+
+impl<const LIMBS: usize> From<JacobiOne<LIMBS>> for PublicParameters<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn from(value: JacobiOne<LIMBS>) -> Self {
+        value.0.into()
+    }
+}
+
+impl<const LIMBS: usize> From<JacobiOne<LIMBS>> for Value<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn from(value: JacobiOne<LIMBS>) -> Self {
+        value.0.into()
+    }
+}
+
+impl<const LIMBS: usize> From<JacobiOne<LIMBS>> for GroupElement<LIMBS> {
+    fn from(value: JacobiOne<LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const LIMBS: usize> From<JacobiOne<LIMBS>> for Uint<LIMBS> {
+    fn from(value: JacobiOne<LIMBS>) -> Self {
+        value.0.into()
+    }
+}
+
+impl<const LIMBS: usize> Neg for JacobiOne<LIMBS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.neg())
+    }
+}
+
+impl<const LIMBS: usize> Add<Self> for JacobiOne<LIMBS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<'r, const LIMBS: usize> Add<&'r Self> for JacobiOne<LIMBS> {
+    type Output = Self;
+
+    fn add(self, rhs: &'r Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<const LIMBS: usize> Sub<Self> for JacobiOne<LIMBS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<'r, const LIMBS: usize> Sub<&'r Self> for JacobiOne<LIMBS> {
+    type Output = Self;
+
+    fn sub(self, rhs: &'r Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<const LIMBS: usize> AddAssign<Self> for JacobiOne<LIMBS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<'r, const LIMBS: usize> AddAssign<&'r Self> for JacobiOne<LIMBS> {
+    fn add_assign(&mut self, rhs: &'r Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const LIMBS: usize> SubAssign<Self> for JacobiOne<LIMBS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<'r, const LIMBS: usize> SubAssign<&'r Self> for JacobiOne<LIMBS> {
+    fn sub_assign(&mut self, rhs: &'r Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const LIMBS: usize> MultiScalarMul for JacobiOne<LIMBS> where Uint<LIMBS>: Encoding {}
+
+impl<const LIMBS: usize> VartimeGroupElement for JacobiOne<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn scalar_mul_vartime<const RHS_LIMBS: usize>(&self, scalar: &Uint<RHS_LIMBS>) -> Self {
+        Self(self.0.scalar_mul_vartime(scalar))
+    }
+
+    fn multi_scalar_mul_vartime<const RHS_LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<RHS_LIMBS>)],
+    ) -> Option<Self> {
+        let bases_and_scalars: Vec<_> = bases_and_scalars
+            .iter()
+            .map(|(base, scalar)| (base.0, *scalar))
+            .collect();
+
+        GroupElement::multi_scalar_mul_vartime(&bases_and_scalars).map(Self)
+    }
+}
+
+impl<const LIMBS: usize> BoundedGroupElement<LIMBS> for JacobiOne<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn lower_bound(public_parameters: &Self::PublicParameters) -> Uint<LIMBS> {
+        // The order of $\mathbb{J}_N$ is $\varphi(N) / 2 = (P - 1)(Q - 1) / 2 > N / 4$, and that of
+        // its subgroup $\mathbb{QR}_N$ is $\varphi(N) / 4 > N / 8$.
+        public_parameters.modulus().shr_vartime(3)
+    }
+}
+
+impl<const LIMBS: usize> crate::Encoding for JacobiOne<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    const ENCODED_LEN: usize = GroupElement::<LIMBS>::ENCODED_LEN;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8], public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        let element = GroupElement::from_bytes(bytes, public_parameters)?;
+
+        Self::new(element.value(), public_parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U256;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{secp256k1, test_helpers};
+
+    /// `N = PQ` for the primes `P = 7` and `Q = 11`.
+    const COMPOSITE_MODULUS: U256 = U256::from_u64(7 * 11);
+
+    #[test]
+    fn satisfies_conformance() {
+        // For a prime modulus $p = 3 \mod 4$, $-1$ is a quadratic non-residue.
+        let public_parameters = PublicParameters::new(secp256k1::MODULUS).unwrap();
+        let minus_one = secp256k1::MODULUS.wrapping_sub(&U256::ONE);
+
+        test_helpers::samplable_conformance::<JacobiOne<{ U256::LIMBS }>>(
+            &public_parameters,
+            vec![
+                Value::new(U256::ZERO, &public_parameters).unwrap(),
+                Value::new(minus_one, &public_parameters).unwrap(),
+            ],
+            &mut OsRng,
+        );
+    }
+
+    #[test]
+    fn accepts_jacobi_one_non_residues_of_composite_modulus() {
+        let public_parameters = PublicParameters::new(COMPOSITE_MODULUS).unwrap();
+        let is_square = |value: u64| (1..77u64).any(|root| root * root % 77 == value);
+
+        // $24$ is a non-residue modulo both $7$ and $11$, and so has Jacobi symbol $1$ modulo $77$
+        // without being a quadratic residue.
+        assert!(!is_square(24));
+        assert_eq!(
+            jacobi_symbol_vartime(&U256::from_u64(24), &COMPOSITE_MODULUS),
+            1
+        );
+        assert!(JacobiOne::<{ U256::LIMBS }>::new(
+            Value::new(U256::from_u64(24), &public_parameters).unwrap(),
+            &public_parameters
+        )
+        .is_ok());
+
+        // $45$ is a non-residue modulo $7$ only, and so has Jacobi symbol $-1$.
+        assert_eq!(
+            jacobi_symbol_vartime(&U256::from_u64(45), &COMPOSITE_MODULUS),
+            -1
+        );
+        assert_eq!(
+            JacobiOne::<{ U256::LIMBS }>::new(
+                Value::new(U256::from_u64(45), &public_parameters).unwrap(),
+                &public_parameters
+            ),
+            Err(crate::Error::InvalidGroupElement)
+        );
+
+        // Exactly half of the elements of $\mathbb{J}_{77}$ (of order $\varphi(77) / 2 = 30$)
+        // are quadratic residues.
+        let accepted: Vec<u64> = (1..77u64)
+            .filter(|value| {
+                JacobiOne::<{ U256::LIMBS }>::new(
+                    Value::new(U256::from_u64(*value), &public_parameters).unwrap(),
+                    &public_parameters,
+                )
+                .is_ok()
+            })
+            .collect();
+        assert_eq!(accepted.len(), 30);
+        assert_eq!(
+            accepted.iter().filter(|value| is_square(**value)).count(),
+            15
+        );
+    }
+}
@@ -0,0 +1,383 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use crypto_bigint::{modular::runtime_mod::DynResidue, rand_core::CryptoRngCore, Encoding, Uint};
+use subtle::{ConditionallySelectable, ConstantTimeGreater};
+
+use super::{GroupElement, JacobiOne, PublicParameters, Value};
+use crate::{
+    BoundedGroupElement, GroupElement as _, MultiScalarMul, Samplable, VartimeGroupElement,
+};
+
+/// An element of the group of signed quadratic residues
+/// $\mathbb{QR}_N^+ = \{|x| : x \in \mathbb{QR}_N\}$, for $|x| = \min(x, N - x)$, under the
+/// operation $x \circ y = |xy \bmod N|$.
+///
+/// For a Blum integer `N = PQ` (i.e. $P = Q = 3 \mod 4$, as is the case for e.g. Paillier-Blum
+/// moduli), $-1$ has Jacobi symbol $1$ but is not a quadratic residue, and so
+/// $\mathbb{J}_N = \mathbb{QR}_N \times \{\pm 1\}$. Hence, $x \mapsto |x|$ is an isomorphism from
+/// the subgroup of quadratic residues $\mathbb{QR}_N$ onto $\mathbb{QR}_N^+$, which, unlike
+/// $\mathbb{QR}_N$, has a membership test that does not require the factorization of `N`:
+/// $\mathbb{QR}_N^+$ is the set of elements $x \leq (N - 1) / 2$ with Jacobi symbol $1$, and
+/// [`Self::new()`] accepts exactly these.
+///
+/// See [Hofheinz and Kiltz (2009)](https://eprint.iacr.org/2009/070.pdf). For moduli that are not
+/// Blum integers none of the above holds (e.g. for a prime `N`, the signed representative of a
+/// quadratic residue is not necessarily one), so `N` must be proven to be a Blum integer, e.g.
+/// with a Paillier-Blum modulus proof, whenever this matters.
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub struct QuadraticResidue<const LIMBS: usize>(GroupElement<LIMBS>);
+
+impl<const LIMBS: usize> QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    /// The signed representative $|x| = \min(x, N - x)$ of `element`, computed in constant time.
+    ///
+    /// `element` must have Jacobi symbol $1$.
+    fn absolute(element: GroupElement<LIMBS>) -> Self {
+        let residue = DynResidue::<LIMBS>::from(element);
+        let half_modulus = residue.params().modulus().shr_vartime(1);
+        let is_negative = Uint::<LIMBS>::from(element).ct_gt(&half_modulus);
+
+        Self(GroupElement::conditional_select(
+            &element,
+            &GroupElement::from(-residue),
+            is_negative,
+        ))
+    }
+}
+
+impl<const LIMBS: usize> Samplable for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn sample(
+        public_parameters: &Self::PublicParameters,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Self> {
+        // Squaring is a 4-to-1 mapping from $\mathbb{Z}_N^*$ to $\mathbb{QR}_N$, so the square of
+        // a uniform element of $\mathbb{Z}_N^*$ is a uniform quadratic residue, and its signed
+        // representative is uniform in $\mathbb{QR}_N^+$.
+        Ok(Self::absolute(
+            GroupElement::sample(public_parameters, rng)?.double(),
+        ))
+    }
+}
+
+impl<const LIMBS: usize> crate::GroupElement for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Value = Value<LIMBS>;
+    type PublicParameters = PublicParameters<LIMBS>;
+
+    fn value(&self) -> Self::Value {
+        self.0.value()
+    }
+
+    fn new(value: Self::Value, public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        let element = GroupElement::from(JacobiOne::new(value, public_parameters)?);
+
+        // Of $x$ and $N - x$, only the smaller is a signed representative.
+        if Uint::<LIMBS>::from(element) > public_parameters.modulus().shr_vartime(1) {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        Ok(Self(element))
+    }
+
+    fn neutral(&self) -> Self {
+        Self(self.0.neutral())
+    }
+
+    fn scalar_mul<const RHS_LIMBS: usize>(&self, scalar: &Uint<RHS_LIMBS>) -> Self {
+        Self::absolute(self.0.scalar_mul(scalar))
+    }
+
+    fn scalar_mul_bounded<const RHS_LIMBS: usize>(
+        &self,
+        scalar: &Uint<RHS_LIMBS>,
+        scalar_bits: usize,
+    ) -> Self {
+        Self::absolute(self.0.scalar_mul_bounded(scalar, scalar_bits))
+    }
+
+    fn double(&self) -> Self {
+        Self::absolute(self.0.double())
+    }
+}
+
+impl<const LIMBS: usize> From<QuadraticResidue<LIMBS>> for PublicParameters<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn from(value: QuadraticResidue<LIMBS>) -> Self {
+        value.0.into()
+    }
+}
+
+impl<const LIMBS: usize> From<QuadraticResidue<LIMBS>> for Value<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn from(value: QuadraticResidue<LIMBS>) -> Self {
+        value.0.into()
+    }
+}
+
+impl<const LIMBS: usize> From<QuadraticResidue<LIMBS>> for GroupElement<LIMBS> {
+    fn from(value: QuadraticResidue<LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const LIMBS: usize> From<QuadraticResidue<LIMBS>> for Uint<LIMBS> {
+    fn from(value: QuadraticResidue<LIMBS>) -> Self {
+        value.0.into()
+    }
+}
+
+impl<const LIMBS: usize> Neg for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::absolute(self.0.neg())
+    }
+}
+
+impl<const LIMBS: usize> Add<Self> for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::absolute(self.0 + rhs.0)
+    }
+}
+
+impl<'r, const LIMBS: usize> Add<&'r Self> for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Output = Self;
+
+    fn add(self, rhs: &'r Self) -> Self::Output {
+        Self::absolute(self.0 + rhs.0)
+    }
+}
+
+impl<const LIMBS: usize> Sub<Self> for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::absolute(self.0 - rhs.0)
+    }
+}
+
+impl<'r, const LIMBS: usize> Sub<&'r Self> for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: &'r Self) -> Self::Output {
+        Self::absolute(self.0 - rhs.0)
+    }
+}
+
+impl<const LIMBS: usize> AddAssign<Self> for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<'r, const LIMBS: usize> AddAssign<&'r Self> for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn add_assign(&mut self, rhs: &'r Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const LIMBS: usize> SubAssign<Self> for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<'r, const LIMBS: usize> SubAssign<&'r Self> for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn sub_assign(&mut self, rhs: &'r Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const LIMBS: usize> MultiScalarMul for QuadraticResidue<LIMBS> where Uint<LIMBS>: Encoding {}
+
+impl<const LIMBS: usize> VartimeGroupElement for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn scalar_mul_vartime<const RHS_LIMBS: usize>(&self, scalar: &Uint<RHS_LIMBS>) -> Self {
+        Self::absolute(self.0.scalar_mul_vartime(scalar))
+    }
+
+    fn multi_scalar_mul_vartime<const RHS_LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<RHS_LIMBS>)],
+    ) -> Option<Self> {
+        let bases_and_scalars: Vec<_> = bases_and_scalars
+            .iter()
+            .map(|(base, scalar)| (base.0, *scalar))
+            .collect();
+
+        GroupElement::multi_scalar_mul_vartime(&bases_and_scalars).map(Self::absolute)
+    }
+}
+
+impl<const LIMBS: usize> BoundedGroupElement<LIMBS> for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn lower_bound(public_parameters: &Self::PublicParameters) -> Uint<LIMBS> {
+        // The order of $\mathbb{QR}_N^+$ is $\varphi(N) / 4 > N / 8$.
+        public_parameters.modulus().shr_vartime(3)
+    }
+}
+
+impl<const LIMBS: usize> crate::Encoding for QuadraticResidue<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    const ENCODED_LEN: usize = GroupElement::<LIMBS>::ENCODED_LEN;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8], public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        let element = GroupElement::from_bytes(bytes, public_parameters)?;
+
+        Self::new(element.value(), public_parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U256;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::test_helpers;
+
+    /// `N = PQ` for the Blum primes `P = 7` and `Q = 11`.
+    const SMALL_BLUM_MODULUS: U256 = U256::from_u64(7 * 11);
+
+    /// `N = PQ` for the Blum primes `P = 0x80000000000000000000000000003053` and
+    /// `Q = 0x40000000000000000000000000018243`.
+    const BLUM_MODULUS: U256 =
+        U256::from_be_hex("2000000000000000000000000000cd3640000000000000000000000048e9cbb9");
+
+    fn value(value: u64, public_parameters: &PublicParameters<{ U256::LIMBS }>) -> Value<4> {
+        Value::new(U256::from_u64(value), public_parameters).unwrap()
+    }
+
+    #[test]
+    fn satisfies_conformance() {
+        let public_parameters = PublicParameters::new(BLUM_MODULUS).unwrap();
+        let minus_one = BLUM_MODULUS.wrapping_sub(&U256::ONE);
+        let jacobi_minus_one = (2..100)
+            .find(|candidate| {
+                JacobiOne::<{ U256::LIMBS }>::new(
+                    value(*candidate, &public_parameters),
+                    &public_parameters,
+                )
+                .is_err()
+            })
+            .unwrap();
+
+        test_helpers::samplable_conformance::<QuadraticResidue<{ U256::LIMBS }>>(
+            &public_parameters,
+            vec![
+                Value::new(U256::ZERO, &public_parameters).unwrap(),
+                // $-1$ has Jacobi symbol $1$, but its signed representative is $1$.
+                Value::new(minus_one, &public_parameters).unwrap(),
+                value(jacobi_minus_one, &public_parameters),
+            ],
+            &mut OsRng,
+        );
+    }
+
+    #[test]
+    fn is_isomorphic_to_quadratic_residues_of_blum_modulus() {
+        let public_parameters = PublicParameters::new(SMALL_BLUM_MODULUS).unwrap();
+        let new = |candidate| {
+            QuadraticResidue::<{ U256::LIMBS }>::new(
+                value(candidate, &public_parameters),
+                &public_parameters,
+            )
+        };
+        let squares: Vec<u64> = (1..77u64)
+            .filter(|root| root % 7 != 0 && root % 11 != 0)
+            .map(|root| root * root % 77)
+            .collect();
+
+        // $\mathbb{QR}_{77}$ has $\varphi(77) / 4 = 15$ elements, whose signed representatives are
+        // exactly the accepted elements.
+        let mut signed_squares: Vec<u64> = squares
+            .iter()
+            .map(|square| (*square).min(77 - square))
+            .collect();
+        signed_squares.sort();
+        signed_squares.dedup();
+        let accepted: Vec<u64> = (1..77u64)
+            .filter(|candidate| new(*candidate).is_ok())
+            .collect();
+
+        assert_eq!(accepted.len(), 15);
+        assert_eq!(accepted, signed_squares);
+
+        // $53$ is a quadratic residue, but only $24 = 77 - 53$ is a signed representative, even
+        // though $24$ itself is not a quadratic residue.
+        assert!(squares.contains(&53) && !squares.contains(&24));
+        assert_eq!(new(53), Err(crate::Error::InvalidGroupElement));
+        assert!(new(24).is_ok());
+
+        // $2$ has Jacobi symbol $-1$.
+        assert_eq!(new(2), Err(crate::Error::InvalidGroupElement));
+
+        // The group operation is multiplication modulo $N$, followed by taking the signed
+        // representative: $4 \cdot 23 = 15 \mod 77$ and $4 \cdot 24 = 19 = -58 \mod 77$.
+        assert_eq!(
+            Uint::from(new(4).unwrap() + new(23).unwrap()),
+            U256::from_u64(15)
+        );
+        assert_eq!(
+            Uint::from(new(4).unwrap() + new(24).unwrap()),
+            U256::from_u64(19)
+        );
+        assert_eq!(
+            Uint::from(new(4).unwrap() - new(24).unwrap()),
+            Uint::from(new(4).unwrap() + -new(24).unwrap())
+        );
+    }
+}
@@ -1,23 +1,31 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    iter,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
 
 use crypto_bigint::{Uint, U256};
 use curve25519_dalek::{
     constants::RISTRETTO_BASEPOINT_POINT,
     ristretto::{CompressedRistretto, RistrettoPoint},
-    traits::Identity,
+    traits::{Identity, MultiscalarMul, VartimeMultiscalarMul},
 };
 use serde::{Deserialize, Serialize};
-use sha3_old::Sha3_512;
+use sha3::{Digest, Sha3_512};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use super::SCALAR_LIMBS;
 use crate::{
+    hash_to_field::expand_message_xmd,
     ristretto::{scalar::Scalar, CURVE_EQUATION_A, CURVE_EQUATION_B, MODULUS, ORDER},
     BoundedGroupElement, CyclicGroupElement, HashToGroup, KnownOrderGroupElement, MulByGenerator,
-    PrimeGroupElement,
+    MultiScalarMul, PrimeGroupElement, VartimeGroupElement,
 };
 
 /// An element of the ristretto prime group.
@@ -212,6 +220,53 @@ impl<'r> MulByGenerator<&'r U256> for GroupElement {
     }
 }
 
+impl MultiScalarMul for GroupElement {
+    fn multi_scalar_mul_bounded<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+        scalar_bits: usize,
+    ) -> Option<Self> {
+        if bases_and_scalars.is_empty() {
+            return None;
+        }
+
+        // `curve25519_dalek`'s (Straus) multi-scalar multiplication operates in constant-time over
+        // the full scalars, so we only need to take the `scalar_bits` least significant bits of
+        // each scalar.
+        let mask = (Uint::<LIMBS>::ONE << scalar_bits).wrapping_sub(&Uint::<LIMBS>::ONE);
+
+        Some(Self(RistrettoPoint::multiscalar_mul(
+            bases_and_scalars
+                .iter()
+                .map(|(_, scalar)| Scalar::from(scalar & mask).0),
+            bases_and_scalars.iter().map(|(base, _)| base.0),
+        )))
+    }
+}
+
+impl VartimeGroupElement for GroupElement {
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        Self(RistrettoPoint::vartime_multiscalar_mul(
+            iter::once(Scalar::from(scalar).0),
+            iter::once(self.0),
+        ))
+    }
+
+    fn multi_scalar_mul_vartime<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+    ) -> Option<Self> {
+        if bases_and_scalars.is_empty() {
+            return None;
+        }
+
+        Some(Self(RistrettoPoint::vartime_multiscalar_mul(
+            bases_and_scalars
+                .iter()
+                .map(|(_, scalar)| Scalar::from(scalar).0),
+            bases_and_scalars.iter().map(|(base, _)| base.0),
+        )))
+    }
+}
+
 impl CyclicGroupElement for GroupElement {
     fn generator(&self) -> Self {
         Self(RISTRETTO_BASEPOINT_POINT)
@@ -259,7 +314,126 @@ impl<'r> MulByGenerator<&'r Scalar> for GroupElement {
 impl PrimeGroupElement<SCALAR_LIMBS> for GroupElement {}
 
 impl HashToGroup for GroupElement {
-    fn hash_to_group(bytes: &[u8]) -> crate::Result<Self> {
-        Ok(Self(RistrettoPoint::hash_from_bytes::<Sha3_512>(bytes)))
+    fn hash_to_group(bytes: &[u8], dst: &[u8]) -> crate::Result<Self> {
+        // Following the `ristretto255_XMD:SHA-512_R255MAP_RO_` suite of RFC 9380 (Appendix B),
+        // expand the message to 64 uniform bytes and apply the ristretto255 map to them.
+        let uniform_bytes: [u8; 64] = expand_message_xmd(bytes, dst, 64)?
+            .try_into()
+            .map_err(|_| crate::Error::HashToGroup)?;
+
+        Ok(Self(RistrettoPoint::from_uniform_bytes(&uniform_bytes)))
+    }
+
+    fn legacy_hash_to_group(bytes: &[u8]) -> crate::Result<Self> {
+        Ok(Self::hash_from_bytes_sha3_512(bytes))
+    }
+}
+
+impl GroupElement {
+    /// Maps the SHA3-512 digest of `bytes` to a ristretto point, as done by
+    /// `RistrettoPoint::hash_from_bytes::<Sha3_512>()`.
+    ///
+    /// This is *not* a domain-separated hash-to-group (use [`HashToGroup`] for that); it only
+    /// exists to reproduce generators derived this way by other libraries, e.g. the default
+    /// Pedersen generators of Bulletproofs.
+    pub fn hash_from_bytes_sha3_512(bytes: &[u8]) -> Self {
+        let uniform_bytes: [u8; 64] = Sha3_512::digest(bytes).into();
+
+        Self(RistrettoPoint::from_uniform_bytes(&uniform_bytes))
+    }
+}
+
+impl crate::Encoding for GroupElement {
+    const ENCODED_LEN: usize = 32;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.compress().to_bytes().to_vec()
+    }
+
+    fn from_bytes(
+        bytes: &[u8],
+        _public_parameters: &Self::PublicParameters,
+    ) -> crate::Result<Self> {
+        let bytes = <[u8; 32]>::try_from(bytes).map_err(|_| crate::Error::InvalidGroupElement)?;
+
+        CompressedRistretto(bytes).try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{Encoding as _, Random};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{ristretto::scalar, test_helpers, Encoding, GroupElement as _};
+
+    #[test]
+    fn satisfies_conformance() {
+        let generator =
+            GroupElement::generator_from_public_parameters(&PublicParameters::default()).unwrap();
+        let elements: Vec<_> = (0..4)
+            .map(|_| generator.scalar_mul(&U256::random(&mut OsRng)))
+            .collect();
+
+        // Points are validated upon deserialization, so every `Value` is a valid one.
+        test_helpers::conformance(&elements, vec![], &mut OsRng);
+    }
+
+    #[test]
+    fn encodes_as_compressed_ristretto() {
+        let public_parameters = PublicParameters::default();
+
+        for i in 0..10u64 {
+            let point = RISTRETTO_BASEPOINT_POINT * curve25519_dalek::scalar::Scalar::from(i);
+            let element = GroupElement(point);
+
+            let bytes = element.to_bytes();
+            assert_eq!(bytes.len(), GroupElement::ENCODED_LEN);
+            assert_eq!(bytes, point.compress().to_bytes());
+            assert_eq!(
+                GroupElement::from_bytes(&bytes, &public_parameters).unwrap(),
+                element
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_point_encodings() {
+        let public_parameters = PublicParameters::default();
+        let bytes = GroupElement(RISTRETTO_BASEPOINT_POINT).to_bytes();
+
+        // Wrong length.
+        assert!(GroupElement::from_bytes(&bytes[1..], &public_parameters).is_err());
+
+        // A field element that is not reduced modulo the field modulus.
+        assert!(GroupElement::from_bytes(&[0xff; 32], &public_parameters).is_err());
+
+        // A negative (i.e. odd) field element, which is not a canonical Ristretto encoding.
+        let mut negative = [0u8; 32];
+        negative[0] = 1;
+        assert!(GroupElement::from_bytes(&negative, &public_parameters).is_err());
+    }
+
+    #[test]
+    fn encodes_scalars_as_big_endian() {
+        let public_parameters = scalar::PublicParameters::default();
+
+        for i in [0u64, 1, 2, u64::MAX] {
+            let scalar = Scalar(curve25519_dalek::scalar::Scalar::from(i));
+
+            let bytes = scalar.to_bytes();
+            let mut dalek_bytes = scalar.0.to_bytes();
+            dalek_bytes.reverse();
+            assert_eq!(bytes.len(), Scalar::ENCODED_LEN);
+            assert_eq!(bytes, dalek_bytes);
+            assert_eq!(bytes, U256::from(i).to_be_bytes().to_vec());
+            assert_eq!(
+                Scalar::from_bytes(&bytes, &public_parameters).unwrap(),
+                scalar
+            );
+        }
+
+        assert!(Scalar::from_bytes(&ORDER.to_be_bytes(), &public_parameters).is_err());
     }
 }
@@ -1,26 +1,32 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::{
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
     cmp::Ordering,
     ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
 };
 
 use crypto_bigint::{rand_core::CryptoRngCore, Encoding, NonZero, Uint, U256};
 use serde::{Deserialize, Serialize};
-use sha3_old::Sha3_512;
+use sha3::{Digest, Sha3_512};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
 
 use super::{GroupElement, SCALAR_LIMBS};
 use crate::{
-    BoundedGroupElement, CyclicGroupElement, GroupElement as _, HashToGroup, Invert,
-    KnownOrderGroupElement, KnownOrderScalar, MulByGenerator, PrimeGroupElement, Reduce, Samplable,
+    hash_to_field::hash_to_field, BoundedGroupElement, CyclicGroupElement, GroupElement as _,
+    HashToGroup, HashToScalar, Invert, KnownOrderGroupElement, KnownOrderScalar, MulByGenerator,
+    MultiScalarMul, PrimeGroupElement, Reduce, Samplable, VartimeGroupElement,
 };
 
 /// A Scalar of the prime field $\mathbb{Z}_p$ over which the ristretto prime group is
 /// defined.
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
-pub struct Scalar(curve25519_dalek::scalar::Scalar);
+pub struct Scalar(pub(super) curve25519_dalek::scalar::Scalar);
 
 impl ConstantTimeEq for Scalar {
     fn ct_eq(&self, other: &Self) -> Choice {
@@ -36,6 +42,12 @@ impl ConstantTimeEq for Scalar {
     }
 }
 
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl ConditionallySelectable for Scalar {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         Self(
@@ -326,6 +338,16 @@ impl PartialOrd for Scalar {
 
 impl KnownOrderScalar<SCALAR_LIMBS> for Scalar {}
 
+impl MultiScalarMul for Scalar {}
+
+impl VartimeGroupElement for Scalar {
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        // Scalar multiplication is a single multiplication in the field, which has no faster
+        // variable-time counterpart.
+        self.scalar_mul(scalar)
+    }
+}
+
 impl KnownOrderGroupElement<SCALAR_LIMBS> for Scalar {
     type Scalar = Self;
     fn order(&self) -> Uint<SCALAR_LIMBS> {
@@ -355,10 +377,84 @@ impl<'r> MulByGenerator<&'r Scalar> for Scalar {
 
 impl PrimeGroupElement<SCALAR_LIMBS> for Scalar {}
 
+impl HashToScalar for Scalar {
+    fn hash_to_scalar(
+        bytes: &[u8],
+        dst: &[u8],
+        _public_parameters: &Self::PublicParameters,
+    ) -> crate::Result<Self> {
+        // `ORDER` is non-zero, so it is safe to `unwrap()`.
+        let [scalar] =
+            hash_to_field::<1, SCALAR_LIMBS>(bytes, dst, &NonZero::new(super::ORDER).unwrap())?;
+
+        Ok(Self::from(scalar))
+    }
+}
+
 impl HashToGroup for Scalar {
-    fn hash_to_group(bytes: &[u8]) -> crate::Result<Self> {
-        Ok(Self(curve25519_dalek::scalar::Scalar::hash_from_bytes::<
-            Sha3_512,
-        >(bytes)))
+    fn hash_to_group(bytes: &[u8], dst: &[u8]) -> crate::Result<Self> {
+        Self::hash_to_scalar(bytes, dst, &PublicParameters::default())
+    }
+
+    fn legacy_hash_to_group(bytes: &[u8]) -> crate::Result<Self> {
+        // Reduces the SHA3-512 digest of `bytes`, as done by
+        // `curve25519_dalek::scalar::Scalar::hash_from_bytes::<Sha3_512>()`.
+        let uniform_bytes: [u8; 64] = Sha3_512::digest(bytes).into();
+
+        Ok(Self(
+            curve25519_dalek::scalar::Scalar::from_bytes_mod_order_wide(&uniform_bytes),
+        ))
+    }
+}
+
+impl crate::Encoding for Scalar {
+    const ENCODED_LEN: usize = 32;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        // `curve25519-dalek` encodes scalars in little-endian, whereas we use big-endian.
+        let mut bytes = self.0.to_bytes();
+        bytes.reverse();
+
+        bytes.to_vec()
+    }
+
+    fn from_bytes(
+        bytes: &[u8],
+        _public_parameters: &Self::PublicParameters,
+    ) -> crate::Result<Self> {
+        let mut bytes =
+            <[u8; 32]>::try_from(bytes).map_err(|_| crate::Error::InvalidGroupElement)?;
+        bytes.reverse();
+
+        // `from_canonical_bytes()` rejects encodings that are not reduced modulo the order.
+        curve25519_dalek::scalar::Scalar::from_canonical_bytes(bytes)
+            .map(Self)
+            .ok_or(crate::Error::InvalidGroupElement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::test_helpers;
+
+    #[test]
+    fn satisfies_conformance() {
+        // Scalars are validated upon deserialization, so every `Value` is a valid one.
+        test_helpers::samplable_conformance::<Scalar>(
+            &PublicParameters::default(),
+            vec![],
+            &mut OsRng,
+        );
+    }
+
+    #[test]
+    fn batch_inverts() {
+        test_helpers::batch_invert_agrees_with_invert::<SCALAR_LIMBS, Scalar>(
+            &PublicParameters::default(),
+            &mut OsRng,
+        );
     }
 }
@@ -1,7 +1,8 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::{
+use alloc::vec::Vec;
+use core::{
     cmp::Ordering,
     ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
 };
@@ -9,9 +10,11 @@ use std::{
 use crypto_bigint::{rand_core::CryptoRngCore, Uint};
 use serde::{Deserialize, Serialize};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
 
 use crate::{
-    BoundedGroupElement, GroupElement, Invert, KnownOrderGroupElement, KnownOrderScalar, Samplable,
+    BoundedGroupElement, Encoding, GroupElement, Invert, KnownOrderGroupElement, KnownOrderScalar,
+    MultiScalarMul, Samplable, VartimeGroupElement,
 };
 
 /// Newtype used to comply with Rust's local-type checks.
@@ -59,12 +62,32 @@ impl<const SCALAR_LIMBS: usize, S: GroupElement> GroupElement for Scalar<SCALAR_
     }
 }
 
+impl<const SCALAR_LIMBS: usize, S: Zeroize> Zeroize for Scalar<SCALAR_LIMBS, S> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<V: Zeroize> Zeroize for Value<V> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl<V: ConstantTimeEq> ConstantTimeEq for Value<V> {
     fn ct_eq(&self, other: &Self) -> Choice {
         self.0.ct_eq(&other.0)
     }
 }
 
+impl<const SCALAR_LIMBS: usize, S: ConditionallySelectable> ConditionallySelectable
+    for Scalar<SCALAR_LIMBS, S>
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(S::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
 impl<V: ConditionallySelectable> ConditionallySelectable for Value<V> {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         Self(V::conditional_select(&a.0, &b.0, choice))
@@ -253,6 +276,39 @@ where
     }
 }
 
+impl<const SCALAR_LIMBS: usize, S: GroupElement> MultiScalarMul for Scalar<SCALAR_LIMBS, S> {
+    fn multi_scalar_mul_bounded<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+        scalar_bits: usize,
+    ) -> Option<Self> {
+        let bases_and_scalars: Vec<_> = bases_and_scalars
+            .iter()
+            .map(|(base, scalar)| (base.0.clone(), *scalar))
+            .collect();
+
+        S::multi_scalar_mul_bounded(&bases_and_scalars, scalar_bits).map(Self)
+    }
+}
+
+impl<const SCALAR_LIMBS: usize, S: VartimeGroupElement> VartimeGroupElement
+    for Scalar<SCALAR_LIMBS, S>
+{
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        Self(self.0.scalar_mul_vartime(scalar))
+    }
+
+    fn multi_scalar_mul_vartime<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+    ) -> Option<Self> {
+        let bases_and_scalars: Vec<_> = bases_and_scalars
+            .iter()
+            .map(|(base, scalar)| (base.0.clone(), *scalar))
+            .collect();
+
+        S::multi_scalar_mul_vartime(&bases_and_scalars).map(Self)
+    }
+}
+
 impl<const SCALAR_LIMBS: usize, S: Samplable> Samplable for Scalar<SCALAR_LIMBS, S> {
     fn sample(
         public_parameters: &Self::PublicParameters,
@@ -261,3 +317,34 @@ impl<const SCALAR_LIMBS: usize, S: Samplable> Samplable for Scalar<SCALAR_LIMBS,
         Ok(Self(S::sample(&public_parameters.0, rng)?))
     }
 }
+
+impl<const SCALAR_LIMBS: usize, S: Encoding> Encoding for Scalar<SCALAR_LIMBS, S> {
+    const ENCODED_LEN: usize = S::ENCODED_LEN;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8], public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        Ok(Self(S::from_bytes(bytes, &public_parameters.0)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U256;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{secp256k1, test_helpers};
+
+    #[test]
+    fn satisfies_conformance() {
+        // Scalars are validated upon deserialization, so every `Value` is a valid one.
+        test_helpers::samplable_conformance::<Scalar<{ U256::LIMBS }, secp256k1::Scalar>>(
+            &PublicParameters(secp256k1::scalar::PublicParameters::default()),
+            vec![],
+            &mut OsRng,
+        );
+    }
+}
@@ -1,15 +1,19 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
 
 use crypto_bigint::{Uint, U256};
 use k256::{
     elliptic_curve,
     elliptic_curve::{
-        group::prime::PrimeCurveAffine,
+        group::{prime::PrimeCurveAffine, GroupEncoding},
         hash2curve::{ExpandMsgXof, GroupDigest},
-        ops::Reduce,
+        ops::{LinearCombinationExt, Reduce},
         point::AffineCoordinates,
         BatchNormalize as _, Group,
     },
@@ -23,7 +27,7 @@ use super::SCALAR_LIMBS;
 use crate::{
     secp256k1::{scalar::Scalar, CURVE_EQUATION_A, CURVE_EQUATION_B, MODULUS, ORDER},
     AffineXCoordinate, BoundedGroupElement, CyclicGroupElement, HashToGroup,
-    KnownOrderGroupElement, MulByGenerator, PrimeGroupElement,
+    KnownOrderGroupElement, MulByGenerator, MultiScalarMul, PrimeGroupElement, VartimeGroupElement,
 };
 
 /// An element of the secp256k1 prime group.
@@ -76,6 +80,12 @@ impl ConditionallySelectable for Value {
     }
 }
 
+impl ConditionallySelectable for GroupElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(ProjectivePoint::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
 impl From<Value> for AffinePoint {
     fn from(value: Value) -> Self {
         value.0
@@ -227,6 +237,84 @@ impl<'r> MulByGenerator<&'r U256> for GroupElement {
     }
 }
 
+impl MultiScalarMul for GroupElement {
+    fn multi_scalar_mul_bounded<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+        scalar_bits: usize,
+    ) -> Option<Self> {
+        if bases_and_scalars.is_empty() {
+            return None;
+        }
+
+        // `k256`'s linear combination operates in constant-time over the full scalars, so we only
+        // need to take the `scalar_bits` least significant bits of each scalar.
+        let mask = (Uint::<LIMBS>::ONE << scalar_bits).wrapping_sub(&Uint::<LIMBS>::ONE);
+        let points_and_scalars: Vec<_> = bases_and_scalars
+            .iter()
+            .map(|(base, scalar)| (base.0, Scalar::from(scalar & mask).0))
+            .collect();
+
+        Some(Self(ProjectivePoint::lincomb_ext(
+            points_and_scalars.as_slice(),
+        )))
+    }
+}
+
+impl VartimeGroupElement for GroupElement {
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        // The linear combination of a single term is never empty, so this is safe to `unwrap()`.
+        Self::multi_scalar_mul_vartime(&[(*self, *scalar)]).unwrap()
+    }
+
+    fn multi_scalar_mul_vartime<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+    ) -> Option<Self> {
+        if bases_and_scalars.is_empty() {
+            return None;
+        }
+
+        // A windowed variant of Straus' method: the doublings are shared between all terms, and
+        // are only performed up to the bit-length of the largest (reduced) scalar.
+        const WINDOW_BITS: usize = 4;
+        const WINDOW_MASK: usize = (1 << WINDOW_BITS) - 1;
+
+        let tables_and_scalars: Vec<_> = bases_and_scalars
+            .iter()
+            .map(|(base, scalar)| {
+                let mut table = [ProjectivePoint::IDENTITY; 1 << WINDOW_BITS];
+                for i in 1..table.len() {
+                    table[i] = table[i - 1] + base.0;
+                }
+
+                (table, U256::from(Scalar::from(scalar)))
+            })
+            .collect();
+
+        let scalar_bits = tables_and_scalars
+            .iter()
+            .map(|(_, scalar)| scalar.bits_vartime())
+            .max()
+            .unwrap_or_default();
+
+        let mut linear_combination = ProjectivePoint::IDENTITY;
+        for window in (0..scalar_bits.div_ceil(WINDOW_BITS)).rev() {
+            for _ in 0..WINDOW_BITS {
+                linear_combination = linear_combination.double();
+            }
+
+            for (table, scalar) in &tables_and_scalars {
+                let digit =
+                    (scalar.shr_vartime(window * WINDOW_BITS).as_words()[0] as usize) & WINDOW_MASK;
+                if digit != 0 {
+                    linear_combination += table[digit];
+                }
+            }
+        }
+
+        Some(Self(linear_combination))
+    }
+}
+
 impl CyclicGroupElement for GroupElement {
     fn generator(&self) -> Self {
         Self(ProjectivePoint::GENERATOR)
@@ -276,13 +364,10 @@ impl<'r> MulByGenerator<&'r Scalar> for GroupElement {
 impl PrimeGroupElement<SCALAR_LIMBS> for GroupElement {}
 
 impl HashToGroup for GroupElement {
-    fn hash_to_group(bytes: &[u8]) -> crate::Result<Self> {
-        Secp256k1::hash_from_bytes::<ExpandMsgXof<Shake256>>(
-            &[bytes],
-            &[b"CURVE_XOF:SHAKE-256_SSWU_RO_"],
-        )
-        .map_err(|_| crate::Error::HashToGroup)
-        .map(Self)
+    fn hash_to_group(bytes: &[u8], dst: &[u8]) -> crate::Result<Self> {
+        Secp256k1::hash_from_bytes::<ExpandMsgXof<Shake256>>(&[bytes], &[dst])
+            .map_err(|_| crate::Error::HashToGroup)
+            .map(Self)
     }
 }
 
@@ -295,3 +380,118 @@ impl AffineXCoordinate<SCALAR_LIMBS> for GroupElement {
         ))
     }
 }
+
+impl crate::Encoding for GroupElement {
+    const ENCODED_LEN: usize = 33;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        // The SEC1 compressed encoding, in which the identity (whose standard encoding is the
+        // single byte `0x00`) is padded with zeros to the fixed length.
+        self.0.to_bytes().to_vec()
+    }
+
+    fn from_bytes(
+        bytes: &[u8],
+        _public_parameters: &Self::PublicParameters,
+    ) -> crate::Result<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        // `from_bytes()` assures the point is on the curve, and as secp256k1 is of prime order,
+        // it therefore belongs to the group.
+        Option::from(ProjectivePoint::from_bytes(bytes.into()))
+            .map(Self)
+            .ok_or(crate::Error::InvalidGroupElement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{Encoding as _, Random};
+    use k256::elliptic_curve::{sec1::ToEncodedPoint, PrimeField};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{secp256k1::scalar, test_helpers, Encoding, GroupElement as _};
+
+    #[test]
+    fn satisfies_conformance() {
+        let generator =
+            GroupElement::generator_from_public_parameters(&PublicParameters::default()).unwrap();
+        let elements: Vec<_> = (0..4)
+            .map(|_| generator.scalar_mul(&U256::random(&mut OsRng)))
+            .collect();
+
+        // Points are validated upon deserialization, so every `Value` is a valid one.
+        test_helpers::conformance(&elements, vec![], &mut OsRng);
+    }
+
+    #[test]
+    fn encodes_as_sec1_compressed() {
+        let public_parameters = PublicParameters::default();
+
+        for i in 1..10u64 {
+            let point = ProjectivePoint::GENERATOR * k256::Scalar::from(i);
+            let element = GroupElement(point);
+
+            let bytes = element.to_bytes();
+            assert_eq!(bytes.len(), GroupElement::ENCODED_LEN);
+            assert_eq!(bytes, point.to_affine().to_encoded_point(true).as_bytes());
+            assert_eq!(
+                GroupElement::from_bytes(&bytes, &public_parameters).unwrap(),
+                element
+            );
+        }
+
+        let neutral = GroupElement(ProjectivePoint::GENERATOR).neutral();
+        assert_eq!(neutral.to_bytes(), vec![0u8; GroupElement::ENCODED_LEN]);
+        assert_eq!(
+            GroupElement::from_bytes(&neutral.to_bytes(), &public_parameters).unwrap(),
+            neutral
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_point_encodings() {
+        let public_parameters = PublicParameters::default();
+        let bytes = GroupElement(ProjectivePoint::GENERATOR).to_bytes();
+
+        // Wrong length.
+        assert!(GroupElement::from_bytes(&bytes[1..], &public_parameters).is_err());
+        assert!(
+            GroupElement::from_bytes(&[bytes.clone(), vec![0]].concat(), &public_parameters)
+                .is_err()
+        );
+
+        // Uncompressed tag.
+        let mut invalid_tag = bytes.clone();
+        invalid_tag[0] = 0x04;
+        assert!(GroupElement::from_bytes(&invalid_tag, &public_parameters).is_err());
+
+        // An x-coordinate that is not reduced modulo the field modulus.
+        let mut non_canonical = vec![0x02];
+        non_canonical.extend(MODULUS.to_be_bytes());
+        assert!(GroupElement::from_bytes(&non_canonical, &public_parameters).is_err());
+    }
+
+    #[test]
+    fn encodes_scalars_as_big_endian() {
+        let public_parameters = scalar::PublicParameters::default();
+
+        for i in [0u64, 1, 2, u64::MAX] {
+            let scalar = Scalar(k256::Scalar::from(i));
+
+            let bytes = scalar.to_bytes();
+            assert_eq!(bytes.len(), Scalar::ENCODED_LEN);
+            assert_eq!(bytes, scalar.0.to_repr().to_vec());
+            assert_eq!(bytes, U256::from(i).to_be_bytes().to_vec());
+            assert_eq!(
+                Scalar::from_bytes(&bytes, &public_parameters).unwrap(),
+                scalar
+            );
+        }
+
+        assert!(Scalar::from_bytes(&ORDER.to_be_bytes(), &public_parameters).is_err());
+    }
+}
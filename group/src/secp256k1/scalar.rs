@@ -1,17 +1,31 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 use crypto_bigint::{rand_core::CryptoRngCore, NonZero, Uint, U256};
-use k256::elliptic_curve::{scalar::FromUintUnchecked, Field};
+use k256::{
+    elliptic_curve::{
+        hash2curve::{ExpandMsgXof, GroupDigest},
+        scalar::FromUintUnchecked,
+        Field, PrimeField,
+    },
+    FieldBytes, Secp256k1,
+};
 use serde::{Deserialize, Serialize};
+use sha3::Shake256;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
 
 use super::{GroupElement, SCALAR_LIMBS};
 use crate::{
-    secp256k1::ORDER, BoundedGroupElement, CyclicGroupElement, Invert, KnownOrderGroupElement,
-    KnownOrderScalar, MulByGenerator, PrimeGroupElement, Reduce, Samplable,
+    secp256k1::ORDER, BoundedGroupElement, CyclicGroupElement, GroupElement as _, HashToGroup,
+    HashToScalar, Invert, KnownOrderGroupElement, KnownOrderScalar, MulByGenerator, MultiScalarMul,
+    PrimeGroupElement, Reduce, Samplable, VartimeGroupElement,
 };
 
 /// A Scalar of the prime field $\mathbb{Z}_p$ over which the secp256k1 prime group is
@@ -25,6 +39,12 @@ impl ConstantTimeEq for Scalar {
     }
 }
 
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl ConditionallySelectable for Scalar {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         Self(k256::Scalar::conditional_select(&a.0, &b.0, choice))
@@ -302,6 +322,16 @@ impl Invert for Scalar {
 
 impl KnownOrderScalar<SCALAR_LIMBS> for Scalar {}
 
+impl MultiScalarMul for Scalar {}
+
+impl VartimeGroupElement for Scalar {
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        // Scalar multiplication is a single multiplication in the field, which has no faster
+        // variable-time counterpart.
+        self.scalar_mul(scalar)
+    }
+}
+
 impl KnownOrderGroupElement<SCALAR_LIMBS> for Scalar {
     type Scalar = Self;
     fn order(&self) -> Uint<SCALAR_LIMBS> {
@@ -330,3 +360,73 @@ impl<'r> MulByGenerator<&'r Scalar> for Scalar {
 }
 
 impl PrimeGroupElement<SCALAR_LIMBS> for Scalar {}
+
+impl HashToScalar for Scalar {
+    fn hash_to_scalar(
+        bytes: &[u8],
+        dst: &[u8],
+        _public_parameters: &Self::PublicParameters,
+    ) -> crate::Result<Self> {
+        // Use the same expander as the `secp256k1_XOF:SHAKE-256_SSWU_RO_` suite used for hashing to
+        // the group.
+        Secp256k1::hash_to_scalar::<ExpandMsgXof<Shake256>>(&[bytes], &[dst])
+            .map_err(|_| crate::Error::HashToGroup)
+            .map(Self)
+    }
+}
+
+impl HashToGroup for Scalar {
+    fn hash_to_group(bytes: &[u8], dst: &[u8]) -> crate::Result<Self> {
+        Self::hash_to_scalar(bytes, dst, &PublicParameters::default())
+    }
+}
+
+impl crate::Encoding for Scalar {
+    const ENCODED_LEN: usize = 32;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes().to_vec()
+    }
+
+    fn from_bytes(
+        bytes: &[u8],
+        _public_parameters: &Self::PublicParameters,
+    ) -> crate::Result<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+        let bytes: &FieldBytes = bytes.into();
+
+        // `from_repr()` rejects non-canonical encodings, i.e. ones that are not reduced modulo
+        // the order.
+        Option::from(k256::Scalar::from_repr(*bytes))
+            .map(Self)
+            .ok_or(crate::Error::InvalidGroupElement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::test_helpers;
+
+    #[test]
+    fn satisfies_conformance() {
+        // Scalars are validated upon deserialization, so every `Value` is a valid one.
+        test_helpers::samplable_conformance::<Scalar>(
+            &PublicParameters::default(),
+            vec![],
+            &mut OsRng,
+        );
+    }
+
+    #[test]
+    fn batch_inverts() {
+        test_helpers::batch_invert_agrees_with_invert::<SCALAR_LIMBS, Scalar>(
+            &PublicParameters::default(),
+            &mut OsRng,
+        );
+    }
+}
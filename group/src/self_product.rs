@@ -1,7 +1,8 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::{
+use alloc::vec::Vec;
+use core::{
     array,
     ops::{Add, AddAssign, BitAnd, Mul, Neg, Sub, SubAssign},
 };
@@ -9,10 +10,11 @@ use std::{
 use crypto_bigint::{rand_core::CryptoRngCore, Uint};
 use serde::{Deserialize, Serialize};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
 
 use crate::{
-    helpers::FlatMapResults, scalar::Scalar, BoundedGroupElement, GroupElement as _,
-    KnownOrderGroupElement, KnownOrderScalar, Samplable,
+    helpers::FlatMapResults, scalar::Scalar, BoundedGroupElement, Encoding, GroupElement as _,
+    KnownOrderGroupElement, KnownOrderScalar, MultiScalarMul, Samplable, VartimeGroupElement,
 };
 
 /// An element of the Self Product of the Group `G` by Itself.
@@ -39,6 +41,12 @@ where
     }
 }
 
+impl<const N: usize, G: Zeroize> Zeroize for GroupElement<N, G> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 /// The public parameters of the Self Product of the Group `G` by Itself.
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct PublicParameters<const N: usize, PP> {
@@ -73,6 +81,14 @@ impl<
     }
 }
 
+impl<const N: usize, GroupElementValue: Serialize + for<'a> Deserialize<'a> + Zeroize> Zeroize
+    for Value<N, GroupElementValue>
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl<const N: usize, GroupElementValue: Serialize + for<'a> Deserialize<'a> + ConstantTimeEq>
     ConstantTimeEq for Value<N, GroupElementValue>
 {
@@ -141,6 +157,55 @@ impl<const N: usize, G: crate::GroupElement> crate::GroupElement for GroupElemen
     }
 }
 
+impl<const N: usize, G: crate::GroupElement> MultiScalarMul for GroupElement<N, G> {
+    fn multi_scalar_mul_bounded<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+        scalar_bits: usize,
+    ) -> Option<Self> {
+        // Compute the linear combination of each coordinate separately.
+        let coordinates: Vec<_> = (0..N)
+            .map(|i| {
+                let bases_and_scalars: Vec<_> = bases_and_scalars
+                    .iter()
+                    .map(|(base, scalar)| (base.0[i].clone(), *scalar))
+                    .collect();
+
+                G::multi_scalar_mul_bounded(&bases_and_scalars, scalar_bits)
+            })
+            .collect::<Option<_>>()?;
+
+        coordinates.try_into().ok().map(Self)
+    }
+}
+
+impl<const N: usize, G: VartimeGroupElement> VartimeGroupElement for GroupElement<N, G> {
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        Self(
+            self.0
+                .clone()
+                .map(|element| element.scalar_mul_vartime(scalar)),
+        )
+    }
+
+    fn multi_scalar_mul_vartime<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+    ) -> Option<Self> {
+        // Compute the linear combination of each coordinate separately.
+        let coordinates: Vec<_> = (0..N)
+            .map(|i| {
+                let bases_and_scalars: Vec<_> = bases_and_scalars
+                    .iter()
+                    .map(|(base, scalar)| (base.0[i].clone(), *scalar))
+                    .collect();
+
+                G::multi_scalar_mul_vartime(&bases_and_scalars)
+            })
+            .collect::<Option<_>>()?;
+
+        coordinates.try_into().ok().map(Self)
+    }
+}
+
 impl<const N: usize, G: crate::GroupElement> From<GroupElement<N, G>> for Value<N, G::Value> {
     fn from(value: GroupElement<N, G>) -> Self {
         Self(value.0.map(|element| element.into()))
@@ -321,3 +386,67 @@ where
         G::order_from_public_parameters(&public_parameters.public_parameters)
     }
 }
+
+impl<const N: usize, G: Encoding> Encoding for GroupElement<N, G> {
+    const ENCODED_LEN: usize = N * G::ENCODED_LEN;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0
+            .iter()
+            .flat_map(|element| element.to_bytes())
+            .collect()
+    }
+
+    fn from_bytes(bytes: &[u8], public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        let public_parameters = &public_parameters.public_parameters;
+
+        if N == 0 {
+            return Err(crate::Error::InvalidPublicParameters);
+        }
+
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        // The encodings of the elements are of a fixed size, so we can simply split the bytes to
+        // `N` equally-sized chunks and decode each separately.
+        Ok(Self(
+            array::from_fn(|i| {
+                G::from_bytes(
+                    &bytes[i * G::ENCODED_LEN..(i + 1) * G::ENCODED_LEN],
+                    public_parameters,
+                )
+            })
+            .flat_map_results()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U256;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{multiplicative, secp256k1, test_helpers};
+
+    #[test]
+    fn satisfies_conformance() {
+        let public_parameters = PublicParameters::<3, _>::new(
+            multiplicative::PublicParameters::new(secp256k1::MODULUS).unwrap(),
+        );
+        let one =
+            multiplicative::Value::new(U256::ONE, &public_parameters.public_parameters).unwrap();
+        let zero =
+            multiplicative::Value::new(U256::ZERO, &public_parameters.public_parameters).unwrap();
+
+        // A single invalid coordinate invalidates the entire element.
+        test_helpers::samplable_conformance::<
+            GroupElement<3, multiplicative::GroupElement<{ U256::LIMBS }>>,
+        >(
+            &public_parameters,
+            vec![Value::from([one, zero, one])],
+            &mut OsRng,
+        );
+    }
+}
@@ -0,0 +1,400 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Conformance checks for [`GroupElement`] implementations.
+//!
+//! Each check asserts a property every group must satisfy over a handful of (non-neutral)
+//! elements of it, so that any implementor can run them against its own group; use
+//! [`conformance`] (or [`samplable_conformance`] for [`Samplable`] groups) to run all of them.
+
+use crypto_bigint::{rand_core::CryptoRngCore, Random, Uint, U128, U256, U512, U64};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{wire::WireEncoding, GroupElement, KnownOrderScalar, Samplable, VartimeGroupElement};
+
+/// The number of elements sampled by [`samplable_conformance`].
+const NUMBER_OF_SAMPLES: usize = 8;
+
+/// Runs all conformance checks over `elements`, which must hold at least three non-neutral
+/// elements of the same group, and over `invalid_values`, which must not be accepted by
+/// [`GroupElement::new()`] under the public parameters of that group.
+pub fn conformance<G: VartimeGroupElement>(
+    elements: &[G],
+    invalid_values: Vec<G::Value>,
+    rng: &mut impl CryptoRngCore,
+) {
+    assert!(
+        elements.len() >= 3,
+        "conformance checks require at least three elements"
+    );
+
+    group_laws_hold(elements);
+    scalar_mul_agrees_with_repeated_addition(elements, rng);
+    scalar_mul_bounded_masks_scalar(elements, rng);
+    multi_scalar_mul_agrees_with_scalar_mul(elements, rng);
+    vartime_agrees_with_constant_time(elements, rng);
+    batch_normalize_agrees_with_value(elements);
+    values_round_trip_serialization(elements);
+    new_rejects_invalid_values::<G>(&elements[0].public_parameters(), invalid_values);
+}
+
+/// Samples elements of the group identified by `public_parameters`, checks their distribution and
+/// runs all conformance checks over them (see [`conformance`]).
+pub fn samplable_conformance<G: Samplable + VartimeGroupElement>(
+    public_parameters: &G::PublicParameters,
+    invalid_values: Vec<G::Value>,
+    rng: &mut impl CryptoRngCore,
+) {
+    let elements = samples_are_distinct::<G>(public_parameters, rng);
+
+    conformance(&elements, invalid_values, rng);
+}
+
+/// Checks the abelian group laws: associativity, commutativity, identity and inverse, and that
+/// the by-value, by-reference and assigning variants of the operations agree.
+pub fn group_laws_hold<G: GroupElement>(elements: &[G]) {
+    for (i, a) in elements.iter().enumerate() {
+        let neutral = a.neutral();
+        assert!(bool::from(neutral.is_neutral()));
+        assert!(
+            !bool::from(a.is_neutral()),
+            "conformance checks require non-neutral elements"
+        );
+
+        assert_eq!(a.clone() + &neutral, *a, "identity");
+        assert_eq!(a.clone() + -a.clone(), neutral, "inverse");
+        assert_eq!(a.clone() - a, neutral, "inverse");
+        assert_eq!(-(-a.clone()), *a, "double negation");
+        assert_eq!(a.double(), a.clone() + a, "doubling");
+
+        for b in &elements[i..] {
+            assert_eq!(a.clone() + b, b.clone() + a, "commutativity");
+            assert_eq!(a.clone() + b, a.clone() + b.clone());
+            assert_eq!(a.clone() - b, a.clone() + -b.clone());
+            assert_eq!(a.clone() - b, a.clone() - b.clone());
+
+            let mut sum = a.clone();
+            sum += b;
+            assert_eq!(sum, a.clone() + b);
+            sum -= b.clone();
+            assert_eq!(sum, *a);
+            sum += b.clone();
+            sum -= b;
+            assert_eq!(sum, *a);
+
+            for c in elements {
+                assert_eq!(
+                    (a.clone() + b) + c,
+                    a.clone() + (b.clone() + c),
+                    "associativity"
+                );
+            }
+        }
+    }
+}
+
+/// Checks that [`GroupElement::scalar_mul()`] agrees with repeated addition, and that it is
+/// compatible with the addition and multiplication of (non-overflowing) scalars.
+pub fn scalar_mul_agrees_with_repeated_addition<G: GroupElement>(
+    elements: &[G],
+    rng: &mut impl CryptoRngCore,
+) {
+    for element in elements {
+        let mut multiple = element.neutral();
+        for i in 0..16u64 {
+            assert_eq!(element.scalar_mul(&U64::from(i)), multiple);
+            assert_eq!(element.scalar_mul(&U128::from(i)), multiple);
+            multiple += element;
+        }
+
+        // Take 64-bit scalars, so that neither their sum nor their product overflow.
+        let first_scalar: U128 = U64::random(rng).resize();
+        let second_scalar: U128 = U64::random(rng).resize();
+
+        assert_eq!(
+            element.scalar_mul(&first_scalar.wrapping_add(&second_scalar)),
+            element.scalar_mul(&first_scalar) + element.scalar_mul(&second_scalar),
+            "distributivity"
+        );
+        assert_eq!(
+            element.scalar_mul(&first_scalar.wrapping_mul(&second_scalar)),
+            element.scalar_mul(&first_scalar).scalar_mul(&second_scalar),
+            "compatibility"
+        );
+    }
+}
+
+/// Checks that [`GroupElement::scalar_mul_bounded()`] only takes into account the requested
+/// number of (least significant) bits of the scalar.
+pub fn scalar_mul_bounded_masks_scalar<G: GroupElement>(
+    elements: &[G],
+    rng: &mut impl CryptoRngCore,
+) {
+    for element in elements {
+        let scalar = U128::random(rng);
+
+        for scalar_bits in [0, 1, 7, 64, 100, U128::BITS - 1] {
+            let mask = (U128::ONE << scalar_bits).wrapping_sub(&U128::ONE);
+
+            assert_eq!(
+                element.scalar_mul_bounded(&scalar, scalar_bits),
+                element.scalar_mul(&(scalar & mask)),
+                "scalar_mul_bounded() must take only {scalar_bits} bits into account"
+            );
+        }
+    }
+}
+
+/// Checks that [`crate::MultiScalarMul`] agrees with summing separate scalar multiplications.
+pub fn multi_scalar_mul_agrees_with_scalar_mul<G: GroupElement>(
+    elements: &[G],
+    rng: &mut impl CryptoRngCore,
+) {
+    let bases_and_scalars: Vec<_> = elements
+        .iter()
+        .map(|element| (element.clone(), U128::random(rng)))
+        .collect();
+
+    let expected = bases_and_scalars
+        .iter()
+        .map(|(base, scalar)| base.scalar_mul(scalar))
+        .reduce(|a, b| a + b);
+    assert_eq!(G::multi_scalar_mul(&bases_and_scalars), expected);
+
+    let expected = bases_and_scalars
+        .iter()
+        .map(|(base, scalar)| base.scalar_mul_bounded(scalar, 64))
+        .reduce(|a, b| a + b);
+    assert_eq!(
+        G::multi_scalar_mul_bounded(&bases_and_scalars, 64),
+        expected
+    );
+
+    assert_eq!(G::multi_scalar_mul::<{ U128::LIMBS }>(&[]), None);
+}
+
+/// Checks that the variable-time operations of [`VartimeGroupElement`] agree with their
+/// constant-time counterparts, over zero, small, random and full-width scalars of several widths.
+///
+/// Scalars of 256 bits and more are also sampled with their most significant bit set (besides
+/// `Uint::MAX`), so that they exceed the order of any (known-order) group of a smaller bit-length,
+/// and must be reduced consistently by both variants.
+pub fn vartime_agrees_with_constant_time<G: VartimeGroupElement>(
+    elements: &[G],
+    rng: &mut impl CryptoRngCore,
+) {
+    vartime_agrees_with_constant_time_over_scalars(
+        elements,
+        &[
+            U64::ZERO,
+            U64::ONE,
+            U64::from(15u64),
+            U64::random(rng),
+            U64::MAX,
+        ],
+    );
+    vartime_agrees_with_constant_time_over_scalars(
+        elements,
+        &[
+            U256::ZERO,
+            U128::random(rng).resize(),
+            U256::random(rng) | (U256::ONE << (U256::BITS - 1)),
+            U256::MAX,
+        ],
+    );
+    vartime_agrees_with_constant_time_over_scalars(
+        elements,
+        &[
+            U512::ZERO,
+            U512::random(rng),
+            U512::random(rng) | (U512::ONE << (U512::BITS - 1)),
+            U512::MAX,
+        ],
+    );
+}
+
+fn vartime_agrees_with_constant_time_over_scalars<G: VartimeGroupElement, const LIMBS: usize>(
+    elements: &[G],
+    scalars: &[Uint<LIMBS>],
+) {
+    for element in elements {
+        for scalar in scalars {
+            let expected = element.scalar_mul(scalar);
+
+            assert_eq!(
+                element.scalar_mul_vartime(scalar),
+                expected,
+                "scalar_mul_vartime() must agree with scalar_mul() for the scalar {scalar}"
+            );
+            assert_eq!(
+                G::multi_scalar_mul_vartime(&[(element.clone(), *scalar)]),
+                Some(expected),
+                "multi_scalar_mul_vartime() of a single term must agree with scalar_mul() for the \
+                 scalar {scalar}"
+            );
+        }
+    }
+
+    // Pair every element with every scalar (in turns), so that zero and full-width scalars are
+    // combined with each other, as well as a linear combination with all scalars being zero.
+    let bases_and_scalars: Vec<_> = elements
+        .iter()
+        .flat_map(|element| scalars.iter().map(|scalar| (element.clone(), *scalar)))
+        .collect();
+    let zero_scalars: Vec<_> = elements
+        .iter()
+        .map(|element| (element.clone(), Uint::<LIMBS>::ZERO))
+        .collect();
+
+    for bases_and_scalars in [bases_and_scalars, zero_scalars] {
+        let expected = bases_and_scalars
+            .iter()
+            .map(|(base, scalar)| base.scalar_mul(scalar))
+            .reduce(|a, b| a + b);
+
+        assert_eq!(G::multi_scalar_mul_vartime(&bases_and_scalars), expected);
+        assert_eq!(
+            G::multi_scalar_mul_vartime(&bases_and_scalars),
+            G::multi_scalar_mul(&bases_and_scalars)
+        );
+    }
+
+    assert_eq!(G::multi_scalar_mul_vartime::<LIMBS>(&[]), None);
+}
+
+/// Checks that the batched conversions of elements (including the neutral element) to their
+/// values agree with [`GroupElement::value()`].
+pub fn batch_normalize_agrees_with_value<G: GroupElement>(elements: &[G]) {
+    let mut elements = elements.to_vec();
+    elements.push(elements[0].neutral());
+    let values: Vec<_> = elements.iter().map(|element| element.value()).collect();
+
+    assert_eq!(G::batch_normalize(elements.clone()), values);
+
+    let elements: [G; 3] = [
+        elements[0].clone(),
+        elements[0].neutral(),
+        elements[1].clone(),
+    ];
+    assert_eq!(
+        G::batch_normalize_const_generic(elements.clone()),
+        elements.map(|element| element.value())
+    );
+}
+
+/// Checks that values and public parameters round-trip serialization, and that elements
+/// instantiated from their (deserialized) values are equal to the original ones.
+pub fn values_round_trip_serialization<G: GroupElement>(elements: &[G]) {
+    let public_parameters = elements[0].public_parameters();
+
+    let bytes = public_parameters.to_wire_bytes().unwrap();
+    assert_eq!(
+        G::PublicParameters::from_wire_bytes(&bytes).unwrap(),
+        public_parameters
+    );
+
+    for element in elements.iter().cloned().chain([elements[0].neutral()]) {
+        let value = element.value();
+        let bytes = value.to_wire_bytes().unwrap();
+        let deserialized_value = G::Value::from_wire_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized_value, value);
+        assert_eq!(
+            G::new(deserialized_value, &public_parameters).unwrap(),
+            element
+        );
+    }
+}
+
+/// Checks that [`GroupElement::new()`] rejects each of the `invalid_values`.
+pub fn new_rejects_invalid_values<G: GroupElement>(
+    public_parameters: &G::PublicParameters,
+    invalid_values: Vec<G::Value>,
+) {
+    for value in invalid_values {
+        assert!(
+            G::new(value.clone(), public_parameters).is_err(),
+            "new() must reject the invalid value {value:?}"
+        );
+    }
+}
+
+/// Checks the sanity of the distribution of [`Samplable::sample()`]: samples must be valid,
+/// non-neutral and pairwise distinct (which holds with overwhelming probability for groups of
+/// cryptographic size). Returns the samples.
+pub fn samples_are_distinct<G: Samplable>(
+    public_parameters: &G::PublicParameters,
+    rng: &mut impl CryptoRngCore,
+) -> Vec<G> {
+    let samples: Vec<G> = (0..NUMBER_OF_SAMPLES)
+        .map(|_| G::sample(public_parameters, rng).unwrap())
+        .collect();
+
+    for (i, sample) in samples.iter().enumerate() {
+        assert_eq!(G::new(sample.value(), public_parameters).unwrap(), *sample);
+        assert!(
+            !bool::from(sample.is_neutral()),
+            "sampled the neutral element"
+        );
+        assert!(
+            samples[..i].iter().all(|other| other != sample),
+            "sampled the same element twice"
+        );
+    }
+
+    samples
+}
+
+/// Checks that [`crate::Invert::batch_invert()`] agrees with inverting each (sampled) scalar
+/// separately, and that it fails if any of the scalars is not invertible.
+pub fn batch_invert_agrees_with_invert<
+    const SCALAR_LIMBS: usize,
+    S: KnownOrderScalar<SCALAR_LIMBS>,
+>(
+    public_parameters: &S::PublicParameters,
+    rng: &mut impl CryptoRngCore,
+) {
+    let scalars = samples_are_distinct::<S>(public_parameters, rng);
+    let inverses: Vec<S> = scalars
+        .iter()
+        .map(|scalar| scalar.invert().unwrap())
+        .collect();
+
+    assert_eq!(
+        Option::from(S::batch_invert(&scalars)),
+        Some(inverses.clone())
+    );
+    assert_eq!(
+        Option::from(S::batch_invert(&scalars[..1])),
+        Some(vec![inverses[0]])
+    );
+    assert_eq!(Option::from(S::batch_invert(&[])), Some(vec![]));
+
+    let mut scalars = scalars;
+    scalars[NUMBER_OF_SAMPLES / 2] = scalars[0].neutral();
+    assert!(
+        bool::from(S::batch_invert(&scalars).is_none()),
+        "batch_invert() must fail if any of the scalars is zero"
+    );
+}
+
+/// Checks that zeroizing `value` wipes each of its (non-zero) secrets, as returned by `secrets`.
+///
+/// Types holding secrets implement [`ZeroizeOnDrop`] by calling [`Zeroize::zeroize()`] upon
+/// drop, so this checks what they wipe when dropped without reading freed memory.
+pub fn zeroizes_secrets<T: Zeroize + ZeroizeOnDrop, S: Default + PartialEq>(
+    mut value: T,
+    secrets: impl Fn(&T) -> Vec<S>,
+) {
+    assert!(
+        secrets(&value).iter().all(|secret| *secret != S::default()),
+        "secrets should be non-zero before zeroizing"
+    );
+
+    value.zeroize();
+
+    assert!(
+        secrets(&value).iter().all(|secret| *secret == S::default()),
+        "zeroize() must wipe all secrets"
+    );
+}
@@ -0,0 +1,508 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, BitAnd, Mul, Neg, Sub, SubAssign};
+
+use crypto_bigint::{rand_core::CryptoRngCore, Uint};
+use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+use crate::{
+    scalar::Scalar, BoundedGroupElement, GroupElement as _, KnownOrderGroupElement,
+    KnownOrderScalar, MultiScalarMul, Samplable, VartimeGroupElement,
+};
+
+/// An element of the Self Product of the Group `G` by Itself, where the number of factors is
+/// only known at runtime.
+///
+/// This is the dynamically-sized counterpart of [`crate::self_product::GroupElement`]: the length
+/// of the vector is part of the [`PublicParameters`] rather than a const generic, which allows
+/// instantiating languages and commitment schemes over batches whose size is not known at
+/// compile-time.
+///
+/// Group operations are only defined between elements of the same group, and thus of the same
+/// length: adding or subtracting vectors of different lengths panics.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct GroupElement<G>(Vec<G>);
+
+impl<G: crate::GroupElement> Samplable for GroupElement<G>
+where
+    G: Samplable,
+{
+    fn sample(
+        public_parameters: &Self::PublicParameters,
+        rng: &mut impl CryptoRngCore,
+    ) -> crate::Result<Self> {
+        if public_parameters.size == 0 {
+            return Err(crate::Error::InvalidPublicParameters);
+        }
+
+        Ok(Self(
+            (0..public_parameters.size)
+                .map(|_| G::sample(&public_parameters.public_parameters, rng))
+                .collect::<crate::Result<_>>()?,
+        ))
+    }
+}
+
+impl<G: Zeroize> Zeroize for GroupElement<G> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// The public parameters of the Self Product of the Group `G` by Itself, of a runtime length
+/// `size`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct PublicParameters<PP> {
+    pub public_parameters: PP,
+    pub size: usize,
+}
+
+impl<PP> PublicParameters<PP> {
+    pub fn new(public_parameters: PP, size: usize) -> Self {
+        Self {
+            public_parameters,
+            size,
+        }
+    }
+}
+
+/// The value of the Self Product of the Group `G` by Itself, of a runtime length.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct Value<GroupElementValue>(Vec<GroupElementValue>);
+
+impl<GroupElementValue: Zeroize> Zeroize for Value<GroupElementValue> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<GroupElementValue: ConstantTimeEq> ConstantTimeEq for Value<GroupElementValue> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // The length of the vector is public (it is part of the public parameters), so we can
+        // safely compare it in variable-time. Following that, we ensure all pairs are equal to
+        // each other using `ct_eq` between the pairs and `bitand` between the results.
+        if self.0.len() != other.0.len() {
+            return Choice::from(0u8);
+        }
+
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .fold(Choice::from(1u8), |choice, (x, y)| {
+                choice.bitand(x.ct_eq(y))
+            })
+    }
+}
+
+impl<G: crate::GroupElement> crate::GroupElement for GroupElement<G> {
+    type Value = Value<G::Value>;
+
+    type PublicParameters = PublicParameters<G::PublicParameters>;
+
+    fn public_parameters(&self) -> Self::PublicParameters {
+        // in [`Self::new()`] we used the same public parameters for all elements, so we just pick
+        // the first. Calling `unwrap()` is safe here because we assure to get at least one
+        // value, i.e., this struct cannot be instantiated for `size == 0`.
+        PublicParameters::new(self.0.first().unwrap().public_parameters(), self.0.len())
+    }
+
+    fn new(value: Self::Value, public_parameters: &Self::PublicParameters) -> crate::Result<Self> {
+        if public_parameters.size == 0 {
+            return Err(crate::Error::InvalidPublicParameters);
+        }
+
+        if value.0.len() != public_parameters.size {
+            return Err(crate::Error::InvalidGroupElement);
+        }
+
+        Ok(Self(
+            value
+                .0
+                .into_iter()
+                .map(|value| G::new(value, &public_parameters.public_parameters))
+                .collect::<crate::Result<_>>()?,
+        ))
+    }
+
+    fn neutral(&self) -> Self {
+        Self(self.0.iter().map(|element| element.neutral()).collect())
+    }
+
+    fn scalar_mul<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|element| element.scalar_mul(scalar))
+                .collect(),
+        )
+    }
+
+    fn scalar_mul_bounded<const LIMBS: usize>(
+        &self,
+        scalar: &Uint<LIMBS>,
+        scalar_bits: usize,
+    ) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|element| element.scalar_mul_bounded(scalar, scalar_bits))
+                .collect(),
+        )
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0.iter().map(|element| element.double()).collect())
+    }
+}
+
+impl<G: crate::GroupElement> MultiScalarMul for GroupElement<G> {
+    fn multi_scalar_mul_bounded<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+        scalar_bits: usize,
+    ) -> Option<Self> {
+        // All bases belong to the same group, and are thus of the same length.
+        let size = bases_and_scalars.first()?.0 .0.len();
+
+        // Compute the linear combination of each coordinate separately.
+        let coordinates = (0..size)
+            .map(|i| {
+                let bases_and_scalars: Vec<_> = bases_and_scalars
+                    .iter()
+                    .map(|(base, scalar)| (base.0[i].clone(), *scalar))
+                    .collect();
+
+                G::multi_scalar_mul_bounded(&bases_and_scalars, scalar_bits)
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self(coordinates))
+    }
+}
+
+impl<G: VartimeGroupElement> VartimeGroupElement for GroupElement<G> {
+    fn scalar_mul_vartime<const LIMBS: usize>(&self, scalar: &Uint<LIMBS>) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|element| element.scalar_mul_vartime(scalar))
+                .collect(),
+        )
+    }
+
+    fn multi_scalar_mul_vartime<const LIMBS: usize>(
+        bases_and_scalars: &[(Self, Uint<LIMBS>)],
+    ) -> Option<Self> {
+        // All bases belong to the same group, and are thus of the same length.
+        let size = bases_and_scalars.first()?.0 .0.len();
+
+        // Compute the linear combination of each coordinate separately.
+        let coordinates = (0..size)
+            .map(|i| {
+                let bases_and_scalars: Vec<_> = bases_and_scalars
+                    .iter()
+                    .map(|(base, scalar)| (base.0[i].clone(), *scalar))
+                    .collect();
+
+                G::multi_scalar_mul_vartime(&bases_and_scalars)
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self(coordinates))
+    }
+}
+
+impl<G: crate::GroupElement> From<GroupElement<G>> for Value<G::Value> {
+    fn from(value: GroupElement<G>) -> Self {
+        Self(value.0.into_iter().map(|element| element.into()).collect())
+    }
+}
+
+impl<GroupElementValue> From<Value<GroupElementValue>> for Vec<GroupElementValue> {
+    fn from(value: Value<GroupElementValue>) -> Self {
+        value.0
+    }
+}
+
+impl<GroupElementValue: From<OtherElementValue>, OtherElementValue> From<Vec<OtherElementValue>>
+    for Value<GroupElementValue>
+{
+    fn from(value: Vec<OtherElementValue>) -> Self {
+        Value(value.into_iter().map(GroupElementValue::from).collect())
+    }
+}
+
+impl<G: crate::GroupElement> From<GroupElement<G>> for PublicParameters<G::PublicParameters> {
+    fn from(value: GroupElement<G>) -> Self {
+        value.public_parameters()
+    }
+}
+
+impl<G: crate::GroupElement> Neg for GroupElement<G> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.into_iter().map(|element| element.neg()).collect())
+    }
+}
+
+impl<G: crate::GroupElement> Add<Self> for GroupElement<G> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self + &rhs
+    }
+}
+
+impl<'r, G: crate::GroupElement> Add<&'r Self> for GroupElement<G> {
+    type Output = Self;
+
+    fn add(mut self, rhs: &'r Self) -> Self::Output {
+        self += rhs;
+
+        self
+    }
+}
+
+impl<G: crate::GroupElement> Sub<Self> for GroupElement<G> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self - &rhs
+    }
+}
+
+impl<'r, G: crate::GroupElement> Sub<&'r Self> for GroupElement<G> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: &'r Self) -> Self::Output {
+        self -= rhs;
+
+        self
+    }
+}
+
+impl<G: crate::GroupElement> AddAssign<Self> for GroupElement<G> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs
+    }
+}
+
+impl<'r, G: crate::GroupElement> AddAssign<&'r Self> for GroupElement<G> {
+    fn add_assign(&mut self, rhs: &'r Self) {
+        // Group operations are only defined between elements of the same group, which share the
+        // same length. Zipping vectors of different lengths would silently truncate the result, so
+        // we refuse to operate on them.
+        assert_eq!(
+            self.0.len(),
+            rhs.0.len(),
+            "cannot add vectors of different lengths"
+        );
+
+        for (element, other) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *element += other;
+        }
+    }
+}
+
+impl<G: crate::GroupElement> SubAssign<Self> for GroupElement<G> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs
+    }
+}
+
+impl<'r, G: crate::GroupElement> SubAssign<&'r Self> for GroupElement<G> {
+    fn sub_assign(&mut self, rhs: &'r Self) {
+        // Group operations are only defined between elements of the same group, which share the
+        // same length. Zipping vectors of different lengths would silently truncate the result, so
+        // we refuse to operate on them.
+        assert_eq!(
+            self.0.len(),
+            rhs.0.len(),
+            "cannot subtract vectors of different lengths"
+        );
+
+        for (element, other) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *element -= other;
+        }
+    }
+}
+
+impl<G: crate::GroupElement> From<GroupElement<G>> for Vec<G> {
+    fn from(value: GroupElement<G>) -> Self {
+        value.0
+    }
+}
+
+impl<'r, G: crate::GroupElement> From<&'r GroupElement<G>> for &'r [G] {
+    fn from(value: &'r GroupElement<G>) -> Self {
+        &value.0
+    }
+}
+
+impl<G: crate::GroupElement> From<Vec<G>> for GroupElement<G> {
+    fn from(value: Vec<G>) -> Self {
+        GroupElement::<G>(value)
+    }
+}
+
+impl<const SCALAR_LIMBS: usize, G: BoundedGroupElement<SCALAR_LIMBS>>
+    BoundedGroupElement<SCALAR_LIMBS> for GroupElement<G>
+{
+    fn lower_bound(public_parameters: &Self::PublicParameters) -> Uint<SCALAR_LIMBS> {
+        G::lower_bound(&public_parameters.public_parameters)
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        S: KnownOrderScalar<SCALAR_LIMBS> + Mul<G, Output = G>,
+        G: KnownOrderGroupElement<SCALAR_LIMBS, Scalar = S>,
+    > Mul<GroupElement<G>> for Scalar<SCALAR_LIMBS, crate::Scalar<SCALAR_LIMBS, G>>
+{
+    type Output = GroupElement<G>;
+
+    fn mul(self, rhs: GroupElement<G>) -> Self::Output {
+        GroupElement::<G>(rhs.0.into_iter().map(|element| self.0 * element).collect())
+    }
+}
+
+impl<'r, const SCALAR_LIMBS: usize, G: KnownOrderGroupElement<SCALAR_LIMBS>>
+    Mul<&'r GroupElement<G>> for Scalar<SCALAR_LIMBS, G::Scalar>
+{
+    type Output = GroupElement<G>;
+
+    fn mul(self, rhs: &'r GroupElement<G>) -> Self::Output {
+        self * rhs.clone()
+    }
+}
+
+impl<
+        const SCALAR_LIMBS: usize,
+        S: KnownOrderScalar<SCALAR_LIMBS> + Mul<G, Output = G>,
+        G: KnownOrderGroupElement<SCALAR_LIMBS, Scalar = S>,
+    > KnownOrderGroupElement<SCALAR_LIMBS> for GroupElement<G>
+where
+    S: Default + ConditionallySelectable,
+{
+    type Scalar = Scalar<SCALAR_LIMBS, S>;
+
+    fn order_from_public_parameters(
+        public_parameters: &Self::PublicParameters,
+    ) -> Uint<SCALAR_LIMBS> {
+        G::order_from_public_parameters(&public_parameters.public_parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::U256;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{multiplicative, secp256k1, test_helpers, CyclicGroupElement};
+
+    fn points(size: u64) -> Vec<secp256k1::GroupElement> {
+        let generator = secp256k1::GroupElement::generator_from_public_parameters(
+            &secp256k1::group_element::PublicParameters::default(),
+        )
+        .unwrap();
+
+        (1..=size)
+            .map(|i| generator.scalar_mul(&U256::from(i)))
+            .collect()
+    }
+
+    #[test]
+    fn instantiates_with_runtime_length() {
+        let public_parameters =
+            PublicParameters::new(secp256k1::group_element::PublicParameters::default(), 3);
+
+        let element = GroupElement::from(points(3));
+        assert_eq!(element.public_parameters(), public_parameters);
+        assert_eq!(
+            GroupElement::new(element.value(), &public_parameters).unwrap(),
+            element
+        );
+
+        assert_eq!(
+            GroupElement::<secp256k1::GroupElement>::new(
+                GroupElement::from(points(2)).value(),
+                &public_parameters
+            ),
+            Err(crate::Error::InvalidGroupElement)
+        );
+        assert_eq!(
+            GroupElement::<secp256k1::GroupElement>::new(
+                element.value(),
+                &PublicParameters::new(secp256k1::group_element::PublicParameters::default(), 0)
+            ),
+            Err(crate::Error::InvalidPublicParameters)
+        );
+    }
+
+    #[test]
+    fn operates_coordinate_wise() {
+        let element = GroupElement::from(points(4));
+        let coordinates: Vec<_> = element.clone().into();
+
+        let sum: Vec<_> = (element.clone() + &element).into();
+        let doubled: Vec<_> = element.double().into();
+        let tripled: Vec<_> = element.scalar_mul(&U256::from(3u64)).into();
+        let multi_scalar_mul: Vec<_> = GroupElement::multi_scalar_mul_vartime(&[
+            (element.clone(), U256::from(2u64)),
+            (element.clone(), U256::ONE),
+        ])
+        .unwrap()
+        .into();
+
+        for i in 0..4 {
+            assert_eq!(sum[i], coordinates[i].double());
+            assert_eq!(doubled[i], coordinates[i].double());
+            assert_eq!(tripled[i], coordinates[i].scalar_mul(&U256::from(3u64)));
+            assert_eq!(multi_scalar_mul[i], tripled[i]);
+        }
+
+        assert!(bool::from((element.clone() - element).is_neutral()));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add vectors of different lengths")]
+    fn panics_on_adding_vectors_of_different_lengths() {
+        let _ = GroupElement::from(points(3)) + GroupElement::from(points(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot subtract vectors of different lengths")]
+    fn panics_on_subtracting_vectors_of_different_lengths() {
+        let _ = GroupElement::from(points(2)) - GroupElement::from(points(3));
+    }
+
+    #[test]
+    fn satisfies_conformance() {
+        let public_parameters = PublicParameters::new(
+            multiplicative::PublicParameters::new(secp256k1::MODULUS).unwrap(),
+            3,
+        );
+        let one =
+            multiplicative::Value::new(U256::ONE, &public_parameters.public_parameters).unwrap();
+        let zero =
+            multiplicative::Value::new(U256::ZERO, &public_parameters.public_parameters).unwrap();
+
+        // Values of the wrong length are invalid, as are ones with a single invalid coordinate.
+        test_helpers::samplable_conformance::<
+            GroupElement<multiplicative::GroupElement<{ U256::LIMBS }>>,
+        >(
+            &public_parameters,
+            vec![
+                Value::from(vec![one, one]),
+                Value::from(vec![one, one, one, one]),
+                Value::from(vec![one, zero, one]),
+            ],
+            &mut OsRng,
+        );
+    }
+}
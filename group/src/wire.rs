@@ -0,0 +1,98 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! A compact binary wire format for all serializable types, including every
+//! [`GroupElement::Value`](crate::GroupElement::Value), proof and protocol message.
+//!
+//! Values are serialized as for a non human-readable format, and so have fixed-size field
+//! encodings: `Uint`s are encoded as their fixed-width little-endian bytes, elliptic curve points
+//! as their SEC1-compressed (or, for Ristretto, 32-byte compressed) encoding, and scalars as
+//! their 32 bytes. Lengths and small integers are varint-encoded, following the
+//! [`postcard`](https://postcard.jamesmunns.com/wire-format) wire format.
+
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+/// A compact binary wire encoding.
+pub trait WireEncoding: Sized {
+    /// Encode `self` in the wire format.
+    fn to_wire_bytes(&self) -> Result<Vec<u8>>;
+
+    /// Decode a value from its wire encoding, rejecting any trailing bytes.
+    fn from_wire_bytes(bytes: &[u8]) -> Result<Self>;
+}
+
+impl<T: Serialize + for<'a> Deserialize<'a>> WireEncoding for T {
+    fn to_wire_bytes(&self) -> Result<Vec<u8>> {
+        postcard::to_allocvec(self).map_err(|_| Error::WireEncoding)
+    }
+
+    fn from_wire_bytes(bytes: &[u8]) -> Result<Self> {
+        match postcard::take_from_bytes(bytes) {
+            Ok((value, [])) => Ok(value),
+            _ => Err(Error::WireEncoding),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Debug;
+
+    use crypto_bigint::{Random, U256};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{ristretto, secp256k1, CyclicGroupElement, GroupElement, Samplable};
+
+    fn assert_wire_size<T: WireEncoding + PartialEq + Debug>(value: &T, size: usize) {
+        let bytes = value.to_wire_bytes().unwrap();
+
+        assert_eq!(bytes.len(), size);
+        assert_eq!(&T::from_wire_bytes(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn encodes_in_fixed_size_fields() {
+        let secp256k1_scalar =
+            secp256k1::Scalar::sample(&secp256k1::scalar::PublicParameters::default(), &mut OsRng)
+                .unwrap();
+        let secp256k1_point = secp256k1::GroupElement::generator_from_public_parameters(
+            &secp256k1::group_element::PublicParameters::default(),
+        )
+        .unwrap()
+        .scalar_mul(&U256::random(&mut OsRng));
+        let ristretto_scalar =
+            ristretto::Scalar::sample(&ristretto::scalar::PublicParameters::default(), &mut OsRng)
+                .unwrap();
+        let ristretto_point = ristretto::GroupElement::generator_from_public_parameters(
+            &ristretto::group_element::PublicParameters::default(),
+        )
+        .unwrap()
+        .scalar_mul(&U256::random(&mut OsRng));
+
+        assert_wire_size(&U256::random(&mut OsRng), 32);
+        assert_wire_size(&secp256k1_scalar.value(), 32);
+        // Points are encoded as their length-prefixed, 33-byte SEC1-compressed encoding.
+        assert_wire_size(&secp256k1_point.value(), 34);
+        assert_wire_size(&ristretto_scalar.value(), 32);
+        assert_wire_size(&ristretto_point.value(), 32);
+
+        // Lengths are varint-encoded.
+        assert_wire_size(&vec![secp256k1_point.value(); 3], 1 + 3 * 34);
+        assert_wire_size(&vec![ristretto_point.value(); 200], 2 + 200 * 32);
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        let bytes = [U256::random(&mut OsRng).to_wire_bytes().unwrap(), vec![0]].concat();
+
+        assert!(matches!(
+            U256::from_wire_bytes(&bytes).unwrap_err(),
+            Error::WireEncoding
+        ));
+    }
+}
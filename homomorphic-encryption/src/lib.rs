@@ -1,7 +1,12 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::{collections::HashMap, fmt::Debug, ops::BitAnd};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{fmt::Debug, ops::BitAnd};
 
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
@@ -12,10 +17,11 @@ use crypto_bigint::{
     CheckedAdd, CheckedMul, NonZero, RandomMod, Uint,
 };
 use group::{
-    GroupElement, KnownOrderGroupElement, KnownOrderScalar, PartyID, Samplable,
-    StatisticalSecuritySizedNumber,
+    collections::HashMap, GroupElement, KnownOrderGroupElement, KnownOrderScalar, PartyID,
+    Samplable, StatisticalSecuritySizedNumber, VartimeGroupElement,
 };
 use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// An error in encryption-related operations.
 #[derive(thiserror::Error, Clone, Debug, PartialEq)]
@@ -32,15 +38,15 @@ pub enum Error {
 
 /// The Result of `new()` operation for types implementing the
 /// [`AdditivelyHomomorphicEncryptionKey`] trait.
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// An Encryption Key of an Additively Homomorphic Encryption scheme.
 pub trait AdditivelyHomomorphicEncryptionKey<const PLAINTEXT_SPACE_SCALAR_LIMBS: usize>:
     PartialEq + Clone + Debug + Eq
 {
     type PlaintextSpaceGroupElement: KnownOrderScalar<PLAINTEXT_SPACE_SCALAR_LIMBS>;
-    type RandomnessSpaceGroupElement: GroupElement + Samplable;
-    type CiphertextSpaceGroupElement: GroupElement;
+    type RandomnessSpaceGroupElement: GroupElement + Samplable + Zeroize;
+    type CiphertextSpaceGroupElement: GroupElement + VartimeGroupElement;
 
     /// The public parameters of the encryption scheme.
     ///
@@ -351,13 +357,15 @@ pub trait AdditivelyHomomorphicEncryptionKey<const PLAINTEXT_SPACE_SCALAR_LIMBS:
 }
 
 /// A Decryption Key of an Additively Homomorphic Encryption scheme.
+///
+/// The secret key must be wiped from memory once the decryption key is dropped.
 pub trait AdditivelyHomomorphicDecryptionKey<
     const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
->: AsRef<EncryptionKey> + Clone + PartialEq
+>: AsRef<EncryptionKey> + Clone + PartialEq + ZeroizeOnDrop
 {
     /// The decryption key used for decryption.
-    type SecretKey;
+    type SecretKey: Zeroize;
 
     /// Instantiate the decryption key from the public parameters of the encryption scheme,
     /// and the secret key.
@@ -392,13 +400,15 @@ pub trait AdditivelyHomomorphicDecryptionKey<
 }
 
 /// A Decryption Key Share of a Threshold Additively Homomorphic Encryption scheme.
+///
+/// The secret key share must be wiped from memory once the decryption key share is dropped.
 pub trait AdditivelyHomomorphicDecryptionKeyShare<
     const PLAINTEXT_SPACE_SCALAR_LIMBS: usize,
     EncryptionKey: AdditivelyHomomorphicEncryptionKey<PLAINTEXT_SPACE_SCALAR_LIMBS>,
->: AsRef<EncryptionKey> + Clone + PartialEq
+>: AsRef<EncryptionKey> + Clone + PartialEq + ZeroizeOnDrop
 {
     /// The decryption key share used for decryption.
-    type SecretKeyShare;
+    type SecretKeyShare: Zeroize;
     /// A decryption share of a ciphertext in the process of Threshold Decryption.
     type DecryptionShare: Clone + Debug + PartialEq + Eq;
     /// A proof that a decryption share was correctly computed on a ciphertext using the decryption
@@ -426,7 +436,7 @@ pub trait AdditivelyHomomorphicDecryptionKeyShare<
         party_id: PartyID,
         secret_key_share: Self::SecretKeyShare,
         public_parameters: &Self::PublicParameters,
-    ) -> std::result::Result<Self, Self::Error>;
+    ) -> core::result::Result<Self, Self::Error>;
 
     /// The Semi-honest variant of Partial Decryption, returns the decryption share without proving
     /// correctness.
@@ -481,7 +491,7 @@ pub trait AdditivelyHomomorphicDecryptionKeyShare<
         decryption_shares: HashMap<PartyID, Self::DecryptionShare>,
         lagrange_coefficients: HashMap<PartyID, Self::LagrangeCoefficient>,
         public_parameters: &Self::PublicParameters,
-    ) -> std::result::Result<EncryptionKey::PlaintextSpaceGroupElement, Self::Error>;
+    ) -> core::result::Result<EncryptionKey::PlaintextSpaceGroupElement, Self::Error>;
 
     /// Finalizes the Threshold Decryption protocol by combining decryption shares. This is the
     /// Maliciously secure variant in which the corresponding zero-knowledge proofs are verified,
@@ -495,7 +505,7 @@ pub trait AdditivelyHomomorphicDecryptionKeyShare<
         lagrange_coefficients: HashMap<PartyID, Self::LagrangeCoefficient>,
         public_parameters: &Self::PublicParameters,
         rng: &mut impl CryptoRngCore,
-    ) -> std::result::Result<Vec<EncryptionKey::PlaintextSpaceGroupElement>, Self::Error>;
+    ) -> core::result::Result<Vec<EncryptionKey::PlaintextSpaceGroupElement>, Self::Error>;
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -1,6 +1,8 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+use alloc::vec::Vec;
+
 pub use decommitment_round::Decommitment;
 pub use proof_share_round::ProofShare;
 
@@ -28,6 +30,7 @@ pub(super) mod test_helpers {
 
     use criterion::measurement::{Measurement, WallTime};
     use group::PartyID;
+    use proof::session::{network::Fault, SessionID};
     use rand_core::OsRng;
 
     use super::*;
@@ -53,6 +56,14 @@ pub(super) mod test_helpers {
         .collect()
     }
 
+    /// The identifier of an aggregation session among `provers`.
+    pub fn session_id(provers: &HashSet<PartyID>) -> SessionID {
+        let mut provers: Vec<_> = provers.iter().copied().collect();
+        provers.sort();
+
+        SessionID::derive(b"maurer aggregation", &provers).unwrap()
+    }
+
     /// Setup aggregation tests.
     fn setup<const REPETITIONS: usize, Lang: Language<REPETITIONS>>(
         language_public_parameters: &Lang::PublicParameters,
@@ -84,6 +95,7 @@ pub(super) mod test_helpers {
                 let party = commitment_round::Party::new_session(
                     party_id,
                     provers.clone(),
+                    session_id(&provers),
                     language_public_parameters.clone(),
                     PhantomData,
                     witnesses,
@@ -105,13 +117,19 @@ pub(super) mod test_helpers {
     ) {
         let (_, commitment_round_parties) =
             setup::<REPETITIONS, Lang>(language_public_parameters, number_of_parties, batch_size);
+        let session_id = session_id(&commitment_round_parties.keys().copied().collect());
 
         let (.., (proof, statements)) =
             proof::aggregation::test_helpers::aggregates(commitment_round_parties);
 
         assert!(
             proof
-                .verify(&PhantomData, language_public_parameters, statements)
+                .verify_in_session(
+                    &session_id,
+                    &PhantomData,
+                    language_public_parameters,
+                    statements
+                )
                 .is_ok(),
             "valid aggregated proofs should verify"
         );
@@ -135,6 +153,119 @@ pub(super) mod test_helpers {
         );
     }
 
+    /// Test that a threshold-tolerant Maurer aggregation session for `Lang` excludes unresponsive
+    /// parties and aggregates over the remaining quorum.
+    pub fn aggregates_over_remaining_quorum<
+        const REPETITIONS: usize,
+        Lang: Language<REPETITIONS> + 'static,
+    >(
+        language_public_parameters: &Lang::PublicParameters,
+        threshold: PartyID,
+        number_of_parties: usize,
+        batch_size: usize,
+    ) {
+        let witnesses = sample_witnesses_for_aggregation::<REPETITIONS, Lang>(
+            language_public_parameters,
+            number_of_parties,
+            batch_size,
+        );
+        let number_of_parties: u16 = witnesses.len().try_into().unwrap();
+        let provers: HashSet<_> = HashSet::from_iter(1..=number_of_parties);
+
+        let public_parameters = language_public_parameters.clone();
+        let (excluded_parties, (proof, statements)) =
+            proof::aggregation::test_helpers::aggregates_over_remaining_quorum(
+                threshold,
+                provers.clone(),
+                session_id(&provers),
+                move |party_id, session_id, provers| {
+                    commitment_round::Party::<REPETITIONS, Lang, PhantomData<()>>::new_session(
+                        party_id,
+                        provers,
+                        session_id,
+                        public_parameters.clone(),
+                        PhantomData,
+                        witnesses[usize::from(party_id) - 1].clone(),
+                        &mut OsRng,
+                    )
+                    .unwrap()
+                },
+            );
+
+        assert_eq!(
+            excluded_parties.len(),
+            usize::from(number_of_parties - threshold)
+        );
+
+        // The restarted session is a new session, among the remaining provers.
+        let remaining_provers = provers
+            .iter()
+            .copied()
+            .filter(|party_id| !excluded_parties.contains(party_id))
+            .collect();
+        assert!(
+            proof
+                .verify_in_session(
+                    &session_id(&provers).restart(&remaining_provers),
+                    &PhantomData,
+                    language_public_parameters,
+                    statements
+                )
+                .is_ok(),
+            "valid aggregated proofs over the remaining quorum should verify"
+        );
+    }
+
+    /// Test that the Maurer aggregation protocol for `Lang` succeeds over the simulated broadcast
+    /// network.
+    pub fn aggregates_over_network<
+        const REPETITIONS: usize,
+        Lang: Language<REPETITIONS> + 'static,
+    >(
+        language_public_parameters: &Lang::PublicParameters,
+        number_of_parties: usize,
+        batch_size: usize,
+    ) {
+        let (_, commitment_round_parties) =
+            setup::<REPETITIONS, Lang>(language_public_parameters, number_of_parties, batch_size);
+        let session_id = session_id(&commitment_round_parties.keys().copied().collect());
+
+        let (proof, statements) =
+            proof::aggregation::test_helpers::aggregates_over_network(commitment_round_parties);
+
+        assert!(
+            proof
+                .verify_in_session(
+                    &session_id,
+                    &PhantomData,
+                    language_public_parameters,
+                    statements
+                )
+                .is_ok(),
+            "valid aggregated proofs should verify"
+        );
+    }
+
+    /// Test that the Maurer aggregation protocol for `Lang` aborts identifiably when `fault` is
+    /// injected into the messages of a party over the simulated broadcast network.
+    pub fn faulty_party_aborts_session_identifiably<
+        const REPETITIONS: usize,
+        Lang: Language<REPETITIONS> + 'static,
+    >(
+        language_public_parameters: &Lang::PublicParameters,
+        number_of_parties: usize,
+        batch_size: usize,
+        fault: Fault,
+    ) {
+        let (_, commitment_round_parties) =
+            setup::<REPETITIONS, Lang>(language_public_parameters, number_of_parties, batch_size);
+
+        proof::aggregation::test_helpers::faulty_party_aborts_session_identifiably(
+            commitment_round_parties,
+            fault,
+        );
+    }
+
     pub fn wrong_decommitment_aborts_session_identifiably<
         const REPETITIONS: usize,
         Lang: Language<REPETITIONS>,
@@ -169,6 +300,87 @@ pub(super) mod test_helpers {
         proof::aggregation::test_helpers::failed_proof_share_verification_aborts_session_identifiably(commitment_round_parties, wrong_commitment_round_parties);
     }
 
+    /// Test that the Maurer aggregation protocol for `Lang` rejects a proof share generated in
+    /// another session, even by a party using the same randomizers over the same statements.
+    pub fn proof_share_from_another_session_aborts_session_identifiably<
+        const REPETITIONS: usize,
+        Lang: Language<REPETITIONS>,
+    >(
+        language_public_parameters: &Lang::PublicParameters,
+        number_of_parties: usize,
+        batch_size: usize,
+    ) {
+        let (_, commitment_round_parties) =
+            setup::<REPETITIONS, Lang>(language_public_parameters, number_of_parties, batch_size);
+
+        let other_session_id = SessionID::new([0xff; 32]);
+        let other_session_commitment_round_parties = commitment_round_parties
+            .clone()
+            .into_iter()
+            .map(|(party_id, party)| {
+                (
+                    party_id,
+                    commitment_round::Party {
+                        session_id: other_session_id,
+                        ..party
+                    },
+                )
+            })
+            .collect();
+
+        proof::aggregation::test_helpers::proof_share_from_another_session_aborts_session_identifiably(
+            commitment_round_parties,
+            other_session_commitment_round_parties,
+        );
+    }
+
+    /// Test that a restarted Maurer aggregation session for `Lang` rejects a proof share generated
+    /// in the aborted attempt it restarts, in which an additional party was excluded.
+    pub fn proof_share_from_aborted_attempt_aborts_restarted_session_identifiably<
+        const REPETITIONS: usize,
+        Lang: Language<REPETITIONS>,
+    >(
+        language_public_parameters: &Lang::PublicParameters,
+        number_of_parties: usize,
+        batch_size: usize,
+    ) {
+        let (_, commitment_round_parties) =
+            setup::<REPETITIONS, Lang>(language_public_parameters, number_of_parties, batch_size);
+
+        let provers: HashSet<_> = commitment_round_parties.keys().copied().collect();
+        let excluded_party = provers.iter().copied().max().unwrap() + 1;
+        let aborted_session_id = session_id(
+            &provers
+                .iter()
+                .copied()
+                .chain(iter::once(excluded_party))
+                .collect(),
+        );
+        let restarted_session_id = aborted_session_id.restart(&provers);
+
+        let [restarted_commitment_round_parties, aborted_commitment_round_parties] =
+            [restarted_session_id, aborted_session_id].map(|session_id| {
+                commitment_round_parties
+                    .clone()
+                    .into_iter()
+                    .map(|(party_id, party)| {
+                        (
+                            party_id,
+                            commitment_round::Party {
+                                session_id,
+                                ..party
+                            },
+                        )
+                    })
+                    .collect()
+            });
+
+        proof::aggregation::test_helpers::proof_share_from_another_session_aborts_session_identifiably(
+            restarted_commitment_round_parties,
+            aborted_commitment_round_parties,
+        );
+    }
+
     /// Benchmark aggregation.
     pub fn benchmark_aggregation<const REPETITIONS: usize, Lang: Language<REPETITIONS>>(
         language_public_parameters: &Lang::PublicParameters,
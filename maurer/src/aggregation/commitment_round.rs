@@ -1,17 +1,20 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::HashSet;
+use alloc::{string::ToString, vec::Vec};
 
-use commitment::Commitment;
-use crypto_bigint::{rand_core::CryptoRngCore, Random};
-use group::{ComputationalSecuritySizedNumber, GroupElement, PartyID};
-use proof::{aggregation, aggregation::CommitmentRoundParty};
+use commitment::{Commitment, HashCommitment};
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::{collections::HashSet, GroupElement, PartyID};
+use proof::{aggregation, aggregation::CommitmentRoundParty, session::SessionID};
 use serde::Serialize;
+use zeroize::Zeroizing;
 
 use crate::{
     aggregation::{decommitment_round, decommitment_round::Decommitment},
-    language, Error, Proof, Result,
+    language,
+    language::StatementSpaceValue,
+    Error, Proof, Result,
 };
 
 #[cfg_attr(any(test, feature = "test_helpers"), derive(Clone))]
@@ -28,10 +31,12 @@ pub struct Party<
     pub party_id: PartyID,
     // The set of parties ${P_i}$ participating in the proof aggregation protocol.
     pub provers: HashSet<PartyID>,
+    // The identifier of this session, bound into the transcript of every round.
+    pub session_id: SessionID,
     pub language_public_parameters: Language::PublicParameters,
     pub protocol_context: ProtocolContext,
-    pub witnesses: Vec<Language::WitnessSpaceGroupElement>,
-    pub randomizers: [Language::WitnessSpaceGroupElement; REPETITIONS],
+    pub witnesses: Zeroizing<Vec<Language::WitnessSpaceGroupElement>>,
+    pub randomizers: Zeroizing<[Language::WitnessSpaceGroupElement; REPETITIONS]>,
     pub statement_masks: [Language::StatementSpaceGroupElement; REPETITIONS],
 }
 
@@ -64,8 +69,6 @@ impl<
             .collect();
         let statements = statements?;
 
-        let commitment_randomness = ComputationalSecuritySizedNumber::random(rng);
-
         let statement_masks_values =
             Language::StatementSpaceGroupElement::batch_normalize_const_generic(
                 self.statement_masks.clone(),
@@ -74,30 +77,28 @@ impl<
         let statements_values =
             Language::StatementSpaceGroupElement::batch_normalize(statements.clone());
 
-        let mut transcript = Proof::<REPETITIONS, Language, ProtocolContext>::setup_transcript(
+        let (commitment, opening) = commitment_scheme::<REPETITIONS, Language, ProtocolContext>(
+            self.party_id,
+            &self.session_id,
             &self.protocol_context,
             &self.language_public_parameters,
-            statements_values.clone(),
-            &statement_masks_values,
+        )?
+        .commit(
+            &(statements_values.clone(), statement_masks_values.to_vec()),
+            rng,
         )?;
 
-        let commitment = Commitment::commit_transcript(
-            self.party_id,
-            COMMITMENT_LABEL.to_string(),
-            &mut transcript,
-            &commitment_randomness,
-        );
-
         let decommitment = Decommitment::<REPETITIONS, Language> {
             statements: statements_values,
             statement_masks: statement_masks_values,
-            commitment_randomness,
+            commitment_randomness: opening.commitment_randomness,
         };
 
         let decommitment_round_party =
             decommitment_round::Party::<REPETITIONS, Language, ProtocolContext> {
                 party_id: self.party_id,
                 provers: self.provers,
+                session_id: self.session_id,
                 language_public_parameters: self.language_public_parameters,
                 protocol_context: self.protocol_context,
                 witnesses: self.witnesses,
@@ -120,6 +121,7 @@ impl<
     pub fn new_session(
         party_id: PartyID,
         provers: HashSet<PartyID>,
+        session_id: SessionID,
         language_public_parameters: Language::PublicParameters,
         protocol_context: ProtocolContext,
         witnesses: Vec<Language::WitnessSpaceGroupElement>,
@@ -136,13 +138,50 @@ impl<
         Ok(Self {
             party_id,
             provers,
+            session_id,
             language_public_parameters,
             protocol_context,
-            witnesses,
-            randomizers,
+            witnesses: Zeroizing::new(witnesses),
+            randomizers: Zeroizing::new(randomizers),
             statement_masks,
         })
     }
 }
 
 pub(super) const COMMITMENT_LABEL: &str = "maurer proof aggregation - commitment round commitment";
+
+/// The statements and statement masks to which a party commits in the commitment round.
+pub(super) type CommittedStatements<const REPETITIONS: usize, Language> = (
+    Vec<StatementSpaceValue<REPETITIONS, Language>>,
+    Vec<StatementSpaceValue<REPETITIONS, Language>>,
+);
+
+/// The [`HashCommitment`] of `party_id` to its statements and statement masks, bound to the
+/// protocol context and the language public parameters, and to the commitment round of session
+/// `session_id`.
+///
+/// The transcript only binds this public data: the committed statements and statement masks are
+/// appended by the [`HashCommitment`] itself.
+pub(super) fn commitment_scheme<
+    const REPETITIONS: usize,
+    Language: language::Language<REPETITIONS>,
+    ProtocolContext: Clone + Serialize,
+>(
+    party_id: PartyID,
+    session_id: &SessionID,
+    protocol_context: &ProtocolContext,
+    language_public_parameters: &Language::PublicParameters,
+) -> Result<HashCommitment<CommittedStatements<REPETITIONS, Language>>> {
+    let mut transcript =
+        Proof::<REPETITIONS, Language, ProtocolContext>::setup_public_parameters_transcript(
+            protocol_context,
+            language_public_parameters,
+        )?;
+    session_id.append_to_transcript(&mut transcript, aggregation::COMMITMENT_ROUND);
+
+    Ok(HashCommitment::new_with_transcript(
+        party_id,
+        COMMITMENT_LABEL.to_string(),
+        transcript,
+    ))
+}
@@ -1,13 +1,20 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::{HashMap, HashSet};
+use alloc::vec::Vec;
 
 use commitment::Commitment;
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::{ComputationalSecuritySizedNumber, PartyID};
-use proof::aggregation::{process_incoming_messages, DecommitmentRoundParty};
+use group::{
+    collections::{HashMap, HashSet},
+    ComputationalSecuritySizedNumber, PartyID,
+};
+use proof::{
+    aggregation::{process_incoming_messages, DecommitmentRoundParty},
+    session::SessionID,
+};
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
 
 use crate::{aggregation::proof_share_round, language, Error, Result};
 
@@ -32,11 +39,12 @@ pub struct Party<
 > {
     pub(super) party_id: PartyID,
     pub(crate) provers: HashSet<PartyID>,
+    pub(super) session_id: SessionID,
     pub(super) language_public_parameters: Language::PublicParameters,
     pub(super) protocol_context: ProtocolContext,
-    pub(super) witnesses: Vec<Language::WitnessSpaceGroupElement>,
+    pub(super) witnesses: Zeroizing<Vec<Language::WitnessSpaceGroupElement>>,
     pub(super) statements: Vec<Language::StatementSpaceGroupElement>,
-    pub(super) randomizers: [Language::WitnessSpaceGroupElement; REPETITIONS],
+    pub(super) randomizers: Zeroizing<[Language::WitnessSpaceGroupElement; REPETITIONS]>,
     pub(super) statement_masks: [Language::StatementSpaceGroupElement; REPETITIONS],
     pub(super) decommitment: Decommitment<REPETITIONS, Language>,
 }
@@ -65,6 +73,7 @@ impl<
             proof_share_round::Party::<REPETITIONS, Language, ProtocolContext> {
                 party_id: self.party_id,
                 provers: self.provers,
+                session_id: self.session_id,
                 language_public_parameters: self.language_public_parameters,
                 protocol_context: self.protocol_context,
                 witnesses: self.witnesses,
@@ -1,11 +1,18 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::{HashMap, HashSet};
+use alloc::vec::Vec;
 
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::{helpers::FlatMapResults, ComputationalSecuritySizedNumber, GroupElement, PartyID};
-use proof::aggregation::{process_incoming_messages, ProofAggregationRoundParty};
+use group::{
+    collections::{HashMap, HashSet},
+    helpers::FlatMapResults,
+    ComputationalSecuritySizedNumber, GroupElement, PartyID,
+};
+use proof::{
+    aggregation::{process_incoming_messages, ProofAggregationRoundParty},
+    session::SessionID,
+};
 use serde::Serialize;
 
 use crate::{
@@ -27,6 +34,7 @@ pub struct Party<
 > {
     pub(super) party_id: PartyID,
     pub(crate) provers: HashSet<PartyID>,
+    pub(super) session_id: SessionID,
     pub(super) language_public_parameters: Language::PublicParameters,
     pub(super) protocol_context: ProtocolContext,
     pub(super) statement_masks:
@@ -116,9 +124,10 @@ impl<
             Language::StatementSpaceGroupElement::batch_normalize_const_generic(
                 self.aggregated_statement_masks,
             );
-        let aggregated_proof = Proof::new(aggregated_statement_masks, aggregated_responses);
+        let aggregated_proof = Proof::new(aggregated_statement_masks.clone(), aggregated_responses);
         if aggregated_proof
-            .verify(
+            .verify_in_session(
+                &self.session_id,
                 &self.protocol_context,
                 &self.language_public_parameters,
                 self.aggregated_statements.clone(),
@@ -128,14 +137,16 @@ impl<
             // Identifiable abort logic: using the challenges of the aggregated proof, validate the
             // individual proofs (i.e. proof share, statement mask produced by every
             // party).
-            let mut transcript = Proof::<REPETITIONS, Language, ProtocolContext>::setup_transcript(
-                &self.protocol_context,
-                &self.language_public_parameters,
-                Language::StatementSpaceGroupElement::batch_normalize(
-                    self.aggregated_statements.clone(),
-                ),
-                &aggregated_statement_masks,
-            )?;
+            let mut transcript =
+                Proof::<REPETITIONS, Language, ProtocolContext>::setup_challenge_transcript(
+                    Some(&self.session_id),
+                    &self.protocol_context,
+                    &self.language_public_parameters,
+                    Language::StatementSpaceGroupElement::batch_normalize(
+                        self.aggregated_statements.clone(),
+                    ),
+                    &aggregated_statement_masks,
+                )?;
 
             let challenges: [Vec<ComputationalSecuritySizedNumber>; REPETITIONS] =
                 Proof::<REPETITIONS, Language, ProtocolContext>::compute_challenges(
@@ -150,7 +161,7 @@ impl<
                         party_id,
                         Proof::<REPETITIONS, Language, ProtocolContext>::new(
                             // Same parties participating in all rounds, safe to `.unwrap()`.
-                            *self.statement_masks.get(&party_id).unwrap(),
+                            self.statement_masks.get(&party_id).unwrap().clone(),
                             Language::WitnessSpaceGroupElement::batch_normalize_const_generic(
                                 proof_share,
                             ),
@@ -1,17 +1,25 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::{HashMap, HashSet};
+use alloc::vec::Vec;
 
 use commitment::Commitment;
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::{helpers::FlatMapResults, GroupElement, PartyID};
-use proof::aggregation::{process_incoming_messages, ProofShareRoundParty};
+use group::{
+    collections::{HashMap, HashSet},
+    helpers::FlatMapResults,
+    GroupElement, PartyID,
+};
+use proof::{
+    aggregation::{process_incoming_messages, ProofShareRoundParty},
+    session::SessionID,
+};
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
 
 use crate::{
     aggregation::{
-        commitment_round::COMMITMENT_LABEL, decommitment_round::Decommitment,
+        commitment_round::commitment_scheme, decommitment_round::Decommitment,
         proof_aggregation_round,
     },
     language::{GroupsPublicParametersAccessors, WitnessSpaceValue},
@@ -37,11 +45,12 @@ pub struct Party<
 > {
     pub(super) party_id: PartyID,
     pub(crate) provers: HashSet<PartyID>,
+    pub(super) session_id: SessionID,
     pub(super) language_public_parameters: Language::PublicParameters,
     pub(super) protocol_context: ProtocolContext,
-    pub(super) witnesses: Vec<Language::WitnessSpaceGroupElement>,
+    pub(super) witnesses: Zeroizing<Vec<Language::WitnessSpaceGroupElement>>,
     pub(super) statements: Vec<Language::StatementSpaceGroupElement>,
-    pub(super) randomizers: [Language::WitnessSpaceGroupElement; REPETITIONS],
+    pub(super) randomizers: Zeroizing<[Language::WitnessSpaceGroupElement; REPETITIONS]>,
     pub(super) statement_masks: [Language::StatementSpaceGroupElement; REPETITIONS],
     pub(super) commitments: HashMap<PartyID, Commitment>,
 }
@@ -67,35 +76,35 @@ impl<
         let decommitments =
             process_incoming_messages(self.party_id, self.provers.clone(), decommitments, true)?;
 
-        let reconstructed_commitments: Result<HashMap<PartyID, Commitment>> = decommitments
+        let mut miscommitting_parties: Vec<PartyID> = decommitments
             .iter()
             .map(|(party_id, decommitment)| {
-                Proof::<REPETITIONS, Language, ProtocolContext>::setup_transcript(
+                commitment_scheme::<REPETITIONS, Language, ProtocolContext>(
+                    *party_id,
+                    &self.session_id,
                     &self.protocol_context,
                     &self.language_public_parameters,
-                    decommitment.statements.clone(),
-                    &decommitment.statement_masks,
                 )
-                .map(|mut transcript| {
+                .map(|commitment_scheme| {
                     (
                         *party_id,
-                        Commitment::commit_transcript(
-                            *party_id,
-                            COMMITMENT_LABEL.to_string(),
-                            &mut transcript,
-                            &decommitment.commitment_randomness,
+                        commitment_scheme.verify(
+                            &self.commitments[party_id],
+                            &commitment::hash::Decommitment {
+                                value: (
+                                    decommitment.statements.clone(),
+                                    decommitment.statement_masks.to_vec(),
+                                ),
+                                commitment_randomness: decommitment.commitment_randomness,
+                            },
                         ),
                     )
                 })
             })
-            .collect();
-
-        let reconstructed_commitments: HashMap<PartyID, Commitment> = reconstructed_commitments?;
-
-        let mut miscommitting_parties: Vec<PartyID> = decommitments
-            .keys()
-            .cloned()
-            .filter(|party_id| reconstructed_commitments[party_id] != self.commitments[party_id])
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .filter(|(_, verification)| verification.is_err())
+            .map(|(party_id, _)| party_id)
             .collect();
         miscommitting_parties.sort();
 
@@ -112,6 +121,7 @@ impl<
                     *party_id,
                     decommitment
                         .statement_masks
+                        .clone()
                         .map(|statement_mask| {
                             Language::StatementSpaceGroupElement::new(
                                 statement_mask,
@@ -226,16 +236,17 @@ impl<
             .collect();
 
         let responses = Proof::<REPETITIONS, Language, ProtocolContext>::prove_inner(
+            Some(&self.session_id),
             &self.protocol_context,
             &self.language_public_parameters,
-            self.witnesses,
+            &self.witnesses,
             aggregated_statements.clone(),
-            self.randomizers,
+            &self.randomizers,
             aggregated_statement_masks.clone(),
         )?
         .responses;
 
-        let proof_share = ProofShare(responses);
+        let proof_share = ProofShare(responses.clone());
 
         let responses = responses
             .map(|value| {
@@ -256,6 +267,7 @@ impl<
             proof_aggregation_round::Party::<REPETITIONS, Language, ProtocolContext> {
                 party_id: self.party_id,
                 provers: self.provers,
+                session_id: self.session_id,
                 language_public_parameters: self.language_public_parameters,
                 protocol_context: self.protocol_context,
                 statements,
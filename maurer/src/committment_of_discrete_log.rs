@@ -1,10 +1,14 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::{marker::PhantomData, ops::Mul};
+use core::{marker::PhantomData, ops::Mul};
 
 use commitment::HomomorphicCommitmentScheme;
-use group::{self_product, BoundedGroupElement, CyclicGroupElement, Samplable};
+use group::{
+    self_product, BoundedGroupElement, CyclicGroupElement, Samplable, VartimeGroupElement,
+};
+use proof::TranscriptVersion;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::{language::GroupsPublicParameters, Result, SOUND_PROOFS_REPETITIONS};
 
@@ -32,8 +36,9 @@ impl<
             + Samplable
             + Mul<GroupElement, Output = GroupElement>
             + for<'r> Mul<&'r GroupElement, Output = GroupElement>
-            + Copy,
-        GroupElement: CyclicGroupElement,
+            + Copy
+            + Zeroize,
+        GroupElement: CyclicGroupElement + VartimeGroupElement,
         CommitmentScheme: HomomorphicCommitmentScheme<
             SCALAR_LIMBS,
             MessageSpaceGroupElement = self_product::GroupElement<1, Scalar>,
@@ -60,7 +65,7 @@ impl<
         language_public_parameters: &Self::PublicParameters,
     ) -> Result<Self::StatementSpaceGroupElement> {
         let base = GroupElement::new(
-            language_public_parameters.base,
+            language_public_parameters.base.clone(),
             &language_public_parameters
                 .groups_public_parameters
                 .statement_space_public_parameters
@@ -195,7 +200,8 @@ impl<
             + Samplable
             + Mul<GroupElement, Output = GroupElement>
             + for<'r> Mul<&'r GroupElement, Output = GroupElement>
-            + Copy,
+            + Copy
+            + Zeroize,
         GroupElement: group::GroupElement<
             Value = GroupElementValue,
             PublicParameters = GroupPublicParameters,
@@ -220,6 +226,7 @@ impl<
                 statement_space_public_parameters: group::PublicParameters::<
                     self_product::GroupElement<2, GroupElement>,
                 >::new(group_public_parameters),
+                transcript_version: TranscriptVersion::default(),
             },
             commitment_scheme_public_parameters,
             base,
@@ -1,10 +1,13 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::{marker::PhantomData, ops::Mul};
+use core::{marker::PhantomData, ops::Mul};
 
 use commitment::{pedersen, pedersen::Pedersen, HomomorphicCommitmentScheme};
-use group::{self_product, KnownOrderGroupElement, Samplable};
+use crypto_bigint::{subtle::ConditionallySelectable, Uint};
+use group::{self_product, KnownOrderGroupElement, Samplable, VartimeGroupElement};
+use proof::TranscriptVersion;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::{language::GroupsPublicParameters, Result, SOUND_PROOFS_REPETITIONS};
 
@@ -36,8 +39,10 @@ where
         + Samplable
         + Mul<GroupElement, Output = GroupElement>
         + for<'r> Mul<&'r GroupElement, Output = GroupElement>
-        + Copy,
-    GroupElement: group::GroupElement,
+        + Copy
+        + Into<Uint<SCALAR_LIMBS>>
+        + Zeroize,
+    GroupElement: group::GroupElement + VartimeGroupElement + ConditionallySelectable,
 {
     type WitnessSpaceGroupElement = self_product::GroupElement<3, Scalar>;
     type StatementSpaceGroupElement = self_product::GroupElement<2, GroupElement>;
@@ -63,7 +68,9 @@ where
         let altered_base_commitment_scheme = Pedersen::new(
             &language_public_parameters
                 .commitment_scheme_public_parameters
-                .with_altered_message_generators([language_public_parameters.base_by_discrete_log]),
+                .with_altered_message_generators([language_public_parameters
+                    .base_by_discrete_log
+                    .clone()]),
         )?;
 
         Ok([
@@ -168,11 +175,12 @@ impl<ScalarPublicParameters, GroupPublicParameters, GroupElementValue>
             + Samplable
             + Mul<GroupElement, Output = GroupElement>
             + for<'r> Mul<&'r GroupElement, Output = GroupElement>
-            + Copy,
-        GroupElement: group::GroupElement<
-            Value = GroupElementValue,
-            PublicParameters = GroupPublicParameters,
-        >,
+            + Copy
+            + Into<Uint<SCALAR_LIMBS>>
+            + Zeroize,
+        GroupElement: group::GroupElement<Value = GroupElementValue, PublicParameters = GroupPublicParameters>
+            + VartimeGroupElement
+            + ConditionallySelectable,
     {
         Self {
             groups_public_parameters: GroupsPublicParameters {
@@ -184,6 +192,7 @@ impl<ScalarPublicParameters, GroupPublicParameters, GroupElementValue>
                 statement_space_public_parameters: group::PublicParameters::<
                     self_product::GroupElement<2, GroupElement>,
                 >::new(group_public_parameters),
+                transcript_version: TranscriptVersion::default(),
             },
             commitment_scheme_public_parameters,
             base_by_discrete_log: base_by_discrete_log.value(),
@@ -1,9 +1,10 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 use commitment::{GroupsPublicParametersAccessors, HomomorphicCommitmentScheme};
 use group::{direct_product, self_product, Samplable};
+use proof::TranscriptVersion;
 use serde::{Deserialize, Serialize};
 
 use crate::{language::GroupsPublicParameters, Result, SOUND_PROOFS_REPETITIONS};
@@ -182,6 +183,7 @@ impl<
                         .commitment_space_public_parameters()
                         .clone(),
                 ),
+                transcript_version: TranscriptVersion::default(),
             },
             first_commitment_scheme_public_parameters,
             second_commitment_scheme_public_parameters,
@@ -0,0 +1,381 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+use core::marker::PhantomData;
+
+use commitment::{
+    pedersen::Pedersen, ElGamal, GroupsPublicParametersAccessors, HomomorphicCommitmentScheme,
+};
+use group::{direct_product, KnownOrderGroupElement, Samplable};
+use proof::TranscriptVersion;
+use serde::{Deserialize, Serialize};
+
+use crate::{language::GroupsPublicParameters, Result, SOUND_PROOFS_REPETITIONS};
+
+/// Equality Between Two Commitments With Different Schemes Maurer Language.
+///
+/// $$ (m,\rho_1,\rho_2) \mapsto (\Com^1(m; \rho_1), \Com^2(m; \rho_2)) $$
+///
+/// For two commitment schemes sharing a message space, e.g. [`ElGamal`] and [`Pedersen`] (see
+/// [`ElGamalPedersenLanguage`]).
+///
+/// SECURITY NOTICE:
+/// Because correctness and zero-knowledge is guaranteed for any group in this language, we choose
+/// to provide a fully generic implementation.
+///
+/// However, knowledge-soundness proofs are group-dependent, and thus we can only assure security
+/// for groups for which we know how to prove it.
+///
+/// In the paper, we have proved it for any prime known-order group; so it is safe to use with a
+/// `PrimeOrderGroupElement`.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug, Eq)]
+pub struct Language<
+    const MESSAGE_SPACE_SCALAR_LIMBS: usize,
+    FirstCommitmentScheme,
+    SecondCommitmentScheme,
+> {
+    _first_commitment_choice: PhantomData<FirstCommitmentScheme>,
+    _second_commitment_choice: PhantomData<SecondCommitmentScheme>,
+}
+
+/// Equality Between an [`ElGamal`] and a [`Pedersen`] Commitment Maurer Language.
+pub type ElGamalPedersenLanguage<const SCALAR_LIMBS: usize, GroupElement> = Language<
+    SCALAR_LIMBS,
+    ElGamal<SCALAR_LIMBS, GroupElement>,
+    Pedersen<
+        1,
+        SCALAR_LIMBS,
+        <GroupElement as KnownOrderGroupElement<SCALAR_LIMBS>>::Scalar,
+        GroupElement,
+    >,
+>;
+
+impl<
+        const MESSAGE_SPACE_SCALAR_LIMBS: usize,
+        FirstCommitmentScheme: HomomorphicCommitmentScheme<MESSAGE_SPACE_SCALAR_LIMBS>,
+        SecondCommitmentScheme: HomomorphicCommitmentScheme<
+            MESSAGE_SPACE_SCALAR_LIMBS,
+            MessageSpaceGroupElement = FirstCommitmentScheme::MessageSpaceGroupElement,
+        >,
+    > crate::Language<SOUND_PROOFS_REPETITIONS>
+    for Language<MESSAGE_SPACE_SCALAR_LIMBS, FirstCommitmentScheme, SecondCommitmentScheme>
+where
+    FirstCommitmentScheme::MessageSpaceGroupElement: Samplable,
+    FirstCommitmentScheme::RandomnessSpaceGroupElement: Samplable,
+    SecondCommitmentScheme::RandomnessSpaceGroupElement: Samplable,
+{
+    type WitnessSpaceGroupElement = direct_product::GroupElement<
+        FirstCommitmentScheme::MessageSpaceGroupElement,
+        direct_product::GroupElement<
+            FirstCommitmentScheme::RandomnessSpaceGroupElement,
+            SecondCommitmentScheme::RandomnessSpaceGroupElement,
+        >,
+    >;
+    type StatementSpaceGroupElement = direct_product::GroupElement<
+        FirstCommitmentScheme::CommitmentSpaceGroupElement,
+        SecondCommitmentScheme::CommitmentSpaceGroupElement,
+    >;
+
+    type PublicParameters = PublicParameters<
+        group::PublicParameters<FirstCommitmentScheme::MessageSpaceGroupElement>,
+        group::PublicParameters<FirstCommitmentScheme::RandomnessSpaceGroupElement>,
+        group::PublicParameters<SecondCommitmentScheme::RandomnessSpaceGroupElement>,
+        group::PublicParameters<FirstCommitmentScheme::CommitmentSpaceGroupElement>,
+        group::PublicParameters<SecondCommitmentScheme::CommitmentSpaceGroupElement>,
+        FirstCommitmentScheme::PublicParameters,
+        SecondCommitmentScheme::PublicParameters,
+    >;
+
+    const NAME: &'static str = "Equality Between Two Commitments With Different Schemes";
+
+    fn homomorphose(
+        witness: &Self::WitnessSpaceGroupElement,
+        language_public_parameters: &Self::PublicParameters,
+    ) -> Result<Self::StatementSpaceGroupElement> {
+        let first_commitment_scheme = FirstCommitmentScheme::new(
+            &language_public_parameters.first_commitment_scheme_public_parameters,
+        )?;
+
+        let second_commitment_scheme = SecondCommitmentScheme::new(
+            &language_public_parameters.second_commitment_scheme_public_parameters,
+        )?;
+
+        let (message, randomnesses): (&_, &_) = witness.into();
+        let (first_randomness, second_randomness): (&_, &_) = randomnesses.into();
+
+        Ok((
+            first_commitment_scheme.commit(message, first_randomness),
+            second_commitment_scheme.commit(message, second_randomness),
+        )
+            .into())
+    }
+}
+
+/// The Public Parameters of the Equality Between Two Commitments With Different Schemes Maurer
+/// Language.
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct PublicParameters<
+    MessageSpacePublicParameters,
+    FirstRandomnessSpacePublicParameters,
+    SecondRandomnessSpacePublicParameters,
+    FirstCommitmentSpacePublicParameters,
+    SecondCommitmentSpacePublicParameters,
+    FirstCommitmentSchemePublicParameters,
+    SecondCommitmentSchemePublicParameters,
+> {
+    pub groups_public_parameters: GroupsPublicParameters<
+        direct_product::PublicParameters<
+            MessageSpacePublicParameters,
+            direct_product::PublicParameters<
+                FirstRandomnessSpacePublicParameters,
+                SecondRandomnessSpacePublicParameters,
+            >,
+        >,
+        direct_product::PublicParameters<
+            FirstCommitmentSpacePublicParameters,
+            SecondCommitmentSpacePublicParameters,
+        >,
+    >,
+    pub first_commitment_scheme_public_parameters: FirstCommitmentSchemePublicParameters,
+    pub second_commitment_scheme_public_parameters: SecondCommitmentSchemePublicParameters,
+}
+
+impl<
+        MessageSpacePublicParameters: Clone,
+        FirstRandomnessSpacePublicParameters: Clone,
+        SecondRandomnessSpacePublicParameters: Clone,
+        FirstCommitmentSpacePublicParameters: Clone,
+        SecondCommitmentSpacePublicParameters: Clone,
+        FirstCommitmentSchemePublicParameters: AsRef<
+            commitment::GroupsPublicParameters<
+                MessageSpacePublicParameters,
+                FirstRandomnessSpacePublicParameters,
+                FirstCommitmentSpacePublicParameters,
+            >,
+        >,
+        SecondCommitmentSchemePublicParameters: AsRef<
+            commitment::GroupsPublicParameters<
+                MessageSpacePublicParameters,
+                SecondRandomnessSpacePublicParameters,
+                SecondCommitmentSpacePublicParameters,
+            >,
+        >,
+    >
+    PublicParameters<
+        MessageSpacePublicParameters,
+        FirstRandomnessSpacePublicParameters,
+        SecondRandomnessSpacePublicParameters,
+        FirstCommitmentSpacePublicParameters,
+        SecondCommitmentSpacePublicParameters,
+        FirstCommitmentSchemePublicParameters,
+        SecondCommitmentSchemePublicParameters,
+    >
+{
+    pub fn new(
+        first_commitment_scheme_public_parameters: FirstCommitmentSchemePublicParameters,
+        second_commitment_scheme_public_parameters: SecondCommitmentSchemePublicParameters,
+    ) -> Self {
+        Self {
+            groups_public_parameters: GroupsPublicParameters {
+                witness_space_public_parameters: direct_product::PublicParameters(
+                    first_commitment_scheme_public_parameters
+                        .message_space_public_parameters()
+                        .clone(),
+                    direct_product::PublicParameters(
+                        first_commitment_scheme_public_parameters
+                            .randomness_space_public_parameters()
+                            .clone(),
+                        second_commitment_scheme_public_parameters
+                            .randomness_space_public_parameters()
+                            .clone(),
+                    ),
+                ),
+                statement_space_public_parameters: direct_product::PublicParameters(
+                    first_commitment_scheme_public_parameters
+                        .commitment_space_public_parameters()
+                        .clone(),
+                    second_commitment_scheme_public_parameters
+                        .commitment_space_public_parameters()
+                        .clone(),
+                ),
+                transcript_version: TranscriptVersion::default(),
+            },
+            first_commitment_scheme_public_parameters,
+            second_commitment_scheme_public_parameters,
+        }
+    }
+}
+
+impl<
+        MessageSpacePublicParameters,
+        FirstRandomnessSpacePublicParameters,
+        SecondRandomnessSpacePublicParameters,
+        FirstCommitmentSpacePublicParameters,
+        SecondCommitmentSpacePublicParameters,
+        FirstCommitmentSchemePublicParameters,
+        SecondCommitmentSchemePublicParameters,
+    >
+    AsRef<
+        GroupsPublicParameters<
+            direct_product::PublicParameters<
+                MessageSpacePublicParameters,
+                direct_product::PublicParameters<
+                    FirstRandomnessSpacePublicParameters,
+                    SecondRandomnessSpacePublicParameters,
+                >,
+            >,
+            direct_product::PublicParameters<
+                FirstCommitmentSpacePublicParameters,
+                SecondCommitmentSpacePublicParameters,
+            >,
+        >,
+    >
+    for PublicParameters<
+        MessageSpacePublicParameters,
+        FirstRandomnessSpacePublicParameters,
+        SecondRandomnessSpacePublicParameters,
+        FirstCommitmentSpacePublicParameters,
+        SecondCommitmentSpacePublicParameters,
+        FirstCommitmentSchemePublicParameters,
+        SecondCommitmentSchemePublicParameters,
+    >
+{
+    fn as_ref(
+        &self,
+    ) -> &GroupsPublicParameters<
+        direct_product::PublicParameters<
+            MessageSpacePublicParameters,
+            direct_product::PublicParameters<
+                FirstRandomnessSpacePublicParameters,
+                SecondRandomnessSpacePublicParameters,
+            >,
+        >,
+        direct_product::PublicParameters<
+            FirstCommitmentSpacePublicParameters,
+            SecondCommitmentSpacePublicParameters,
+        >,
+    > {
+        &self.groups_public_parameters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use commitment::{elgamal, pedersen};
+    use group::{secp256k1, CyclicGroupElement, GroupElement};
+    use rand_core::OsRng;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{language, test_helpers};
+
+    type Lang = ElGamalPedersenLanguage<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>;
+
+    fn language_public_parameters() -> language::PublicParameters<SOUND_PROOFS_REPETITIONS, Lang> {
+        PublicParameters::new(
+            elgamal::PublicParameters::derive_default::<
+                { secp256k1::SCALAR_LIMBS },
+                secp256k1::GroupElement,
+            >()
+            .unwrap(),
+            pedersen::PublicParameters::derive_default::<
+                { secp256k1::SCALAR_LIMBS },
+                secp256k1::GroupElement,
+            >()
+            .unwrap(),
+        )
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn valid_proof_verifies(#[case] batch_size: usize) {
+        test_helpers::valid_proof_verifies::<SOUND_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        );
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn invalid_proof_fails_verification(#[case] batch_size: usize) {
+        // No invalid values as secp256k1 statically defines group,
+        // `k256::AffinePoint` assures deserialized values are on curve,
+        // and `Value` can only be instantiated through deserialization
+        test_helpers::invalid_proof_fails_verification::<SOUND_PROOFS_REPETITIONS, Lang>(
+            None,
+            None,
+            &language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn proof_over_invalid_public_parameters_fails_verification(#[case] batch_size: usize) {
+        let verifier_public_parameters = language_public_parameters();
+        let generator = secp256k1::GroupElement::generator_from_public_parameters(
+            &secp256k1::group_element::PublicParameters::default(),
+        )
+        .unwrap();
+
+        let mut prover_public_parameters = verifier_public_parameters.clone();
+        prover_public_parameters
+            .first_commitment_scheme_public_parameters
+            .public_key = generator.value();
+
+        test_helpers::proof_over_invalid_public_parameters_fails_verification::<
+            SOUND_PROOFS_REPETITIONS,
+            Lang,
+        >(
+            &prover_public_parameters,
+            &verifier_public_parameters,
+            batch_size,
+            &mut OsRng,
+        );
+
+        let mut prover_public_parameters = verifier_public_parameters.clone();
+        prover_public_parameters.second_commitment_scheme_public_parameters =
+            prover_public_parameters
+                .second_commitment_scheme_public_parameters
+                .with_altered_randomness_generator((generator + generator).value());
+
+        test_helpers::proof_over_invalid_public_parameters_fails_verification::<
+            SOUND_PROOFS_REPETITIONS,
+            Lang,
+        >(
+            &prover_public_parameters,
+            &verifier_public_parameters,
+            batch_size,
+            &mut OsRng,
+        );
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn proof_with_incomplete_transcript_fails(#[case] batch_size: usize) {
+        test_helpers::proof_with_incomplete_transcript_fails::<SOUND_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(2, 1)]
+    #[case(3, 2)]
+    fn aggregates(#[case] number_of_parties: usize, #[case] batch_size: usize) {
+        test_helpers::aggregates::<SOUND_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters(),
+            number_of_parties,
+            batch_size,
+        );
+    }
+}
@@ -1,9 +1,10 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
-use commitment::{GroupsPublicParametersAccessors, HomomorphicCommitmentScheme};
+use commitment::{ElGamal, GroupsPublicParametersAccessors, HomomorphicCommitmentScheme};
 use group::{direct_product, Samplable};
+use proof::TranscriptVersion;
 use serde::{Deserialize, Serialize};
 
 use crate::{language::GroupsPublicParameters, Result};
@@ -18,6 +19,10 @@ pub struct Language<
     _commitment_choice: PhantomData<CommitmentScheme>,
 }
 
+/// Knowledge of an Opening of an [`ElGamal`] Commitment Maurer Language.
+pub type ElGamalLanguage<const REPETITIONS: usize, const SCALAR_LIMBS: usize, GroupElement> =
+    Language<REPETITIONS, SCALAR_LIMBS, ElGamal<SCALAR_LIMBS, GroupElement>>;
+
 impl<
         const REPETITIONS: usize,
         const MESSAGE_SPACE_SCALAR_LIMBS: usize,
@@ -160,6 +165,7 @@ impl<
                 statement_space_public_parameters: commitment_scheme_public_parameters
                     .commitment_space_public_parameters()
                     .clone(),
+                transcript_version: TranscriptVersion::default(),
             },
             commitment_scheme_public_parameters,
         }
@@ -204,7 +210,7 @@ impl<
 #[cfg(any(test, feature = "benchmarking"))]
 #[allow(unused_imports)]
 mod tests {
-    use commitment::{pedersen, pedersen::Pedersen};
+    use commitment::{elgamal, multipedersen, pedersen, pedersen::Pedersen};
     use group::secp256k1;
     use rand_core::OsRng;
     use rstest::rstest;
@@ -425,6 +431,184 @@ mod tests {
             Lang<SOUND_PROOFS_REPETITIONS, 1>,
         >(&language_public_parameters, number_of_parties, batch_size);
     }
+
+    pub(crate) type VectorLang = Language<
+        SOUND_PROOFS_REPETITIONS,
+        { secp256k1::SCALAR_LIMBS },
+        multipedersen::vector::MultiPedersen<
+            { secp256k1::SCALAR_LIMBS },
+            secp256k1::Scalar,
+            secp256k1::GroupElement,
+        >,
+    >;
+
+    /// Public parameters of the language over commitments to vectors of `dimension` messages,
+    /// where the dimension is only known at runtime.
+    pub(crate) fn vector_language_public_parameters(
+        dimension: usize,
+    ) -> language::PublicParameters<SOUND_PROOFS_REPETITIONS, VectorLang> {
+        PublicParameters::new::<
+            SOUND_PROOFS_REPETITIONS,
+            { secp256k1::SCALAR_LIMBS },
+            multipedersen::vector::MultiPedersen<
+                { secp256k1::SCALAR_LIMBS },
+                secp256k1::Scalar,
+                secp256k1::GroupElement,
+            >,
+        >(multipedersen::vector::PublicParameters::new(
+            pedersen::PublicParameters::derive_default::<
+                { secp256k1::SCALAR_LIMBS },
+                secp256k1::GroupElement,
+            >()
+            .unwrap(),
+            dimension,
+        ))
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(3, 1)]
+    #[case(5, 2)]
+    fn valid_vector_proof_verifies(#[case] dimension: usize, #[case] batch_size: usize) {
+        test_helpers::valid_proof_verifies::<SOUND_PROOFS_REPETITIONS, VectorLang>(
+            &vector_language_public_parameters(dimension),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+
+    #[rstest]
+    #[case(3, 1)]
+    #[case(5, 2)]
+    fn invalid_vector_proof_fails_verification(
+        #[case] dimension: usize,
+        #[case] batch_size: usize,
+    ) {
+        test_helpers::invalid_proof_fails_verification::<SOUND_PROOFS_REPETITIONS, VectorLang>(
+            None,
+            None,
+            &vector_language_public_parameters(dimension),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+
+    #[rstest]
+    #[case(3, 1)]
+    #[case(5, 2)]
+    fn vector_proof_over_invalid_public_parameters_fails_verification(
+        #[case] dimension: usize,
+        #[case] batch_size: usize,
+    ) {
+        let verifier_public_parameters = vector_language_public_parameters(dimension);
+        let mut prover_public_parameters = verifier_public_parameters.clone();
+
+        prover_public_parameters
+            .commitment_scheme_public_parameters
+            .pedersen_public_parameters
+            .message_generators[0] = prover_public_parameters
+            .commitment_scheme_public_parameters
+            .pedersen_public_parameters
+            .randomness_generator;
+
+        test_helpers::proof_over_invalid_public_parameters_fails_verification::<
+            SOUND_PROOFS_REPETITIONS,
+            VectorLang,
+        >(
+            &prover_public_parameters,
+            &verifier_public_parameters,
+            batch_size,
+            &mut OsRng,
+        );
+    }
+
+    #[rstest]
+    #[case(2, 3, 1)]
+    #[case(3, 4, 2)]
+    fn aggregates_vector_proofs(
+        #[case] number_of_parties: usize,
+        #[case] dimension: usize,
+        #[case] batch_size: usize,
+    ) {
+        test_helpers::aggregates::<SOUND_PROOFS_REPETITIONS, VectorLang>(
+            &vector_language_public_parameters(dimension),
+            number_of_parties,
+            batch_size,
+        );
+    }
+
+    pub(crate) type SecpElGamalLanguage = ElGamalLanguage<
+        SOUND_PROOFS_REPETITIONS,
+        { secp256k1::SCALAR_LIMBS },
+        secp256k1::GroupElement,
+    >;
+
+    pub(crate) fn elgamal_language_public_parameters(
+    ) -> language::PublicParameters<SOUND_PROOFS_REPETITIONS, SecpElGamalLanguage> {
+        PublicParameters::new::<
+            SOUND_PROOFS_REPETITIONS,
+            { secp256k1::SCALAR_LIMBS },
+            ElGamal<{ secp256k1::SCALAR_LIMBS }, secp256k1::GroupElement>,
+        >(
+            elgamal::PublicParameters::derive_default::<
+                { secp256k1::SCALAR_LIMBS },
+                secp256k1::GroupElement,
+            >()
+            .unwrap(),
+        )
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn valid_elgamal_proof_verifies(#[case] batch_size: usize) {
+        test_helpers::valid_proof_verifies::<SOUND_PROOFS_REPETITIONS, SecpElGamalLanguage>(
+            &elgamal_language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn invalid_elgamal_proof_fails_verification(#[case] batch_size: usize) {
+        test_helpers::invalid_proof_fails_verification::<
+            SOUND_PROOFS_REPETITIONS,
+            SecpElGamalLanguage,
+        >(
+            None,
+            None,
+            &elgamal_language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn elgamal_proof_over_invalid_public_parameters_fails_verification(#[case] batch_size: usize) {
+        let verifier_public_parameters = elgamal_language_public_parameters();
+        let mut prover_public_parameters = verifier_public_parameters.clone();
+
+        prover_public_parameters
+            .commitment_scheme_public_parameters
+            .public_key = prover_public_parameters
+            .commitment_scheme_public_parameters
+            .generator;
+
+        test_helpers::proof_over_invalid_public_parameters_fails_verification::<
+            SOUND_PROOFS_REPETITIONS,
+            SecpElGamalLanguage,
+        >(
+            &prover_public_parameters,
+            &verifier_public_parameters,
+            batch_size,
+            &mut OsRng,
+        );
+    }
 }
 
 #[cfg(feature = "benchmarking")]
@@ -432,7 +616,10 @@ pub(crate) mod benches {
     use criterion::Criterion;
 
     use crate::{
-        knowledge_of_decommitment::tests::{language_public_parameters, Lang},
+        knowledge_of_decommitment::tests::{
+            elgamal_language_public_parameters, language_public_parameters,
+            vector_language_public_parameters, Lang, SecpElGamalLanguage, VectorLang,
+        },
         test_helpers, BIT_SOUNDNESS_PROOFS_REPETITIONS, SOUND_PROOFS_REPETITIONS,
     };
 
@@ -462,6 +649,20 @@ pub(crate) mod benches {
             Lang<SOUND_PROOFS_REPETITIONS, 1>,
         >(&sound_language_public_parameters, None, false, None);
 
+        test_helpers::benchmark_proof::<SOUND_PROOFS_REPETITIONS, VectorLang>(
+            &vector_language_public_parameters(4),
+            Some("4 messages".to_string()),
+            false,
+            None,
+        );
+
+        test_helpers::benchmark_proof::<SOUND_PROOFS_REPETITIONS, SecpElGamalLanguage>(
+            &elgamal_language_public_parameters(),
+            Some("ElGamal".to_string()),
+            false,
+            None,
+        );
+
         let language_public_parameters1 =
             language_public_parameters::<BIT_SOUNDNESS_PROOFS_REPETITIONS, 1>();
         let language_public_parameters2 =
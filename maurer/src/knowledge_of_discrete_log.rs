@@ -1,9 +1,11 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::ops::Mul;
+use core::ops::Mul;
 
-use group::{CyclicGroupElement, Samplable};
-use serde::Serialize;
+use group::{CyclicGroupElement, Samplable, VartimeGroupElement};
+use proof::TranscriptVersion;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::{language::GroupsPublicParameters, Result, SOUND_PROOFS_REPETITIONS};
 
@@ -32,8 +34,9 @@ impl<
             + Samplable
             + Mul<GroupElement, Output = GroupElement>
             + for<'r> Mul<&'r GroupElement, Output = GroupElement>
-            + Copy,
-        GroupElement: group::GroupElement,
+            + Copy
+            + Zeroize,
+        GroupElement: group::GroupElement + VartimeGroupElement,
     > crate::Language<REPETITIONS> for Language<Scalar, GroupElement>
 {
     type WitnessSpaceGroupElement = Scalar;
@@ -52,7 +55,7 @@ impl<
         language_public_parameters: &Self::PublicParameters,
     ) -> Result<Self::StatementSpaceGroupElement> {
         let generator = GroupElement::new(
-            language_public_parameters.base,
+            language_public_parameters.base.clone(),
             &language_public_parameters
                 .groups_public_parameters
                 .statement_space_public_parameters,
@@ -63,7 +66,7 @@ impl<
 }
 
 /// The Public Parameters of Schnorr's Knowledge of Discrete Log Maurer Language.
-#[derive(Debug, PartialEq, Serialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct PublicParameters<ScalarPublicParameters, GroupPublicParameters, GroupElementValue> {
     pub groups_public_parameters:
         GroupsPublicParameters<ScalarPublicParameters, GroupPublicParameters>,
@@ -84,7 +87,8 @@ impl<ScalarPublicParameters, GroupPublicParameters, GroupElementValue>
             + Samplable
             + Mul<GroupElement, Output = GroupElement>
             + for<'r> Mul<&'r GroupElement, Output = GroupElement>
-            + Copy,
+            + Copy
+            + Zeroize,
         GroupElement: group::GroupElement<Value = GroupElementValue, PublicParameters = GroupPublicParameters>
             + CyclicGroupElement,
     {
@@ -92,6 +96,7 @@ impl<ScalarPublicParameters, GroupPublicParameters, GroupElementValue>
             groups_public_parameters: GroupsPublicParameters {
                 witness_space_public_parameters: scalar_group_public_parameters,
                 statement_space_public_parameters: group_public_parameters,
+                transcript_version: TranscriptVersion::default(),
             },
             base,
         }
@@ -111,7 +116,7 @@ pub type Proof<Scalar, GroupElement, ProtocolContext> =
     crate::Proof<SOUND_PROOFS_REPETITIONS, Language<Scalar, GroupElement>, ProtocolContext>;
 
 pub(super) mod private {
-    use std::marker::PhantomData;
+    use core::marker::PhantomData;
 
     use serde::{Deserialize, Serialize};
 
@@ -125,13 +130,16 @@ pub(super) mod private {
 #[cfg(any(test, feature = "benchmarking"))]
 #[allow(unused_imports)]
 mod tests {
+    use core::marker::PhantomData;
+
     use crypto_bigint::U256;
-    use group::{secp256k1, GroupElement};
+    use group::{secp256k1, wire::WireEncoding, GroupElement, PartyID};
+    use proof::session::network::Fault;
     use rand_core::OsRng;
     use rstest::rstest;
 
     use super::*;
-    use crate::{language, test_helpers};
+    use crate::{language, language::GroupsPublicParametersAccessors, test_helpers, Error};
 
     pub(crate) type Lang = Language<secp256k1::Scalar, secp256k1::GroupElement>;
     pub(crate) type FischlinLang<const REPETITIONS: usize> =
@@ -276,6 +284,184 @@ mod tests {
         )
     }
 
+    #[rstest]
+    #[case(1)]
+    #[case(3)]
+    fn proof_with_mismatched_transcript_version_fails(#[case] batch_size: usize) {
+        let canonical_language_public_parameters =
+            language_public_parameters::<SOUND_PROOFS_REPETITIONS>();
+        assert_eq!(
+            canonical_language_public_parameters.transcript_version(),
+            TranscriptVersion::CanonicalBinary
+        );
+
+        let mut json_language_public_parameters = canonical_language_public_parameters.clone();
+        json_language_public_parameters
+            .groups_public_parameters
+            .transcript_version = TranscriptVersion::Json;
+
+        let witnesses = test_helpers::sample_witnesses::<SOUND_PROOFS_REPETITIONS, Lang>(
+            &json_language_public_parameters,
+            batch_size,
+            &mut OsRng,
+        );
+        let (proof, statements) = test_helpers::generate_valid_proof::<
+            SOUND_PROOFS_REPETITIONS,
+            Lang,
+        >(&json_language_public_parameters, witnesses, &mut OsRng);
+
+        assert!(
+            proof
+                .verify(
+                    &PhantomData,
+                    &json_language_public_parameters,
+                    statements.clone()
+                )
+                .is_ok(),
+            "valid proofs should verify under the transcript version they were generated with"
+        );
+
+        assert!(
+            matches!(
+                proof
+                    .verify(
+                        &PhantomData,
+                        &canonical_language_public_parameters,
+                        statements
+                    )
+                    .err()
+                    .unwrap(),
+                Error::Proof(proof::Error::ProofVerification)
+            ),
+            "proofs should not verify under a different transcript version"
+        );
+    }
+
+    #[rstest]
+    #[case(TranscriptVersion::Json)]
+    #[case(TranscriptVersion::CanonicalBinary)]
+    fn proof_verifies_over_deserialized_public_parameters(
+        #[case] transcript_version: TranscriptVersion,
+    ) {
+        let mut prover_public_parameters = language_public_parameters::<SOUND_PROOFS_REPETITIONS>();
+        prover_public_parameters
+            .groups_public_parameters
+            .transcript_version = transcript_version;
+
+        let witnesses = test_helpers::sample_witnesses::<SOUND_PROOFS_REPETITIONS, Lang>(
+            &prover_public_parameters,
+            2,
+            &mut OsRng,
+        );
+        let (proof, statements) = test_helpers::generate_valid_proof::<
+            SOUND_PROOFS_REPETITIONS,
+            Lang,
+        >(&prover_public_parameters, witnesses, &mut OsRng);
+
+        // The verifier receives the public parameters over the wire, along with the transcript
+        // version they were generated under.
+        let verifier_public_parameters: language::PublicParameters<SOUND_PROOFS_REPETITIONS, Lang> =
+            serde_json::from_str(&serde_json::to_string(&prover_public_parameters).unwrap())
+                .unwrap();
+        assert_eq!(verifier_public_parameters, prover_public_parameters);
+
+        assert!(
+            proof
+                .verify(&PhantomData, &verifier_public_parameters, statements)
+                .is_ok(),
+            "valid proofs should verify over the deserialized public parameters"
+        );
+    }
+
+    #[test]
+    fn encodes_proofs_compactly() {
+        let language_public_parameters = language_public_parameters::<SOUND_PROOFS_REPETITIONS>();
+        let witnesses = test_helpers::sample_witnesses::<SOUND_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters,
+            3,
+            &mut OsRng,
+        );
+        let (proof, _) = test_helpers::generate_valid_proof::<SOUND_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters,
+            witnesses,
+            &mut OsRng,
+        );
+
+        // A statement mask (a length-prefixed compressed point) and a response (a scalar) per
+        // repetition, regardless of the batch size.
+        assert_eq!(
+            proof.to_wire_bytes().unwrap().len(),
+            SOUND_PROOFS_REPETITIONS * ((1 + 33) + 32)
+        );
+    }
+
+    #[test]
+    fn verifies_legacy_json_test_vectors() {
+        // The public parameters, as serialized before the transcript version was introduced,
+        // deserialize with the legacy JSON transcript version, and serialize back to the same JSON.
+        let legacy_serialized_public_parameters = r#"{"groups_public_parameters":{"witness_space_public_parameters":{"name":"The finite field of integers modulo prime q $\\mathbb{Z}_q$","order":"414136d08c5ed2bf3ba048afe6dcaebafeffffffffffffffffffffffffffffff","generator":"0000000000000000000000000000000000000000000000000000000000000001"},"statement_space_public_parameters":{"name":"Secp256k1","curve_type":"Weierstrass","order":"414136d08c5ed2bf3ba048afe6dcaebafeffffffffffffffffffffffffffffff","modulus":"2ffcfffffeffffffffffffffffffffffffffffffffffffffffffffffffffffff","generator":"0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798","curve_equation_a":"0000000000000000000000000000000000000000000000000000000000000000","curve_equation_b":"0700000000000000000000000000000000000000000000000000000000000000"}},"base":"0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"}"#;
+        let json_language_public_parameters: language::PublicParameters<
+            SOUND_PROOFS_REPETITIONS,
+            Lang,
+        > = serde_json::from_str(legacy_serialized_public_parameters).unwrap();
+
+        let mut expected_language_public_parameters =
+            language_public_parameters::<SOUND_PROOFS_REPETITIONS>();
+        expected_language_public_parameters
+            .groups_public_parameters
+            .transcript_version = TranscriptVersion::Json;
+        assert_eq!(
+            json_language_public_parameters,
+            expected_language_public_parameters
+        );
+        assert_eq!(
+            serde_json::to_string(&json_language_public_parameters).unwrap(),
+            legacy_serialized_public_parameters
+        );
+
+        // A proof generated prior to the introduction of the transcript version, for the
+        // witnesses $3$ and `0x0123..ef` and an empty protocol context.
+        let proof: Proof<secp256k1::Scalar, secp256k1::GroupElement, PhantomData<()>> =
+            serde_json::from_str(
+                r#"{"statement_masks":["03B8841E8AA67B66C5B715E51AA77E931CE9947DDACE9EBC4269E865C051D142D2"],"responses":["C7E316BE48BCE2153795B2232E559923EBA706152D094FB3C10137107FE488BA"],"_protocol_context_choice":null}"#,
+            )
+            .unwrap();
+        let statements: Vec<_> = [
+            U256::from(3u64),
+            U256::from_be_hex("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"),
+        ]
+        .into_iter()
+        .map(|witness| {
+            <Lang as language::Language<SOUND_PROOFS_REPETITIONS>>::homomorphose(
+                &secp256k1::Scalar::from(witness),
+                &json_language_public_parameters,
+            )
+            .unwrap()
+        })
+        .collect();
+
+        assert!(
+            proof
+                .verify(
+                    &PhantomData,
+                    &json_language_public_parameters,
+                    statements.clone()
+                )
+                .is_ok(),
+            "proofs generated prior to the introduction of the transcript version should verify under the legacy JSON transcript version"
+        );
+        assert!(
+            proof
+                .verify(
+                    &PhantomData,
+                    &language_public_parameters::<SOUND_PROOFS_REPETITIONS>(),
+                    statements
+                )
+                .is_err(),
+            "proofs generated prior to the introduction of the transcript version should not verify under the canonical binary transcript version"
+        );
+    }
+
     #[rstest]
     #[case(1, 1)]
     #[case(1, 2)]
@@ -308,6 +494,60 @@ mod tests {
         >(&language_public_parameters, number_of_parties, batch_size);
     }
 
+    #[rstest]
+    #[case(1, 1)]
+    #[case(2, 1)]
+    #[case(3, 2)]
+    fn aggregates_over_network(#[case] number_of_parties: usize, #[case] batch_size: usize) {
+        let language_public_parameters = language_public_parameters::<SOUND_PROOFS_REPETITIONS>();
+
+        test_helpers::aggregates_over_network::<SOUND_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters,
+            number_of_parties,
+            batch_size,
+        );
+    }
+
+    #[rstest]
+    #[case(2, 1, Fault::Drop)]
+    #[case(3, 2, Fault::Drop)]
+    #[case(3, 1, Fault::Delay(3))]
+    #[case(2, 1, Fault::Corrupt)]
+    #[case(3, 2, Fault::Corrupt)]
+    fn faulty_party_aborts_session_identifiably(
+        #[case] number_of_parties: usize,
+        #[case] batch_size: usize,
+        #[case] fault: Fault,
+    ) {
+        let language_public_parameters = language_public_parameters::<SOUND_PROOFS_REPETITIONS>();
+
+        test_helpers::faulty_party_aborts_session_identifiably::<SOUND_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters,
+            number_of_parties,
+            batch_size,
+            fault,
+        );
+    }
+
+    #[rstest]
+    #[case(1, 2, 1)]
+    #[case(2, 3, 1)]
+    #[case(3, 5, 2)]
+    fn aggregates_over_remaining_quorum(
+        #[case] threshold: PartyID,
+        #[case] number_of_parties: usize,
+        #[case] batch_size: usize,
+    ) {
+        let language_public_parameters = language_public_parameters::<SOUND_PROOFS_REPETITIONS>();
+
+        test_helpers::aggregates_over_remaining_quorum::<SOUND_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters,
+            threshold,
+            number_of_parties,
+            batch_size,
+        );
+    }
+
     #[rstest]
     #[case(2, 1)]
     #[case(3, 1)]
@@ -339,6 +579,38 @@ mod tests {
             Lang,
         >(&language_public_parameters, number_of_parties, batch_size);
     }
+
+    #[rstest]
+    #[case(2, 1)]
+    #[case(3, 1)]
+    #[case(5, 2)]
+    fn proof_share_from_another_session_aborts_session_identifiably(
+        #[case] number_of_parties: usize,
+        #[case] batch_size: usize,
+    ) {
+        let language_public_parameters = language_public_parameters::<SOUND_PROOFS_REPETITIONS>();
+
+        test_helpers::proof_share_from_another_session_aborts_session_identifiably::<
+            SOUND_PROOFS_REPETITIONS,
+            Lang,
+        >(&language_public_parameters, number_of_parties, batch_size);
+    }
+
+    #[rstest]
+    #[case(2, 1)]
+    #[case(3, 1)]
+    #[case(5, 2)]
+    fn proof_share_from_aborted_attempt_aborts_restarted_session_identifiably(
+        #[case] number_of_parties: usize,
+        #[case] batch_size: usize,
+    ) {
+        let language_public_parameters = language_public_parameters::<SOUND_PROOFS_REPETITIONS>();
+
+        test_helpers::proof_share_from_aborted_attempt_aborts_restarted_session_identifiably::<
+            SOUND_PROOFS_REPETITIONS,
+            Lang,
+        >(&language_public_parameters, number_of_parties, batch_size);
+    }
 }
 
 #[cfg(feature = "benchmarking")]
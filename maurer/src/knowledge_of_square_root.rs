@@ -0,0 +1,206 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+use core::marker::PhantomData;
+
+use crypto_bigint::{Encoding, Uint};
+use group::{multiplicative, GroupElement as _};
+use proof::TranscriptVersion;
+use serde::{Deserialize, Serialize};
+
+use crate::{language::GroupsPublicParameters, Result, BIT_SOUNDNESS_PROOFS_REPETITIONS};
+
+/// Knowledge of Square Root Maurer Language.
+///
+/// $$ \tau \mapsto \tau^2 \mod N $$
+///
+/// Proves that the statements are quadratic residues modulo $N$, by proving knowledge of their
+/// square roots; e.g. that the randomness generator $t = \tau^2$ of
+/// [`RingPedersen`](commitment::RingPedersen) public parameters is a quadratic residue.
+///
+/// SECURITY NOTICE:
+/// The order of $\mathbb{Z}_N^*$ is hidden from the verifier, so that knowledge-soundness can
+/// only be assured with binary challenges, and this language must be used with
+/// [`BIT_SOUNDNESS_PROOFS_REPETITIONS`] repetitions: from two responses $z, z'$ to challenges
+/// that differ in one bit, $\tau = z / z'$ is extracted.
+///
+/// Zero-knowledge is perfect, as the randomizers are sampled uniformly from $\mathbb{Z}_N^*$.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug, Eq)]
+pub struct Language<const LIMBS: usize> {
+    _group_element_choice: PhantomData<[(); LIMBS]>,
+}
+
+impl<const LIMBS: usize> crate::Language<BIT_SOUNDNESS_PROOFS_REPETITIONS> for Language<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    type WitnessSpaceGroupElement = multiplicative::GroupElement<LIMBS>;
+    type StatementSpaceGroupElement = multiplicative::GroupElement<LIMBS>;
+
+    type PublicParameters = PublicParameters<LIMBS>;
+
+    const NAME: &'static str = "Knowledge of Square Root";
+
+    fn homomorphose(
+        witness: &Self::WitnessSpaceGroupElement,
+        _language_public_parameters: &Self::PublicParameters,
+    ) -> Result<Self::StatementSpaceGroupElement> {
+        Ok(witness.double())
+    }
+}
+
+/// The Public Parameters of the Knowledge of Square Root Maurer Language.
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct PublicParameters<const LIMBS: usize>
+where
+    Uint<LIMBS>: Encoding,
+{
+    pub groups_public_parameters: GroupsPublicParameters<
+        multiplicative::PublicParameters<LIMBS>,
+        multiplicative::PublicParameters<LIMBS>,
+    >,
+}
+
+impl<const LIMBS: usize> PublicParameters<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    pub fn new(group_public_parameters: multiplicative::PublicParameters<LIMBS>) -> Self {
+        Self {
+            groups_public_parameters: GroupsPublicParameters {
+                witness_space_public_parameters: group_public_parameters.clone(),
+                statement_space_public_parameters: group_public_parameters,
+                transcript_version: TranscriptVersion::default(),
+            },
+        }
+    }
+}
+
+impl<const LIMBS: usize>
+    AsRef<
+        GroupsPublicParameters<
+            multiplicative::PublicParameters<LIMBS>,
+            multiplicative::PublicParameters<LIMBS>,
+        >,
+    > for PublicParameters<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn as_ref(
+        &self,
+    ) -> &GroupsPublicParameters<
+        multiplicative::PublicParameters<LIMBS>,
+        multiplicative::PublicParameters<LIMBS>,
+    > {
+        &self.groups_public_parameters
+    }
+}
+
+pub type Proof<const LIMBS: usize, ProtocolContext> =
+    crate::Proof<BIT_SOUNDNESS_PROOFS_REPETITIONS, Language<LIMBS>, ProtocolContext>;
+
+#[cfg(test)]
+mod tests {
+    use commitment::ring_pedersen;
+    use crypto_bigint::{U1024, U256, U512};
+    use rand_core::OsRng;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::test_helpers;
+
+    /// A product of two 256-bit safe primes, and hence a Blum integer.
+    const N: U512 = U512::from_be_hex("907127679D90180A1BC2CEE979BD5E494292F1491CFD325194D1F6D998019F44F13C87192206A8018869755B2B83ABE65C6FECA9852F94C7D4CDCC4C4D9A928D");
+
+    type Lang = Language<{ U512::LIMBS }>;
+
+    fn language_public_parameters() -> PublicParameters<{ U512::LIMBS }> {
+        PublicParameters::new(multiplicative::PublicParameters::new(N).unwrap())
+    }
+
+    #[test]
+    fn proves_randomness_generator_is_quadratic_residue() {
+        let (commitment_scheme_public_parameters, randomness_generator_square_root, _) =
+            ring_pedersen::PublicParameters::<1, { U256::LIMBS }, { U1024::LIMBS }, { U512::LIMBS }>::setup(
+                N, 200, &mut OsRng,
+            )
+            .unwrap();
+        let language_public_parameters = language_public_parameters();
+        let randomness_generator = multiplicative::GroupElement::new(
+            commitment_scheme_public_parameters.randomness_generator,
+            &language_public_parameters
+                .groups_public_parameters
+                .statement_space_public_parameters,
+        )
+        .unwrap();
+
+        let (proof, statements) = Proof::prove(
+            &(),
+            &language_public_parameters,
+            vec![randomness_generator_square_root],
+            &mut OsRng,
+        )
+        .unwrap();
+        assert_eq!(statements, vec![randomness_generator]);
+        assert!(proof
+            .verify(&(), &language_public_parameters, statements)
+            .is_ok());
+
+        // For a Blum integer $N$, $-1$ is a quadratic non-residue with Jacobi symbol $1$, and so
+        // is $-t$.
+        let minus_one = multiplicative::GroupElement::new(
+            multiplicative::Value::new(
+                N.wrapping_sub(&U512::ONE),
+                &language_public_parameters
+                    .groups_public_parameters
+                    .statement_space_public_parameters,
+            )
+            .unwrap(),
+            &language_public_parameters
+                .groups_public_parameters
+                .statement_space_public_parameters,
+        )
+        .unwrap();
+        assert!(proof
+            .verify(
+                &(),
+                &language_public_parameters,
+                vec![randomness_generator + minus_one]
+            )
+            .is_err());
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn valid_proof_verifies(#[case] batch_size: usize) {
+        test_helpers::valid_proof_verifies::<BIT_SOUNDNESS_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn invalid_proof_fails_verification(#[case] batch_size: usize) {
+        test_helpers::invalid_proof_fails_verification::<BIT_SOUNDNESS_PROOFS_REPETITIONS, Lang>(
+            None,
+            None,
+            &language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn proof_with_incomplete_transcript_fails(#[case] batch_size: usize) {
+        test_helpers::proof_with_incomplete_transcript_fails::<BIT_SOUNDNESS_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+}
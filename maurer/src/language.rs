@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 use core::fmt::Debug;
 
-use group::{ComputationalSecuritySizedNumber, GroupElement, Samplable};
-use serde::{Deserialize, Serialize};
+use group::{ComputationalSecuritySizedNumber, GroupElement, Samplable, VartimeGroupElement};
+use proof::TranscriptVersion;
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
+use zeroize::Zeroize;
 
 use crate::{proof::BIT_SOUNDNESS_PROOFS_REPETITIONS, Error, Result};
 
@@ -17,10 +19,13 @@ pub trait Language<
     const REPETITIONS: usize,
 >: Clone + PartialEq + Eq + Debug {
     /// An element of the witness space $(\HH_\pp, +)$
-    type WitnessSpaceGroupElement: GroupElement + Samplable;
+    type WitnessSpaceGroupElement: GroupElement + Samplable + Zeroize;
 
     /// An element in the associated statement space $(\GG_\pp, \cdot)$,
-    type StatementSpaceGroupElement: GroupElement;
+    ///
+    /// Statements are public, and so are evaluated with variable-time operations upon
+    /// verification.
+    type StatementSpaceGroupElement: GroupElement + VartimeGroupElement;
 
     /// Public parameters for a language family $\pp \gets \Setup(1^\kappa)$.
     ///
@@ -89,10 +94,61 @@ pub type StatementSpacePublicParameters<const REPETITIONS: usize, L> =
 pub type StatementSpaceValue<const REPETITIONS: usize, L> =
     group::Value<StatementSpaceGroupElement<REPETITIONS, L>>;
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Deserialize)]
 pub struct GroupsPublicParameters<WitnessSpacePublicParameters, StatementSpacePublicParameters> {
     pub witness_space_public_parameters: WitnessSpacePublicParameters,
     pub statement_space_public_parameters: StatementSpacePublicParameters,
+    /// The encoding used to append messages to the Fiat-Shamir transcript of proofs over this
+    /// language.
+    ///
+    /// This is serialized along with the public parameters, so that the prover and the verifier
+    /// agree on it, and is itself bound to the transcript under
+    /// [`TranscriptVersion::CanonicalBinary`]. Under [`TranscriptVersion::Json`] it is left out
+    /// of the JSON encoding, which is the one hashed into the legacy transcripts, so that these
+    /// stay identical to those of proofs generated prior to its introduction; public parameters
+    /// serialized back then deserialize with [`TranscriptVersion::Json`].
+    #[serde(default = "legacy_transcript_version")]
+    pub transcript_version: TranscriptVersion,
+}
+
+/// The transcript version of public parameters serialized without one, i.e. prior to the
+/// introduction of [`TranscriptVersion`].
+fn legacy_transcript_version() -> TranscriptVersion {
+    TranscriptVersion::Json
+}
+
+impl<WitnessSpacePublicParameters: Serialize, StatementSpacePublicParameters: Serialize> Serialize
+    for GroupsPublicParameters<WitnessSpacePublicParameters, StatementSpacePublicParameters>
+{
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Only human-readable (i.e. JSON) encodings may omit the transcript version, as they
+        // name their fields; the binary ones always carry it.
+        let skip_transcript_version =
+            serializer.is_human_readable() && self.transcript_version == TranscriptVersion::Json;
+
+        let mut state = serializer.serialize_struct(
+            "GroupsPublicParameters",
+            if skip_transcript_version { 2 } else { 3 },
+        )?;
+        state.serialize_field(
+            "witness_space_public_parameters",
+            &self.witness_space_public_parameters,
+        )?;
+        state.serialize_field(
+            "statement_space_public_parameters",
+            &self.statement_space_public_parameters,
+        )?;
+        if skip_transcript_version {
+            state.skip_field("transcript_version")?;
+        } else {
+            state.serialize_field("transcript_version", &self.transcript_version)?;
+        }
+
+        state.end()
+    }
 }
 
 pub trait GroupsPublicParametersAccessors<
@@ -109,6 +165,10 @@ pub trait GroupsPublicParametersAccessors<
     fn statement_space_public_parameters(&'a self) -> &'a StatementSpacePublicParameters {
         &self.as_ref().statement_space_public_parameters
     }
+
+    fn transcript_version(&'a self) -> TranscriptVersion {
+        self.as_ref().transcript_version
+    }
 }
 
 impl<
@@ -1,6 +1,10 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub use language::Language;
 pub use proof::{fischlin, Proof, BIT_SOUNDNESS_PROOFS_REPETITIONS, SOUND_PROOFS_REPETITIONS};
 
@@ -9,10 +13,13 @@ pub mod committment_of_discrete_log;
 pub mod discrete_log_ratio_of_committed_values;
 pub mod knowledge_of_decommitment;
 pub mod knowledge_of_discrete_log;
+pub mod knowledge_of_square_root;
 
 pub mod equality_between_commitments_with_different_public_parameters;
+pub mod equality_between_commitments_with_different_schemes;
 pub mod language;
 mod proof;
+pub mod ring_pedersen_setup;
 pub mod vector_commitment_of_discrete_log;
 
 #[cfg(any(test, feature = "benchmarking"))]
@@ -49,12 +56,12 @@ pub enum Error {
 }
 
 /// Maurer result.
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 impl TryInto<::proof::aggregation::Error> for Error {
     type Error = Error;
 
-    fn try_into(self) -> std::result::Result<::proof::aggregation::Error, Self::Error> {
+    fn try_into(self) -> core::result::Result<::proof::aggregation::Error, Self::Error> {
         match self {
             Error::Aggregation(e) => Ok(e),
             e => Err(e),
@@ -3,13 +3,18 @@
 
 pub mod fischlin;
 
-use std::{array, marker::PhantomData};
+use alloc::vec::Vec;
+use core::{array, marker::PhantomData};
 
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::{helpers::FlatMapResults, ComputationalSecuritySizedNumber, GroupElement, Samplable};
+use group::{
+    helpers::FlatMapResults, ComputationalSecuritySizedNumber, GroupElement, Samplable,
+    VartimeGroupElement,
+};
 use merlin::Transcript;
-use proof::TranscriptProtocol;
+use proof::{aggregation, session::SessionID, TranscriptProtocol};
 use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::{
     language,
@@ -98,6 +103,9 @@ impl<
         randomizers: [Language::WitnessSpaceGroupElement; REPETITIONS],
         statement_masks: [Language::StatementSpaceGroupElement; REPETITIONS],
     ) -> Result<(Self, Vec<Language::StatementSpaceGroupElement>)> {
+        let witnesses = Zeroizing::new(witnesses);
+        let randomizers = Zeroizing::new(randomizers);
+
         let statements: Result<Vec<Language::StatementSpaceGroupElement>> = witnesses
             .iter()
             .map(|witness| Language::homomorphose(witness, language_public_parameters))
@@ -105,11 +113,12 @@ impl<
         let statements = statements?;
 
         Self::prove_inner(
+            None,
             protocol_context,
             language_public_parameters,
-            witnesses,
+            &witnesses,
             statements.clone(),
-            randomizers,
+            &randomizers,
             statement_masks,
         )
         .map(|proof| (proof, statements))
@@ -122,25 +131,34 @@ impl<
         statements: Vec<Language::StatementSpaceGroupElement>,
         rng: &mut impl CryptoRngCore,
     ) -> Result<Self> {
+        let witnesses = Zeroizing::new(witnesses);
         let (randomizers, statement_masks) =
             Self::sample_randomizers_and_statement_masks(language_public_parameters, rng)?;
+        let randomizers = Zeroizing::new(randomizers);
 
         Self::prove_inner(
+            None,
             protocol_context,
             language_public_parameters,
-            witnesses,
+            &witnesses,
             statements,
-            randomizers,
+            &randomizers,
             statement_masks,
         )
     }
 
+    /// Computes the proof given the `witnesses` and `randomizers`, which are borrowed so that the
+    /// caller stays in charge of wiping them from memory.
+    ///
+    /// When proving a share of a proof aggregated in session `session_id`, the challenges are bound
+    /// to that session.
     pub(super) fn prove_inner(
+        session_id: Option<&SessionID>,
         protocol_context: &ProtocolContext,
         language_public_parameters: &Language::PublicParameters,
-        witnesses: Vec<Language::WitnessSpaceGroupElement>,
+        witnesses: &[Language::WitnessSpaceGroupElement],
         statements: Vec<Language::StatementSpaceGroupElement>,
-        randomizers: [Language::WitnessSpaceGroupElement; REPETITIONS],
+        randomizers: &[Language::WitnessSpaceGroupElement; REPETITIONS],
         statement_masks: [Language::StatementSpaceGroupElement; REPETITIONS],
     ) -> Result<Self> {
         if witnesses.is_empty() {
@@ -155,7 +173,8 @@ impl<
         let statements_values =
             Language::StatementSpaceGroupElement::batch_normalize(statements.clone());
 
-        let mut transcript = Self::setup_transcript(
+        let mut transcript = Self::setup_challenge_transcript(
+            session_id,
             protocol_context,
             language_public_parameters,
             statements_values,
@@ -168,33 +187,15 @@ impl<
         let challenge_bit_size = Language::challenge_bits()?;
         let responses = Language::WitnessSpaceGroupElement::batch_normalize_const_generic(
             randomizers
-                .into_iter()
+                .iter()
                 .zip(challenges)
                 .map(|(randomizer, challenges)| {
-                    witnesses
-                        .clone()
-                        .into_iter()
-                        .zip(challenges)
-                        .filter_map(|(witness, challenge)| {
-                            if challenge_bit_size == 1 {
-                                // A special case that needs special caring.
-                                if challenge == ComputationalSecuritySizedNumber::ZERO {
-                                    None
-                                } else {
-                                    Some(witness)
-                                }
-                            } else {
-                                // Using the "small exponents" method for batching.
-                                Some(witness.scalar_mul_bounded(&challenge, challenge_bit_size))
-                            }
-                        })
-                        .reduce(|a, b| a + b)
-                        .map_or(
-                            randomizer.clone(),
-                            |witnesses_and_challenges_linear_combination| {
-                                randomizer + witnesses_and_challenges_linear_combination
-                            },
-                        )
+                    Self::linear_combination(witnesses, challenges, challenge_bit_size).map_or(
+                        randomizer.clone(),
+                        |witnesses_and_challenges_linear_combination| {
+                            randomizer.clone() + witnesses_and_challenges_linear_combination
+                        },
+                    )
                 })
                 .collect::<Vec<_>>()
                 .try_into()
@@ -211,7 +212,39 @@ impl<
         language_public_parameters: &Language::PublicParameters,
         statements: Vec<Language::StatementSpaceGroupElement>,
     ) -> Result<()> {
-        let mut transcript = Self::setup_transcript(
+        self.verify_with_session(
+            None,
+            protocol_context,
+            language_public_parameters,
+            statements,
+        )
+    }
+
+    /// Verify a batched Maurer zero-knowledge proof, aggregated in session `session_id`.
+    pub fn verify_in_session(
+        &self,
+        session_id: &SessionID,
+        protocol_context: &ProtocolContext,
+        language_public_parameters: &Language::PublicParameters,
+        statements: Vec<Language::StatementSpaceGroupElement>,
+    ) -> Result<()> {
+        self.verify_with_session(
+            Some(session_id),
+            protocol_context,
+            language_public_parameters,
+            statements,
+        )
+    }
+
+    fn verify_with_session(
+        &self,
+        session_id: Option<&SessionID>,
+        protocol_context: &ProtocolContext,
+        language_public_parameters: &Language::PublicParameters,
+        statements: Vec<Language::StatementSpaceGroupElement>,
+    ) -> Result<()> {
+        let mut transcript = Self::setup_challenge_transcript(
+            session_id,
             protocol_context,
             language_public_parameters,
             Language::StatementSpaceGroupElement::batch_normalize(statements.clone()),
@@ -245,6 +278,7 @@ impl<
     ) -> Result<()> {
         let responses = self
             .responses
+            .clone()
             .map(|response| {
                 Language::WitnessSpaceGroupElement::new(
                     response,
@@ -255,6 +289,7 @@ impl<
 
         let statement_masks = self
             .statement_masks
+            .clone()
             .map(|statement_mask| {
                 Language::StatementSpaceGroupElement::new(
                     statement_mask,
@@ -273,29 +308,17 @@ impl<
                 .into_iter()
                 .zip(challenges)
                 .map(|(statement_mask, challenges)| {
-                    statements
-                        .clone()
-                        .into_iter()
-                        .zip(challenges)
-                        .filter_map(|(statement, challenge)| {
-                            if challenge_bit_size == 1 {
-                                // A special case that needs special caring
-                                if challenge == ComputationalSecuritySizedNumber::ZERO {
-                                    None
-                                } else {
-                                    Some(statement)
-                                }
-                            } else {
-                                Some(statement.scalar_mul_bounded(&challenge, challenge_bit_size))
-                            }
-                        })
-                        .reduce(|a, b| a + b)
-                        .map_or(
-                            statement_mask.clone(),
-                            |statements_and_challenges_linear_combination| {
-                                statement_mask + statements_and_challenges_linear_combination
-                            },
-                        )
+                    Self::linear_combination_vartime(
+                        statements.clone(),
+                        challenges,
+                        challenge_bit_size,
+                    )
+                    .map_or(
+                        statement_mask.clone(),
+                        |statements_and_challenges_linear_combination| {
+                            statement_mask + statements_and_challenges_linear_combination
+                        },
+                    )
                 })
                 .collect::<Vec<_>>()
                 .try_into()
@@ -307,6 +330,75 @@ impl<
         Err(proof::Error::ProofVerification)?
     }
 
+    /// Compute the linear combination of `elements` with `challenges` as coefficients, using
+    /// multi-scalar multiplication. Returns `None` if no element participates in it.
+    ///
+    /// The (secret) `elements` are copied for the computation, and these copies are wiped
+    /// afterwards.
+    fn linear_combination<Element: GroupElement + Zeroize>(
+        elements: &[Element],
+        challenges: Vec<ComputationalSecuritySizedNumber>,
+        challenge_bit_size: usize,
+    ) -> Option<Element> {
+        if challenge_bit_size == 1 {
+            // A special case that needs special caring: only the least significant bit of each
+            // (public) challenge is taken.
+            elements
+                .iter()
+                .zip(challenges)
+                .filter(|(_, challenge)| challenge.bit_vartime(0))
+                .map(|(element, _)| element.clone())
+                .reduce(|a, b| a + b)
+        } else {
+            // Using the "small exponents" method for batching.
+            let mut elements_and_challenges: Vec<_> =
+                elements.iter().cloned().zip(challenges).collect();
+
+            let linear_combination =
+                Element::multi_scalar_mul_bounded(&elements_and_challenges, challenge_bit_size);
+
+            elements_and_challenges
+                .iter_mut()
+                .for_each(|(element, _)| element.zeroize());
+
+            linear_combination
+        }
+    }
+
+    /// Compute the linear combination of the (public) `statements` with `challenges` as
+    /// coefficients, in variable-time. Returns `None` if no statement participates in it.
+    ///
+    /// Must only be used for verification, in which all inputs are public.
+    fn linear_combination_vartime(
+        statements: Vec<Language::StatementSpaceGroupElement>,
+        challenges: Vec<ComputationalSecuritySizedNumber>,
+        challenge_bit_size: usize,
+    ) -> Option<Language::StatementSpaceGroupElement> {
+        if challenge_bit_size == 1 {
+            // A special case that needs special caring: only the least significant bit of each
+            // challenge is taken.
+            statements
+                .into_iter()
+                .zip(challenges)
+                .filter(|(_, challenge)| challenge.bit_vartime(0))
+                .map(|(statement, _)| statement)
+                .reduce(|a, b| a + b)
+        } else {
+            // Take only the `challenge_bit_size` least significant bits of each challenge.
+            let mask = (ComputationalSecuritySizedNumber::ONE << challenge_bit_size)
+                .wrapping_sub(&ComputationalSecuritySizedNumber::ONE);
+            let statements_and_challenges: Vec<_> = statements
+                .into_iter()
+                .zip(challenges)
+                .map(|(statement, challenge)| (statement, challenge & mask))
+                .collect();
+
+            Language::StatementSpaceGroupElement::multi_scalar_mul_vartime(
+                &statements_and_challenges,
+            )
+        }
+    }
+
     #[allow(clippy::type_complexity)]
     pub(super) fn sample_randomizers_and_statement_masks(
         language_public_parameters: &Language::PublicParameters,
@@ -324,41 +416,62 @@ impl<
         .flat_map_results()?;
 
         let statement_masks = randomizers
-            .clone()
-            .map(|randomizer| Language::homomorphose(&randomizer, language_public_parameters))
+            .each_ref()
+            .map(|randomizer| Language::homomorphose(randomizer, language_public_parameters))
             .flat_map_results()?;
 
         Ok((randomizers, statement_masks))
     }
 
-    pub(super) fn setup_transcript(
+    /// Set up a transcript bound to the language, `protocol_context` and
+    /// `language_public_parameters`, but not yet to any statement.
+    pub(super) fn setup_public_parameters_transcript(
         protocol_context: &ProtocolContext,
         language_public_parameters: &Language::PublicParameters,
-        statements: Vec<group::Value<Language::StatementSpaceGroupElement>>,
-        statement_masks_values: &[group::Value<Language::StatementSpaceGroupElement>; REPETITIONS],
     ) -> Result<Transcript> {
+        let version = language_public_parameters.transcript_version();
         let mut transcript = Transcript::new(Language::NAME.as_bytes());
 
-        transcript.serialize_to_transcript_as_json(b"protocol context", protocol_context)?;
+        transcript.serialize_to_transcript_with_version(
+            b"protocol context",
+            protocol_context,
+            version,
+        )?;
 
-        transcript.serialize_to_transcript_as_json(
+        transcript.serialize_to_transcript_with_version(
             b"language public parameters",
             language_public_parameters,
+            version,
         )?;
 
-        transcript.serialize_to_transcript_as_json(
+        transcript.serialize_to_transcript_with_version(
             b"witness space public parameters",
             language_public_parameters.witness_space_public_parameters(),
+            version,
         )?;
 
-        transcript.serialize_to_transcript_as_json(
+        transcript.serialize_to_transcript_with_version(
             b"statement space public parameters",
             language_public_parameters.statement_space_public_parameters(),
+            version,
         )?;
 
+        Ok(transcript)
+    }
+
+    pub(super) fn setup_transcript(
+        protocol_context: &ProtocolContext,
+        language_public_parameters: &Language::PublicParameters,
+        statements: Vec<group::Value<Language::StatementSpaceGroupElement>>,
+        statement_masks_values: &[group::Value<Language::StatementSpaceGroupElement>; REPETITIONS],
+    ) -> Result<Transcript> {
+        let version = language_public_parameters.transcript_version();
+        let mut transcript =
+            Self::setup_public_parameters_transcript(protocol_context, language_public_parameters)?;
+
         if statements.iter().any(|statement| {
             transcript
-                .serialize_to_transcript_as_json(b"statement value", &statement)
+                .serialize_to_transcript_with_version(b"statement value", &statement, version)
                 .is_err()
         }) {
             return Err(Error::InvalidParameters);
@@ -366,7 +479,11 @@ impl<
 
         if statement_masks_values.iter().any(|statement_mask| {
             transcript
-                .serialize_to_transcript_as_json(b"statement mask value", &statement_mask)
+                .serialize_to_transcript_with_version(
+                    b"statement mask value",
+                    &statement_mask,
+                    version,
+                )
                 .is_err()
         }) {
             return Err(Error::InvalidParameters);
@@ -375,6 +492,29 @@ impl<
         Ok(transcript)
     }
 
+    /// Set up the transcript from which the challenges are derived, binding it to the proof share
+    /// round of session `session_id` for proofs aggregated in a session.
+    pub(super) fn setup_challenge_transcript(
+        session_id: Option<&SessionID>,
+        protocol_context: &ProtocolContext,
+        language_public_parameters: &Language::PublicParameters,
+        statements: Vec<group::Value<Language::StatementSpaceGroupElement>>,
+        statement_masks_values: &[group::Value<Language::StatementSpaceGroupElement>; REPETITIONS],
+    ) -> Result<Transcript> {
+        let mut transcript = Self::setup_transcript(
+            protocol_context,
+            language_public_parameters,
+            statements,
+            statement_masks_values,
+        )?;
+
+        if let Some(session_id) = session_id {
+            session_id.append_to_transcript(&mut transcript, aggregation::PROOF_SHARE_ROUND);
+        }
+
+        Ok(transcript)
+    }
+
     pub(crate) fn compute_challenges(
         batch_size: usize,
         transcript: &mut Transcript,
@@ -405,6 +545,8 @@ pub(super) mod test_helpers {
     use std::marker::PhantomData;
 
     use criterion::measurement::{Measurement, WallTime};
+    use group::wire::WireEncoding;
+    use proof::TranscriptVersion;
     use rand_core::OsRng;
 
     use super::*;
@@ -456,8 +598,15 @@ pub(super) mod test_helpers {
             rng,
         );
 
+        let decoded_proof =
+            Proof::from_wire_bytes(&proof.to_wire_bytes().unwrap()).expect("proofs should decode");
+        assert_eq!(
+            decoded_proof, proof,
+            "proofs should round-trip through the wire format"
+        );
+
         assert!(
-            proof
+            decoded_proof
                 .verify(&PhantomData, language_public_parameters, statements)
                 .is_ok(),
             "valid proofs should verify"
@@ -510,7 +659,7 @@ pub(super) mod test_helpers {
         );
 
         let mut invalid_proof = valid_proof.clone();
-        invalid_proof.responses = [wrong_witness.value(); REPETITIONS];
+        invalid_proof.responses = array::from_fn(|_| wrong_witness.value());
 
         assert!(
             matches!(
@@ -524,7 +673,7 @@ pub(super) mod test_helpers {
         );
 
         let mut invalid_proof = valid_proof.clone();
-        invalid_proof.statement_masks = [wrong_statement.neutral().value(); REPETITIONS];
+        invalid_proof.statement_masks = array::from_fn(|_| wrong_statement.neutral().value());
 
         assert!(
             matches!(
@@ -538,7 +687,7 @@ pub(super) mod test_helpers {
         );
 
         let mut invalid_proof = valid_proof.clone();
-        invalid_proof.responses = [wrong_witness.neutral().value(); REPETITIONS];
+        invalid_proof.responses = array::from_fn(|_| wrong_witness.neutral().value());
 
         assert!(
             matches!(
@@ -553,7 +702,8 @@ pub(super) mod test_helpers {
 
         if let Some(invalid_statement_space_value) = invalid_statement_space_value {
             let mut invalid_proof = valid_proof.clone();
-            invalid_proof.statement_masks = [invalid_statement_space_value; REPETITIONS];
+            invalid_proof.statement_masks =
+                array::from_fn(|_| invalid_statement_space_value.clone());
 
             assert!(matches!(
             invalid_proof
@@ -571,7 +721,7 @@ pub(super) mod test_helpers {
 
         if let Some(invalid_witness_space_value) = invalid_witness_space_value {
             let mut invalid_proof = valid_proof.clone();
-            invalid_proof.responses = [invalid_witness_space_value; REPETITIONS];
+            invalid_proof.responses = array::from_fn(|_| invalid_witness_space_value.clone());
 
             assert!(matches!(
             invalid_proof
@@ -640,6 +790,12 @@ pub(super) mod test_helpers {
             [group::Value<Language::StatementSpaceGroupElement>; REPETITIONS],
         >,
     ) -> Transcript {
+        let version = language_public_parameters
+            .as_ref()
+            .map_or_else(TranscriptVersion::default, |language_public_parameters| {
+                language_public_parameters.transcript_version()
+            });
+
         let mut transcript = if language_name {
             Transcript::new(Language::NAME.as_bytes())
         } else {
@@ -648,35 +804,42 @@ pub(super) mod test_helpers {
 
         if let Some(protocol_context) = protocol_context {
             transcript
-                .serialize_to_transcript_as_json(b"protocol context", &protocol_context)
+                .serialize_to_transcript_with_version(
+                    b"protocol context",
+                    &protocol_context,
+                    version,
+                )
                 .unwrap()
         }
 
         language_public_parameters.map(|language_public_parameters| {
             transcript
-                .serialize_to_transcript_as_json(
+                .serialize_to_transcript_with_version(
                     b"language public parameters",
                     &language_public_parameters,
+                    version,
                 )
                 .unwrap();
 
             transcript
-                .serialize_to_transcript_as_json(
+                .serialize_to_transcript_with_version(
                     b"witness space public parameters",
                     &language_public_parameters.witness_space_public_parameters(),
+                    version,
                 )
                 .unwrap();
 
-            transcript.serialize_to_transcript_as_json(
+            transcript.serialize_to_transcript_with_version(
                 b"statement space public parameters",
                 &language_public_parameters.statement_space_public_parameters(),
+                version,
             )
         });
 
         if let Some(statements) = statements {
             statements.iter().for_each(|statement| {
                 transcript
-                    .serialize_to_transcript_as_json(b"statement value", &statement)
+                    .serialize_to_transcript_with_version(b"statement value", &statement, version)
                     .unwrap()
             })
         }
@@ -684,7 +847,11 @@ pub(super) mod test_helpers {
         if let Some(statement_masks) = statement_masks_values {
             statement_masks.iter().for_each(|statement_mask| {
                 transcript
-                    .serialize_to_transcript_as_json(b"statement mask value", &statement_mask)
+                    .serialize_to_transcript_with_version(
+                        b"statement mask value",
+                        &statement_mask,
+                        version,
+                    )
                     .unwrap()
             })
         }
@@ -725,7 +892,7 @@ pub(super) mod test_helpers {
                         Some(protocol_context.clone()),
                         Some(language_public_parameters.clone()),
                         Some(statement_values.clone()),
-                        Some(proof.statement_masks),
+                        Some(proof.statement_masks.clone()),
                     ),
                     language_public_parameters,
                     statements.clone()
@@ -743,7 +910,7 @@ pub(super) mod test_helpers {
                             Some(protocol_context.clone()),
                             Some(language_public_parameters.clone()),
                             Some(statement_values.clone()),
-                            Some(proof.statement_masks),
+                            Some(proof.statement_masks.clone()),
                         ),
                         language_public_parameters,
                         statements.clone()
@@ -764,7 +931,7 @@ pub(super) mod test_helpers {
                             None,
                             Some(language_public_parameters.clone()),
                             Some(statement_values.clone()),
-                            Some(proof.statement_masks),
+                            Some(proof.statement_masks.clone()),
                         ),
                         language_public_parameters,
                         statements.clone()
@@ -785,7 +952,7 @@ pub(super) mod test_helpers {
                             Some(protocol_context.clone()),
                             None,
                             Some(statement_values.clone()),
-                            Some(proof.statement_masks),
+                            Some(proof.statement_masks.clone()),
                         ),
                         language_public_parameters,
                         statements.clone()
@@ -806,7 +973,7 @@ pub(super) mod test_helpers {
                             Some(protocol_context.clone()),
                             Some(language_public_parameters.clone()),
                             None,
-                            Some(proof.statement_masks),
+                            Some(proof.statement_masks.clone()),
                         ),
                         language_public_parameters,
                         statements.clone()
@@ -888,7 +1055,7 @@ pub(super) mod test_helpers {
                     statements_values.clone(),
                     // just a stub value as the value doesn't affect the benchmarking of
                     // this function
-                    &[*statements_values.first().unwrap(); REPETITIONS],
+                    &array::from_fn(|_| statements_values.first().unwrap().clone()),
                 )
                 .unwrap(),
             );
@@ -938,3 +1105,91 @@ pub(super) mod test_helpers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use group::secp256k1;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{knowledge_of_discrete_log, BIT_SOUNDNESS_PROOFS_REPETITIONS};
+
+    type Lang = knowledge_of_discrete_log::Language<secp256k1::Scalar, secp256k1::GroupElement>;
+
+    #[test]
+    fn binary_challenges_only_take_their_least_significant_bit() {
+        let group_public_parameters = secp256k1::group_element::PublicParameters::default();
+        let language_public_parameters: language::PublicParameters<
+            BIT_SOUNDNESS_PROOFS_REPETITIONS,
+            Lang,
+        > = knowledge_of_discrete_log::PublicParameters::new::<
+            secp256k1::Scalar,
+            secp256k1::GroupElement,
+        >(
+            secp256k1::scalar::PublicParameters::default(),
+            group_public_parameters.clone(),
+            group_public_parameters.generator,
+        );
+
+        // The prover need not know the discrete log of the statement to forge a proof for the
+        // challenge $1$: it takes random responses $z$ and sets the statement masks to
+        // $t = \phi(z) - X$.
+        let statement =
+            <Lang as language::Language<BIT_SOUNDNESS_PROOFS_REPETITIONS>>::homomorphose(
+                &secp256k1::Scalar::sample(
+                    language_public_parameters.witness_space_public_parameters(),
+                    &mut OsRng,
+                )
+                .unwrap(),
+                &language_public_parameters,
+            )
+            .unwrap();
+        let responses: [secp256k1::Scalar; BIT_SOUNDNESS_PROOFS_REPETITIONS] =
+            array::from_fn(|_| {
+                secp256k1::Scalar::sample(
+                    language_public_parameters.witness_space_public_parameters(),
+                    &mut OsRng,
+                )
+                .unwrap()
+            });
+        let statement_masks = responses.map(|response| {
+            (<Lang as language::Language<BIT_SOUNDNESS_PROOFS_REPETITIONS>>::homomorphose(
+                &response,
+                &language_public_parameters,
+            )
+            .unwrap()
+                - statement)
+                .value()
+        });
+        let proof = Proof::<BIT_SOUNDNESS_PROOFS_REPETITIONS, Lang, ()>::new(
+            statement_masks,
+            responses.map(|response| response.value()),
+        );
+
+        let challenges = |challenge: u64| {
+            array::from_fn(|_| vec![ComputationalSecuritySizedNumber::from(challenge)])
+        };
+
+        assert!(proof
+            .verify_inner(challenges(1), &language_public_parameters, vec![statement])
+            .is_ok());
+        assert!(proof
+            .verify_inner(challenges(3), &language_public_parameters, vec![statement])
+            .is_ok());
+
+        // A non-zero challenge whose least significant bit is $0$ is the challenge $0$, for which
+        // the forged responses are invalid.
+        assert!(proof
+            .verify_inner(challenges(2), &language_public_parameters, vec![statement])
+            .is_err());
+        assert!(proof
+            .verify_inner(challenges(0), &language_public_parameters, vec![statement])
+            .is_err());
+
+        // Fiat-Shamir challenges are (almost) never zero, so that this forgery would verify if any
+        // non-zero challenge were taken as $1$.
+        assert!(proof
+            .verify(&(), &language_public_parameters, vec![statement])
+            .is_err());
+    }
+}
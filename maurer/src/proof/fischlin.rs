@@ -1,19 +1,25 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::array;
+use alloc::{vec, vec::Vec};
+use core::array;
 
 use crypto_bigint::{rand_core::CryptoRngCore, Concat};
 use group::{ComputationalSecuritySizedNumber, GroupElement};
 use merlin::Transcript;
-use proof::TranscriptProtocol;
+use proof::{TranscriptProtocol, TranscriptVersion};
 use serde::{Deserialize, Serialize};
 
-use crate::{language, language::WitnessSpaceValue, Error, Result};
+use crate::{
+    language,
+    language::{GroupsPublicParametersAccessors, WitnessSpaceValue},
+    Error, Result,
+};
 
 /// A Universally Composable (UC) Maurer Zero-Knowledge Proof via Fischlin's transform.
 /// Implements [Chen and Lindell (2024)](https://eprint.iacr.org/2024/526.pdf).
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(bound = "")]
 pub struct Proof<
     // Number of parallel repetitions $\rho$ required to get a negligible knowledge error.
     const REPETITIONS: usize,
@@ -75,7 +81,13 @@ impl<
             let mut response = randomizer; // $z_i$
 
             loop {
-                if Self::hash_hits_target(common_hash, i, challenge, &response.value())? {
+                if Self::hash_hits_target(
+                    common_hash,
+                    i,
+                    challenge,
+                    &response.value(),
+                    language_public_parameters.transcript_version(),
+                )? {
                     break;
                 }
 
@@ -99,7 +111,9 @@ impl<
         }
         let challenges_and_responses = challenges_and_responses.map(|res| res.unwrap());
 
-        let challenges = challenges_and_responses.map(|(challenge, _)| challenge);
+        let challenges = challenges_and_responses
+            .clone()
+            .map(|(challenge, _)| challenge);
 
         let responses = challenges_and_responses.map(|(_, response)| response);
 
@@ -132,10 +146,16 @@ impl<
         let hash_checks: Vec<_> = self
             .challenges
             .into_iter()
-            .zip(self.maurer_proof.responses)
+            .zip(self.maurer_proof.responses.clone())
             .enumerate()
             .map(|(i, (challenge, response))| {
-                Self::hash_hits_target(common_hash, i, challenge, &response)
+                Self::hash_hits_target(
+                    common_hash,
+                    i,
+                    challenge,
+                    &response,
+                    language_public_parameters.transcript_version(),
+                )
             })
             .collect();
 
@@ -181,6 +201,7 @@ impl<
         i: usize,
         challenge: ComputationalSecuritySizedNumber,
         response: &WitnessSpaceValue<REPETITIONS, Language>,
+        transcript_version: TranscriptVersion,
     ) -> Result<bool> {
         // Set up the transcript
         let mut transcript = Transcript::new(b"Fischlin hash check");
@@ -191,7 +212,11 @@ impl<
         // Add the challenge $e_i$
         transcript.append_uint(b"challenge", &challenge);
         // Add the response $z_i$
-        let res = transcript.serialize_to_transcript_as_json(b"response", &response);
+        let res = transcript.serialize_to_transcript_with_version(
+            b"response",
+            &response,
+            transcript_version,
+        );
         if res.is_err() {
             return Err(res.err().unwrap())?;
         }
@@ -219,6 +244,7 @@ pub(crate) mod test_helpers {
     use std::{marker::PhantomData, time::Duration};
 
     use criterion::measurement::{Measurement, WallTime};
+    use group::wire::WireEncoding;
     use rand_core::OsRng;
 
     use super::*;
@@ -249,7 +275,14 @@ pub(crate) mod test_helpers {
         let (proof, statement) =
             generate_valid_fischlin_proof::<REPETITIONS, Language>(language_public_parameters, rng);
 
-        let res = proof.verify(&PhantomData, language_public_parameters, statement);
+        let decoded_proof =
+            Proof::from_wire_bytes(&proof.to_wire_bytes().unwrap()).expect("proofs should decode");
+        assert_eq!(
+            decoded_proof, proof,
+            "Fischlin proofs should round-trip through the wire format"
+        );
+
+        let res = decoded_proof.verify(&PhantomData, language_public_parameters, statement);
         assert!(
             res.is_ok(),
             "valid Fischlin proofs should verify, got error {:?}",
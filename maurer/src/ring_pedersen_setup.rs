@@ -0,0 +1,354 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use commitment::{ring_pedersen, GroupsPublicParametersAccessors as _};
+use crypto_bigint::{Encoding, Uint};
+use group::{bounded_integers, multiplicative, GroupElement as _, StatisticalSecuritySizedNumber};
+use proof::TranscriptVersion;
+use serde::{Deserialize, Serialize};
+
+use crate::{language::GroupsPublicParameters, Result, BIT_SOUNDNESS_PROOFS_REPETITIONS};
+
+/// Ring-Pedersen Setup Parameters Maurer Language.
+///
+/// $$ \lambda \mapsto t^\lambda \mod N $$
+///
+/// Proves that the message generators $s_i = t^{\lambda_i}$ of
+/// [`RingPedersen`](commitment::RingPedersen) public parameters belong to the subgroup generated
+/// by the randomness generator $t$, which the statistical hiding of the commitment relies on.
+///
+/// SECURITY NOTICE:
+/// The order of $\mathbb{Z}_N^*$ is hidden from the verifier, so that knowledge-soundness can
+/// only be assured with binary challenges, and this language must be used with
+/// [`BIT_SOUNDNESS_PROOFS_REPETITIONS`] repetitions: from two responses $z, z'$ to challenges
+/// that differ in one bit, $\lambda = z - z'$ (over the integers) is extracted.
+///
+/// Zero-knowledge is statistical, as the randomizers are sampled from a range larger than that
+/// of the witnesses by [`StatisticalSecuritySizedNumber::BITS`] bits; the statistical distance
+/// grows linearly with the batch size.
+///
+/// This language proves nothing about the randomness generator $t$ or the modulus $N$ themselves,
+/// which are taken from the public parameters as is. These are proven separately: that $N$ is a
+/// Paillier-Blum modulus with [`proof::paillier_blum_modulus`], and that $t$ is a quadratic
+/// residue with [`crate::knowledge_of_square_root`]. That the prime factors of $N$ are safe
+/// primes, and that $t$ generates all of $\mathbb{QR}_N$ rather than a subgroup of it, is not
+/// proven by any of these, and the verifier must get these guarantees elsewhere, e.g. from a
+/// trusted setup.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug, Eq)]
+pub struct Language<const WITNESS_LIMBS: usize, const LIMBS: usize> {
+    _witness_choice: PhantomData<[(); WITNESS_LIMBS]>,
+    _group_element_choice: PhantomData<[(); LIMBS]>,
+}
+
+impl<const WITNESS_LIMBS: usize, const LIMBS: usize>
+    crate::Language<BIT_SOUNDNESS_PROOFS_REPETITIONS> for Language<WITNESS_LIMBS, LIMBS>
+where
+    Uint<WITNESS_LIMBS>: Encoding,
+    Uint<LIMBS>: Encoding,
+{
+    type WitnessSpaceGroupElement = bounded_integers::GroupElement<WITNESS_LIMBS>;
+    type StatementSpaceGroupElement = multiplicative::GroupElement<LIMBS>;
+
+    type PublicParameters = PublicParameters<WITNESS_LIMBS, LIMBS>;
+
+    const NAME: &'static str = "Ring-Pedersen Setup Parameters";
+
+    fn homomorphose(
+        witness: &Self::WitnessSpaceGroupElement,
+        language_public_parameters: &Self::PublicParameters,
+    ) -> Result<Self::StatementSpaceGroupElement> {
+        let base = multiplicative::GroupElement::new(
+            language_public_parameters.base,
+            &language_public_parameters
+                .groups_public_parameters
+                .statement_space_public_parameters,
+        )?;
+
+        Ok(witness.mul_group_element(&base))
+    }
+}
+
+/// The Public Parameters of the Ring-Pedersen Setup Parameters Maurer Language.
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct PublicParameters<const WITNESS_LIMBS: usize, const LIMBS: usize>
+where
+    Uint<LIMBS>: Encoding,
+{
+    pub groups_public_parameters: GroupsPublicParameters<
+        bounded_integers::PublicParameters<WITNESS_LIMBS>,
+        multiplicative::PublicParameters<LIMBS>,
+    >,
+    pub base: multiplicative::Value<LIMBS>,
+}
+
+impl<const WITNESS_LIMBS: usize, const LIMBS: usize> PublicParameters<WITNESS_LIMBS, LIMBS>
+where
+    Uint<WITNESS_LIMBS>: Encoding,
+    Uint<LIMBS>: Encoding,
+{
+    /// Instantiate the language public parameters for proving the well-formedness of
+    /// `commitment_scheme_public_parameters`, whose discrete logs $\lambda_i$ are sampled from
+    /// their randomness space.
+    ///
+    /// Returns the public parameters alongside the statements to prove (and verify) the proof
+    /// over, i.e. the message generators.
+    pub fn new<const BATCH_SIZE: usize, const MESSAGE_LIMBS: usize, const RANDOMNESS_LIMBS: usize>(
+        commitment_scheme_public_parameters: &ring_pedersen::PublicParameters<
+            BATCH_SIZE,
+            MESSAGE_LIMBS,
+            RANDOMNESS_LIMBS,
+            LIMBS,
+        >,
+    ) -> Result<(Self, Vec<multiplicative::GroupElement<LIMBS>>)>
+    where
+        Uint<RANDOMNESS_LIMBS>: Encoding,
+    {
+        let commitment_space_public_parameters = commitment_scheme_public_parameters
+            .commitment_space_public_parameters()
+            .clone();
+
+        let witness_space_public_parameters = bounded_integers::PublicParameters::new(
+            commitment_scheme_public_parameters
+                .randomness_space_public_parameters()
+                .sample_bits()
+                + StatisticalSecuritySizedNumber::BITS,
+        )?;
+
+        let statements = commitment_scheme_public_parameters
+            .message_generators
+            .iter()
+            .map(|value| {
+                multiplicative::GroupElement::new(*value, &commitment_space_public_parameters)
+            })
+            .collect::<group::Result<Vec<_>>>()?;
+
+        Ok((
+            Self {
+                groups_public_parameters: GroupsPublicParameters {
+                    witness_space_public_parameters,
+                    statement_space_public_parameters: commitment_space_public_parameters,
+                    transcript_version: TranscriptVersion::default(),
+                },
+                base: commitment_scheme_public_parameters.randomness_generator,
+            },
+            statements,
+        ))
+    }
+}
+
+impl<const WITNESS_LIMBS: usize, const LIMBS: usize>
+    AsRef<
+        GroupsPublicParameters<
+            bounded_integers::PublicParameters<WITNESS_LIMBS>,
+            multiplicative::PublicParameters<LIMBS>,
+        >,
+    > for PublicParameters<WITNESS_LIMBS, LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn as_ref(
+        &self,
+    ) -> &GroupsPublicParameters<
+        bounded_integers::PublicParameters<WITNESS_LIMBS>,
+        multiplicative::PublicParameters<LIMBS>,
+    > {
+        &self.groups_public_parameters
+    }
+}
+
+pub type Proof<const WITNESS_LIMBS: usize, const LIMBS: usize, ProtocolContext> =
+    crate::Proof<BIT_SOUNDNESS_PROOFS_REPETITIONS, Language<WITNESS_LIMBS, LIMBS>, ProtocolContext>;
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{U1024, U256, U512};
+    use group::Samplable;
+    use rand_core::OsRng;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        knowledge_of_square_root, language::GroupsPublicParametersAccessors as _, test_helpers,
+    };
+
+    /// Safe primes $P, Q$ and their product $N$; a small modulus keeps the $128$ repetitions cheap.
+    const P: U256 =
+        U256::from_be_hex("9BE54368FDAEB7E5D45D1EF3F6A1EAF9642CB55F167E01B5378E341AFE8737AB");
+    const Q: U256 =
+        U256::from_be_hex("ED311C1AFE7490C94D5BC73D59E7991FBE8C926A5C95E6F9A61B9B16C805C6A7");
+    const N: U512 = U512::from_be_hex("907127679D90180A1BC2CEE979BD5E494292F1491CFD325194D1F6D998019F44F13C87192206A8018869755B2B83ABE65C6FECA9852F94C7D4CDCC4C4D9A928D");
+
+    type Lang = Language<{ U1024::LIMBS }, { U512::LIMBS }>;
+
+    type CommitmentSchemePublicParameters =
+        ring_pedersen::PublicParameters<2, { U256::LIMBS }, { U1024::LIMBS }, { U512::LIMBS }>;
+
+    fn language_public_parameters() -> PublicParameters<{ U1024::LIMBS }, { U512::LIMBS }> {
+        let (commitment_scheme_public_parameters, ..) =
+            CommitmentSchemePublicParameters::setup(N, 200, &mut OsRng).unwrap();
+
+        PublicParameters::new(&commitment_scheme_public_parameters)
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn proves_setup_parameters() {
+        let (commitment_scheme_public_parameters, _, discrete_logs) =
+            CommitmentSchemePublicParameters::setup(N, 200, &mut OsRng).unwrap();
+        let (language_public_parameters, statements) =
+            PublicParameters::<{ U1024::LIMBS }, { U512::LIMBS }>::new(
+                &commitment_scheme_public_parameters,
+            )
+            .unwrap();
+
+        let witnesses = discrete_logs
+            .map(|discrete_log| {
+                bounded_integers::GroupElement::new(
+                    discrete_log.value().resize(),
+                    language_public_parameters.witness_space_public_parameters(),
+                )
+                .unwrap()
+            })
+            .to_vec();
+
+        let (proof, proven_statements) =
+            Proof::prove(&(), &language_public_parameters, witnesses, &mut OsRng).unwrap();
+        assert_eq!(proven_statements, statements);
+        assert!(proof
+            .verify(&(), &language_public_parameters, statements.clone())
+            .is_ok());
+
+        // A message generator outside the subgroup generated by $t$.
+        let mut wrong_statements = statements;
+        wrong_statements[1] = multiplicative::GroupElement::sample(
+            language_public_parameters.statement_space_public_parameters(),
+            &mut OsRng,
+        )
+        .unwrap();
+        assert!(proof
+            .verify(&(), &language_public_parameters, wrong_statements)
+            .is_err());
+    }
+
+    #[test]
+    fn proves_well_formed_public_parameters() {
+        let (commitment_scheme_public_parameters, randomness_generator_square_root, discrete_logs) =
+            CommitmentSchemePublicParameters::setup(N, 200, &mut OsRng).unwrap();
+
+        // $N$ is a Paillier-Blum modulus.
+        let (p, q) = (P.resize(), Q.resize());
+        let modulus_proof =
+            proof::paillier_blum_modulus::Proof::prove(&(), &N, &p, &q, &mut OsRng).unwrap();
+        assert!(modulus_proof.verify(&(), &N).is_ok());
+
+        // $t$ is a quadratic residue.
+        let square_root_language_public_parameters =
+            knowledge_of_square_root::PublicParameters::new(
+                commitment_scheme_public_parameters
+                    .commitment_space_public_parameters()
+                    .clone(),
+            );
+        let (square_root_proof, randomness_generator) = knowledge_of_square_root::Proof::prove(
+            &(),
+            &square_root_language_public_parameters,
+            vec![randomness_generator_square_root],
+            &mut OsRng,
+        )
+        .unwrap();
+        assert_eq!(
+            randomness_generator[0].value(),
+            commitment_scheme_public_parameters.randomness_generator
+        );
+        assert!(square_root_proof
+            .verify(
+                &(),
+                &square_root_language_public_parameters,
+                randomness_generator
+            )
+            .is_ok());
+
+        // $s_i \in \langle t \rangle$.
+        let (language_public_parameters, statements) =
+            PublicParameters::<{ U1024::LIMBS }, { U512::LIMBS }>::new(
+                &commitment_scheme_public_parameters,
+            )
+            .unwrap();
+        let witnesses = discrete_logs
+            .map(|discrete_log| {
+                bounded_integers::GroupElement::new(
+                    discrete_log.value().resize(),
+                    language_public_parameters.witness_space_public_parameters(),
+                )
+                .unwrap()
+            })
+            .to_vec();
+        let (setup_proof, _) =
+            Proof::prove(&(), &language_public_parameters, witnesses, &mut OsRng).unwrap();
+        assert!(setup_proof
+            .verify(&(), &language_public_parameters, statements)
+            .is_ok());
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn valid_proof_verifies(#[case] batch_size: usize) {
+        test_helpers::valid_proof_verifies::<BIT_SOUNDNESS_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn invalid_proof_fails_verification(#[case] batch_size: usize) {
+        test_helpers::invalid_proof_fails_verification::<BIT_SOUNDNESS_PROOFS_REPETITIONS, Lang>(
+            None,
+            None,
+            &language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn proof_over_invalid_public_parameters_fails_verification(#[case] batch_size: usize) {
+        let verifier_public_parameters = language_public_parameters();
+        let mut prover_public_parameters = verifier_public_parameters.clone();
+        prover_public_parameters.base = multiplicative::GroupElement::new(
+            prover_public_parameters.base,
+            prover_public_parameters.statement_space_public_parameters(),
+        )
+        .unwrap()
+        .double()
+        .value();
+
+        test_helpers::proof_over_invalid_public_parameters_fails_verification::<
+            BIT_SOUNDNESS_PROOFS_REPETITIONS,
+            Lang,
+        >(
+            &prover_public_parameters,
+            &verifier_public_parameters,
+            batch_size,
+            &mut OsRng,
+        );
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn proof_with_incomplete_transcript_fails(#[case] batch_size: usize) {
+        test_helpers::proof_with_incomplete_transcript_fails::<BIT_SOUNDNESS_PROOFS_REPETITIONS, Lang>(
+            &language_public_parameters(),
+            batch_size,
+            &mut OsRng,
+        )
+    }
+}
@@ -1,13 +1,15 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::{marker::PhantomData, ops::Mul};
+use core::{marker::PhantomData, ops::Mul};
 
 use commitment::{GroupsPublicParametersAccessors, HomomorphicCommitmentScheme};
 use group::{
     direct_product, helpers::FlatMapResults, self_product, BoundedGroupElement, CyclicGroupElement,
-    Samplable,
+    Samplable, VartimeGroupElement,
 };
+use proof::TranscriptVersion;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::{language::GroupsPublicParameters, Error, Result, SOUND_PROOFS_REPETITIONS};
 
@@ -44,8 +46,9 @@ impl<
             + Samplable
             + Mul<GroupElement, Output = GroupElement>
             + for<'r> Mul<&'r GroupElement, Output = GroupElement>
-            + Copy,
-        GroupElement: CyclicGroupElement,
+            + Copy
+            + Zeroize,
+        GroupElement: CyclicGroupElement + VartimeGroupElement,
         CommitmentScheme: HomomorphicCommitmentScheme<
             SCALAR_LIMBS,
             MessageSpaceGroupElement = self_product::GroupElement<BATCH_SIZE, Scalar>,
@@ -82,6 +85,7 @@ impl<
 
         let bases = language_public_parameters
             .bases
+            .clone()
             .map(|base| {
                 GroupElement::new(base, language_public_parameters.group_public_parameters())
             })
@@ -264,7 +268,8 @@ impl<
             + Samplable
             + Mul<GroupElement, Output = GroupElement>
             + for<'r> Mul<&'r GroupElement, Output = GroupElement>
-            + Copy,
+            + Copy
+            + Zeroize,
         GroupElement: group::GroupElement<
             Value = GroupElementValue,
             PublicParameters = GroupPublicParameters,
@@ -302,6 +307,7 @@ impl<
                         .clone(),
                     group_public_parameters,
                 ),
+                transcript_version: TranscriptVersion::default(),
             },
             commitment_scheme_public_parameters,
             bases,
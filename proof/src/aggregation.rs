@@ -10,14 +10,18 @@
 
 #![allow(clippy::type_complexity)]
 
-use std::{
-    collections::{HashMap, HashSet},
-    fmt::Debug,
-};
+use alloc::vec::Vec;
+use core::fmt::Debug;
 
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::PartyID;
+use group::{
+    collections::{HashMap, HashSet},
+    PartyID,
+};
 use serde::{Deserialize, Serialize};
+pub use threshold::Quorum;
+
+pub mod threshold;
 
 /// Proof aggregation error.
 #[derive(thiserror::Error, Debug)]
@@ -47,10 +51,37 @@ pub enum Error {
 
     #[error("parties {:?} sent a proof share that does not pass verification", .0)]
     ProofShareVerification(Vec<PartyID>),
+
+    #[error("parties {:?} were excluded from the session, leaving fewer provers than the threshold", .0)]
+    ThresholdNotReached(Vec<PartyID>),
+}
+
+impl Error {
+    /// The parties to blame for this error, if it is attributable to any.
+    pub fn blamed_parties(&self) -> Option<&[PartyID]> {
+        match self {
+            Error::UnresponsiveParties(parties)
+            | Error::InvalidCommitment(parties)
+            | Error::InvalidDecommitment(parties)
+            | Error::WrongDecommitment(parties)
+            | Error::WrongNumberOfDecommittedStatements(parties)
+            | Error::InvalidProofShare(parties)
+            | Error::ProofShareVerification(parties) => Some(parties),
+            Error::NonParticipatingParty | Error::ThresholdNotReached(_) => None,
+        }
+    }
 }
 
 /// Proof aggregation result.
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// The round identifier bound into the transcript of the commitment round, together with the
+/// session identifier.
+pub const COMMITMENT_ROUND: u64 = 0;
+
+/// The round identifier bound into the transcript from which the challenges answered in the proof
+/// share round are derived, together with the session identifier.
+pub const PROOF_SHARE_ROUND: u64 = 2;
 
 /// The commitment round party of a proof aggregation protocol.
 pub trait CommitmentRoundParty<Output>: Sized {
@@ -73,7 +104,7 @@ pub trait CommitmentRoundParty<Output>: Sized {
     fn commit_statements_and_statement_mask(
         self,
         rng: &mut impl CryptoRngCore,
-    ) -> std::result::Result<(Self::Commitment, Self::DecommitmentRoundParty), Self::Error>;
+    ) -> core::result::Result<(Self::Commitment, Self::DecommitmentRoundParty), Self::Error>;
 }
 
 /// The decommitment round party of a proof aggregation protocol.
@@ -100,7 +131,7 @@ pub trait DecommitmentRoundParty<Output>: Sized {
         self,
         commitments: HashMap<PartyID, Self::Commitment>,
         rng: &mut impl CryptoRngCore,
-    ) -> std::result::Result<(Self::Decommitment, Self::ProofShareRoundParty), Self::Error>;
+    ) -> core::result::Result<(Self::Decommitment, Self::ProofShareRoundParty), Self::Error>;
 }
 
 /// The proof share round party of a proof aggregation protocol.
@@ -127,7 +158,7 @@ pub trait ProofShareRoundParty<Output>: Sized {
         self,
         decommitments: HashMap<PartyID, Self::Decommitment>,
         rng: &mut impl CryptoRngCore,
-    ) -> std::result::Result<(Self::ProofShare, Self::ProofAggregationRoundParty), Self::Error>;
+    ) -> core::result::Result<(Self::ProofShare, Self::ProofAggregationRoundParty), Self::Error>;
 }
 
 /// The proof aggregation round party of a proof aggregation protocol.
@@ -144,7 +175,7 @@ pub trait ProofAggregationRoundParty<Output>: Sized {
         self,
         proof_shares: HashMap<PartyID, Self::ProofShare>,
         rng: &mut impl CryptoRngCore,
-    ) -> std::result::Result<Output, Self::Error>;
+    ) -> core::result::Result<Output, Self::Error>;
 }
 
 pub fn process_incoming_messages<T>(
@@ -194,6 +225,12 @@ pub mod test_helpers {
     use rand_core::OsRng;
 
     use super::*;
+    use crate::session::{
+        aggregation_session,
+        envelope::test_helpers::generate_keys,
+        network::{Fault, Network},
+        threshold_aggregation_session, SessionID,
+    };
 
     pub fn commitment_round<Output, P: CommitmentRoundParty<Output>>(
         commitment_round_parties: HashMap<PartyID, P>,
@@ -421,6 +458,67 @@ pub mod test_helpers {
             }));
     }
 
+    /// Test identifiable abort of proof shares replayed from another session.
+    ///
+    /// `other_session_commitment_round_parties` should prove the same statements in a different
+    /// session, so that only the binding of the session into the transcript separates the two.
+    pub fn proof_share_from_another_session_aborts_session_identifiably<
+        Output,
+        P: CommitmentRoundParty<Output>,
+    >(
+        commitment_round_parties: HashMap<PartyID, P>,
+        other_session_commitment_round_parties: HashMap<PartyID, P>,
+    ) {
+        let (commitments, decommitment_round_parties) =
+            commitment_round(commitment_round_parties).unwrap();
+
+        let (decommitments, proof_share_round_parties) =
+            decommitment_round(commitments, decommitment_round_parties).unwrap();
+
+        let (proof_shares, proof_aggregation_round_parties) =
+            proof_share_round(decommitments, proof_share_round_parties).unwrap();
+
+        let (other_session_commitments, other_session_decommitment_round_parties) =
+            commitment_round(other_session_commitment_round_parties).unwrap();
+
+        let (other_session_decommitments, other_session_proof_share_round_parties) =
+            decommitment_round(
+                other_session_commitments,
+                other_session_decommitment_round_parties,
+            )
+            .unwrap();
+
+        let (other_session_proof_shares, _) = proof_share_round(
+            other_session_decommitments,
+            other_session_proof_share_round_parties,
+        )
+        .unwrap();
+
+        let replaying_party = *proof_shares.keys().choose(&mut OsRng).unwrap();
+
+        let proof_shares: HashMap<_, _> = proof_shares
+            .into_iter()
+            .map(|(party_id, proof_share)| {
+                if party_id == replaying_party {
+                    (party_id, other_session_proof_shares[&party_id].clone())
+                } else {
+                    (party_id, proof_share)
+                }
+            })
+            .collect();
+
+        assert!(proof_aggregation_round_parties
+            .into_iter()
+            .filter(|(party_id, _)| *party_id != replaying_party)
+            .all(|(_, party)| {
+                let res = party.aggregate_proof_shares(proof_shares.clone(), &mut OsRng);
+                matches!(
+                    res.err().unwrap().try_into().unwrap(),
+                    Error::ProofShareVerification(parties) if parties == vec![replaying_party]
+                )
+            }));
+    }
+
     /// Test identifiable abort of unresponsive parties.
     pub fn unresponsive_parties_aborts_session_identifiably<
         Output,
@@ -524,6 +622,172 @@ pub mod test_helpers {
             }));
     }
 
+    /// Test that a threshold-tolerant session excludes parties that were unresponsive in the
+    /// commitment round, and then aggregates over the remaining quorum of provers.
+    ///
+    /// The session is run end to end over the simulated broadcast network, in which all but
+    /// `threshold` of the `provers` never respond, and restarts without any intervention.
+    ///
+    /// Returns the parties excluded from the session and the output of the restarted session.
+    /// Requires `threshold` to be smaller than the number of `provers`.
+    pub fn aggregates_over_remaining_quorum<
+        Output: Clone + PartialEq + Debug + 'static,
+        P: CommitmentRoundParty<Output> + 'static,
+    >(
+        threshold: PartyID,
+        provers: HashSet<PartyID>,
+        session_id: SessionID,
+        new_session: impl Fn(PartyID, SessionID, HashSet<PartyID>) -> P + Clone + 'static,
+    ) -> (Vec<PartyID>, Output)
+    where
+        P::Error: From<Error> + From<group::Error> + 'static,
+    {
+        let round_timeout = 1;
+        let number_of_unresponsive_parties = provers.len() - usize::from(threshold);
+        let mut unresponsive_parties = provers
+            .iter()
+            .copied()
+            .choose_multiple(&mut OsRng, number_of_unresponsive_parties);
+        unresponsive_parties.sort();
+
+        let quorum = Quorum::new(threshold, provers.clone()).unwrap();
+
+        let outputs = Network::new(
+            provers
+                .iter()
+                .filter(|party_id| !unresponsive_parties.contains(party_id))
+                .map(|&party_id| {
+                    threshold_aggregation_session(
+                        party_id,
+                        session_id,
+                        quorum.clone(),
+                        new_session.clone(),
+                    )
+                }),
+            round_timeout,
+        )
+        .run(&mut OsRng);
+
+        assert_eq!(outputs.len(), usize::from(threshold));
+
+        let outputs: Vec<_> = outputs
+            .into_values()
+            .map(|output| output.unwrap())
+            .collect();
+
+        // All honest parties should agree on the excluded parties and on the output.
+        let (output, quorum) = outputs.first().unwrap().clone();
+        assert!(outputs
+            .into_iter()
+            .all(|(other_output, other_quorum)| other_output == output && other_quorum == quorum));
+        assert_eq!(quorum.excluded_parties(), unresponsive_parties);
+
+        // Excluding any further party should bring us below the threshold.
+        let mut exhausted_quorum = quorum.clone();
+        assert!(matches!(
+            exhausted_quorum
+                .exclude(quorum.provers().iter().copied().take(1))
+                .err()
+                .unwrap(),
+            Error::ThresholdNotReached(_)
+        ));
+        assert_eq!(exhausted_quorum, quorum);
+
+        (quorum.excluded_parties(), output)
+    }
+
+    /// Test aggregation over the simulated broadcast network, with authenticated messages delayed
+    /// within the round timeout.
+    pub fn aggregates_over_network<Output: 'static, P: CommitmentRoundParty<Output> + 'static>(
+        commitment_round_parties: HashMap<PartyID, P>,
+    ) -> Output
+    where
+        P::Error: From<Error> + From<group::Error> + 'static,
+    {
+        let round_timeout = 2;
+        let mut provers: Vec<_> = commitment_round_parties.keys().copied().collect();
+        provers.sort();
+
+        let session_id = SessionID::derive(b"aggregation", &provers).unwrap();
+        let keys = generate_keys(provers.iter().copied().max().unwrap_or_default());
+
+        let network = provers.iter().fold(
+            Network::new(
+                commitment_round_parties
+                    .into_iter()
+                    .map(|(party_id, party)| {
+                        aggregation_session(party_id, party).authenticated(
+                            session_id,
+                            keys[&party_id],
+                            keys.clone(),
+                        )
+                    }),
+                round_timeout,
+            ),
+            |network, party_id| {
+                let round_number = (0..3).choose(&mut OsRng).unwrap();
+
+                network.inject_fault(*party_id, round_number, Fault::Delay(round_timeout - 1))
+            },
+        );
+
+        let outputs = network.run(&mut OsRng);
+
+        assert_eq!(outputs.len(), provers.len());
+
+        outputs
+            .into_values()
+            .map(|output| output.unwrap())
+            .next()
+            .unwrap()
+    }
+
+    /// Test identifiable abort over the simulated broadcast network, by injecting `fault` into the
+    /// authenticated message of a random party in a random round.
+    pub fn faulty_party_aborts_session_identifiably<
+        Output: 'static,
+        P: CommitmentRoundParty<Output> + 'static,
+    >(
+        commitment_round_parties: HashMap<PartyID, P>,
+        fault: Fault,
+    ) where
+        P::Error: From<Error> + From<group::Error> + 'static,
+    {
+        let round_timeout = 2;
+        let faulty_party = *commitment_round_parties.keys().choose(&mut OsRng).unwrap();
+        let round_number = (0..3).choose(&mut OsRng).unwrap();
+
+        let mut provers: Vec<_> = commitment_round_parties.keys().copied().collect();
+        provers.sort();
+
+        let session_id = SessionID::derive(b"aggregation", &provers).unwrap();
+        let keys = generate_keys(provers.iter().copied().max().unwrap_or_default());
+
+        let outputs = Network::new(
+            commitment_round_parties
+                .into_iter()
+                .map(|(party_id, party)| {
+                    aggregation_session(party_id, party).authenticated(
+                        session_id,
+                        keys[&party_id],
+                        keys.clone(),
+                    )
+                }),
+            round_timeout,
+        )
+        .inject_fault(faulty_party, round_number, fault)
+        .run(&mut OsRng);
+
+        assert!(outputs
+            .into_iter()
+            .filter(|(party_id, _)| *party_id != faulty_party)
+            .all(|(_, output)| {
+                let error: Error = output.err().unwrap().try_into().unwrap();
+
+                error.blamed_parties() == Some(&[faulty_party][..])
+            }));
+    }
+
     /// Test aggregation.
     pub fn aggregates<Output, P: CommitmentRoundParty<Output>>(
         commitment_round_parties: HashMap<PartyID, P>,
@@ -0,0 +1,166 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Threshold-tolerant proof aggregation.
+//!
+//! By default, a proof aggregation session aborts identifiably as soon as any prover is
+//! unresponsive or misbehaves. [`Quorum`] instead lets a party exclude the parties blamed by such
+//! an abort, and restart the session from the commitment round over the remaining provers, for as
+//! long as at least `threshold` of them remain. [`threshold_aggregation_session`] drives this end
+//! to end, restarting the session automatically until it succeeds or the threshold is no longer
+//! reached.
+//!
+//! [`threshold_aggregation_session`]: crate::session::threshold_aggregation_session
+//!
+//! SECURITY NOTE: the restarted session must be instantiated anew (i.e. via `new_session()`), so
+//! that fresh randomizers are sampled. Never re-use a party of the aborted session: a party that
+//! answers two different challenges with the same randomizers leaks its witness. The restarted
+//! session must also run in a session of its own, derived with [`SessionID::restart()`], so that
+//! the messages of the aborted session are rejected in it.
+//!
+//! [`SessionID::restart()`]: crate::session::SessionID::restart
+//!
+//! As the protocol assumes a reliable broadcast channel, all honest parties observe the same
+//! messages, and thus agree on the blamed parties and on the provers of the restarted session.
+
+use alloc::{vec, vec::Vec};
+
+use group::{collections::HashSet, PartyID};
+
+use crate::aggregation::{Error, Result};
+
+/// The set of provers participating in a threshold-tolerant proof aggregation session, which
+/// shrinks as blamed parties are excluded across restarts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Quorum {
+    // The minimal number of provers required for the session to proceed.
+    threshold: PartyID,
+    // The set of provers which were not excluded from the session.
+    provers: HashSet<PartyID>,
+    // The set of parties excluded from the session.
+    excluded_parties: HashSet<PartyID>,
+}
+
+impl Quorum {
+    /// Instantiate a new quorum of `provers`, which can proceed as long as at least `threshold` of
+    /// them remain.
+    pub fn new(threshold: PartyID, provers: HashSet<PartyID>) -> Result<Self> {
+        if threshold == 0 || provers.len() < usize::from(threshold) {
+            return Err(Error::ThresholdNotReached(vec![]));
+        }
+
+        Ok(Self {
+            threshold,
+            provers,
+            excluded_parties: HashSet::new(),
+        })
+    }
+
+    /// The threshold of provers required for the session to proceed.
+    pub fn threshold(&self) -> PartyID {
+        self.threshold
+    }
+
+    /// The remaining provers, with whom the session should be (re)started.
+    pub fn provers(&self) -> &HashSet<PartyID> {
+        &self.provers
+    }
+
+    /// The parties excluded from the session so far, sorted.
+    pub fn excluded_parties(&self) -> Vec<PartyID> {
+        let mut excluded_parties: Vec<_> = self.excluded_parties.iter().copied().collect();
+        excluded_parties.sort();
+
+        excluded_parties
+    }
+
+    /// Exclude `parties` from the session.
+    ///
+    /// Returns [`Error::ThresholdNotReached`] if fewer than `threshold` provers would remain, in
+    /// which case the session cannot be restarted, and the quorum is left unchanged.
+    pub fn exclude(&mut self, parties: impl IntoIterator<Item = PartyID>) -> Result<()> {
+        let parties: HashSet<PartyID> = parties
+            .into_iter()
+            .filter(|party_id| self.provers.contains(party_id))
+            .collect();
+
+        if self.provers.len() - parties.len() < usize::from(self.threshold) {
+            let mut excluded_parties: Vec<_> =
+                self.excluded_parties.union(&parties).copied().collect();
+            excluded_parties.sort();
+
+            return Err(Error::ThresholdNotReached(excluded_parties));
+        }
+
+        self.provers.retain(|party_id| !parties.contains(party_id));
+        self.excluded_parties.extend(parties);
+
+        Ok(())
+    }
+
+    /// Exclude the parties blamed by an aborted session.
+    ///
+    /// Returns `error` if it does not identify any party to blame, in which case the abort cannot
+    /// be recovered from by a restart.
+    pub fn exclude_blamed_parties(&mut self, error: Error) -> Result<()> {
+        match error.blamed_parties() {
+            Some(blamed_parties) if !blamed_parties.is_empty() => {
+                let blamed_parties = blamed_parties.to_vec();
+
+                self.exclude(blamed_parties)
+            }
+            _ => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn excludes_blamed_parties() {
+        let mut quorum = Quorum::new(3, HashSet::from([1, 2, 3, 4, 5])).unwrap();
+
+        quorum
+            .exclude_blamed_parties(Error::UnresponsiveParties(vec![4]))
+            .unwrap();
+        quorum
+            .exclude_blamed_parties(Error::ProofShareVerification(vec![2]))
+            .unwrap();
+
+        assert_eq!(quorum.provers(), &HashSet::from([1, 3, 5]));
+        assert_eq!(quorum.excluded_parties(), vec![2, 4]);
+    }
+
+    #[test]
+    fn fails_below_threshold() {
+        assert!(matches!(
+            Quorum::new(4, HashSet::from([1, 2, 3])).err().unwrap(),
+            Error::ThresholdNotReached(parties) if parties.is_empty()
+        ));
+
+        let mut quorum = Quorum::new(2, HashSet::from([1, 2, 3])).unwrap();
+
+        assert!(matches!(
+            quorum.exclude_blamed_parties(Error::InvalidDecommitment(vec![1, 3])).err().unwrap(),
+            Error::ThresholdNotReached(parties) if parties == vec![1, 3]
+        ));
+        assert_eq!(quorum.provers(), &HashSet::from([1, 2, 3]));
+        assert!(quorum.excluded_parties().is_empty());
+    }
+
+    #[test]
+    fn fails_on_unattributable_errors() {
+        let mut quorum = Quorum::new(2, HashSet::from([1, 2, 3])).unwrap();
+
+        assert!(matches!(
+            quorum
+                .exclude_blamed_parties(Error::NonParticipatingParty)
+                .err()
+                .unwrap(),
+            Error::NonParticipatingParty
+        ));
+        assert_eq!(quorum.provers(), &HashSet::from([1, 2, 3]));
+    }
+}
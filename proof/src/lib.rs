@@ -1,12 +1,20 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub use range::{AggregatableRangeProof, RangeProof};
-pub use transcript_protocol::TranscriptProtocol;
+pub use transcript_protocol::{
+    canonical_encoding, CanonicalEncoding, TranscriptProtocol, TranscriptVersion,
+};
 mod transcript_protocol;
 
 pub mod aggregation;
+pub mod paillier_blum_modulus;
 pub mod range;
+pub mod session;
 
 /// Proof error.
 #[derive(thiserror::Error, Debug)]
@@ -23,6 +31,9 @@ pub enum Error {
     #[error("serialization/deserialization error")]
     Serialization(#[from] serde_json::Error),
 
+    #[error("canonical encoding error")]
+    CanonicalEncoding(#[from] canonical_encoding::Error),
+
     #[error("invalid proof: did not satisfy the verification equation")]
     ProofVerification,
 
@@ -34,12 +45,12 @@ pub enum Error {
 }
 
 /// Proof result.
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 impl TryInto<aggregation::Error> for Error {
     type Error = Self;
 
-    fn try_into(self) -> std::result::Result<aggregation::Error, Self::Error> {
+    fn try_into(self) -> core::result::Result<aggregation::Error, Self::Error> {
         match self {
             Error::Aggregation(e) => Ok(e),
             e => Err(e),
@@ -0,0 +1,380 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! A proof that an RSA modulus is a Paillier-Blum modulus.
+
+use alloc::vec::Vec;
+
+use crypto_bigint::{
+    modular::runtime_mod::{DynResidue, DynResidueParams},
+    rand_core::CryptoRngCore,
+    Encoding, NonZero, RandomMod, Uint,
+};
+use group::{helpers::primality::is_prime, ComputationalSecuritySizedNumber};
+use merlin::Transcript;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result, TranscriptProtocol};
+
+/// The number of repetitions of the proof; each repetition can be answered for a modulus that is
+/// not a Paillier-Blum modulus with probability at most $1/2$.
+pub const REPETITIONS: usize = ComputationalSecuritySizedNumber::BITS;
+
+/// A non-interactive proof that a modulus $N$ is a Paillier-Blum modulus: $N = PQ$ for primes
+/// $P = Q = 3 \mod 4$, with $\gcd(N, \varphi(N)) = 1$.
+///
+/// This is the Paillier-Blum modulus proof $\Pi^{\textsf{mod}}$ of
+/// [CGGMP21](https://eprint.iacr.org/2021/060.pdf). The prover sends $w$ with Jacobi symbol $-1$,
+/// and for every challenge $y_i \in \mathbb{Z}_N$ responds with $z_i = y_i^{N^{-1} \mod
+/// \varphi(N)}$ and a fourth root $x_i$ of $y_i' = (-1)^{a_i} w^{b_i} y_i$, for the (unique)
+/// $a_i, b_i \in \{0, 1\}$ making $y_i'$ a quadratic residue. The verifier checks that $N$ is an
+/// odd composite, that $z_i^N = y_i$ and that $x_i^4 = y_i'$.
+///
+/// SECURITY NOTICE:
+/// This does *not* prove that $P$ and $Q$ are large (nor that they are safe primes), which e.g.
+/// requires a separate no-small-factors proof.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Proof<const LIMBS: usize>
+where
+    Uint<LIMBS>: Encoding,
+{
+    w: Uint<LIMBS>,
+    responses: Vec<Response<LIMBS>>,
+}
+
+/// The response to a single challenge $y$.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Response<const LIMBS: usize>
+where
+    Uint<LIMBS>: Encoding,
+{
+    /// A fourth root of $(-1)^a w^b y$.
+    x: Uint<LIMBS>,
+    a: bool,
+    b: bool,
+    /// The $N$-th root of $y$.
+    z: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> Proof<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    /// Proves that `modulus` $N = PQ$ is a Paillier-Blum modulus, given its prime factors `p` and
+    /// `q`.
+    pub fn prove(
+        protocol_context: &impl Serialize,
+        modulus: &Uint<LIMBS>,
+        p: &Uint<LIMBS>,
+        q: &Uint<LIMBS>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Self> {
+        let (product, carry) = p.mul_wide(q);
+        if product != *modulus
+            || carry != Uint::ZERO
+            || p == q
+            || p.as_words()[0] & 3 != 3
+            || q.as_words()[0] & 3 != 3
+        {
+            return Err(Error::InvalidParameters);
+        }
+
+        // $\varphi(N) = N - P - Q + 1$.
+        let totient = modulus
+            .wrapping_sub(p)
+            .wrapping_sub(q)
+            .wrapping_add(&Uint::ONE);
+        let (modulus_inverse, is_invertible) = modulus.inv_mod(&totient);
+        if !bool::from(is_invertible) {
+            return Err(Error::InvalidParameters);
+        }
+
+        let p_factor = PrimeFactor::new(p);
+        let q_factor = PrimeFactor::new(q);
+        let q_inverse = DynResidue::new(q, p_factor.params).invert().0;
+        let modulus_params = DynResidueParams::new(modulus);
+
+        // Sample $w$ with Jacobi symbol $-1$, i.e. a quadratic residue modulo exactly one of the
+        // prime factors.
+        let modulus_non_zero = NonZero::new(*modulus).unwrap();
+        let w = loop {
+            let w = Uint::<LIMBS>::random_mod(rng, &modulus_non_zero);
+
+            let (w_p, w_q) = (p_factor.residue(&w), q_factor.residue(&w));
+            if (p_factor.is_quadratic_residue(&w_p) && q_factor.is_non_residue(&w_q))
+                || (p_factor.is_non_residue(&w_p) && q_factor.is_quadratic_residue(&w_q))
+            {
+                break w;
+            }
+        };
+
+        let mut transcript = Self::setup_transcript(protocol_context, modulus, &w)?;
+        let responses = Self::challenges(modulus, &mut transcript)
+            .into_iter()
+            .map(|y| {
+                let z = DynResidue::new(&y, modulus_params)
+                    .pow(&modulus_inverse)
+                    .retrieve();
+
+                // Exactly one of $y, -y, wy, -wy$ is a quadratic residue modulo both $P$ and $Q$,
+                // as $-1$ is a non-residue modulo both and $w$ modulo exactly one of them.
+                let (a, b) = [(false, false), (true, false), (false, true), (true, true)]
+                    .into_iter()
+                    .find(|(a, b)| {
+                        p_factor.is_quadratic_residue(&p_factor.twist(&y, &w, *a, *b))
+                            && q_factor.is_quadratic_residue(&q_factor.twist(&y, &w, *a, *b))
+                    })
+                    .ok_or(Error::InvalidParameters)?;
+
+                // Combine the fourth roots modulo $P$ and $Q$ with the CRT:
+                // $x = x_Q + Q \cdot ((x_P - x_Q) \cdot Q^{-1} \mod P)$.
+                let x_p = p_factor.fourth_root(&p_factor.twist(&y, &w, a, b));
+                let x_q = q_factor.fourth_root(&q_factor.twist(&y, &w, a, b));
+                let h = ((p_factor.residue(&x_p) - p_factor.residue(&x_q)) * q_inverse).retrieve();
+                let x = x_q.wrapping_add(&q.wrapping_mul(&h));
+
+                Ok(Response { x, a, b, z })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { w, responses })
+    }
+
+    /// Verifies that `modulus` is a Paillier-Blum modulus.
+    pub fn verify(&self, protocol_context: &impl Serialize, modulus: &Uint<LIMBS>) -> Result<()> {
+        if modulus.as_words()[0] & 1 == 0
+            || *modulus == Uint::ONE
+            || is_prime(modulus)
+            || self.w == Uint::ZERO
+            || self.w >= *modulus
+            || self.responses.len() != REPETITIONS
+        {
+            return Err(Error::ProofVerification);
+        }
+
+        let params = DynResidueParams::new(modulus);
+        let w = DynResidue::new(&self.w, params);
+        let mut transcript = Self::setup_transcript(protocol_context, modulus, &self.w)?;
+
+        let verified = Self::challenges(modulus, &mut transcript)
+            .into_iter()
+            .zip(&self.responses)
+            .all(|(y, response)| {
+                let y = DynResidue::new(&y, params);
+                let x = DynResidue::new(&response.x, params);
+                let z = DynResidue::new(&response.z, params);
+
+                let mut twisted_y = if response.b { w * y } else { y };
+                if response.a {
+                    twisted_y = -twisted_y;
+                }
+
+                response.x < *modulus
+                    && response.z < *modulus
+                    && z.pow_bounded_exp(modulus, modulus.bits_vartime()) == y
+                    && x.square().square() == twisted_y
+            });
+
+        if verified {
+            Ok(())
+        } else {
+            Err(Error::ProofVerification)
+        }
+    }
+
+    fn setup_transcript(
+        protocol_context: &impl Serialize,
+        modulus: &Uint<LIMBS>,
+        w: &Uint<LIMBS>,
+    ) -> Result<Transcript> {
+        let mut transcript = Transcript::new(b"Paillier-Blum Modulus");
+
+        transcript.serialize_to_transcript(b"protocol context", protocol_context)?;
+        transcript.append_uint(b"modulus", modulus);
+        transcript.append_uint(b"w", w);
+
+        Ok(transcript)
+    }
+
+    /// Derives the challenges $y_i$, uniformly in $[0, N)$ by rejection sampling.
+    fn challenges(modulus: &Uint<LIMBS>, transcript: &mut Transcript) -> Vec<Uint<LIMBS>> {
+        let mask = Uint::<LIMBS>::ONE
+            .shl_vartime(modulus.bits_vartime())
+            .wrapping_sub(&Uint::ONE);
+
+        (0..REPETITIONS)
+            .map(|_| loop {
+                let challenge = transcript.challenge::<LIMBS>(b"challenge").bitand(&mask);
+
+                if challenge < *modulus {
+                    transcript.append_uint(b"challenge", &challenge);
+
+                    break challenge;
+                }
+            })
+            .collect()
+    }
+}
+
+/// A prime factor $P = 3 \mod 4$ of the modulus, for which square roots of quadratic residues are
+/// computed as $x^{(P + 1) / 4}$.
+struct PrimeFactor<const LIMBS: usize> {
+    params: DynResidueParams<LIMBS>,
+    /// $(P - 1) / 2$, the exponent of Euler's criterion.
+    euler_exponent: Uint<LIMBS>,
+    /// $(P + 1) / 4$.
+    square_root_exponent: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> PrimeFactor<LIMBS> {
+    fn new(prime: &Uint<LIMBS>) -> Self {
+        Self {
+            params: DynResidueParams::new(prime),
+            euler_exponent: prime.shr_vartime(1),
+            square_root_exponent: prime.shr_vartime(2).wrapping_add(&Uint::ONE),
+        }
+    }
+
+    fn residue(&self, value: &Uint<LIMBS>) -> DynResidue<LIMBS> {
+        DynResidue::new(value, self.params)
+    }
+
+    fn is_quadratic_residue(&self, value: &DynResidue<LIMBS>) -> bool {
+        value.pow(&self.euler_exponent) == DynResidue::one(self.params)
+    }
+
+    fn is_non_residue(&self, value: &DynResidue<LIMBS>) -> bool {
+        value.pow(&self.euler_exponent) == -DynResidue::one(self.params)
+    }
+
+    /// Computes $(-1)^a w^b y$.
+    fn twist(&self, y: &Uint<LIMBS>, w: &Uint<LIMBS>, a: bool, b: bool) -> DynResidue<LIMBS> {
+        let mut twisted_y = self.residue(y);
+        if b {
+            twisted_y *= self.residue(w);
+        }
+        if a {
+            twisted_y = -twisted_y;
+        }
+
+        twisted_y
+    }
+
+    /// Computes the fourth root of the quadratic residue `value` which is itself a quadratic
+    /// residue; as every square root computed is a power of a quadratic residue, it is one too.
+    fn fourth_root(&self, value: &DynResidue<LIMBS>) -> Uint<LIMBS> {
+        value
+            .pow(&self.square_root_exponent)
+            .pow(&self.square_root_exponent)
+            .retrieve()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{U256, U512};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    /// Safe primes, and hence Blum primes.
+    const P: U256 =
+        U256::from_be_hex("9BE54368FDAEB7E5D45D1EF3F6A1EAF9642CB55F167E01B5378E341AFE8737AB");
+    const Q: U256 =
+        U256::from_be_hex("ED311C1AFE7490C94D5BC73D59E7991FBE8C926A5C95E6F9A61B9B16C805C6A7");
+
+    fn factors() -> (U512, U512, U512) {
+        let (p, q) = (P.resize(), Q.resize());
+
+        (p.wrapping_mul(&q), p, q)
+    }
+
+    #[test]
+    fn proves_paillier_blum_modulus() {
+        let (n, p, q) = factors();
+
+        let proof = Proof::prove(&"context", &n, &p, &q, &mut OsRng).unwrap();
+        assert!(proof.verify(&"context", &n).is_ok());
+
+        // The proof is bound to the protocol context and the modulus.
+        assert!(matches!(
+            proof.verify(&"other context", &n),
+            Err(Error::ProofVerification)
+        ));
+        assert!(matches!(
+            proof.verify(&"context", &n.wrapping_add(&U512::from_u8(2))),
+            Err(Error::ProofVerification)
+        ));
+
+        let mut wrong_proof = proof.clone();
+        wrong_proof.responses[7].b = !wrong_proof.responses[7].b;
+        assert!(matches!(
+            wrong_proof.verify(&"context", &n),
+            Err(Error::ProofVerification)
+        ));
+
+        let mut wrong_proof = proof;
+        wrong_proof.responses.pop();
+        assert!(matches!(
+            wrong_proof.verify(&"context", &n),
+            Err(Error::ProofVerification)
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_factors() {
+        let (n, p, q) = factors();
+
+        assert!(matches!(
+            Proof::prove(&(), &n, &p, &p, &mut OsRng),
+            Err(Error::InvalidParameters)
+        ));
+
+        // $5$ and $13$ are $1 \mod 4$.
+        assert!(matches!(
+            Proof::prove(
+                &(),
+                &U512::from_u8(65),
+                &U512::from_u8(5),
+                &U512::from_u8(13),
+                &mut OsRng
+            ),
+            Err(Error::InvalidParameters)
+        ));
+
+        assert!(matches!(
+            Proof::prove(&(), &n.wrapping_add(&U512::from_u8(2)), &p, &q, &mut OsRng),
+            Err(Error::InvalidParameters)
+        ));
+    }
+
+    #[test]
+    fn rejects_prime_modulus() {
+        // For a prime $P = 3 \mod 4$, $z = y$ is the $P$-th root of $y$, and fourth roots of
+        // either $y$ or $-y$ can be computed, so that only the primality test rejects the proof.
+        let p: U512 = P.resize();
+        let factor = PrimeFactor::new(&p);
+        let w = p.wrapping_sub(&U512::ONE);
+
+        let mut transcript = Proof::setup_transcript(&(), &p, &w).unwrap();
+        let responses = Proof::challenges(&p, &mut transcript)
+            .into_iter()
+            .map(|y| {
+                let a = !factor.is_quadratic_residue(&factor.residue(&y));
+                let x = factor.fourth_root(&factor.twist(&y, &w, a, false));
+
+                Response {
+                    x,
+                    a,
+                    b: false,
+                    z: y,
+                }
+            })
+            .collect();
+
+        assert!(matches!(
+            Proof { w, responses }.verify(&(), &p),
+            Err(Error::ProofVerification)
+        ));
+    }
+}
@@ -1,18 +1,21 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+#[cfg(feature = "bulletproofs")]
 pub mod bulletproofs;
 
-use std::{
-    collections::{HashMap, HashSet},
-    fmt::Debug,
-};
+use alloc::vec::Vec;
+use core::fmt::Debug;
 
 use commitment::{GroupsPublicParametersAccessors, HomomorphicCommitmentScheme};
 use crypto_bigint::rand_core::CryptoRngCore;
-use group::{self_product, NumbersGroupElement, PartyID};
+use group::{
+    collections::{HashMap, HashSet},
+    self_product, NumbersGroupElement, PartyID,
+};
 use merlin::Transcript;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::aggregation;
 
@@ -31,7 +34,8 @@ pub trait RangeProof<
 
     /// An element of the group from which the range proof's commitment scheme message space is composed,
     /// used to prove a single range claim.
-    type RangeClaimGroupElement: NumbersGroupElement<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>;
+    type RangeClaimGroupElement: NumbersGroupElement<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS>
+        + Zeroize;
 
     /// The commitment scheme used for the range proof
     type CommitmentScheme<const NUM_RANGE_CLAIMS: usize>: HomomorphicCommitmentScheme<COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS, MessageSpaceGroupElement=self_product::GroupElement<NUM_RANGE_CLAIMS, Self::RangeClaimGroupElement>>;
@@ -16,17 +16,18 @@ pub mod decommitment_round;
 pub mod proof_aggregation_round;
 pub mod proof_share_round;
 
-use std::{
-    array,
-    collections::{HashMap, HashSet},
-    iter,
-};
+use alloc::{vec, vec::Vec};
+use core::{array, iter};
 
 use bulletproofs::{BulletproofGens, PedersenGens};
-use commitment::{MultiPedersen, Pedersen};
+use commitment::MultiPedersen;
 use crypto_bigint::{rand_core::CryptoRngCore, U256, U64};
 use curve25519_dalek::ristretto::RistrettoPoint;
-use group::{helpers::FlatMapResults, ristretto, GroupElement as _, PartyID};
+use group::{
+    collections::{HashMap, HashSet},
+    helpers::FlatMapResults,
+    ristretto, GroupElement as _, PartyID,
+};
 use merlin::Transcript;
 pub use proof_aggregation_round::Output;
 use serde::{Deserialize, Serialize};
@@ -375,36 +376,16 @@ pub struct PublicParameters<const NUM_RANGE_CLAIMS: usize> {
     pub number_of_range_claims: usize,
 }
 
+/// The commitment generators are those of bulletproofs' [`PedersenGens::default()`], as the range
+/// proof commits with them. They are reproduced by
+/// [`commitment::pedersen::PublicParameters::bulletproofs_default()`], whose randomness generator
+/// is not derived with `HashToGroup`.
 impl<const NUM_RANGE_CLAIMS: usize> Default for PublicParameters<NUM_RANGE_CLAIMS> {
     fn default() -> Self {
-        let scalar_public_parameters = ristretto::scalar::PublicParameters::default();
-        let group_public_parameters = ristretto::group_element::PublicParameters::default();
-
-        let commitment_generators = PedersenGens::default();
-
-        let commitment_scheme_public_parameters = commitment::PublicParameters::<
-            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
-            Pedersen<
-                1,
-                COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
-                ristretto::Scalar,
-                ristretto::GroupElement,
-            >,
-        >::new::<
-            COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS,
-            ristretto::Scalar,
-            ristretto::GroupElement,
-        >(
-            scalar_public_parameters,
-            group_public_parameters,
-            [commitment_generators.B.compress().try_into().unwrap()],
-            commitment_generators
-                .B_blinding
-                .compress()
-                .try_into()
-                .unwrap(),
-        )
-        .into();
+        let commitment_scheme_public_parameters =
+            commitment::pedersen::PublicParameters::bulletproofs_default()
+                .unwrap()
+                .into();
 
         Self {
             commitment_scheme_public_parameters,
@@ -573,7 +554,7 @@ mod tests {
     use std::collections::{HashMap, HashSet};
 
     use bulletproofs::range_proof_mpc::party;
-    use group::{ristretto::Scalar, PartyID, Samplable};
+    use group::{ristretto::Scalar, wire::WireEncoding, PartyID, Samplable};
     use rand::{prelude::IteratorRandom, Rng};
     use rand_core::OsRng;
     use rstest::rstest;
@@ -640,6 +621,28 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn default_public_parameters_use_bulletproofs_generators() {
+        let commitment_generators = PedersenGens::default();
+        let public_parameters = PublicParameters::<NUM_RANGE_CLAIMS>::default();
+        let pedersen_public_parameters = &public_parameters
+            .commitment_scheme_public_parameters
+            .pedersen_public_parameters;
+
+        assert_eq!(
+            pedersen_public_parameters.message_generators,
+            [commitment_generators.B.compress().try_into().unwrap()]
+        );
+        assert_eq!(
+            pedersen_public_parameters.randomness_generator,
+            commitment_generators
+                .B_blinding
+                .compress()
+                .try_into()
+                .unwrap()
+        );
+    }
+
     #[rstest]
     #[case(2, 2, 1)]
     #[case(2, 4, 3)]
@@ -659,6 +662,9 @@ mod tests {
         let public_parameters = PublicParameters::default();
         let transcript = Transcript::new("".as_bytes());
 
+        let range_proof = super::RangeProof::from_wire_bytes(&range_proof.to_wire_bytes().unwrap())
+            .expect("aggregated range proofs should decode");
+
         assert!(
             range_proof
                 .verify(&public_parameters, commitments, transcript, &mut OsRng)
@@ -1,13 +1,14 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::{collections::HashSet, iter};
+use alloc::vec::Vec;
+use core::iter;
 
 use bulletproofs::{
     range_proof_mpc::{dealer::Dealer, messages::BitCommitment, party},
     BulletproofGens, PedersenGens,
 };
 use crypto_bigint::{rand_core::CryptoRngCore, U256, U64};
-use group::PartyID;
+use group::{collections::HashSet, PartyID};
 use merlin::Transcript;
 
 use super::{decommitment_round, COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS, RANGE_CLAIM_BITS};
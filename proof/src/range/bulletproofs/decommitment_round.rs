@@ -3,11 +3,8 @@
 
 #![allow(non_snake_case)]
 
-use std::{
-    collections::{HashMap, HashSet},
-    iter,
-    ops::Neg,
-};
+use alloc::vec::Vec;
+use core::{iter, ops::Neg};
 
 use bulletproofs::{
     range_proof_mpc::{
@@ -19,7 +16,10 @@ use bulletproofs::{
 };
 use crypto_bigint::rand_core::CryptoRngCore;
 use curve25519_dalek::{ristretto::RistrettoPoint, traits::Identity};
-use group::PartyID;
+use group::{
+    collections::{HashMap, HashSet},
+    PartyID,
+};
 
 use crate::{
     aggregation,
@@ -1,11 +1,8 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::{
-    collections::{HashMap, HashSet},
-    iter,
-    ops::Neg,
-};
+use alloc::vec::Vec;
+use core::{iter, ops::Neg};
 
 use bulletproofs::{
     exp_iter,
@@ -17,7 +14,10 @@ use bulletproofs::{
 };
 use crypto_bigint::rand_core::CryptoRngCore;
 use curve25519_dalek::scalar::Scalar;
-use group::{ristretto, PartyID};
+use group::{
+    collections::{HashMap, HashSet},
+    ristretto, PartyID,
+};
 
 use super::{COMMITMENT_SCHEME_MESSAGE_SPACE_SCALAR_LIMBS, RANGE_CLAIM_BITS};
 use crate::{
@@ -1,10 +1,8 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::{
-    collections::{HashMap, HashSet},
-    iter,
-};
+use alloc::vec::Vec;
+use core::iter;
 
 use bulletproofs::range_proof_mpc::{
     dealer::DealerAwaitingPolyCommitments,
@@ -13,7 +11,10 @@ use bulletproofs::range_proof_mpc::{
 };
 use crypto_bigint::rand_core::CryptoRngCore;
 use curve25519_dalek::{ristretto::RistrettoPoint, traits::Identity};
-use group::{ristretto, PartyID};
+use group::{
+    collections::{HashMap, HashSet},
+    ristretto, PartyID,
+};
 
 use crate::{
     aggregation,
@@ -106,7 +107,7 @@ impl<const NUM_RANGE_CLAIMS: usize> ProofShareRoundParty<super::Output<NUM_RANGE
                     .apply_challenge(&poly_challenge)
                     .map_err(bulletproofs::ProofError::from)
             })
-            .collect::<std::result::Result<Vec<_>, _>>()
+            .collect::<core::result::Result<Vec<_>, _>>()
             .map_err(|_| Error::InternalError)?;
 
         let proof_aggregation_round_party = proof_aggregation_round::Party {
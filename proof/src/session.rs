@@ -0,0 +1,1010 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! A generic session runtime for multi-round protocols over a broadcast channel.
+//!
+//! A [`Session`] drives a single party through the rounds of a protocol. In every round, the party
+//! receives the (serialized) messages broadcast by all parties in the previous round, and either
+//! broadcasts a message of its own and proceeds to the next round, or finalizes with an output.
+//!
+//! Rounds are type-erased behind the [`Round`] trait, so that protocols composed of different
+//! sub-protocols (e.g. a proof aggregation session followed by another) can be driven uniformly
+//! by the same runtime.
+//!
+//! Over an unauthenticated transport, sessions should be [`Session::authenticated`], so that every
+//! message is carried in a signed [`Envelope`] bound to the session and round it was sent in.
+
+#![allow(clippy::type_complexity)]
+
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use crypto_bigint::rand_core::CryptoRngCore;
+pub use envelope::{Envelope, SessionID, Signer, Verifier};
+use group::{
+    collections::{HashMap, HashSet},
+    wire::WireEncoding,
+    PartyID,
+};
+
+use crate::aggregation::{
+    self, CommitmentRoundParty, DecommitmentRoundParty, ProofAggregationRoundParty,
+    ProofShareRoundParty, Quorum,
+};
+
+pub mod envelope;
+#[cfg(feature = "test_helpers")]
+pub mod network;
+
+/// The outcome of advancing a round (or a session) of a protocol.
+pub enum Transition<Next, Output> {
+    /// Broadcast `message`, and proceed to `next` once the messages of the other parties for this
+    /// round are received.
+    Continue { message: Vec<u8>, next: Next },
+    /// The protocol has been completed, yielding `output`.
+    Finalize(Output),
+}
+
+/// A single round of a multi-round protocol, from the point of view of a single party.
+pub trait Round<Output, Error> {
+    /// Advance this round, given the `messages` broadcast by the parties in the previous round.
+    /// Receives `self` and not `&self`, to enforce the next round can be instantiated only via a
+    /// valid state transition.
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<Transition<Box<dyn Round<Output, Error>>, Output>, Error>;
+}
+
+/// A session of a multi-round protocol, from the point of view of a single party.
+pub struct Session<Output, Error> {
+    party_id: PartyID,
+    round_number: usize,
+    round: Box<dyn Round<Output, Error>>,
+}
+
+impl<Output: 'static, Error: 'static> Session<Output, Error> {
+    /// Instantiate a new session for `party_id`, starting at `round`.
+    pub fn new(party_id: PartyID, round: impl Round<Output, Error> + 'static) -> Self {
+        Self {
+            party_id,
+            round_number: 0,
+            round: Box::new(round),
+        }
+    }
+
+    /// The party running this session.
+    pub fn party_id(&self) -> PartyID {
+        self.party_id
+    }
+
+    /// The number of the current round, starting at `0`.
+    pub fn round_number(&self) -> usize {
+        self.round_number
+    }
+
+    /// Advance the current round of this session, given the `messages` broadcast by the parties in
+    /// the previous round (for the first round, this is typically empty.)
+    pub fn advance(
+        self,
+        messages: HashMap<PartyID, Vec<u8>>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Transition<Self, Output>, Error> {
+        let rng: &mut dyn CryptoRngCore = rng;
+
+        Ok(match self.round.advance(messages, rng)? {
+            Transition::Continue { message, next } => Transition::Continue {
+                message,
+                next: Self {
+                    party_id: self.party_id,
+                    round_number: self.round_number + 1,
+                    round: next,
+                },
+            },
+            Transition::Finalize(output) => Transition::Finalize(output),
+        })
+    }
+
+    /// Transform the output of this session with `f`.
+    pub fn map<NewOutput: 'static>(
+        self,
+        f: impl FnOnce(Output) -> Result<NewOutput, Error> + 'static,
+    ) -> Session<NewOutput, Error> {
+        Session {
+            party_id: self.party_id,
+            round_number: self.round_number,
+            round: Box::new(Map {
+                round: self.round,
+                f: Box::new(f),
+            }),
+        }
+    }
+
+    /// Transform the error of this session with `f`.
+    pub fn map_err<NewError: 'static>(
+        self,
+        f: impl Fn(Error) -> NewError + 'static,
+    ) -> Session<Output, NewError> {
+        Session {
+            party_id: self.party_id,
+            round_number: self.round_number,
+            round: Box::new(MapErr {
+                round: self.round,
+                f: Box::new(f),
+            }),
+        }
+    }
+
+    /// Continue this session with the session returned by `f` once it finalizes.
+    ///
+    /// The first round of the subsequent session is advanced with no messages, immediately after
+    /// the last round of this session, so that no communication round is wasted.
+    pub fn and_then<NewOutput: 'static>(
+        self,
+        f: impl FnOnce(Output, &mut dyn CryptoRngCore) -> Result<Session<NewOutput, Error>, Error>
+            + 'static,
+    ) -> Session<NewOutput, Error> {
+        Session {
+            party_id: self.party_id,
+            round_number: self.round_number,
+            round: Box::new(AndThen {
+                round: self.round,
+                f: Box::new(f),
+            }),
+        }
+    }
+
+    /// Authenticate the messages of this session.
+    ///
+    /// Outgoing messages are sealed in an [`Envelope`] for session `session_id` and the current
+    /// round, signed with `signer`. Incoming messages are opened and verified against the key of
+    /// their sender in `verifiers`. A message which fails to open, e.g. as it belongs to another
+    /// session or round, did arrive, and so its sender must not be identified as unresponsive.
+    /// Instead, it is replaced with an empty message, which fails to decode as the (non-empty)
+    /// message of any round, so that the session identifies its sender as sending an invalid
+    /// message.
+    pub fn authenticated<S: Signer + 'static, V: Verifier + 'static>(
+        self,
+        session_id: SessionID,
+        signer: S,
+        verifiers: HashMap<PartyID, V>,
+    ) -> Self {
+        Session {
+            party_id: self.party_id,
+            round_number: self.round_number,
+            round: Box::new(Authenticated {
+                session_id,
+                party_id: self.party_id,
+                round_number: self.round_number as u64,
+                signer,
+                verifiers,
+                round: self.round,
+            }),
+        }
+    }
+
+    /// Run this session in parallel with `other`.
+    ///
+    /// The messages of both sessions are broadcast together, in a single message per round. The
+    /// joint session finalizes once both sessions have finalized.
+    pub fn join<OtherOutput: 'static>(
+        self,
+        other: Session<OtherOutput, Error>,
+    ) -> Session<(Output, OtherOutput), Error>
+    where
+        Error: From<group::Error>,
+    {
+        Session {
+            party_id: self.party_id,
+            round_number: self.round_number,
+            round: Box::new(Join {
+                first: Branch::Running(self.round),
+                second: Branch::Running(other.round),
+            }),
+        }
+    }
+
+    /// Run `sessions` in parallel.
+    ///
+    /// The messages of all sessions are broadcast together, in a single message per round. The
+    /// joint session finalizes once all sessions have finalized.
+    pub fn join_all(party_id: PartyID, sessions: Vec<Self>) -> Session<Vec<Output>, Error>
+    where
+        Error: From<group::Error>,
+    {
+        Session {
+            party_id,
+            round_number: 0,
+            round: Box::new(JoinAll {
+                branches: sessions
+                    .into_iter()
+                    .map(|session| Branch::Running(session.round))
+                    .collect(),
+            }),
+        }
+    }
+}
+
+struct Map<Output, NewOutput, Error> {
+    round: Box<dyn Round<Output, Error>>,
+    f: Box<dyn FnOnce(Output) -> Result<NewOutput, Error>>,
+}
+
+impl<Output: 'static, NewOutput: 'static, Error: 'static> Round<NewOutput, Error>
+    for Map<Output, NewOutput, Error>
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<Transition<Box<dyn Round<NewOutput, Error>>, NewOutput>, Error> {
+        Ok(match self.round.advance(messages, rng)? {
+            Transition::Continue { message, next } => Transition::Continue {
+                message,
+                next: Box::new(Map {
+                    round: next,
+                    f: self.f,
+                }),
+            },
+            Transition::Finalize(output) => Transition::Finalize((self.f)(output)?),
+        })
+    }
+}
+
+struct MapErr<Output, Error, NewError> {
+    round: Box<dyn Round<Output, Error>>,
+    f: Box<dyn Fn(Error) -> NewError>,
+}
+
+impl<Output: 'static, Error: 'static, NewError: 'static> Round<Output, NewError>
+    for MapErr<Output, Error, NewError>
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<Transition<Box<dyn Round<Output, NewError>>, Output>, NewError> {
+        match self.round.advance(messages, rng) {
+            Ok(Transition::Continue { message, next }) => Ok(Transition::Continue {
+                message,
+                next: Box::new(MapErr {
+                    round: next,
+                    f: self.f,
+                }),
+            }),
+            Ok(Transition::Finalize(output)) => Ok(Transition::Finalize(output)),
+            Err(e) => Err((self.f)(e)),
+        }
+    }
+}
+
+struct AndThen<Output, NewOutput, Error> {
+    round: Box<dyn Round<Output, Error>>,
+    f: Box<dyn FnOnce(Output, &mut dyn CryptoRngCore) -> Result<Session<NewOutput, Error>, Error>>,
+}
+
+impl<Output: 'static, NewOutput: 'static, Error: 'static> Round<NewOutput, Error>
+    for AndThen<Output, NewOutput, Error>
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<Transition<Box<dyn Round<NewOutput, Error>>, NewOutput>, Error> {
+        match self.round.advance(messages, rng)? {
+            Transition::Continue { message, next } => Ok(Transition::Continue {
+                message,
+                next: Box::new(AndThen {
+                    round: next,
+                    f: self.f,
+                }),
+            }),
+            Transition::Finalize(output) => {
+                let session = (self.f)(output, rng)?;
+
+                session.round.advance(HashMap::new(), rng)
+            }
+        }
+    }
+}
+
+struct Authenticated<Output, Error, S, V> {
+    session_id: SessionID,
+    party_id: PartyID,
+    round_number: u64,
+    signer: S,
+    verifiers: HashMap<PartyID, V>,
+    round: Box<dyn Round<Output, Error>>,
+}
+
+impl<Output: 'static, Error: 'static, S: Signer + 'static, V: Verifier + 'static>
+    Round<Output, Error> for Authenticated<Output, Error, S, V>
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<Transition<Box<dyn Round<Output, Error>>, Output>, Error> {
+        // Messages received in this round were sent in the previous one.
+        let messages = match self.round_number.checked_sub(1) {
+            Some(previous_round_number) => messages
+                .into_iter()
+                .map(|(party_id, message)| {
+                    let message = Envelope::from_bytes(&message)
+                        .and_then(|envelope| {
+                            envelope.open(&self.session_id, previous_round_number, &self.verifiers)
+                        })
+                        .ok()
+                        .filter(|(sender, _)| *sender == party_id)
+                        .map(|(_, message)| message)
+                        .unwrap_or_default();
+
+                    (party_id, message)
+                })
+                .collect(),
+            None => HashMap::new(),
+        };
+
+        Ok(match self.round.advance(messages, rng)? {
+            Transition::Continue { message, next } => Transition::Continue {
+                message: Envelope::seal(
+                    self.session_id,
+                    self.round_number,
+                    self.party_id,
+                    message,
+                    &self.signer,
+                )
+                .to_bytes(),
+                next: Box::new(Authenticated {
+                    session_id: self.session_id,
+                    party_id: self.party_id,
+                    round_number: self.round_number + 1,
+                    signer: self.signer,
+                    verifiers: self.verifiers,
+                    round: next,
+                }),
+            },
+            Transition::Finalize(output) => Transition::Finalize(output),
+        })
+    }
+}
+
+/// A session running as part of a joint session, which may finalize before the others.
+enum Branch<Output, Error> {
+    Running(Box<dyn Round<Output, Error>>),
+    Finalized(Output),
+}
+
+impl<Output: 'static, Error: 'static> Branch<Output, Error> {
+    /// Advance this branch, returning the message it broadcasts in this round (empty, if it had
+    /// already finalized.)
+    fn advance(
+        self,
+        messages: HashMap<PartyID, Vec<u8>>,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Vec<u8>, Self), Error> {
+        match self {
+            Branch::Running(round) => Ok(match round.advance(messages, rng)? {
+                Transition::Continue { message, next } => (message, Branch::Running(next)),
+                Transition::Finalize(output) => (vec![], Branch::Finalized(output)),
+            }),
+            finalized => Ok((vec![], finalized)),
+        }
+    }
+
+    fn output(self) -> Option<Output> {
+        match self {
+            Branch::Running(_) => None,
+            Branch::Finalized(output) => Some(output),
+        }
+    }
+}
+
+struct Join<Output, OtherOutput, Error> {
+    first: Branch<Output, Error>,
+    second: Branch<OtherOutput, Error>,
+}
+
+impl<Output: 'static, OtherOutput: 'static, Error: From<group::Error> + 'static>
+    Round<(Output, OtherOutput), Error> for Join<Output, OtherOutput, Error>
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<
+        Transition<Box<dyn Round<(Output, OtherOutput), Error>>, (Output, OtherOutput)>,
+        Error,
+    > {
+        let mut messages = split_messages(messages, 2).into_iter();
+        let first_messages = messages.next().unwrap_or_default();
+        let second_messages = messages.next().unwrap_or_default();
+
+        let (first_message, first) = self.first.advance(first_messages, rng)?;
+        let (second_message, second) = self.second.advance(second_messages, rng)?;
+
+        Ok(match (first, second) {
+            (Branch::Finalized(first_output), Branch::Finalized(second_output)) => {
+                Transition::Finalize((first_output, second_output))
+            }
+            (first, second) => Transition::Continue {
+                message: vec![first_message, second_message].to_wire_bytes()?,
+                next: Box::new(Join { first, second }),
+            },
+        })
+    }
+}
+
+struct JoinAll<Output, Error> {
+    branches: Vec<Branch<Output, Error>>,
+}
+
+impl<Output: 'static, Error: From<group::Error> + 'static> Round<Vec<Output>, Error>
+    for JoinAll<Output, Error>
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<Transition<Box<dyn Round<Vec<Output>, Error>>, Vec<Output>>, Error> {
+        let messages = split_messages(messages, self.branches.len());
+
+        let (messages, branches): (Vec<_>, Vec<_>) = self
+            .branches
+            .into_iter()
+            .zip(messages)
+            .map(|(branch, messages)| branch.advance(messages, rng))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        if branches
+            .iter()
+            .all(|branch| matches!(branch, Branch::Finalized(_)))
+        {
+            return Ok(Transition::Finalize(
+                branches.into_iter().filter_map(Branch::output).collect(),
+            ));
+        }
+
+        Ok(Transition::Continue {
+            message: messages.to_wire_bytes()?,
+            next: Box::new(JoinAll { branches }),
+        })
+    }
+}
+
+/// Split the joint messages of `number_of_sessions` parallel sessions into the messages of each
+/// session.
+///
+/// A party sending a malformed joint message did send a message, and so must not be identified as
+/// unresponsive. Instead, it is given an empty message in every session, which fails to decode as
+/// the (non-empty) message of any round, so that the sessions themselves identify it as sending an
+/// invalid message.
+fn split_messages(
+    messages: HashMap<PartyID, Vec<u8>>,
+    number_of_sessions: usize,
+) -> Vec<HashMap<PartyID, Vec<u8>>> {
+    let mut split_messages = vec![HashMap::new(); number_of_sessions];
+
+    messages.into_iter().for_each(|(party_id, message)| {
+        let messages = Vec::<Vec<u8>>::from_wire_bytes(&message)
+            .ok()
+            .filter(|messages| messages.len() == number_of_sessions)
+            .unwrap_or_else(|| vec![vec![]; number_of_sessions]);
+
+        split_messages
+            .iter_mut()
+            .zip(messages)
+            .for_each(|(split_messages, message)| {
+                split_messages.insert(party_id, message);
+            });
+    });
+
+    split_messages
+}
+
+/// Deserialize the `messages` received from all parties, blaming the parties whose messages could
+/// not be deserialized with `error`.
+fn deserialize_messages<Message: WireEncoding>(
+    messages: HashMap<PartyID, Vec<u8>>,
+    error: impl FnOnce(Vec<PartyID>) -> aggregation::Error,
+) -> aggregation::Result<HashMap<PartyID, Message>> {
+    let messages: HashMap<_, _> = messages
+        .into_iter()
+        .map(|(party_id, message)| (party_id, Message::from_wire_bytes(&message)))
+        .collect();
+
+    let mut parties_sending_invalid_messages: Vec<PartyID> = messages
+        .iter()
+        .filter(|(_, message)| message.is_err())
+        .map(|(party_id, _)| *party_id)
+        .collect();
+    parties_sending_invalid_messages.sort();
+
+    if !parties_sending_invalid_messages.is_empty() {
+        return Err(error(parties_sending_invalid_messages));
+    }
+
+    Ok(messages
+        .into_iter()
+        .filter_map(|(party_id, message)| message.ok().map(|message| (party_id, message)))
+        .collect())
+}
+
+/// Instantiate a proof aggregation session for `party_id`, starting at the commitment round.
+pub fn aggregation_session<Output: 'static, P: CommitmentRoundParty<Output> + 'static>(
+    party_id: PartyID,
+    commitment_round_party: P,
+) -> Session<Output, P::Error>
+where
+    P::Error: From<aggregation::Error> + From<group::Error> + 'static,
+{
+    aggregation_session_with_decommitments(party_id, commitment_round_party)
+        .map(|(output, _)| Ok(output))
+}
+
+/// Instantiate a proof aggregation session for `party_id`, starting at the commitment round,
+/// which outputs the decommitments of all parties alongside the output of the protocol.
+pub fn aggregation_session_with_decommitments<
+    Output: 'static,
+    P: CommitmentRoundParty<Output> + 'static,
+>(
+    party_id: PartyID,
+    commitment_round_party: P,
+) -> Session<(Output, HashMap<PartyID, Decommitment<Output, P>>), P::Error>
+where
+    P::Error: From<aggregation::Error> + From<group::Error> + 'static,
+{
+    Session::new(party_id, CommitmentRound(commitment_round_party))
+}
+
+/// Instantiate a threshold-tolerant proof aggregation session for `party_id` over the provers of
+/// `quorum`, which outputs the final quorum alongside the output of the protocol.
+///
+/// Whenever the session aborts identifiably, the blamed parties are excluded from the quorum, and
+/// the session is restarted from the commitment round over the remaining provers, with a party
+/// instantiated anew by `new_session` (so that fresh randomizers are sampled.) The first attempt
+/// runs in session `session_id`, and every restart in the session derived from that of the aborted
+/// attempt by [`SessionID::restart()`], so that the messages of an aborted attempt are rejected in
+/// the next. The commitment round of the restarted session is advanced immediately, in the round in
+/// which the abort occurred. The session aborts once fewer than `threshold` provers remain, or on
+/// an unattributable error.
+pub fn threshold_aggregation_session<Output: 'static, P: CommitmentRoundParty<Output> + 'static>(
+    party_id: PartyID,
+    session_id: SessionID,
+    quorum: Quorum,
+    new_session: impl Fn(PartyID, SessionID, HashSet<PartyID>) -> P + 'static,
+) -> Session<(Output, Quorum), P::Error>
+where
+    P::Error: From<aggregation::Error> + From<group::Error> + 'static,
+{
+    let new_session: Box<dyn Fn(SessionID, HashSet<PartyID>) -> Session<Output, P::Error>> =
+        Box::new(move |session_id, provers| {
+            aggregation_session(party_id, new_session(party_id, session_id, provers))
+        });
+
+    Session::new(
+        party_id,
+        ThresholdRound {
+            party_id,
+            session_id,
+            round: new_session(session_id, quorum.provers().clone()).round,
+            quorum,
+            new_session,
+        },
+    )
+}
+
+/// The decommitment of a proof aggregation session starting at `P`.
+pub type Decommitment<Output, P> =
+    <<P as CommitmentRoundParty<Output>>::DecommitmentRoundParty as DecommitmentRoundParty<
+        Output,
+    >>::Decommitment;
+
+struct CommitmentRound<P>(P);
+
+struct DecommitmentRound<P>(P);
+
+struct ProofShareRound<P>(P);
+
+struct ProofAggregationRound<P, Decommitment> {
+    party: P,
+    decommitments: HashMap<PartyID, Decommitment>,
+}
+
+struct ThresholdRound<Output, Error> {
+    party_id: PartyID,
+    session_id: SessionID,
+    quorum: Quorum,
+    new_session: Box<dyn Fn(SessionID, HashSet<PartyID>) -> Session<Output, Error>>,
+    round: Box<dyn Round<Output, Error>>,
+}
+
+impl<Output: 'static, Error: 'static> Round<(Output, Quorum), Error>
+    for ThresholdRound<Output, Error>
+where
+    Error: From<aggregation::Error> + TryInto<aggregation::Error, Error = Error>,
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<Transition<Box<dyn Round<(Output, Quorum), Error>>, (Output, Quorum)>, Error> {
+        let ThresholdRound {
+            party_id,
+            session_id,
+            mut quorum,
+            new_session,
+            round,
+        } = *self;
+
+        match round.advance(messages, rng) {
+            Ok(Transition::Continue { message, next }) => Ok(Transition::Continue {
+                message,
+                next: Box::new(ThresholdRound {
+                    party_id,
+                    session_id,
+                    quorum,
+                    new_session,
+                    round: next,
+                }),
+            }),
+            Ok(Transition::Finalize(output)) => Ok(Transition::Finalize((output, quorum))),
+            Err(error) => {
+                quorum.exclude_blamed_parties(error.try_into()?)?;
+
+                if !quorum.provers().contains(&party_id) {
+                    return Err(aggregation::Error::NonParticipatingParty.into());
+                }
+
+                // All honest parties observe the same messages, and thus restart together, in the
+                // same session.
+                let session_id = session_id.restart(quorum.provers());
+                let round = new_session(session_id, quorum.provers().clone()).round;
+
+                Box::new(ThresholdRound {
+                    party_id,
+                    session_id,
+                    quorum,
+                    new_session,
+                    round,
+                })
+                .advance(HashMap::new(), rng)
+            }
+        }
+    }
+}
+
+impl<Output: 'static, P: CommitmentRoundParty<Output> + 'static>
+    Round<(Output, HashMap<PartyID, Decommitment<Output, P>>), P::Error> for CommitmentRound<P>
+where
+    P::Error: From<aggregation::Error> + From<group::Error> + 'static,
+{
+    fn advance(
+        self: Box<Self>,
+        _messages: HashMap<PartyID, Vec<u8>>,
+        mut rng: &mut dyn CryptoRngCore,
+    ) -> Result<
+        Transition<
+            Box<dyn Round<(Output, HashMap<PartyID, Decommitment<Output, P>>), P::Error>>,
+            (Output, HashMap<PartyID, Decommitment<Output, P>>),
+        >,
+        P::Error,
+    > {
+        let (commitment, decommitment_round_party) =
+            self.0.commit_statements_and_statement_mask(&mut rng)?;
+
+        Ok(Transition::Continue {
+            message: commitment.to_wire_bytes()?,
+            next: Box::new(DecommitmentRound(decommitment_round_party)),
+        })
+    }
+}
+
+impl<Output: 'static, P: DecommitmentRoundParty<Output> + 'static>
+    Round<(Output, HashMap<PartyID, P::Decommitment>), P::Error> for DecommitmentRound<P>
+where
+    P::Error: From<aggregation::Error> + From<group::Error> + 'static,
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        mut rng: &mut dyn CryptoRngCore,
+    ) -> Result<
+        Transition<
+            Box<dyn Round<(Output, HashMap<PartyID, P::Decommitment>), P::Error>>,
+            (Output, HashMap<PartyID, P::Decommitment>),
+        >,
+        P::Error,
+    > {
+        let commitments = deserialize_messages(messages, aggregation::Error::InvalidCommitment)?;
+
+        let (decommitment, proof_share_round_party) = self
+            .0
+            .decommit_statements_and_statement_mask(commitments, &mut rng)?;
+
+        Ok(Transition::Continue {
+            message: decommitment.to_wire_bytes()?,
+            next: Box::new(ProofShareRound(proof_share_round_party)),
+        })
+    }
+}
+
+impl<Output: 'static, P: ProofShareRoundParty<Output> + 'static>
+    Round<(Output, HashMap<PartyID, P::Decommitment>), P::Error> for ProofShareRound<P>
+where
+    P::Error: From<aggregation::Error> + From<group::Error> + 'static,
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        mut rng: &mut dyn CryptoRngCore,
+    ) -> Result<
+        Transition<
+            Box<dyn Round<(Output, HashMap<PartyID, P::Decommitment>), P::Error>>,
+            (Output, HashMap<PartyID, P::Decommitment>),
+        >,
+        P::Error,
+    > {
+        let decommitments: HashMap<_, P::Decommitment> =
+            deserialize_messages(messages, aggregation::Error::InvalidDecommitment)?;
+
+        let (proof_share, proof_aggregation_round_party) = self
+            .0
+            .generate_proof_share(decommitments.clone(), &mut rng)?;
+
+        Ok(Transition::Continue {
+            message: proof_share.to_wire_bytes()?,
+            next: Box::new(ProofAggregationRound {
+                party: proof_aggregation_round_party,
+                decommitments,
+            }),
+        })
+    }
+}
+
+impl<Output: 'static, P: ProofAggregationRoundParty<Output> + 'static, Decommitment: 'static>
+    Round<(Output, HashMap<PartyID, Decommitment>), P::Error>
+    for ProofAggregationRound<P, Decommitment>
+where
+    P::Error: From<aggregation::Error> + From<group::Error> + 'static,
+{
+    fn advance(
+        self: Box<Self>,
+        messages: HashMap<PartyID, Vec<u8>>,
+        mut rng: &mut dyn CryptoRngCore,
+    ) -> Result<
+        Transition<
+            Box<dyn Round<(Output, HashMap<PartyID, Decommitment>), P::Error>>,
+            (Output, HashMap<PartyID, Decommitment>),
+        >,
+        P::Error,
+    > {
+        let proof_shares = deserialize_messages(messages, aggregation::Error::InvalidProofShare)?;
+
+        let output = self.party.aggregate_proof_shares(proof_shares, &mut rng)?;
+
+        Ok(Transition::Finalize((output, self.decommitments)))
+    }
+}
+
+#[cfg(all(test, feature = "test_helpers"))]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{
+        session::{
+            envelope::test_helpers::generate_keys,
+            network::{Fault, Network},
+        },
+        Error,
+    };
+
+    /// A toy protocol, in which every party broadcasts `value`, and outputs the sum of all values.
+    struct SumRound {
+        value: u64,
+    }
+
+    struct SumAggregationRound;
+
+    impl Round<u64, Error> for SumRound {
+        fn advance(
+            self: Box<Self>,
+            _messages: HashMap<PartyID, Vec<u8>>,
+            _rng: &mut dyn CryptoRngCore,
+        ) -> Result<Transition<Box<dyn Round<u64, Error>>, u64>, Error> {
+            Ok(Transition::Continue {
+                message: self.value.to_wire_bytes()?,
+                next: Box::new(SumAggregationRound),
+            })
+        }
+    }
+
+    impl Round<u64, Error> for SumAggregationRound {
+        fn advance(
+            self: Box<Self>,
+            messages: HashMap<PartyID, Vec<u8>>,
+            _rng: &mut dyn CryptoRngCore,
+        ) -> Result<Transition<Box<dyn Round<u64, Error>>, u64>, Error> {
+            messages
+                .values()
+                .map(|message| u64::from_wire_bytes(message).map_err(Error::from))
+                .sum::<Result<u64, Error>>()
+                .map(Transition::Finalize)
+        }
+    }
+
+    fn sum_session(party_id: PartyID, value: u64) -> Session<u64, Error> {
+        Session::new(party_id, SumRound { value })
+    }
+
+    #[test]
+    fn runs_composed_sessions() {
+        let sessions = (1..=3).map(|party_id| {
+            sum_session(party_id, party_id.into())
+                .join(Session::join_all(
+                    party_id,
+                    vec![sum_session(party_id, 1), sum_session(party_id, 10)],
+                ))
+                .map(|(sum, sums)| Ok(sum + sums.into_iter().sum::<u64>()))
+                .and_then(move |sum, _| Ok(sum_session(party_id, sum)))
+        });
+
+        let outputs = Network::new(sessions, 1).run(&mut OsRng);
+
+        assert_eq!(outputs.len(), 3);
+        assert!(outputs
+            .into_values()
+            .all(|output| output.unwrap() == 3 * (1 + 2 + 3 + 3 + 30)));
+    }
+
+    #[test]
+    fn drops_undelivered_messages() {
+        let sessions = (1..=3).map(|party_id| sum_session(party_id, party_id.into()));
+
+        let outputs = Network::new(sessions, 2)
+            .inject_fault(2, 0, Fault::Delay(1))
+            .inject_fault(3, 0, Fault::Delay(2))
+            .run(&mut OsRng);
+
+        assert_eq!(outputs[&1].as_ref().unwrap(), &(1 + 2));
+        assert_eq!(outputs[&2].as_ref().unwrap(), &(1 + 2));
+        // A party always receives its own message.
+        assert_eq!(outputs[&3].as_ref().unwrap(), &(1 + 2 + 3));
+
+        let sessions = (1..=3).map(|party_id| sum_session(party_id, party_id.into()));
+
+        let outputs = Network::new(sessions, 1)
+            .inject_fault(1, 0, Fault::Drop)
+            .run(&mut OsRng);
+
+        assert_eq!(outputs[&1].as_ref().unwrap(), &(1 + 2 + 3));
+        assert_eq!(outputs[&2].as_ref().unwrap(), &(2 + 3));
+    }
+
+    #[test]
+    fn rejects_malformed_joint_messages() {
+        let sessions = (1..=3).map(|party_id| {
+            Session::join_all(
+                party_id,
+                vec![
+                    sum_session(party_id, party_id.into()),
+                    sum_session(party_id, party_id.into()),
+                ],
+            )
+        });
+
+        let outputs = Network::new(sessions, 1)
+            .inject_fault(2, 0, Fault::Truncate)
+            .run(&mut OsRng);
+
+        // The malformed message of party 2 is not ignored as if it were never sent, but rather
+        // fails to decode in every session.
+        assert!(outputs[&1].is_err());
+        assert!(outputs[&3].is_err());
+        assert_eq!(outputs[&2].as_ref().unwrap(), &vec![1 + 2 + 3, 1 + 2 + 3]);
+
+        // Both undecodable joint messages and ones of the wrong number of sessions are malformed.
+        let messages = [
+            (1, vec![1]),
+            (2, vec![vec![2u8]].to_wire_bytes().unwrap()),
+            (3, vec![vec![3u8], vec![4u8]].to_wire_bytes().unwrap()),
+        ]
+        .into_iter()
+        .collect();
+
+        let split_messages = split_messages(messages, 2);
+
+        assert_eq!(split_messages[0][&1], Vec::<u8>::new());
+        assert_eq!(split_messages[1][&2], Vec::<u8>::new());
+        assert_eq!(split_messages[0][&3], vec![3u8]);
+        assert_eq!(split_messages[1][&3], vec![4u8]);
+    }
+
+    #[test]
+    fn rejects_unauthenticated_messages() {
+        let keys = generate_keys(3);
+        let session_id = SessionID::new([1; 32]);
+        let authenticated_sum_session = |party_id: PartyID, session_id| {
+            sum_session(party_id, party_id.into()).authenticated(
+                session_id,
+                keys[&party_id],
+                keys.clone(),
+            )
+        };
+
+        let sessions = (1..=3).map(|party_id| authenticated_sum_session(party_id, session_id));
+
+        let outputs = Network::new(sessions, 1).run(&mut OsRng);
+
+        assert!(outputs
+            .into_values()
+            .all(|output| output.unwrap() == 1 + 2 + 3));
+
+        // Messages for another session are rejected as invalid, rather than ignored as if they
+        // were never sent.
+        let sessions = (1..=2)
+            .map(|party_id| authenticated_sum_session(party_id, session_id))
+            .chain([authenticated_sum_session(3, SessionID::new([2; 32]))]);
+
+        let outputs = Network::new(sessions, 1).run(&mut OsRng);
+
+        assert!(outputs.into_values().all(|output| output.is_err()));
+
+        // Messages whose signature does not verify are rejected as invalid.
+        let sessions = (1..=3).map(|party_id| authenticated_sum_session(party_id, session_id));
+
+        let outputs = Network::new(sessions, 1)
+            .inject_fault(2, 0, Fault::Corrupt)
+            .run(&mut OsRng);
+
+        assert!(outputs[&1].is_err());
+        assert!(outputs[&3].is_err());
+        assert_eq!(outputs[&2].as_ref().unwrap(), &(1 + 2 + 3));
+    }
+
+    #[test]
+    fn rejects_stale_messages() {
+        let keys = generate_keys(3);
+        let session_id = SessionID::new([1; 32]);
+        let sessions = |authenticated: bool| {
+            let keys = keys.clone();
+
+            (1..=3).map(move |party_id| {
+                let session = sum_session(party_id, party_id.into())
+                    .and_then(move |sum, _| Ok(sum_session(party_id, sum)));
+
+                if authenticated {
+                    session.authenticated(session_id, keys[&party_id], keys.clone())
+                } else {
+                    session
+                }
+            })
+        };
+
+        // The first message of party 3 misses its round, and arrives in the second round instead,
+        // in which party 3 sends no other message.
+        let network = |authenticated| {
+            Network::new(sessions(authenticated), 2)
+                .inject_fault(3, 0, Fault::Delay(2))
+                .inject_fault(3, 1, Fault::Drop)
+        };
+
+        // Unless messages are bound to their round, the stale message is accepted in place of the
+        // message of the second round.
+        let outputs = network(false).run(&mut OsRng);
+
+        assert_eq!(outputs[&1].as_ref().unwrap(), &((1 + 2) + (1 + 2) + 3));
+
+        let outputs = network(true).run(&mut OsRng);
+
+        assert!(outputs[&1].is_err());
+        assert!(outputs[&2].is_err());
+        assert_eq!(
+            outputs[&3].as_ref().unwrap(),
+            &((1 + 2) + (1 + 2) + (1 + 2 + 3))
+        );
+    }
+}
@@ -0,0 +1,441 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! Signed envelopes for the messages broadcast in a session.
+//!
+//! An [`Envelope`] carries the serialized message of a party for a single round of a single
+//! session. The session identifier, round number and sender are bound into a transcript together
+//! with the payload, and the resulting digest is signed by the sender, so that a message can never
+//! be replayed in another round or session, or attributed to another party.
+
+use alloc::vec::Vec;
+
+use group::{
+    collections::{HashMap, HashSet},
+    PartyID,
+};
+use merlin::Transcript;
+use serde::{Deserialize, Serialize};
+
+use crate::{TranscriptProtocol, TranscriptVersion};
+
+/// Envelope error.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("malformed envelope: could not decode the canonical encoding")]
+    MalformedEnvelope,
+
+    #[error("the envelope belongs to a different session")]
+    WrongSession,
+
+    #[error("the envelope belongs to round {received} and not round {expected}")]
+    WrongRound { expected: u64, received: u64 },
+
+    #[error("the envelope was sent by an unknown party {0}")]
+    UnknownSender(PartyID),
+
+    #[error("invalid signature")]
+    InvalidSignature,
+}
+
+/// Envelope result.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// A unique identifier of a session, agreed upon by all of its participating parties.
+///
+/// Proof aggregation sessions bind it, together with the identifier of each round, into the
+/// Fiat-Shamir transcript of that round, so that messages and proofs can not be replayed across
+/// sessions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SessionID([u8; 32]);
+
+impl SessionID {
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Derive a session identifier from a protocol `label` and an agreed-upon `context`, e.g. the
+    /// set of participating parties and a nonce.
+    pub fn derive(label: &'static [u8], context: &impl Serialize) -> crate::Result<Self> {
+        let mut transcript = Transcript::new(b"session id");
+        transcript.append_message(b"label", label);
+        transcript.serialize_to_transcript_with_version(
+            b"context",
+            context,
+            TranscriptVersion::CanonicalBinary,
+        )?;
+
+        let mut bytes = [0u8; 32];
+        transcript.challenge_bytes(b"session id", &mut bytes);
+
+        Ok(Self(bytes))
+    }
+
+    /// Derive the identifier of the session restarting this (aborted) session over `provers`.
+    ///
+    /// Every attempt of a restarted session thus has its own identifier, bound to that of the
+    /// attempt it restarts, so that the messages of an aborted attempt are rejected in the next.
+    pub fn restart(&self, provers: &HashSet<PartyID>) -> Self {
+        let mut provers: Vec<_> = provers.iter().copied().collect();
+        provers.sort();
+
+        let mut transcript = Transcript::new(b"session id");
+        transcript.append_message(b"label", b"restart");
+        transcript.append_message(b"previous session id", &self.0);
+        transcript.append_u64(b"number of provers", provers.len() as u64);
+        provers
+            .into_iter()
+            .for_each(|party_id| transcript.append_u64(b"prover", party_id.into()));
+
+        let mut bytes = [0u8; 32];
+        transcript.challenge_bytes(b"session id", &mut bytes);
+
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Bind this session, and its round `round_number`, into `transcript`.
+    pub fn append_to_transcript(&self, transcript: &mut Transcript, round_number: u64) {
+        transcript.append_message(b"session id", &self.0);
+        transcript.append_u64(b"round number", round_number);
+    }
+}
+
+/// A signing key, used by a party to sign its envelopes.
+pub trait Signer {
+    fn sign(&self, message: &[u8]) -> Vec<u8>;
+}
+
+/// A verifying key, used to verify the signatures on the envelopes of a party.
+pub trait Verifier {
+    fn verify(&self, message: &[u8], signature: &[u8]) -> bool;
+}
+
+/// A signed envelope, carrying the message broadcast by `sender` in round `round_number` of the
+/// session `session_id`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Envelope {
+    session_id: SessionID,
+    round_number: u64,
+    sender: PartyID,
+    payload: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+impl Envelope {
+    /// Seal `payload` in an envelope for round `round_number` of session `session_id`, signed by
+    /// `sender` with `signer`.
+    pub fn seal(
+        session_id: SessionID,
+        round_number: u64,
+        sender: PartyID,
+        payload: Vec<u8>,
+        signer: &impl Signer,
+    ) -> Self {
+        let signature = signer.sign(&Self::signed_message(
+            &session_id,
+            round_number,
+            sender,
+            &payload,
+        ));
+
+        Self {
+            session_id,
+            round_number,
+            sender,
+            payload,
+            signature,
+        }
+    }
+
+    /// Open this envelope, returning its sender and payload.
+    ///
+    /// Rejects envelopes that do not belong to round `round_number` of session `session_id`, or
+    /// which are not signed by their sender under its key in `verifiers`.
+    pub fn open<V: Verifier>(
+        self,
+        session_id: &SessionID,
+        round_number: u64,
+        verifiers: &HashMap<PartyID, V>,
+    ) -> Result<(PartyID, Vec<u8>)> {
+        if &self.session_id != session_id {
+            return Err(Error::WrongSession);
+        }
+
+        if self.round_number != round_number {
+            return Err(Error::WrongRound {
+                expected: round_number,
+                received: self.round_number,
+            });
+        }
+
+        let verifier = verifiers
+            .get(&self.sender)
+            .ok_or(Error::UnknownSender(self.sender))?;
+
+        let message = Self::signed_message(
+            &self.session_id,
+            self.round_number,
+            self.sender,
+            &self.payload,
+        );
+
+        if !verifier.verify(&message, &self.signature) {
+            return Err(Error::InvalidSignature);
+        }
+
+        Ok((self.sender, self.payload))
+    }
+
+    pub fn session_id(&self) -> &SessionID {
+        &self.session_id
+    }
+
+    pub fn round_number(&self) -> u64 {
+        self.round_number
+    }
+
+    pub fn sender(&self) -> PartyID {
+        self.sender
+    }
+
+    /// The canonical binary encoding of this envelope:
+    /// `session_id || round_number || sender || len(payload) || payload || len(signature) ||
+    /// signature`, with all integers encoded as little-endian and lengths as `u64`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            self.session_id.0.len() + 8 + 2 + 8 + self.payload.len() + 8 + self.signature.len(),
+        );
+
+        bytes.extend_from_slice(&self.session_id.0);
+        bytes.extend_from_slice(&self.round_number.to_le_bytes());
+        bytes.extend_from_slice(&self.sender.to_le_bytes());
+        bytes.extend_from_slice(&(self.payload.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.payload);
+        bytes.extend_from_slice(&(self.signature.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.signature);
+
+        bytes
+    }
+
+    /// Decode an envelope from its canonical binary encoding, as produced by [`Self::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader(bytes);
+
+        let session_id = SessionID(reader.read_array()?);
+        let round_number = u64::from_le_bytes(reader.read_array()?);
+        let sender = PartyID::from_le_bytes(reader.read_array()?);
+        let payload = reader.read_vec()?;
+        let signature = reader.read_vec()?;
+
+        if !reader.0.is_empty() {
+            return Err(Error::MalformedEnvelope);
+        }
+
+        Ok(Self {
+            session_id,
+            round_number,
+            sender,
+            payload,
+            signature,
+        })
+    }
+
+    /// The message signed by the sender: a digest of a transcript binding the session, round,
+    /// sender and payload.
+    fn signed_message(
+        session_id: &SessionID,
+        round_number: u64,
+        sender: PartyID,
+        payload: &[u8],
+    ) -> [u8; 64] {
+        let mut transcript = Transcript::new(b"envelope");
+        session_id.append_to_transcript(&mut transcript, round_number);
+        transcript.append_u64(b"sender", sender.into());
+        transcript.append_message(b"payload", payload);
+
+        let mut message = [0u8; 64];
+        transcript.challenge_bytes(b"signed message", &mut message);
+
+        message
+    }
+}
+
+/// A cursor over the canonical encoding of an envelope.
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.0.len() < N {
+            return Err(Error::MalformedEnvelope);
+        }
+
+        let (array, rest) = self.0.split_at(N);
+        self.0 = rest;
+
+        array.try_into().map_err(|_| Error::MalformedEnvelope)
+    }
+
+    fn read_vec(&mut self) -> Result<Vec<u8>> {
+        let length = usize::try_from(u64::from_le_bytes(self.read_array()?))
+            .map_err(|_| Error::MalformedEnvelope)?;
+
+        if self.0.len() < length {
+            return Err(Error::MalformedEnvelope);
+        }
+
+        let (vec, rest) = self.0.split_at(length);
+        self.0 = rest;
+
+        Ok(vec.to_vec())
+    }
+}
+
+#[cfg(feature = "test_helpers")]
+pub mod test_helpers {
+    use super::*;
+
+    /// A symmetric message authentication key, implementing both [`Signer`] and [`Verifier`].
+    ///
+    /// For testing only: any party holding the key can sign on behalf of its owner.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct SharedKey(pub [u8; 32]);
+
+    impl SharedKey {
+        fn tag(&self, message: &[u8]) -> Vec<u8> {
+            let mut transcript = Transcript::new(b"shared key");
+            transcript.append_message(b"key", &self.0);
+            transcript.append_message(b"message", message);
+
+            let mut tag = vec![0u8; 32];
+            transcript.challenge_bytes(b"tag", &mut tag);
+
+            tag
+        }
+    }
+
+    impl Signer for SharedKey {
+        fn sign(&self, message: &[u8]) -> Vec<u8> {
+            self.tag(message)
+        }
+    }
+
+    impl Verifier for SharedKey {
+        fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+            self.tag(message) == signature
+        }
+    }
+
+    /// Generate a [`SharedKey`] for each of the parties `1..=number_of_parties`.
+    pub fn generate_keys(number_of_parties: PartyID) -> HashMap<PartyID, SharedKey> {
+        (1..=number_of_parties)
+            .map(|party_id| {
+                let mut key = [0u8; 32];
+                key[..2].copy_from_slice(&party_id.to_le_bytes());
+
+                (party_id, SharedKey(key))
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "test_helpers"))]
+mod tests {
+    use super::{test_helpers::generate_keys, *};
+
+    #[test]
+    fn opens_sealed_envelopes() {
+        let keys = generate_keys(2);
+        let session_id = SessionID::derive(b"test", &(1..=2).collect::<Vec<PartyID>>()).unwrap();
+
+        let envelope = Envelope::seal(session_id, 3, 2, b"payload".to_vec(), &keys[&2]);
+        let decoded_envelope = Envelope::from_bytes(&envelope.to_bytes()).unwrap();
+
+        assert_eq!(decoded_envelope, envelope);
+        assert_eq!(
+            decoded_envelope.open(&session_id, 3, &keys).unwrap(),
+            (2, b"payload".to_vec())
+        );
+    }
+
+    #[test]
+    fn derives_distinct_restart_session_ids() {
+        let session_id = SessionID::new([1; 32]);
+        let restart_session_id = session_id.restart(&HashSet::from([1, 2, 3]));
+
+        assert_eq!(
+            restart_session_id,
+            session_id.restart(&HashSet::from([3, 2, 1]))
+        );
+        assert_ne!(restart_session_id, session_id);
+        assert_ne!(
+            restart_session_id,
+            session_id.restart(&HashSet::from([1, 2]))
+        );
+        assert_ne!(
+            restart_session_id,
+            SessionID::new([2; 32]).restart(&HashSet::from([1, 2, 3]))
+        );
+        assert_ne!(
+            restart_session_id.restart(&HashSet::from([1, 2])),
+            session_id.restart(&HashSet::from([1, 2]))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_envelopes() {
+        let keys = generate_keys(2);
+        let session_id = SessionID::new([1; 32]);
+        let envelope = Envelope::seal(session_id, 3, 2, b"payload".to_vec(), &keys[&2]);
+
+        assert_eq!(
+            envelope
+                .clone()
+                .open(&SessionID::new([2; 32]), 3, &keys)
+                .unwrap_err(),
+            Error::WrongSession
+        );
+
+        assert_eq!(
+            envelope.clone().open(&session_id, 4, &keys).unwrap_err(),
+            Error::WrongRound {
+                expected: 4,
+                received: 3
+            }
+        );
+
+        assert_eq!(
+            Envelope::seal(session_id, 3, 3, b"payload".to_vec(), &keys[&2])
+                .open(&session_id, 3, &keys)
+                .unwrap_err(),
+            Error::UnknownSender(3)
+        );
+
+        let mut forged_envelope = envelope.clone();
+        forged_envelope.sender = 1;
+        assert_eq!(
+            forged_envelope.open(&session_id, 3, &keys).unwrap_err(),
+            Error::InvalidSignature
+        );
+
+        let mut forged_envelope = envelope.clone();
+        forged_envelope.payload = b"forged".to_vec();
+        assert_eq!(
+            forged_envelope.open(&session_id, 3, &keys).unwrap_err(),
+            Error::InvalidSignature
+        );
+
+        let bytes = envelope.to_bytes();
+        assert_eq!(
+            Envelope::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+            Error::MalformedEnvelope
+        );
+        assert_eq!(
+            Envelope::from_bytes(&[bytes.as_slice(), &[0]].concat()).unwrap_err(),
+            Error::MalformedEnvelope
+        );
+    }
+}
@@ -0,0 +1,169 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! An in-memory broadcast network for simulating sessions of multi-round protocols.
+//!
+//! All parties advance in lock-step: in each round, every party broadcasts its message, and waits
+//! `round_timeout` ticks for the messages of the other parties before advancing to the next round.
+//! Faults can be injected into the messages of any party in any round, in order to test the
+//! liveness and identifiable abort properties of protocols under adversarial scheduling.
+//!
+//! Faults are applied to the broadcast message itself, and so are observed consistently by all
+//! receiving parties, as guaranteed by a reliable broadcast channel. The sender always receives its
+//! own message intact.
+//!
+//! A party receives at most one message from every other party in each round: a late message is
+//! only observed in a round in which its sender broadcast no other (timely) message.
+
+use crypto_bigint::rand_core::CryptoRngCore;
+use group::{collections::HashMap, PartyID};
+
+use crate::session::{Session, Transition};
+
+/// A fault injected into a broadcast message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fault {
+    /// The message is never delivered.
+    Drop,
+    /// The message is delivered after the given number of ticks. A message delayed by `delay`
+    /// ticks misses its round if this reaches the round timeout, and arrives `delay /
+    /// round_timeout` rounds late instead.
+    Delay(u64),
+    /// A random bit of the message is flipped.
+    Corrupt,
+    /// The last byte of the message is removed, so that it can no longer be decoded.
+    Truncate,
+}
+
+/// An in-memory broadcast network, running the sessions of all parties.
+pub struct Network<Output, Error> {
+    sessions: HashMap<PartyID, Session<Output, Error>>,
+    round_timeout: u64,
+    faults: HashMap<(PartyID, usize), Fault>,
+}
+
+impl<Output: 'static, Error: 'static> Network<Output, Error> {
+    /// Instantiate a new network running `sessions`, in which parties wait `round_timeout` ticks
+    /// for the messages of each round.
+    pub fn new(
+        sessions: impl IntoIterator<Item = Session<Output, Error>>,
+        round_timeout: u64,
+    ) -> Self {
+        Self {
+            sessions: sessions
+                .into_iter()
+                .map(|session| (session.party_id(), session))
+                .collect(),
+            round_timeout,
+            faults: HashMap::new(),
+        }
+    }
+
+    /// Inject `fault` into the message broadcast by `party_id` in round `round_number`.
+    pub fn inject_fault(mut self, party_id: PartyID, round_number: usize, fault: Fault) -> Self {
+        self.faults.insert((party_id, round_number), fault);
+
+        self
+    }
+
+    /// Run the sessions of all parties to completion.
+    ///
+    /// Returns the output of every party, or the error with which its session aborted.
+    pub fn run(self, rng: &mut impl CryptoRngCore) -> HashMap<PartyID, Result<Output, Error>> {
+        let mut sessions = self.sessions;
+        let mut outputs = HashMap::new();
+        let mut messages = HashMap::new();
+        let mut late_broadcasts: HashMap<usize, HashMap<PartyID, Vec<u8>>> = HashMap::new();
+        let mut round_number = 0;
+
+        while !sessions.is_empty() {
+            let mut broadcasts = HashMap::new();
+
+            sessions = sessions
+                .into_iter()
+                .filter_map(|(party_id, session)| {
+                    let messages = messages.remove(&party_id).unwrap_or_default();
+
+                    match session.advance(messages, rng) {
+                        Ok(Transition::Continue { message, next }) => {
+                            broadcasts.insert(party_id, message);
+
+                            Some((party_id, next))
+                        }
+                        Ok(Transition::Finalize(output)) => {
+                            outputs.insert(party_id, Ok(output));
+
+                            None
+                        }
+                        Err(e) => {
+                            outputs.insert(party_id, Err(e));
+
+                            None
+                        }
+                    }
+                })
+                .collect();
+
+            let mut delivered_broadcasts =
+                late_broadcasts.remove(&round_number).unwrap_or_default();
+
+            for (&sender, message) in &broadcasts {
+                let message = match self.faults.get(&(sender, round_number)) {
+                    None => message.clone(),
+                    Some(Fault::Drop) => continue,
+                    Some(Fault::Delay(delay)) => {
+                        let rounds_late = delay
+                            .checked_div(self.round_timeout)
+                            .and_then(|rounds_late| usize::try_from(rounds_late).ok())
+                            .unwrap_or(usize::MAX);
+
+                        if rounds_late > 0 {
+                            late_broadcasts
+                                .entry(round_number.saturating_add(rounds_late))
+                                .or_default()
+                                .insert(sender, message.clone());
+
+                            continue;
+                        }
+
+                        message.clone()
+                    }
+                    Some(Fault::Corrupt) => corrupt(message.clone(), rng),
+                    Some(Fault::Truncate) => message[..message.len().saturating_sub(1)].to_vec(),
+                };
+
+                delivered_broadcasts.insert(sender, message);
+            }
+
+            messages = sessions
+                .keys()
+                .map(|&party_id| {
+                    let mut messages = delivered_broadcasts.clone();
+
+                    // A party always receives its own message intact.
+                    if let Some(message) = broadcasts.get(&party_id) {
+                        messages.insert(party_id, message.clone());
+                    }
+
+                    (party_id, messages)
+                })
+                .collect();
+
+            round_number += 1;
+        }
+
+        outputs
+    }
+}
+
+/// Flip a random bit of `message`.
+fn corrupt(mut message: Vec<u8>, rng: &mut impl CryptoRngCore) -> Vec<u8> {
+    if message.is_empty() {
+        return vec![rng.next_u32() as u8];
+    }
+
+    let bit = usize::try_from(rng.next_u64()).unwrap_or_default() % (message.len() * 8);
+    message[bit / 8] ^= 1 << (bit % 8);
+
+    message
+}
@@ -1,13 +1,50 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+use alloc::{vec, vec::Vec};
+
+pub use canonical_encoding::CanonicalEncoding;
 use crypto_bigint::{Encoding, Limb, Uint};
 use merlin::Transcript;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+
+pub mod canonical_encoding;
+
+/// The version of the encoding used to append serializable messages to transcripts.
+///
+/// This is a runtime parameter agreed upon by the prover and the verifier, e.g. as part of the
+/// public parameters of the proven language. It defaults to
+/// [`TranscriptVersion::CanonicalBinary`]; [`TranscriptVersion::Json`] must be selected to verify
+/// proofs generated prior to its introduction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TranscriptVersion {
+    /// The legacy encoding, which pretty-prints messages as JSON.
+    Json,
+    /// The canonical, length-prefixed binary encoding of [`CanonicalEncoding`].
+    #[default]
+    CanonicalBinary,
+}
 
 /// A transcript protocol for fiat-shamir transforms of interactive to non-interactive proofs.
 pub trait TranscriptProtocol {
-    fn serialize_to_transcript_as_json<T: Serialize>(
+    /// Append `message` to the transcript, encoded with the default [`TranscriptVersion`].
+    fn serialize_to_transcript<T: Serialize + ?Sized>(
+        &mut self,
+        label: &'static [u8],
+        message: &T,
+    ) -> crate::Result<()> {
+        self.serialize_to_transcript_with_version(label, message, TranscriptVersion::default())
+    }
+
+    /// Append `message` to the transcript, encoded with `version`.
+    fn serialize_to_transcript_with_version<T: Serialize + ?Sized>(
+        &mut self,
+        label: &'static [u8],
+        message: &T,
+        version: TranscriptVersion,
+    ) -> crate::Result<()>;
+
+    fn serialize_to_transcript_as_json<T: Serialize + ?Sized>(
         &mut self,
         label: &'static [u8],
         message: &T,
@@ -21,7 +58,28 @@ pub trait TranscriptProtocol {
 }
 
 impl TranscriptProtocol for Transcript {
-    fn serialize_to_transcript_as_json<T: Serialize>(
+    fn serialize_to_transcript_with_version<T: Serialize + ?Sized>(
+        &mut self,
+        label: &'static [u8],
+        message: &T,
+        version: TranscriptVersion,
+    ) -> crate::Result<()> {
+        match version {
+            TranscriptVersion::Json => self.serialize_to_transcript_as_json(label, message)?,
+            TranscriptVersion::CanonicalBinary => {
+                // A leading zero byte separates canonical encodings from JSON encodings, which are
+                // always valid UTF-8 strings starting with a printable character.
+                let mut encoded_message = vec![0u8];
+                encoded_message.extend(message.canonical_encoding()?);
+
+                self.append_message(label, &encoded_message);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn serialize_to_transcript_as_json<T: Serialize + ?Sized>(
         &mut self,
         label: &'static [u8],
         message: &T,
@@ -47,3 +105,106 @@ impl TranscriptProtocol for Transcript {
         Uint::<LIMBS>::from_le_slice(&buf)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+
+    use crypto_bigint::U256;
+    use group::{secp256k1, CyclicGroupElement, GroupElement};
+
+    use super::*;
+
+    fn transcript_challenge(version: TranscriptVersion) -> U256 {
+        let scalar_public_parameters = secp256k1::scalar::PublicParameters::default();
+        let group_public_parameters = secp256k1::group_element::PublicParameters::default();
+        let generator = secp256k1::GroupElement::new(
+            secp256k1::GroupElement::generator_value_from_public_parameters(
+                &group_public_parameters,
+            ),
+            &group_public_parameters,
+        )
+        .unwrap();
+
+        let mut transcript = Transcript::new(b"test vectors");
+        transcript
+            .serialize_to_transcript_with_version(
+                b"public parameters",
+                &(&scalar_public_parameters, &group_public_parameters),
+                version,
+            )
+            .unwrap();
+        transcript
+            .serialize_to_transcript_with_version(
+                b"statement value",
+                &[generator.value(), (generator + generator).value()],
+                version,
+            )
+            .unwrap();
+
+        transcript.challenge(b"challenge")
+    }
+
+    #[test]
+    fn transcripts_match_test_vectors() {
+        assert_eq!(
+            transcript_challenge(TranscriptVersion::Json),
+            U256::from_be_hex("2FEBBD774E04FD2B29FC548BB76C971915588A84D2A736D281B37BAAEB9A5BE5")
+        );
+
+        assert_eq!(
+            transcript_challenge(TranscriptVersion::CanonicalBinary),
+            U256::from_be_hex("B9EFE1FCE000776B5625F3F4A885FCE53DE2075A171D08C0158B7FE12F92CA8B")
+        );
+
+        assert_eq!(
+            transcript_challenge(TranscriptVersion::default()),
+            transcript_challenge(TranscriptVersion::CanonicalBinary)
+        );
+    }
+
+    #[test]
+    fn encodings_match_test_vectors() {
+        let scalar_public_parameters = secp256k1::scalar::PublicParameters::default();
+        let group_public_parameters = secp256k1::group_element::PublicParameters::default();
+        let generator_value = secp256k1::GroupElement::generator_value_from_public_parameters(
+            &group_public_parameters,
+        );
+        let scalar = secp256k1::Scalar::from(U256::from(3u64));
+
+        assert_eq!(
+            hex(&scalar_public_parameters.canonical_encoding().unwrap()),
+            "3a00000000000000\
+             5468652066696e697465206669656c64206f6620696e746567657273206d6f64756c6f207072696d65\
+             207120245c6d61746862627b5a7d5f7124\
+             414136d08c5ed2bf3ba048afe6dcaebafeffffffffffffffffffffffffffffff\
+             0000000000000000000000000000000000000000000000000000000000000001"
+        );
+        assert_eq!(
+            hex(&group_public_parameters.canonical_encoding().unwrap()),
+            "0900000000000000536563703235366b31\
+             0b000000000000005765696572737472617373\
+             414136d08c5ed2bf3ba048afe6dcaebafeffffffffffffffffffffffffffffff\
+             2ffcfffffeffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+             21000000000000000279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0700000000000000000000000000000000000000000000000000000000000000"
+        );
+        assert_eq!(
+            hex(&generator_value.canonical_encoding().unwrap()),
+            "21000000000000000279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        );
+        assert_eq!(
+            hex(&scalar.value().canonical_encoding().unwrap()),
+            "0000000000000000000000000000000000000000000000000000000000000003"
+        );
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut hex, byte| {
+            write!(hex, "{byte:02x}").unwrap();
+
+            hex
+        })
+    }
+}
@@ -0,0 +1,468 @@
+// Author: dWallet Labs, Ltd.
+// SPDX-License-Identifier: BSD-3-Clause-Clear
+
+//! A canonical, length-prefixed binary encoding of serializable values, for use in transcripts.
+//!
+//! The encoding is defined over the serde data model, and is thus available for every
+//! `GroupElement::Value` and `PublicParameters` type (and any other `Serialize` type):
+//! - integers are encoded in fixed-width little-endian, `bool`s as a single byte and `char`s as a
+//!   `u32`;
+//! - strings and byte arrays are prefixed by their length in bytes, and sequences by their number
+//!   of elements, both as a `u64`;
+//! - tuples and structs are encoded as the concatenation of their fields, as their arity is fixed
+//!   by their type;
+//! - `Option`s and enum variants are prefixed by a tag (`0`/`1`, and the `u32` variant index
+//!   respectively);
+//! - maps are prefixed by their number of entries, which are sorted by their encoded keys, so that
+//!   the encoding does not depend on the iteration order of the map.
+//!
+//! Values are serialized as for a non human-readable format, which e.g. encodes big integers as
+//! their raw bytes rather than hex strings.
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use serde::{ser, Serialize};
+
+/// Canonical encoding error.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("serialization error: {0}")]
+    Serialization(String),
+}
+
+impl ser::Error for Error {
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        Error::Serialization(msg.to_string())
+    }
+}
+
+/// Canonical encoding result.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// A canonical, length-prefixed binary encoding.
+pub trait CanonicalEncoding {
+    /// Encode `self` canonically.
+    fn canonical_encoding(&self) -> Result<Vec<u8>>;
+}
+
+impl<T: Serialize + ?Sized> CanonicalEncoding for T {
+    fn canonical_encoding(&self) -> Result<Vec<u8>> {
+        let mut serializer = Serializer::default();
+        self.serialize(&mut serializer)?;
+
+        Ok(serializer.0)
+    }
+}
+
+#[derive(Default)]
+struct Serializer(Vec<u8>);
+
+impl Serializer {
+    fn write_length(&mut self, length: usize) {
+        self.0.extend_from_slice(&(length as u64).to_le_bytes());
+    }
+}
+
+/// A serializer for sequences, which are prefixed by their number of elements.
+///
+/// If the length of the sequence is not known in advance, its elements are encoded into an
+/// intermediate buffer, and are written once it is complete.
+enum SequenceSerializer<'a> {
+    Direct(&'a mut Serializer),
+    Buffered {
+        serializer: &'a mut Serializer,
+        buffer: Serializer,
+        length: usize,
+    },
+}
+
+/// A serializer for maps, whose entries are encoded into an intermediate buffer, and are written
+/// sorted by their encoded keys once the map is complete.
+struct MapSerializer<'a> {
+    serializer: &'a mut Serializer,
+    entries: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl<'a> ser::Serializer for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = SequenceSerializer<'a>;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = MapSerializer<'a>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.0.push(u8::from(v));
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<()> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.0.push(v);
+        Ok(())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<()> {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.0.extend_from_slice(&v.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.0.extend_from_slice(&v.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.serialize_u32(v.into())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.serialize_bytes(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.write_length(v.len());
+        self.0.extend_from_slice(v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.serialize_u8(0)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        self.serialize_u8(1)?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        self.serialize_u32(variant_index)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.serialize_u32(variant_index)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(match len {
+            Some(length) => {
+                self.write_length(length);
+
+                SequenceSerializer::Direct(self)
+            }
+            None => SequenceSerializer::Buffered {
+                serializer: self,
+                buffer: Serializer::default(),
+                length: 0,
+            },
+        })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        self.serialize_u32(variant_index)?;
+        Ok(self)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Ok(MapSerializer {
+            serializer: self,
+            entries: vec![],
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        self.serialize_u32(variant_index)?;
+        Ok(self)
+    }
+}
+
+impl ser::SerializeSeq for SequenceSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        match self {
+            SequenceSerializer::Direct(serializer) => value.serialize(&mut **serializer),
+            SequenceSerializer::Buffered { buffer, length, .. } => {
+                *length += 1;
+
+                value.serialize(buffer)
+            }
+        }
+    }
+
+    fn end(self) -> Result<()> {
+        if let SequenceSerializer::Buffered {
+            serializer,
+            buffer,
+            length,
+        } = self
+        {
+            serializer.write_length(length);
+            serializer.0.extend(buffer.0);
+        }
+
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for MapSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.entries.push((key.canonical_encoding()?, vec![]));
+
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let (_, entry_value) = self
+            .entries
+            .last_mut()
+            .ok_or_else(|| ser::Error::custom("map value serialized before its key"))?;
+        *entry_value = value.canonical_encoding()?;
+
+        Ok(())
+    }
+
+    fn end(mut self) -> Result<()> {
+        self.entries.sort();
+
+        self.serializer.write_length(self.entries.len());
+        self.entries.into_iter().for_each(|(key, value)| {
+            self.serializer.0.extend(key);
+            self.serializer.0.extend(value);
+        });
+
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for &mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use super::*;
+
+    #[test]
+    fn encodes_canonically() {
+        #[derive(Serialize)]
+        struct Struct {
+            a: u8,
+            b: Option<u16>,
+            c: Vec<u32>,
+            d: String,
+        }
+
+        assert_eq!(
+            Struct {
+                a: 1,
+                b: Some(2),
+                c: vec![3],
+                d: "ab".to_string(),
+            }
+            .canonical_encoding()
+            .unwrap(),
+            [
+                vec![1],
+                vec![1, 2, 0],
+                vec![1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0],
+                vec![2, 0, 0, 0, 0, 0, 0, 0, b'a', b'b'],
+            ]
+            .concat()
+        );
+
+        let map: HashMap<u16, bool> = (0..100).map(|i| (i, i % 3 == 0)).collect();
+        let ordered_map: BTreeMap<u16, bool> = map.clone().into_iter().collect();
+
+        assert_eq!(
+            map.canonical_encoding().unwrap(),
+            ordered_map.canonical_encoding().unwrap()
+        );
+    }
+}
@@ -8,6 +8,7 @@ use homomorphic_encryption::{
     GroupsPublicParametersAccessors,
 };
 use subtle::{Choice, CtOption};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use crate::{
     encryption_key::PublicParameters, CiphertextSpaceGroupElement, EncryptionKey,
@@ -23,6 +24,20 @@ pub struct DecryptionKey {
     pub secret_key: PaillierModulusSizedNumber,
 }
 
+impl Zeroize for DecryptionKey {
+    fn zeroize(&mut self) {
+        self.secret_key.zeroize();
+    }
+}
+
+impl Drop for DecryptionKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for DecryptionKey {}
+
 impl DecryptionKey {
     /// Generates a new Paillier Key Pair.
     pub fn generate(
@@ -106,6 +121,7 @@ impl AsRef<EncryptionKey> for DecryptionKey {
 
 #[cfg(test)]
 mod tests {
+
     use group::{secp256k1, GroupElement};
     use homomorphic_encryption::{
         AdditivelyHomomorphicDecryptionKey, GroupsPublicParametersAccessors,
@@ -167,6 +183,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn zeroizes_secret_key() {
+        let public_parameters = PublicParameters::new(N).unwrap();
+
+        group::test_helpers::zeroizes_secrets(
+            DecryptionKey::new(SECRET_KEY, &public_parameters).unwrap(),
+            |decryption_key| vec![decryption_key.secret_key],
+        );
+    }
+
     #[test]
     fn encrypt_decrypts() {
         let public_parameters = PublicParameters::new(N).unwrap();
@@ -1,14 +1,16 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
-use std::{
-    collections::{HashMap, HashSet},
-    ops::Neg,
-};
+use alloc::{vec, vec::Vec};
+use core::ops::Neg;
 
 #[cfg(feature = "benchmarking")]
 pub(crate) use benches::{benchmark_combine_decryption_shares, benchmark_decryption_share};
 use crypto_bigint::{rand_core::CryptoRngCore, MultiExponentiateBoundedExp, NonZero};
-use group::{GroupElement, PartyID};
+use group::{
+    additive,
+    collections::{HashMap, HashSet},
+    GroupElement, Invert, PartyID,
+};
 use homomorphic_encryption::{
     AdditivelyHomomorphicDecryptionKeyShare, AdditivelyHomomorphicEncryptionKey,
     GroupsPublicParametersAccessors,
@@ -17,6 +19,7 @@ pub use public_parameters::PublicParameters;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use subtle::{Choice, CtOption};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use crate::{
     error::{ProtocolError, SanityCheckError},
@@ -39,6 +42,20 @@ pub struct DecryptionKeyShare {
     decryption_key_share: SecretKeyShareSizedNumber,
 }
 
+impl Zeroize for DecryptionKeyShare {
+    fn zeroize(&mut self) {
+        self.decryption_key_share.zeroize();
+    }
+}
+
+impl Drop for DecryptionKeyShare {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for DecryptionKeyShare {}
+
 impl AsRef<EncryptionKey> for DecryptionKeyShare {
     fn as_ref(&self) -> &EncryptionKey {
         &self.encryption_key
@@ -97,7 +114,6 @@ impl AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, Encry
         let n2 = *public_parameters
             .encryption_scheme_public_parameters
             .ciphertext_space_public_parameters()
-            .params
             .modulus();
 
         let public_verification_key = public_parameters
@@ -233,7 +249,6 @@ impl AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, Encry
         let n2 = *public_parameters
             .encryption_scheme_public_parameters
             .ciphertext_space_public_parameters()
-            .params
             .modulus();
         let batch_size = ciphertexts.len();
 
@@ -255,8 +270,7 @@ impl AdditivelyHomomorphicDecryptionKeyShare<PLAINTEXT_SPACE_SCALAR_LIMBS, Encry
 
         let decryption_share_bases: Vec<PaillierModulusSizedNumber> = iter
             .map(|ciphertext| {
-                ciphertext
-                    .0
+                PaillierRingElement::from(ciphertext)
                     .pow_bounded_exp(&PaillierModulusSizedNumber::from(2u8), 2)
                     .pow_bounded_exp(
                         &public_parameters.n_factorial,
@@ -357,7 +371,6 @@ impl DecryptionKeyShare {
         let n2 = *public_parameters
             .encryption_scheme_public_parameters
             .ciphertext_space_public_parameters()
-            .params
             .modulus();
 
         #[cfg(not(feature = "parallel"))]
@@ -367,8 +380,7 @@ impl DecryptionKeyShare {
 
         let decryption_share_bases: Vec<PaillierModulusSizedNumber> = iter
             .map(|ciphertext| {
-                ciphertext
-                    .0
+                PaillierRingElement::from(ciphertext)
                     .pow_bounded_exp(&PaillierModulusSizedNumber::from(2u8), 2)
                     .pow_bounded_exp(
                         &public_parameters.n_factorial,
@@ -421,7 +433,6 @@ impl DecryptionKeyShare {
         let n2 = *public_parameters
             .encryption_scheme_public_parameters
             .ciphertext_space_public_parameters()
-            .params
             .modulus();
 
         let batch_size = decryption_shares
@@ -464,7 +475,10 @@ impl DecryptionKeyShare {
 
         // Compute $c_j' = c_{j}^{2n!\lambda_{0,j}^{S}}=c_{j}^{2{n\choose j}(-1)^{j-1}\Pi_{j'\in [n]
         // \setminus S} (j'-j)\Pi_{j' \in S}j'}$.
-        let plaintexts = iter.map(|i| {
+        let (c_prime_parts_needing_inversion, c_prime_parts_not_needing_inversion): (
+            Vec<_>,
+            Vec<_>,
+        ) = iter.map(|i| {
             let decryption_shares_and_lagrange_coefficients: Vec<(
                 PartyID,
                 PaillierModulusSizedNumber,
@@ -543,8 +557,45 @@ impl DecryptionKeyShare {
                             )
                         });
 
-                    let c_prime = c_prime_part_needing_inversion.invert().0 *
-                        c_prime_part_not_needing_inversion;
+                    (c_prime_part_needing_inversion, c_prime_part_not_needing_inversion)
+                },
+            )
+            .unzip();
+
+        // Invert the parts needing inversion of all ciphertexts at once using Montgomery's trick,
+        // which takes a single inversion in the ring instead of one per ciphertext.
+        let ring_public_parameters = additive::PublicParameters {
+            modulus: NonZero::new(n2).unwrap(),
+        };
+        let c_prime_parts_needing_inversion = c_prime_parts_needing_inversion
+            .into_iter()
+            .map(|c_prime_part_needing_inversion| {
+                additive::GroupElement::new(
+                    c_prime_part_needing_inversion.as_natural_number(),
+                    &ring_public_parameters,
+                )
+            })
+            .collect::<group::Result<Vec<_>>>()?;
+        let inverted_c_prime_parts: Vec<_> = Option::from(additive::GroupElement::batch_invert(
+            &c_prime_parts_needing_inversion,
+        ))
+        .ok_or(Error::SanityCheckError(SanityCheckError::InvalidParams()))?;
+
+        #[cfg(not(feature = "parallel"))]
+        let iter = inverted_c_prime_parts
+            .into_iter()
+            .zip(c_prime_parts_not_needing_inversion);
+        #[cfg(feature = "parallel")]
+        let iter = inverted_c_prime_parts
+            .into_par_iter()
+            .zip(c_prime_parts_not_needing_inversion);
+
+        let plaintexts = iter
+            .map(
+                |(inverted_c_prime_part, c_prime_part_not_needing_inversion)| {
+                    let c_prime = PaillierModulusSizedNumber::from(inverted_c_prime_part)
+                        .as_ring_element(&n2)
+                        * c_prime_part_not_needing_inversion;
 
                     // $^2{\Pi_{j' \in S}j'}$
                     // This computation is independent of `j` so it could be done outside the loop
@@ -636,7 +687,6 @@ pub mod test_exports {
 
         let n2 = encryption_scheme_public_parameters
             .ciphertext_space_public_parameters()
-            .params
             .modulus();
 
         let n_factorial = (2..=n)
@@ -711,6 +761,17 @@ pub mod test_exports {
         (public_parameters, decryption_key_shares)
     }
 
+    #[test]
+    fn zeroizes_decryption_key_share() {
+        let (public_parameters, decryption_key_shares) =
+            deal_trusted_shares(2, 3, N, SECRET_KEY, BASE);
+
+        group::test_helpers::zeroizes_secrets(
+            DecryptionKeyShare::new(1, decryption_key_shares[&1], &public_parameters).unwrap(),
+            |decryption_key_share| vec![decryption_key_share.decryption_key_share],
+        );
+    }
+
     #[test]
     fn generates_decryption_share() {
         let n = 3;
@@ -837,8 +898,7 @@ pub mod test_exports {
         let decryption_share_bases: Vec<PaillierModulusSizedNumber> = ciphertexts
             .iter()
             .map(|ciphertext| {
-                ciphertext
-                    .0
+                PaillierRingElement::from(ciphertext)
                     .pow_bounded_exp(&PaillierModulusSizedNumber::from(2u16 * (2 * 3)), 4)
                     .as_natural_number()
             })
@@ -1140,7 +1200,6 @@ mod benches {
                         public_parameters
                             .encryption_scheme_public_parameters
                             .ciphertext_space_public_parameters()
-                            .params
                             .modulus(),
                     )
                     .pow_bounded_exp(&PaillierModulusSizedNumber::from(2u8), 2)
@@ -1166,7 +1225,6 @@ mod benches {
                                 public_parameters
                                     .encryption_scheme_public_parameters
                                     .ciphertext_space_public_parameters()
-                                    .params
                                     .modulus(),
                             )
                             .pow_bounded_exp(
@@ -1190,7 +1248,6 @@ mod benches {
                                 *public_parameters
                                     .encryption_scheme_public_parameters
                                     .ciphertext_space_public_parameters()
-                                    .params
                                     .modulus(),
                                 number_of_parties,
                                 threshold,
@@ -1206,7 +1263,6 @@ mod benches {
                                 *public_parameters
                                     .encryption_scheme_public_parameters
                                     .ciphertext_space_public_parameters()
-                                    .params
                                     .modulus(),
                                 number_of_parties,
                                 threshold,
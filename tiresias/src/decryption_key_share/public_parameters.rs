@@ -1,10 +1,10 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::collections::HashMap;
+use alloc::{vec, vec::Vec};
 
 use gcd::Gcd;
-use group::PartyID;
+use group::{collections::HashMap, PartyID};
 use homomorphic_encryption::GroupsPublicParametersAccessors;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -1,12 +1,10 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use group::additive;
+use group::{additive, multiplicative};
 
 use crate::{LargeBiPrimeSizedNumber, PaillierModulusSizedNumber};
 
-mod multiplicative;
-
 pub const PLAINTEXT_SPACE_SCALAR_LIMBS: usize = LargeBiPrimeSizedNumber::LIMBS;
 pub const RANDOMNESS_SPACE_SCALAR_LIMBS: usize = LargeBiPrimeSizedNumber::LIMBS;
 
@@ -25,3 +23,53 @@ pub type CiphertextSpacePublicParameters =
 pub type PlaintextSpaceValue = LargeBiPrimeSizedNumber;
 pub type RandomnessSpaceValue = multiplicative::Value<RANDOMNESS_SPACE_SCALAR_LIMBS>;
 pub type CiphertextSpaceValue = multiplicative::Value<CIPHERTEXT_SPACE_SCALAR_LIMBS>;
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::NonZero;
+    use group::test_helpers;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::test_exports::{N, N2};
+
+    #[test]
+    fn plaintext_space_satisfies_conformance() {
+        // `new()` reduces plaintexts modulo `N`, so every `Value` is a valid one.
+        test_helpers::samplable_conformance::<PlaintextSpaceGroupElement>(
+            &PlaintextSpacePublicParameters {
+                modulus: NonZero::new(N).unwrap(),
+            },
+            vec![],
+            &mut OsRng,
+        );
+    }
+
+    #[test]
+    fn randomness_space_satisfies_conformance() {
+        let public_parameters = RandomnessSpacePublicParameters::new(N).unwrap();
+        let zero =
+            RandomnessSpaceValue::new(LargeBiPrimeSizedNumber::ZERO, &public_parameters).unwrap();
+
+        test_helpers::samplable_conformance::<RandomnessSpaceGroupElement>(
+            &public_parameters,
+            vec![zero],
+            &mut OsRng,
+        );
+    }
+
+    #[test]
+    fn ciphertext_space_satisfies_conformance() {
+        let public_parameters = CiphertextSpacePublicParameters::new(N2).unwrap();
+        let zero = CiphertextSpaceValue::new(PaillierModulusSizedNumber::ZERO, &public_parameters)
+            .unwrap();
+        // $N^2 = 0 \mod N^2$, so `N` is invalid as well.
+        let n = CiphertextSpaceValue::new(N.resize(), &public_parameters).unwrap();
+
+        test_helpers::samplable_conformance::<CiphertextSpaceGroupElement>(
+            &public_parameters,
+            vec![zero, n],
+            &mut OsRng,
+        );
+    }
+}
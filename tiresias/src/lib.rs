@@ -1,6 +1,10 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub use ::group::ComputationalSecuritySizedNumber;
 #[cfg(feature = "benchmarking")]
 use criterion::criterion_group;
@@ -1,6 +1,8 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
+use alloc::{vec, vec::Vec};
+
 use crypto_bigint::{Encoding, Limb, Uint};
 #[cfg(feature = "benchmarking")]
 pub(crate) use equality_of_discrete_logs::benchmark_proof_of_equality_of_discrete_logs;
@@ -49,7 +51,7 @@ pub enum Error {
     ProofVerificationError(),
 }
 
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 #[cfg(test)]
 mod tests {
@@ -1,18 +1,20 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
+use alloc::{vec, vec::Vec};
+
 #[cfg(feature = "benchmarking")]
 pub(crate) use benches::benchmark_proof_of_equality_of_discrete_logs;
 use crypto_bigint::{
-    modular::runtime_mod::DynResidueParams, rand_core::CryptoRngCore, MultiExponentiateBoundedExp,
-    NonZero, RandomMod,
+    modular::runtime_mod::DynResidueParams, rand_core::CryptoRngCore, NonZero, RandomMod,
 };
+use group::VartimeGroupElement;
 use merlin::Transcript;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     batch_verification::batch_verification,
     proofs::{Error, Result, TranscriptProtocol},
-    secret_key_share_size_upper_bound, AsNaturalNumber, AsRingElement,
+    secret_key_share_size_upper_bound, AsNaturalNumber, AsRingElement, CiphertextSpaceGroupElement,
     ComputationalSecuritySizedNumber, PaillierModulusSizedNumber, PaillierRingElement,
     ProofOfEqualityOfDiscreteLogsRandomnessSizedNumber, SecretKeyShareSizedNumber,
 };
@@ -503,31 +505,42 @@ impl ProofOfEqualityOfDiscreteLogs {
             })
             .collect();
 
+        // The decryption shares, their bases and the randomizers (which are derived from the
+        // transcript) are all public, and so the batching is computed in variable-time.
         let bases_and_exponents: Vec<_> = decryption_shares_and_bases
             .iter()
             .zip(randomizers.iter())
-            .map(|((a, _), c)| (a.as_ring_element(&n2), *c))
+            .map(|((a, _), c)| {
+                (
+                    CiphertextSpaceGroupElement::from(a.as_ring_element(&n2)),
+                    *c,
+                )
+            })
             .collect();
 
+        // `decryption_shares_and_bases` is non-empty, so it is safe to `unwrap()`.
         let batched_decryption_share_base: PaillierModulusSizedNumber =
-            PaillierRingElement::multi_exponentiate_bounded_exp(
-                bases_and_exponents.as_slice(),
-                ComputationalSecuritySizedNumber::BITS,
-            )
-            .as_natural_number();
+            CiphertextSpaceGroupElement::multi_scalar_mul_vartime(bases_and_exponents.as_slice())
+                .map(PaillierRingElement::from)
+                .unwrap()
+                .as_natural_number();
 
         let bases_and_exponents: Vec<_> = decryption_shares_and_bases
             .iter()
             .zip(randomizers.iter())
-            .map(|((_, b), c)| (b.as_ring_element(&n2), *c))
+            .map(|((_, b), c)| {
+                (
+                    CiphertextSpaceGroupElement::from(b.as_ring_element(&n2)),
+                    *c,
+                )
+            })
             .collect();
 
         let batched_decryption_share: PaillierModulusSizedNumber =
-            PaillierRingElement::multi_exponentiate_bounded_exp(
-                bases_and_exponents.as_slice(),
-                ComputationalSecuritySizedNumber::BITS,
-            )
-            .as_natural_number();
+            CiphertextSpaceGroupElement::multi_scalar_mul_vartime(bases_and_exponents.as_slice())
+                .map(PaillierRingElement::from)
+                .unwrap()
+                .as_natural_number();
 
         Ok((
             base,
@@ -553,6 +566,7 @@ impl Default for ProofOfEqualityOfDiscreteLogs {
 
 #[cfg(test)]
 mod tests {
+    use group::wire::WireEncoding;
     use rand_core::OsRng;
 
     use super::*;
@@ -610,6 +624,16 @@ mod tests {
             &mut OsRng,
         );
 
+        let bytes = proof.to_wire_bytes().unwrap();
+        assert_eq!(
+            bytes.len(),
+            2 * PaillierModulusSizedNumber::BYTES
+                + ProofOfEqualityOfDiscreteLogsRandomnessSizedNumber::BYTES,
+            "proofs should be encoded in fixed-size fields"
+        );
+
+        let proof = ProofOfEqualityOfDiscreteLogs::from_wire_bytes(&bytes).unwrap();
+
         assert!(proof
             .verify(
                 n2,
@@ -1,7 +1,8 @@
 // Author: dWallet Labs, Ltd.
 // SPDX-License-Identifier: BSD-3-Clause-Clear
 
-use std::{
+use alloc::vec::Vec;
+use core::{
     iter,
     ops::{Add, Mul},
 };
@@ -27,7 +28,7 @@ pub enum Error {
     InvalidParams(),
 }
 
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 impl<T> Polynomial<T>
 where
@@ -101,7 +102,7 @@ where
 {
     type Error = Error;
 
-    fn try_from(coefficients: Vec<T>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(coefficients: Vec<T>) -> core::result::Result<Self, Self::Error> {
         if coefficients.is_empty() {
             return Err(Error::InvalidParams());
         }